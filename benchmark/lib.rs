@@ -203,6 +203,66 @@ pub fn benchmark_gotgraph_direct_traversal(
     start.elapsed()
 }
 
+/// Benchmark gotgraph direct traversal using a single fold over the edges
+pub fn benchmark_gotgraph_fold_traversal(
+    graph: &VecGraph<usize, usize>,
+    iterations: usize,
+) -> std::time::Duration {
+    let start = std::time::Instant::now();
+    let mut global_total: usize = 0;
+    for _ in 0..iterations {
+        let total = graph.nodes().sum::<usize>()
+            + graph.fold_edges(0, |total, _, _, &edge_value| total + edge_value);
+        global_total = global_total.wrapping_add(total);
+    }
+    // Use the total to prevent optimization
+    std::hint::black_box(global_total);
+    start.elapsed()
+}
+
+/// Benchmark gotgraph traversal reading each edge's target by hand
+pub fn benchmark_gotgraph_neighbor_traversal(
+    graph: &VecGraph<usize, usize>,
+    iterations: usize,
+) -> std::time::Duration {
+    let start = std::time::Instant::now();
+    let mut global_total: usize = 0;
+    for _ in 0..iterations {
+        let mut total = 0;
+        for node_idx in graph.node_indices() {
+            for edge_idx in graph.outgoing_edge_indices(node_idx) {
+                let [_, target] = graph.endpoints(edge_idx);
+                total += *graph.edge(edge_idx) + *graph.node(target);
+            }
+        }
+        global_total = global_total.wrapping_add(total);
+    }
+    // Use the total to prevent optimization
+    std::hint::black_box(global_total);
+    start.elapsed()
+}
+
+/// Benchmark gotgraph traversal reading each edge's target with `outgoing_targets`
+pub fn benchmark_gotgraph_targets_traversal(
+    graph: &VecGraph<usize, usize>,
+    iterations: usize,
+) -> std::time::Duration {
+    let start = std::time::Instant::now();
+    let mut global_total: usize = 0;
+    for _ in 0..iterations {
+        let mut total = 0;
+        for node_idx in graph.node_indices() {
+            for (_, &edge_value, _, &target_value) in graph.outgoing_targets(node_idx) {
+                total += edge_value + target_value;
+            }
+        }
+        global_total = global_total.wrapping_add(total);
+    }
+    // Use the total to prevent optimization
+    std::hint::black_box(global_total);
+    start.elapsed()
+}
+
 /// Benchmark petgraph DiGraph traversal
 pub fn benchmark_petgraph_traversal(
     graph: &DiGraph<usize, usize>,
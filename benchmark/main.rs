@@ -20,6 +20,9 @@ use gotgraph_benchmark::{
     benchmark_petgraph_stable_creation,
     benchmark_gotgraph_scoped_traversal,
     benchmark_gotgraph_direct_traversal,
+    benchmark_gotgraph_fold_traversal,
+    benchmark_gotgraph_neighbor_traversal,
+    benchmark_gotgraph_targets_traversal,
     benchmark_petgraph_traversal,
     benchmark_petgraph_stable_traversal,
 };
@@ -89,6 +92,30 @@ fn bench_graph_traversal(c: &mut Criterion) {
                 })
             });
         
+        group.bench_with_input(BenchmarkId::new("gotgraph_fold", size), &gotgraph_graph,
+            |b, graph| {
+                b.iter(|| {
+                    let time = benchmark_gotgraph_fold_traversal(graph, 1);
+                    black_box(time)
+                })
+            });
+        
+        group.bench_with_input(BenchmarkId::new("gotgraph_neighbor", size), &gotgraph_graph,
+            |b, graph| {
+                b.iter(|| {
+                    let time = benchmark_gotgraph_neighbor_traversal(graph, 1);
+                    black_box(time)
+                })
+            });
+        
+        group.bench_with_input(BenchmarkId::new("gotgraph_targets", size), &gotgraph_graph,
+            |b, graph| {
+                b.iter(|| {
+                    let time = benchmark_gotgraph_targets_traversal(graph, 1);
+                    black_box(time)
+                })
+            });
+        
         group.bench_with_input(BenchmarkId::new("petgraph", size), &petgraph_graph,
             |b, graph| {
                 b.iter(|| {
@@ -255,12 +282,144 @@ fn bench_scope_operations(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_len(c: &mut Criterion) {
+    let mut group = c.benchmark_group("len");
+
+    for size in [100, 1000, 10000].iter() {
+        let num_nodes = *size;
+        let num_edges = num_nodes * 2;
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let edges = generate_random_edges(num_nodes, num_edges, &mut rng);
+
+        let mut graph: VecGraph<usize, ()> = VecGraph::default();
+        let node_indices: Vec<_> = (0..num_nodes).map(|i| graph.add_node(i)).collect();
+        for &(from, to) in edges.iter() {
+            graph.add_edge((), node_indices[from], node_indices[to]);
+        }
+
+        // What the trait defaults of `len_nodes`/`len_edges` do
+        group.bench_with_input(BenchmarkId::new("count_indices", size), &graph, |b, graph| {
+            b.iter(|| {
+                black_box(black_box(graph).node_indices().count())
+                    + black_box(black_box(graph).edge_indices().count())
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("len_override", size), &graph, |b, graph| {
+            b.iter(|| black_box(black_box(graph).len_nodes()) + black_box(black_box(graph).len_edges()))
+        });
+
+        // Inside a scope the calls go through `Context` and `&VecGraph`
+        group.bench_with_input(BenchmarkId::new("len_scoped", size), &graph, |b, graph| {
+            graph.scope(|ctx| {
+                b.iter(|| black_box(black_box(&ctx).len_nodes()) + black_box(black_box(&ctx).len_edges()))
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_reserve_edges(c: &mut Criterion) {
+    let mut group = c.benchmark_group("reserve_edges");
+
+    for size in [50, 100, 200].iter() {
+        let grid_size = *size;
+
+        for reserve in [false, true] {
+            let name = if reserve { "grid_reserved" } else { "grid_unreserved" };
+            group.bench_with_input(BenchmarkId::new(name, size), &grid_size, |b, &grid_size| {
+                b.iter(|| {
+                    let mut graph: VecGraph<usize, ()> = VecGraph::default();
+                    if reserve {
+                        graph.reserve_edges_for(2 * grid_size * (grid_size - 1));
+                    }
+                    let nodes: Vec<_> = (0..grid_size * grid_size).map(|i| graph.add_node(i)).collect();
+                    for row in 0..grid_size {
+                        for col in 0..grid_size {
+                            let node = nodes[row * grid_size + col];
+                            if col + 1 < grid_size {
+                                graph.add_edge((), node, nodes[row * grid_size + col + 1]);
+                            }
+                            if row + 1 < grid_size {
+                                graph.add_edge((), node, nodes[(row + 1) * grid_size + col]);
+                            }
+                        }
+                    }
+                    black_box(graph)
+                })
+            });
+        }
+    }
+    group.finish();
+}
+
+fn bench_remove_edges(c: &mut Criterion) {
+    let mut group = c.benchmark_group("remove_edges");
+
+    for size in [1000, 5000, 20000].iter() {
+        let num_nodes = *size;
+        let num_edges = num_nodes * 4;
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let edges = generate_random_edges(num_nodes, num_edges, &mut rng);
+        // Positions to remove, each taken modulo the number of remaining edges
+        let order: Vec<usize> = (0..num_edges).map(|_| rng.gen()).collect();
+
+        group.bench_with_input(BenchmarkId::new("gotgraph", size), &edges, |b, edges| {
+            b.iter_batched(
+                || {
+                    let mut graph: VecGraph<usize, ()> = VecGraph::default();
+                    let nodes: Vec<_> = (0..num_nodes).map(|i| graph.add_node(i)).collect();
+                    for &(from, to) in edges.iter() {
+                        graph.add_edge((), nodes[from], nodes[to]);
+                    }
+                    graph
+                },
+                |mut graph| {
+                    for &pos in &order {
+                        let ix = graph.edge_indices().nth(pos % graph.len_edges()).unwrap();
+                        graph.remove_edge(ix);
+                    }
+                    black_box(graph)
+                },
+                criterion::BatchSize::LargeInput,
+            )
+        });
+
+        group.bench_with_input(BenchmarkId::new("petgraph", size), &edges, |b, edges| {
+            b.iter_batched(
+                || {
+                    let mut graph = DiGraph::<usize, ()>::new();
+                    let nodes: Vec<_> = (0..num_nodes).map(|i| graph.add_node(i)).collect();
+                    for &(from, to) in edges.iter() {
+                        graph.add_edge(nodes[from], nodes[to], ());
+                    }
+                    graph
+                },
+                |mut graph| {
+                    for &pos in &order {
+                        let ix = petgraph::graph::EdgeIndex::new(pos % graph.edge_count());
+                        graph.remove_edge(ix);
+                    }
+                    black_box(graph)
+                },
+                criterion::BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_graph_creation,
     bench_graph_traversal,
     bench_scc_algorithms,
     bench_memory_usage,
-    bench_scope_operations
+    bench_scope_operations,
+    bench_len,
+    bench_reserve_edges,
+    bench_remove_edges
 );
 criterion_main!(benches);
\ No newline at end of file
@@ -0,0 +1,263 @@
+use crate::prelude::*;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::ops::Add;
+
+/// Entry of the A* frontier, ordered so that `BinaryHeap` pops the lowest estimate first.
+///
+/// Ties are broken by insertion order, which keeps the search deterministic.
+struct Frontier<W, N> {
+    estimate: W,
+    seq: usize,
+    cost: W,
+    node: N,
+}
+
+impl<W: Ord, N> PartialEq for Frontier<W, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<W: Ord, N> Eq for Frontier<W, N> {}
+
+impl<W: Ord, N> PartialOrd for Frontier<W, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<W: Ord, N> Ord for Frontier<W, N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+/// Finds a cheapest path from `start` to `goal` using the A* search algorithm.
+///
+/// Edge costs must be non-negative, with `W::default()` as zero. The heuristic estimates
+/// the remaining cost from a node to `goal`; as long as it never overestimates it
+/// (it is *admissible*), the returned path is a cheapest one. A heuristic that always
+/// returns zero turns the search into Dijkstra's algorithm.
+///
+/// Among frontier nodes with the same estimated total cost, the one discovered first is
+/// expanded first, so the result is deterministic for a given graph.
+///
+/// # Algorithm Details
+///
+/// Nodes are expanded in order of their cost from `start` plus the heuristic estimate,
+/// using a binary heap as the priority queue. A node is expanded again if a cheaper path
+/// to it is found later, which can only happen with an inconsistent heuristic.
+///
+/// - **Time Complexity**: O((V + E) log V) with a consistent heuristic, where V is the
+///   number of vertices and E is the number of edges
+/// - **Space Complexity**: O(V + E)
+///
+/// # Parameters
+///
+/// - `graph`: The graph to search
+/// - `start`: The node the path starts at
+/// - `goal`: The node the path ends at
+/// - `cost`: Returns the cost of traversing an edge
+/// - `heuristic`: Returns an estimate of the cost from a node to `goal`
+///
+/// # Returns
+///
+/// The total cost of the path and the edges along it in order from `start` to `goal`,
+/// or `None` if `goal` is not reachable. If `start == goal` the cost is zero and the
+/// path is empty.
+///
+/// # Panics
+///
+/// Panics if `start` or `goal` is not a valid node index of `graph`.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::astar;
+/// use gotgraph::prelude::*;
+///
+/// // Nodes are points on a line, edges cost their length
+/// let mut graph: VecGraph<u32, u32> = VecGraph::default();
+/// let a = graph.add_node(0);
+/// let b = graph.add_node(4);
+/// let c = graph.add_node(10);
+/// let ab = graph.add_edge(4, a, b);
+/// let bc = graph.add_edge(6, b, c);
+/// graph.add_edge(15, a, c);
+///
+/// let (cost, path) = astar(&graph, a, c, |_, &w| w, |_, &pos| 10 - pos).unwrap();
+/// assert_eq!(cost, 10);
+/// assert_eq!(path, vec![ab, bc]);
+/// ```
+pub fn astar<G, W>(
+    graph: &G,
+    start: G::NodeIx,
+    goal: G::NodeIx,
+    cost: impl Fn(G::EdgeIx, &G::Edge) -> W,
+    heuristic: impl Fn(G::NodeIx, &G::Node) -> W,
+) -> Option<(W, Vec<G::EdgeIx>)>
+where
+    G: Graph,
+    W: Copy + Ord + Add<Output = W> + Default,
+{
+    assert!(
+        graph.exists_node_index(start),
+        "Start node does not exist in the graph"
+    );
+    assert!(
+        graph.exists_node_index(goal),
+        "Goal node does not exist in the graph"
+    );
+
+    search(graph, start, |node| node == goal, cost, heuristic)
+}
+
+/// Finds a cheapest path from `start` to any node satisfying `is_goal`, using A* search.
+///
+/// This is a variant of [`astar`] for searches with several acceptable goals, or a goal
+/// that is easier to recognize than to name. The heuristic should estimate the cost to
+/// the nearest goal; the same conditions as for [`astar`] apply for the result to be
+/// optimal. The search stops at the first goal node expanded, which is the cheapest one
+/// to reach.
+///
+/// # Parameters
+///
+/// - `graph`: The graph to search
+/// - `start`: The node the path starts at
+/// - `is_goal`: Returns whether a node is a goal
+/// - `cost`: Returns the cost of traversing an edge
+/// - `heuristic`: Returns an estimate of the cost from a node to the nearest goal
+///
+/// # Returns
+///
+/// The total cost of the path and the nodes along it, starting with `start` and ending
+/// with the goal that was reached, or `None` if no goal is reachable. If `start` is a
+/// goal the cost is zero and the path only contains `start`.
+///
+/// # Panics
+///
+/// Panics if `start` is not a valid node index of `graph`.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::astar_until;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, u32> = VecGraph::default();
+/// let home = graph.add_node("home");
+/// let corner = graph.add_node("corner");
+/// let shop_a = graph.add_node("shop");
+/// let shop_b = graph.add_node("shop");
+/// graph.add_edge(2, home, corner);
+/// graph.add_edge(5, corner, shop_a);
+/// graph.add_edge(4, home, shop_b);
+///
+/// let (cost, path) =
+///     astar_until(&graph, home, |n| *graph.node(n) == "shop", |&w| w, |_| 0).unwrap();
+/// assert_eq!(cost, 4);
+/// assert_eq!(path, vec![home, shop_b]);
+/// ```
+pub fn astar_until<G, FC, FH, W>(
+    graph: &G,
+    start: G::NodeIx,
+    is_goal: impl Fn(G::NodeIx) -> bool,
+    cost: FC,
+    heuristic: FH,
+) -> Option<(W, Vec<G::NodeIx>)>
+where
+    G: Graph,
+    FC: Fn(&G::Edge) -> W,
+    FH: Fn(G::NodeIx) -> W,
+    W: Copy + Ord + Add<Output = W> + Default,
+{
+    assert!(
+        graph.exists_node_index(start),
+        "Start node does not exist in the graph"
+    );
+
+    let (total, edges) = search(
+        graph,
+        start,
+        is_goal,
+        |_, edge| cost(edge),
+        |node, _| heuristic(node),
+    )?;
+    let path = core::iter::once(start)
+        .chain(edges.into_iter().map(|edge_ix| graph.endpoints(edge_ix)[1]))
+        .collect();
+    Some((total, path))
+}
+
+/// Runs A* from `start` until a node satisfying `is_goal` is expanded, returning the cost
+/// and the edges of the path to it.
+fn search<G, W>(
+    graph: &G,
+    start: G::NodeIx,
+    is_goal: impl Fn(G::NodeIx) -> bool,
+    cost: impl Fn(G::EdgeIx, &G::Edge) -> W,
+    heuristic: impl Fn(G::NodeIx, &G::Node) -> W,
+) -> Option<(W, Vec<G::EdgeIx>)>
+where
+    G: Graph,
+    W: Copy + Ord + Add<Output = W> + Default,
+{
+    let mut best = graph.init_node_map(|_, _| None::<W>);
+    let mut came_from = graph.init_node_map(|_, _| None::<G::EdgeIx>);
+    let mut frontier = BinaryHeap::new();
+    let mut seq = 0;
+
+    best[start] = Some(W::default());
+    frontier.push(Frontier {
+        estimate: heuristic(start, graph.node(start)),
+        seq,
+        cost: W::default(),
+        node: start,
+    });
+
+    while let Some(Frontier {
+        cost: current,
+        node,
+        ..
+    }) = frontier.pop()
+    {
+        // Skip entries superseded by a cheaper path found after they were pushed
+        if best[node].is_some_and(|b| current > b) {
+            continue;
+        }
+
+        if is_goal(node) {
+            let mut path = Vec::new();
+            let mut at = node;
+            while at != start {
+                let edge_ix = came_from[at].expect("visited node should have a predecessor");
+                path.push(edge_ix);
+                at = graph.endpoints(edge_ix)[0];
+            }
+            path.reverse();
+            return Some((current, path));
+        }
+
+        for (edge_ix, edge) in graph.outgoing_edge_pairs(node) {
+            let [_, to] = graph.endpoints(edge_ix);
+            let candidate = current + cost(edge_ix, edge);
+            if best[to].map_or(true, |b| candidate < b) {
+                best[to] = Some(candidate);
+                came_from[to] = Some(edge_ix);
+                seq += 1;
+                frontier.push(Frontier {
+                    estimate: candidate + heuristic(to, graph.node(to)),
+                    seq,
+                    cost: candidate,
+                    node: to,
+                });
+            }
+        }
+    }
+
+    None
+}
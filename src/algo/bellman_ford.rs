@@ -0,0 +1,115 @@
+use crate::prelude::*;
+use crate::Mapping;
+
+/// Error returned by [`bellman_ford`] when a negative-weight cycle is reachable from the
+/// start node, so that shortest distances are not well defined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NegativeCycle;
+
+impl std::fmt::Display for NegativeCycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "graph contains a negative-weight cycle reachable from the start node"
+        )
+    }
+}
+
+impl std::error::Error for NegativeCycle {}
+
+/// Computes single-source shortest path distances using the Bellman-Ford algorithm.
+///
+/// Unlike Dijkstra's algorithm, edge costs may be negative. Distances are computed for
+/// directed paths starting at `start`.
+///
+/// # Algorithm Details
+///
+/// Every edge is relaxed up to V - 1 times (stopping early once a pass changes nothing),
+/// then one more pass checks whether any distance can still be improved, which happens
+/// exactly when a negative cycle is reachable from `start`.
+///
+/// - **Time Complexity**: O(V · E) where V is the number of vertices and E is the number of edges
+/// - **Space Complexity**: O(V) for the distance mapping
+///
+/// # Parameters
+///
+/// - `graph`: The graph to search
+/// - `start`: The node distances are measured from
+/// - `cost`: Returns the cost of traversing an edge
+///
+/// # Returns
+///
+/// A mapping from each node to its distance from `start`, or `None` if it is unreachable.
+/// Returns `Err(NegativeCycle)` if a negative cycle is reachable from `start`.
+///
+/// # Panics
+///
+/// Panics if `start` is not a valid node index of `graph`.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::bellman_ford;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, i64> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// let c = graph.add_node("C");
+/// graph.add_edge(4, a, b);
+/// graph.add_edge(-2, b, c);
+/// graph.add_edge(3, a, c);
+///
+/// let dist = bellman_ford(&graph, a, |&w| w).unwrap();
+/// assert_eq!(dist[a], Some(0));
+/// assert_eq!(dist[b], Some(4));
+/// assert_eq!(dist[c], Some(2));
+/// ```
+pub fn bellman_ford<G, F>(
+    graph: &G,
+    start: G::NodeIx,
+    cost: F,
+) -> Result<impl Mapping<G::NodeIx, Option<i64>> + use<'_, G, F>, NegativeCycle>
+where
+    G: Graph,
+    F: Fn(&G::Edge) -> i64,
+{
+    assert!(
+        graph.exists_node_index(start),
+        "Start node does not exist in the graph"
+    );
+
+    let mut dist = graph.init_node_map(|_, _| None);
+    dist[start] = Some(0i64);
+
+    for _ in 1..graph.len_nodes() {
+        if !relax(graph, &cost, &mut dist) {
+            break;
+        }
+    }
+
+    if relax(graph, &cost, &mut dist) {
+        return Err(NegativeCycle);
+    }
+
+    Ok(dist)
+}
+
+/// Relaxes every edge once, returning whether any distance improved
+fn relax<G: Graph>(
+    graph: &G,
+    cost: &impl Fn(&G::Edge) -> i64,
+    dist: &mut impl Mapping<G::NodeIx, Option<i64>>,
+) -> bool {
+    let mut changed = false;
+    for (_, [from, to], edge) in graph.edge_triples() {
+        if let Some(d) = dist[from] {
+            let candidate = d.saturating_add(cost(edge));
+            if dist[to].map_or(true, |current| candidate < current) {
+                dist[to] = Some(candidate);
+                changed = true;
+            }
+        }
+    }
+    changed
+}
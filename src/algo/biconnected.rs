@@ -0,0 +1,170 @@
+use crate::prelude::*;
+
+/// Iterates over the edges connected to `node` together with the node at their other end.
+fn incident<G: Graph>(
+    graph: &G,
+    node: G::NodeIx,
+) -> impl Iterator<Item = (G::EdgeIx, G::NodeIx)> + '_ {
+    graph.connecting_edge_indices(node).map(move |e| {
+        let [from, to] = graph.endpoints(e);
+        (e, if from == node { to } else { from })
+    })
+}
+
+/// Runs an iterative depth-first search over the undirected view of the graph and
+/// returns its cut vertices and cut edges, both sorted by index.
+fn cut_elements<G: Graph>(graph: &G) -> (Vec<G::NodeIx>, Vec<G::EdgeIx>) {
+    // Discovery time of each node, and the earliest discovery time reachable from its
+    // DFS subtree through at most one edge that is not a tree edge
+    let mut discovery = graph.init_node_map(|_, _| None);
+    let mut low = graph.init_node_map(|_, _| 0usize);
+    let mut is_cut = graph.init_node_map(|_, _| false);
+    let mut bridges = Vec::new();
+    let mut time = 0;
+
+    // Each frame holds a node, its parent until the tree edge back to it is skipped, the
+    // tree edge it was discovered through and the edges left to explore
+    let mut stack = Vec::new();
+    for root in graph.node_indices() {
+        if discovery[root].is_some() {
+            continue;
+        }
+        discovery[root] = Some(time);
+        low[root] = time;
+        time += 1;
+        let mut root_children = 0;
+        stack.push((root, None, None, incident(graph, root)));
+
+        while let Some((node, parent, _, edges)) = stack.last_mut() {
+            let node = *node;
+            if let Some((edge, next)) = edges.next() {
+                if next == node {
+                    continue;
+                }
+                // Parallel edges to the parent are back edges, so only one edge back to
+                // it is skipped. Which one does not matter, and unlike comparing with the
+                // tree edge this also works when the two ends of an undirected edge see
+                // different indices, as with `UnVecGraph`.
+                if Some(next) == *parent {
+                    *parent = None;
+                    continue;
+                }
+                match discovery[next] {
+                    Some(d) => low[node] = low[node].min(d),
+                    None => {
+                        discovery[next] = Some(time);
+                        low[next] = time;
+                        time += 1;
+                        if stack.len() == 1 {
+                            root_children += 1;
+                        }
+                        stack.push((next, Some(node), Some(edge), incident(graph, next)));
+                    }
+                }
+                continue;
+            }
+
+            let (_, _, tree_edge, _) = stack.pop().expect("the stack is not empty");
+            let Some(&(parent, _, _, _)) = stack.last() else {
+                break;
+            };
+            low[parent] = low[parent].min(low[node]);
+            let parent_discovery = discovery[parent].expect("stacked nodes are discovered");
+            if low[node] > parent_discovery {
+                bridges.push(tree_edge.expect("non-root nodes have a tree edge"));
+            }
+            if low[node] >= parent_discovery && stack.len() > 1 {
+                is_cut[parent] = true;
+            }
+        }
+
+        is_cut[root] = root_children >= 2;
+    }
+
+    let points = graph.node_indices().filter(|&ix| is_cut[ix]).collect();
+    bridges.sort_unstable();
+    (points, bridges)
+}
+
+/// Finds the articulation points (cut vertices) of a graph.
+///
+/// Edge directions are ignored. A node is an articulation point if removing it, together
+/// with its edges, increases the number of connected components. Self-loops never
+/// matter, and parallel edges count as separate connections.
+///
+/// # Algorithm Details
+///
+/// Uses an iterative depth-first search computing the discovery time and low-link value
+/// of every node, so deep graphs do not overflow the call stack.
+///
+/// - **Time Complexity**: O(V + E) where V is the number of vertices and E is the number of edges
+/// - **Space Complexity**: O(V)
+///
+/// # Returns
+///
+/// The articulation points, in the order of [`Graph::node_indices`].
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::articulation_points;
+/// use gotgraph::prelude::*;
+///
+/// // Two triangles sharing node C
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// let c = graph.add_node("C");
+/// let d = graph.add_node("D");
+/// let e = graph.add_node("E");
+/// graph.add_edge((), a, b);
+/// graph.add_edge((), b, c);
+/// graph.add_edge((), c, a);
+/// graph.add_edge((), c, d);
+/// graph.add_edge((), d, e);
+/// graph.add_edge((), e, c);
+///
+/// assert_eq!(articulation_points(&graph), vec![c]);
+/// ```
+pub fn articulation_points<G: Graph>(graph: &G) -> Vec<G::NodeIx> {
+    cut_elements(graph).0
+}
+
+/// Finds the bridges (cut edges) of a graph.
+///
+/// Edge directions are ignored. An edge is a bridge if removing it increases the number
+/// of connected components. An edge with a parallel edge between the same two nodes, in
+/// either direction, is never a bridge, and neither is a self-loop.
+///
+/// # Algorithm Details
+///
+/// Shares the depth-first search of [`articulation_points`].
+///
+/// - **Time Complexity**: O(V + E) where V is the number of vertices and E is the number of edges
+/// - **Space Complexity**: O(V)
+///
+/// # Returns
+///
+/// The bridges, sorted by edge index. When the indices carry an orientation, as with
+/// [`UnVecGraph`](crate::undirected::UnVecGraph), each bridge is oriented the way the
+/// search crossed it.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::bridges;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// let c = graph.add_node("C");
+/// graph.add_edge((), a, b);
+/// graph.add_edge((), b, a);
+/// let bc = graph.add_edge((), b, c);
+///
+/// assert_eq!(bridges(&graph), vec![bc]);
+/// ```
+pub fn bridges<G: Graph>(graph: &G) -> Vec<G::EdgeIx> {
+    cut_elements(graph).1
+}
@@ -0,0 +1,156 @@
+use crate::prelude::*;
+use crate::Mapping;
+use std::collections::VecDeque;
+
+/// Two-colors every connected component with a breadth-first search, treating edges as
+/// undirected. On failure returns an odd cycle.
+fn two_color<G: Graph>(
+    graph: &G,
+) -> Result<impl Mapping<G::NodeIx, Option<bool>> + use<'_, G>, Vec<G::NodeIx>> {
+    let mut color = graph.init_node_map(|_, _| None);
+    // BFS tree edge each node was discovered through, and its depth in the tree
+    let mut parent = graph.init_node_map(|_, _| None);
+    let mut depth = graph.init_node_map(|_, _| 0usize);
+    let mut queue = VecDeque::new();
+
+    for root in graph.node_indices() {
+        if color[root].is_some() {
+            continue;
+        }
+        color[root] = Some(false);
+        queue.push_back(root);
+
+        while let Some(node) = queue.pop_front() {
+            let node_color = color[node].expect("queued nodes are colored");
+            let neighbours = graph
+                .outgoing_edge_indices(node)
+                .map(|e| graph.endpoints(e)[1])
+                .chain(
+                    graph
+                        .incoming_edge_indices(node)
+                        .map(|e| graph.endpoints(e)[0]),
+                );
+            for next in neighbours {
+                match color[next] {
+                    None => {
+                        color[next] = Some(!node_color);
+                        parent[next] = Some(node);
+                        depth[next] = depth[node] + 1;
+                        queue.push_back(next);
+                    }
+                    Some(c) if c == node_color => {
+                        return Err(cycle_through(node, next, &parent, &depth));
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+
+    Ok(color)
+}
+
+/// Builds the cycle formed by the BFS tree paths from `a` and `b` to their lowest common
+/// ancestor, closed by the edge between `a` and `b`.
+fn cycle_through<K: Copy + Eq>(
+    a: K,
+    b: K,
+    parent: &impl Mapping<K, Option<K>>,
+    depth: &impl Mapping<K, usize>,
+) -> Vec<K> {
+    let (mut x, mut y) = (a, b);
+    let mut from_a = vec![x];
+    let mut from_b = Vec::new();
+    while depth[x] > depth[y] {
+        x = parent[x].expect("non-root nodes have a parent");
+        from_a.push(x);
+    }
+    while depth[y] > depth[x] {
+        from_b.push(y);
+        y = parent[y].expect("non-root nodes have a parent");
+    }
+    while x != y {
+        x = parent[x].expect("non-root nodes have a parent");
+        from_a.push(x);
+        from_b.push(y);
+        y = parent[y].expect("non-root nodes have a parent");
+    }
+    from_a.extend(from_b.into_iter().rev());
+    from_a
+}
+
+/// Checks whether a graph is bipartite and returns a two-coloring if it is.
+///
+/// Edge directions are ignored. Every connected component is colored independently with
+/// a breadth-first search, so disconnected graphs are supported and isolated nodes get
+/// an arbitrary color. A graph containing a self-loop is never bipartite.
+///
+/// # Algorithm Details
+///
+/// - **Time Complexity**: O(V + E) where V is the number of vertices and E is the number of edges
+/// - **Space Complexity**: O(V) for the colors and the queue
+///
+/// # Returns
+///
+/// A mapping from each node to its side (`false` or `true`) such that every edge connects
+/// nodes on different sides, or `None` if the graph contains an odd cycle.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::bipartite;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// let c = graph.add_node("C");
+/// graph.add_edge((), a, b);
+/// graph.add_edge((), c, b);
+///
+/// {
+///     let sides = bipartite(&graph).unwrap();
+///     assert_ne!(sides[a], sides[b]);
+///     assert_eq!(sides[a], sides[c]);
+/// }
+///
+/// // Closing the triangle makes it non-bipartite
+/// graph.add_edge((), a, c);
+/// assert!(bipartite(&graph).is_none());
+/// ```
+pub fn bipartite<G: Graph>(graph: &G) -> Option<impl Mapping<G::NodeIx, bool> + use<'_, G>> {
+    two_color(graph)
+        .ok()
+        .map(|color| color.map(|c| c.expect("every node is colored")))
+}
+
+/// Finds a cycle of odd length, which exists exactly when the graph is not bipartite.
+///
+/// Edge directions are ignored, as in [`bipartite`].
+///
+/// # Returns
+///
+/// The nodes of an odd cycle in order, each consecutive pair (and the last and first
+/// node) being connected by an edge in some direction, or `None` if the graph is
+/// bipartite. A self-loop is reported as a cycle of a single node.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::odd_cycle;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// let c = graph.add_node("C");
+/// graph.add_edge((), a, b);
+/// graph.add_edge((), b, c);
+/// assert!(odd_cycle(&graph).is_none());
+///
+/// graph.add_edge((), c, a);
+/// assert_eq!(odd_cycle(&graph).unwrap().len(), 3);
+/// ```
+pub fn odd_cycle<G: Graph>(graph: &G) -> Option<Vec<G::NodeIx>> {
+    two_color(graph).err()
+}
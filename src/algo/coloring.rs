@@ -0,0 +1,83 @@
+use crate::prelude::*;
+use crate::Mapping;
+
+/// Colors the nodes so that adjacent nodes get different colors, using few colors.
+///
+/// Edge directions are ignored. Nodes are colored one by one, each one getting the
+/// smallest color not used by an already colored neighbour. Self-loops are ignored, as
+/// no coloring could satisfy them.
+///
+/// # Algorithm Details
+///
+/// Nodes are visited in the Welsh-Powell order, by decreasing [`Graph::degree`], ties
+/// being broken by the order of `node_indices`. The number of colors is at most one more
+/// than the maximum degree, but is not guaranteed to be minimal.
+///
+/// - **Time Complexity**: O(V log V + E) where V is the number of vertices and E is the number of edges
+/// - **Space Complexity**: O(V)
+///
+/// # Returns
+///
+/// A mapping from each node to its color in `0..colors`, and the number of colors used.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::greedy_coloring;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// let c = graph.add_node("C");
+/// graph.add_edge((), a, b);
+/// graph.add_edge((), b, c);
+///
+/// let (colors, len) = greedy_coloring(&graph);
+/// assert_eq!(len, 2);
+/// assert_ne!(colors[a], colors[b]);
+/// assert_eq!(colors[a], colors[c]);
+/// ```
+pub fn greedy_coloring<G: Graph>(
+    graph: &G,
+) -> (impl Mapping<G::NodeIx, usize> + use<'_, G>, usize) {
+    let degrees = graph.degree_map();
+    let mut order: Vec<_> = graph.node_indices().collect();
+    order.sort_by_key(|&ix| {
+        let (in_degree, out_degree) = degrees[ix];
+        std::cmp::Reverse(in_degree + out_degree)
+    });
+
+    let mut color = graph.init_node_map(|_, _| None);
+    let mut len = 0;
+    // Colors taken by the neighbours of the current node, reused between nodes
+    let mut taken = Vec::new();
+
+    for node in order {
+        taken.clear();
+        let neighbours = graph
+            .outgoing_edge_indices(node)
+            .map(|e| graph.endpoints(e)[1])
+            .chain(
+                graph
+                    .incoming_edge_indices(node)
+                    .map(|e| graph.endpoints(e)[0]),
+            );
+        for next in neighbours {
+            if let Some(c) = color[next] {
+                if next != node {
+                    if taken.len() <= c {
+                        taken.resize(c + 1, false);
+                    }
+                    taken[c] = true;
+                }
+            }
+        }
+
+        let c = taken.iter().position(|&t| !t).unwrap_or(taken.len());
+        color[node] = Some(c);
+        len = len.max(c + 1);
+    }
+
+    (color.map(|c| c.expect("every node is colored")), len)
+}
@@ -0,0 +1,121 @@
+use crate::algo::{depth_first_search, CycleDetector};
+use crate::prelude::*;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Color {
+    /// Not visited yet
+    White,
+    /// On the DFS stack, at the given depth
+    Grey(usize),
+    /// Finished, together with everything reachable from it
+    Black,
+}
+
+/// Returns whether the graph contains a directed cycle.
+///
+/// Self-loops are cycles of length 1. This runs a [`depth_first_search`] over all nodes
+/// with a [`CycleDetector`]; see [`find_cycle`] to also get the edges of a cycle.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::has_cycle;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// graph.add_edge((), a, b);
+/// assert!(!has_cycle(&graph));
+///
+/// graph.add_edge((), b, a);
+/// assert!(has_cycle(&graph));
+/// ```
+pub fn has_cycle<G: Graph>(graph: &G) -> bool {
+    let mut detector = CycleDetector::new();
+    depth_first_search(graph, graph.node_indices(), &mut detector);
+    detector.has_cycle()
+}
+
+/// Finds a directed cycle in the graph.
+///
+/// Self-loops are cycles of length 1. Parallel edges between two nodes do not form a
+/// cycle by themselves, only edges in both directions do.
+///
+/// # Algorithm Details
+///
+/// Runs an iterative depth-first search from every unvisited node in the order of
+/// `node_indices`, coloring nodes white (unvisited), grey (on the DFS stack) and black
+/// (finished). The first edge found pointing to a grey node closes a cycle with the
+/// tree edges on the stack.
+///
+/// - **Time Complexity**: O(V + E) where V is the number of vertices and E is the number of edges
+/// - **Space Complexity**: O(V)
+///
+/// # Returns
+///
+/// The edges of a cycle in order, each one starting where the previous one ends and the
+/// last one ending where the first one starts, or `None` if the graph is acyclic.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::find_cycle;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// let c = graph.add_node("c");
+/// graph.add_edge((), a, b);
+/// graph.add_edge((), b, c);
+/// graph.add_edge((), c, a);
+///
+/// let cycle = find_cycle(&graph).unwrap();
+/// let names: Vec<_> = cycle
+///     .iter()
+///     .map(|&e| *graph.node(graph.endpoints(e)[0]))
+///     .collect();
+/// assert_eq!(names.join(" -> "), "a -> b -> c");
+/// ```
+pub fn find_cycle<G: Graph>(graph: &G) -> Option<Vec<G::EdgeIx>> {
+    let mut color = graph.init_node_map(|_, _| Color::White);
+    // Tree edges leading to the nodes on the stack, except the root
+    let mut path = Vec::new();
+    let mut stack = Vec::new();
+
+    for root in graph.node_indices() {
+        if color[root] != Color::White {
+            continue;
+        }
+        color[root] = Color::Grey(0);
+        stack.push((root, graph.outgoing_edge_indices(root)));
+
+        while let Some((node, edges)) = stack.last_mut() {
+            let node = *node;
+            let Some(edge) = edges.next() else {
+                color[node] = Color::Black;
+                stack.pop();
+                path.pop();
+                continue;
+            };
+
+            let [_, next] = graph.endpoints(edge);
+            match color[next] {
+                Color::White => {
+                    color[next] = Color::Grey(stack.len());
+                    path.push(edge);
+                    stack.push((next, graph.outgoing_edge_indices(next)));
+                }
+                Color::Grey(depth) => {
+                    let mut cycle = path.split_off(depth);
+                    cycle.push(edge);
+                    return Some(cycle);
+                }
+                Color::Black => {}
+            }
+        }
+    }
+
+    None
+}
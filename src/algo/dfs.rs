@@ -0,0 +1,261 @@
+use crate::prelude::*;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Color {
+    /// Not discovered yet
+    White,
+    /// Discovered but not finished, i.e. on the DFS stack
+    Grey,
+    /// Finished, together with everything reachable from it
+    Black,
+}
+
+/// Callbacks for the events of a [`depth_first_search`].
+///
+/// Every method has an empty default implementation, so a visitor only implements the
+/// events it needs. Edges are reported with their source and target, where the source
+/// is the node being explored.
+///
+/// Each edge is reported through exactly one of [`DfsVisitor::tree_edge`],
+/// [`DfsVisitor::back_edge`] and [`DfsVisitor::forward_or_cross_edge`], depending on
+/// the state of its target when the edge is examined.
+pub trait DfsVisitor<G: Graph> {
+    /// Called when `node` is reached for the first time, before any of its edges.
+    fn discover_node(&mut self, _node: G::NodeIx) {}
+
+    /// Called when all outgoing edges of `node` and everything reachable through them
+    /// have been explored.
+    fn finish_node(&mut self, _node: G::NodeIx) {}
+
+    /// Called for an edge to an undiscovered node, which is discovered next.
+    fn tree_edge(&mut self, _edge: G::EdgeIx, _from: G::NodeIx, _to: G::NodeIx) {}
+
+    /// Called for an edge to a node that is discovered but not finished, i.e. an
+    /// ancestor of `from` in the DFS tree or `from` itself. Such an edge closes a cycle.
+    fn back_edge(&mut self, _edge: G::EdgeIx, _from: G::NodeIx, _to: G::NodeIx) {}
+
+    /// Called for an edge to a finished node, either a descendant of `from` reached
+    /// through another path or a node of an earlier branch or search tree.
+    fn forward_or_cross_edge(&mut self, _edge: G::EdgeIx, _from: G::NodeIx, _to: G::NodeIx) {}
+}
+
+/// Runs a depth-first search from each of `starts`, reporting every event to `visitor`.
+///
+/// The searches share their state, so a start node already reached from an earlier one
+/// is skipped and nothing is reported twice. Pass `graph.node_indices()` to cover the
+/// whole graph, including disconnected components. Outgoing edges are followed in the
+/// order of `outgoing_edge_indices`.
+///
+/// # Algorithm Details
+///
+/// Runs an iterative depth-first search, coloring nodes white (undiscovered), grey (on
+/// the DFS stack) and black (finished) in a mapping created by `init_node_map`. The
+/// color of the target of each examined edge decides its classification.
+///
+/// - **Time Complexity**: O(V + E) where V is the number of vertices and E is the number of edges
+/// - **Space Complexity**: O(V)
+///
+/// # Panics
+///
+/// Panics if a start node does not exist in the graph.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::{depth_first_search, DfsVisitor};
+/// use gotgraph::prelude::*;
+///
+/// #[derive(Default)]
+/// struct Counter {
+///     discovered: usize,
+///     back_edges: usize,
+/// }
+///
+/// impl<G: Graph> DfsVisitor<G> for Counter {
+///     fn discover_node(&mut self, _node: G::NodeIx) {
+///         self.discovered += 1;
+///     }
+///
+///     fn back_edge(&mut self, _edge: G::EdgeIx, _from: G::NodeIx, _to: G::NodeIx) {
+///         self.back_edges += 1;
+///     }
+/// }
+///
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// let c = graph.add_node("C");
+/// graph.add_edge((), a, b);
+/// graph.add_edge((), b, a);
+///
+/// let mut counter = Counter::default();
+/// depth_first_search(&graph, [a], &mut counter);
+/// assert_eq!((counter.discovered, counter.back_edges), (2, 1));
+///
+/// depth_first_search(&graph, [c], &mut counter);
+/// assert_eq!(counter.discovered, 3);
+/// ```
+pub fn depth_first_search<G, V>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::NodeIx>,
+    visitor: &mut V,
+) where
+    G: Graph,
+    V: DfsVisitor<G>,
+{
+    let mut color = graph.init_node_map(|_, _| Color::White);
+    let mut stack = Vec::new();
+
+    for root in starts {
+        assert!(
+            graph.exists_node_index(root),
+            "Node index {:?} does not exist",
+            root
+        );
+        if color[root] != Color::White {
+            continue;
+        }
+        color[root] = Color::Grey;
+        visitor.discover_node(root);
+        stack.push((root, graph.outgoing_edge_indices(root)));
+
+        while let Some((node, edges)) = stack.last_mut() {
+            let node = *node;
+            let Some(edge) = edges.next() else {
+                color[node] = Color::Black;
+                visitor.finish_node(node);
+                stack.pop();
+                continue;
+            };
+
+            let [_, next] = graph.endpoints(edge);
+            match color[next] {
+                Color::White => {
+                    visitor.tree_edge(edge, node, next);
+                    color[next] = Color::Grey;
+                    visitor.discover_node(next);
+                    stack.push((next, graph.outgoing_edge_indices(next)));
+                }
+                Color::Grey => visitor.back_edge(edge, node, next),
+                Color::Black => visitor.forward_or_cross_edge(edge, node, next),
+            }
+        }
+    }
+}
+
+/// A [`DfsVisitor`] that detects directed cycles.
+///
+/// Records the first back edge of the search. A directed graph has a cycle exactly if a
+/// depth-first search over all its nodes finds a back edge; self-loops count.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::{depth_first_search, CycleDetector};
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// graph.add_edge((), a, b);
+/// let ba = graph.add_edge((), b, a);
+///
+/// let mut detector = CycleDetector::new();
+/// depth_first_search(&graph, graph.node_indices(), &mut detector);
+/// assert!(detector.has_cycle());
+/// assert_eq!(detector.back_edge(), Some(ba));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct CycleDetector<EI> {
+    back_edge: Option<EI>,
+}
+
+impl<EI: Copy> CycleDetector<EI> {
+    /// Creates a detector that has not seen a back edge yet.
+    pub fn new() -> Self {
+        CycleDetector { back_edge: None }
+    }
+
+    /// Returns whether a back edge, and therefore a cycle, was found.
+    pub fn has_cycle(&self) -> bool {
+        self.back_edge.is_some()
+    }
+
+    /// Returns the first back edge found. The cycle it closes runs from its target
+    /// through tree edges to its source.
+    pub fn back_edge(&self) -> Option<EI> {
+        self.back_edge
+    }
+}
+
+impl<EI: Copy> Default for CycleDetector<EI> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G: Graph> DfsVisitor<G> for CycleDetector<G::EdgeIx> {
+    fn back_edge(&mut self, edge: G::EdgeIx, _from: G::NodeIx, _to: G::NodeIx) {
+        self.back_edge.get_or_insert(edge);
+    }
+}
+
+/// A [`DfsVisitor`] that records the nodes in the order they finish.
+///
+/// In an acyclic graph every node finishes after all nodes reachable from it, so the
+/// reverse of the finish order of a search over all nodes is a topological order.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::{depth_first_search, FinishOrder};
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// let c = graph.add_node("C");
+/// graph.add_edge((), b, c);
+/// graph.add_edge((), c, a);
+///
+/// let mut finish = FinishOrder::new();
+/// depth_first_search(&graph, graph.node_indices(), &mut finish);
+/// assert_eq!(finish.order(), &[a, c, b]);
+/// assert_eq!(finish.into_topological_order(), vec![b, c, a]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct FinishOrder<NI> {
+    order: Vec<NI>,
+}
+
+impl<NI> FinishOrder<NI> {
+    /// Creates an empty finish order.
+    pub fn new() -> Self {
+        FinishOrder { order: Vec::new() }
+    }
+
+    /// Returns the nodes in the order they finished.
+    pub fn order(&self) -> &[NI] {
+        &self.order
+    }
+
+    /// Returns the nodes in the reverse of the order they finished, which is a
+    /// topological order if the search covered an acyclic graph.
+    pub fn into_topological_order(self) -> Vec<NI> {
+        let mut order = self.order;
+        order.reverse();
+        order
+    }
+}
+
+impl<NI> Default for FinishOrder<NI> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G: Graph> DfsVisitor<G> for FinishOrder<G::NodeIx> {
+    fn finish_node(&mut self, node: G::NodeIx) {
+        self.order.push(node);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::prelude::*;
+use crate::Mapping;
+use core::marker::PhantomData;
+
+/// A disjoint-set (union-find) forest over the nodes of a graph, created with
+/// [`disjoint_set`].
+///
+/// Every node starts out in its own set. [`DisjointSet::union`] merges two sets and
+/// [`DisjointSet::find`] returns a representative node of the set containing a node, so
+/// two nodes are in the same set exactly when they have the same representative.
+///
+/// The parent and rank of each node are kept in a mapping created with
+/// [`Graph::init_node_map`], so the structure works with any graph type, including
+/// tags inside a scope.
+///
+/// # Algorithm Details
+///
+/// Uses union by rank and path compression (path halving), so any sequence of
+/// operations takes nearly constant amortized time per operation.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::disjoint_set;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// let c = graph.add_node("C");
+///
+/// let mut sets = disjoint_set(&graph);
+/// assert!(sets.union(a, b));
+/// assert!(!sets.union(b, a));
+/// assert!(sets.same_set(a, b));
+/// assert!(!sets.same_set(a, c));
+/// ```
+#[derive(Debug)]
+pub struct DisjointSet<K, M> {
+    _key: PhantomData<K>,
+    // parent and rank of each element
+    slots: M,
+}
+
+/// Creates a disjoint-set forest with every node of `graph` in its own set.
+///
+/// This is a free function rather than a constructor because the type of the mapping
+/// depends on the graph and cannot be named by the caller.
+pub fn disjoint_set<G: Graph>(
+    graph: &G,
+) -> DisjointSet<G::NodeIx, impl Mapping<G::NodeIx, (G::NodeIx, u32)> + use<'_, G>> {
+    DisjointSet {
+        _key: PhantomData,
+        slots: graph.init_node_map(|ix, _| (ix, 0)),
+    }
+}
+
+impl<K: Copy + Eq, M: Mapping<K, (K, u32)>> DisjointSet<K, M> {
+    /// Returns the representative of the set containing `node`.
+    ///
+    /// Panics if `node` was not in the graph the structure was created from.
+    pub fn find(&mut self, mut node: K) -> K {
+        while self.slots[node].0 != node {
+            let grandparent = self.slots[self.slots[node].0].0;
+            self.slots[node].0 = grandparent;
+            node = grandparent;
+        }
+        node
+    }
+
+    /// Merges the sets containing `a` and `b`.
+    ///
+    /// Returns `false` if they were already in the same set.
+    pub fn union(&mut self, a: K, b: K) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        let (rank_a, rank_b) = (self.slots[a].1, self.slots[b].1);
+        if rank_a < rank_b {
+            self.slots[a].0 = b;
+        } else {
+            self.slots[b].0 = a;
+            if rank_a == rank_b {
+                self.slots[a].1 += 1;
+            }
+        }
+        true
+    }
+
+    /// Returns whether `a` and `b` are in the same set.
+    pub fn same_set(&mut self, a: K, b: K) -> bool {
+        self.find(a) == self.find(b)
+    }
+}
@@ -0,0 +1,164 @@
+use crate::graph::DefaultMap;
+use crate::prelude::*;
+use crate::Mapping;
+use std::collections::HashMap;
+use std::ops::Add;
+
+/// Dense matrix of shortest path distances between every pair of nodes, returned by
+/// [`floyd_warshall`].
+///
+/// Indexed by a pair of node indices: `matrix[(a, b)]` is the distance from `a` to `b`,
+/// or `None` if `b` is not reachable from `a`. The distances are stored row-major, one
+/// `Option<W>` per pair of nodes, so the matrix takes O(V²) memory.
+#[derive(Debug, Clone)]
+pub struct ApspMatrix<W, K> {
+    positions: HashMap<K, usize>,
+    // Nodes in the order of `node_indices`, by their dense position
+    nodes: Vec<K>,
+    dist: Vec<Option<W>>,
+    negative_cycle: bool,
+}
+
+impl<W, K: Copy + Eq + std::hash::Hash> ApspMatrix<W, K> {
+    /// Returns whether the graph contains a cycle of negative total cost.
+    ///
+    /// The distances are not well defined in that case. They are left as they were when
+    /// the cycle was found and should not be relied upon.
+    pub fn has_negative_cycle(&self) -> bool {
+        self.negative_cycle
+    }
+
+    /// Converts the matrix into a mapping from every node to a map of the distances from
+    /// it.
+    ///
+    /// `matrix[(a, b)]` becomes `maps[a][&b]`. The outer mapping iterates in the order of
+    /// `node_indices` of the graph the matrix was computed on.
+    pub fn into_mapping_of_maps(self) -> impl Mapping<K, HashMap<K, Option<W>>> {
+        let n = self.nodes.len();
+        let mut dist = self.dist.into_iter();
+        self.nodes
+            .iter()
+            .map(|&from| {
+                let row = self.nodes.iter().copied().zip(dist.by_ref().take(n));
+                (from, row.collect())
+            })
+            .collect::<DefaultMap<_, _>>()
+    }
+
+    fn position(&self, ix: K) -> usize {
+        *self
+            .positions
+            .get(&ix)
+            .expect("Node not found in the matrix")
+    }
+}
+
+impl<W, K: Copy + Eq + std::hash::Hash> std::ops::Index<(K, K)> for ApspMatrix<W, K> {
+    type Output = Option<W>;
+
+    fn index(&self, (from, to): (K, K)) -> &Self::Output {
+        &self.dist[self.position(from) * self.nodes.len() + self.position(to)]
+    }
+}
+
+/// Computes the shortest path distances between all pairs of nodes using the
+/// Floyd-Warshall algorithm.
+///
+/// Edge costs may be negative, with `W::default()` as zero. The distance from a node to
+/// itself is zero unless it lies on a negative cycle. If the graph contains a cycle of
+/// negative total cost, [`ApspMatrix::has_negative_cycle`] is set and the distances should
+/// not be relied upon.
+///
+/// The result holds a distance for every pair of nodes, so it is meant for small or dense
+/// graphs: with 4000 nodes and `i64` costs the matrix already takes 256 MiB. For sparse
+/// graphs, running a single-source search such as [`bellman_ford`](fn@crate::algo::bellman_ford)
+/// from each node of interest uses far less memory.
+///
+/// # Algorithm Details
+///
+/// The nodes are first numbered densely in the order of `node_indices`, so any index type
+/// works, including the tags of a [`Context`](crate::graph::Context). Then, for every node
+/// `k` in turn, each distance is improved by paths going through `k`. The search stops as
+/// soon as a node is found to lie on a negative cycle, which keeps the costs from
+/// compounding.
+///
+/// - **Time Complexity**: O(V³ + E) where V is the number of vertices and E is the number of edges
+/// - **Space Complexity**: O(V²)
+///
+/// # Parameters
+///
+/// - `graph`: The graph to search
+/// - `cost`: Returns the cost of traversing an edge
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::floyd_warshall;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, i32> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// let c = graph.add_node("C");
+/// graph.add_edge(4, a, b);
+/// graph.add_edge(-2, b, c);
+/// graph.add_edge(3, a, c);
+///
+/// let dist = floyd_warshall(&graph, |_, &w| w);
+/// assert!(!dist.has_negative_cycle());
+/// assert_eq!(dist[(a, c)], Some(2));
+/// assert_eq!(dist[(b, b)], Some(0));
+/// assert_eq!(dist[(c, a)], None);
+/// ```
+pub fn floyd_warshall<G, W>(
+    graph: &G,
+    cost: impl Fn(G::EdgeIx, &G::Edge) -> W,
+) -> ApspMatrix<W, G::NodeIx>
+where
+    G: Graph,
+    W: Copy + Ord + Add<Output = W> + Default,
+{
+    let nodes: Vec<_> = graph.node_indices().collect();
+    let positions: HashMap<_, _> = nodes.iter().enumerate().map(|(i, &ix)| (ix, i)).collect();
+    let n = nodes.len();
+
+    let mut dist = vec![None; n * n];
+    for i in 0..n {
+        dist[i * n + i] = Some(W::default());
+    }
+    for (edge, [from, to], data) in graph.edge_triples() {
+        let slot = &mut dist[positions[&from] * n + positions[&to]];
+        let w = cost(edge, data);
+        if slot.map_or(true, |current| w < current) {
+            *slot = Some(w);
+        }
+    }
+
+    let mut negative_cycle = false;
+    for k in 0..n {
+        for i in 0..n {
+            let Some(through) = dist[i * n + k] else {
+                continue;
+            };
+            for j in 0..n {
+                if let Some(rest) = dist[k * n + j] {
+                    let candidate = through + rest;
+                    if dist[i * n + j].map_or(true, |current| candidate < current) {
+                        dist[i * n + j] = Some(candidate);
+                    }
+                }
+            }
+        }
+        if (0..n).any(|i| dist[i * n + i].is_some_and(|d| d < W::default())) {
+            negative_cycle = true;
+            break;
+        }
+    }
+
+    ApspMatrix {
+        positions,
+        nodes,
+        dist,
+        negative_cycle,
+    }
+}
@@ -0,0 +1,280 @@
+use crate::prelude::*;
+use std::collections::HashMap;
+
+/// Error returned by [`IncrementalCycles::try_add_edge`] and
+/// [`IncrementalCycles::add_edge_to`] when an edge would close a directed cycle.
+///
+/// The payload of the rejected edge is handed back, so no data is lost. It is `()` for
+/// [`IncrementalCycles::try_add_edge`], which does not take a payload.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CycleCreated<T = ()> {
+    payload: T,
+}
+
+impl<T> CycleCreated<T> {
+    /// Returns the payload of the edge that was rejected.
+    pub fn into_inner(self) -> T {
+        self.payload
+    }
+}
+
+impl<T> core::fmt::Debug for CycleCreated<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CycleCreated").finish_non_exhaustive()
+    }
+}
+
+impl<T> core::fmt::Display for CycleCreated<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "edge would create a directed cycle")
+    }
+}
+
+impl<T> std::error::Error for CycleCreated<T> {}
+
+/// A directed acyclic graph that keeps a topological order up to date while edges are
+/// inserted one at a time.
+///
+/// Every edge passed to [`IncrementalCycles::try_add_edge`] is either accepted, in which
+/// case [`IncrementalCycles::order`] keeps listing `from` before `to`, or rejected with
+/// [`CycleCreated`] because it would close a directed cycle. Rejected edges leave the
+/// structure unchanged.
+///
+/// The structure only stores the node indices and the accepted edges. Use
+/// [`IncrementalCycles::add_node_to`] and [`IncrementalCycles::add_edge_to`] to insert
+/// into a graph and the structure together, or [`IncrementalCycles::from_graph`] to start
+/// from an existing graph. Removal is not supported; since [`VecGraph`] renumbers indices
+/// when removing, the structure must be rebuilt after removing from the graph.
+///
+/// # Algorithm Details
+///
+/// Uses the dynamic topological sort of Pearce and Kelly. An edge that already goes
+/// forward in the current order is accepted in constant time. Otherwise only the nodes
+/// whose position lies between the endpoints are searched: those reachable from `to`
+/// and those reaching `from`. If `from` is reachable from `to` the edge is rejected,
+/// otherwise the two sets are reordered among the positions they already occupy.
+///
+/// - **Time Complexity**: O(δ log δ) per insertion, where δ is the number of nodes and
+///   edges in the affected region of the order
+/// - **Space Complexity**: O(V + E)
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::IncrementalCycles;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let mut cycles = IncrementalCycles::new(3);
+/// let a = cycles.add_node_to(&mut graph, "a");
+/// let b = cycles.add_node_to(&mut graph, "b");
+/// let c = cycles.add_node_to(&mut graph, "c");
+///
+/// cycles.add_edge_to(&mut graph, (), c, b).unwrap();
+/// cycles.add_edge_to(&mut graph, (), b, a).unwrap();
+/// assert!(cycles.add_edge_to(&mut graph, (), a, c).is_err());
+///
+/// assert_eq!(graph.len_edges(), 2);
+/// assert_eq!(cycles.order().collect::<Vec<_>>(), vec![c, b, a]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IncrementalCycles<K> {
+    ids: HashMap<K, usize>,
+    // The following are indexed by dense ids assigned in insertion order
+    keys: Vec<K>,
+    outgoing: Vec<Vec<usize>>,
+    incoming: Vec<Vec<usize>>,
+    position: Vec<usize>,
+    // Scratch marks for the searches, all false between calls
+    visited: Vec<bool>,
+    // Ids in topological order
+    order: Vec<usize>,
+}
+
+impl<K: Copy + Eq + std::hash::Hash> IncrementalCycles<K> {
+    /// Creates an empty structure with room for `num_hint` nodes.
+    pub fn new(num_hint: usize) -> Self {
+        IncrementalCycles {
+            ids: HashMap::with_capacity(num_hint),
+            keys: Vec::with_capacity(num_hint),
+            outgoing: Vec::with_capacity(num_hint),
+            incoming: Vec::with_capacity(num_hint),
+            position: Vec::with_capacity(num_hint),
+            visited: Vec::with_capacity(num_hint),
+            order: Vec::with_capacity(num_hint),
+        }
+    }
+
+    /// Adds a node at the end of the order.
+    ///
+    /// Returns `false` if the node was already present.
+    pub fn add_node(&mut self, ix: K) -> bool {
+        if self.ids.contains_key(&ix) {
+            return false;
+        }
+        let id = self.keys.len();
+        self.ids.insert(ix, id);
+        self.keys.push(ix);
+        self.outgoing.push(Vec::new());
+        self.incoming.push(Vec::new());
+        self.position.push(self.order.len());
+        self.visited.push(false);
+        self.order.push(id);
+        true
+    }
+
+    /// Returns whether the node has been added.
+    pub fn contains_node(&self, ix: K) -> bool {
+        self.ids.contains_key(&ix)
+    }
+
+    /// Returns the number of nodes.
+    pub fn len_nodes(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Adds an edge from `from` to `to` unless it would close a directed cycle.
+    ///
+    /// Self-loops are cycles of length 1 and are always rejected. Parallel edges are
+    /// accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CycleCreated`] if `from` is reachable from `to` through the accepted
+    /// edges. The structure is left unchanged in that case.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node has not been added.
+    pub fn try_add_edge(&mut self, from: K, to: K) -> Result<(), CycleCreated> {
+        let x = self.ids[&from];
+        let y = self.ids[&to];
+        if x == y {
+            return Err(CycleCreated { payload: () });
+        }
+
+        let (lower, upper) = (self.position[y], self.position[x]);
+        if lower < upper {
+            // Nodes reachable from `to` that currently precede `from`
+            let Some(mut forward) = self.search_forward(y, upper) else {
+                return Err(CycleCreated { payload: () });
+            };
+            // Nodes reaching `from` that currently follow `to`
+            let mut backward = self.search_backward(x, lower);
+
+            forward.sort_unstable_by_key(|&id| self.position[id]);
+            backward.sort_unstable_by_key(|&id| self.position[id]);
+            backward.extend(forward);
+            let mut slots: Vec<_> = backward.iter().map(|&id| self.position[id]).collect();
+            slots.sort_unstable();
+            for (id, slot) in backward.into_iter().zip(slots) {
+                self.visited[id] = false;
+                self.position[id] = slot;
+                self.order[slot] = id;
+            }
+        }
+
+        self.outgoing[x].push(y);
+        self.incoming[y].push(x);
+        Ok(())
+    }
+
+    /// Returns the nodes in a topological order of the accepted edges.
+    ///
+    /// For every accepted edge, `from` comes before `to`.
+    pub fn order(&self) -> impl Iterator<Item = K> + '_ {
+        self.order.iter().map(|&id| self.keys[id])
+    }
+
+    // Marks and returns the nodes reachable from `start` with a position below `upper`,
+    // or clears the marks and returns `None` if the node at `upper` is reachable
+    fn search_forward(&mut self, start: usize, upper: usize) -> Option<Vec<usize>> {
+        let mut found = vec![start];
+        self.visited[start] = true;
+        let mut cursor = 0;
+        while let Some(&id) = found.get(cursor) {
+            cursor += 1;
+            for &next in &self.outgoing[id] {
+                if self.position[next] == upper {
+                    for &id in &found {
+                        self.visited[id] = false;
+                    }
+                    return None;
+                }
+                if !self.visited[next] && self.position[next] < upper {
+                    self.visited[next] = true;
+                    found.push(next);
+                }
+            }
+        }
+        Some(found)
+    }
+
+    // Marks and returns the nodes reaching `start` with a position above `lower`
+    fn search_backward(&mut self, start: usize, lower: usize) -> Vec<usize> {
+        let mut found = vec![start];
+        self.visited[start] = true;
+        let mut cursor = 0;
+        while let Some(&id) = found.get(cursor) {
+            cursor += 1;
+            for &prev in &self.incoming[id] {
+                if !self.visited[prev] && self.position[prev] > lower {
+                    self.visited[prev] = true;
+                    found.push(prev);
+                }
+            }
+        }
+        found
+    }
+
+    /// Creates a structure holding every node and edge of `graph`.
+    ///
+    /// The nodes are added in the order of `node_indices` and the edges in the order of
+    /// `edge_indices`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CycleCreated`] if the graph contains a directed cycle.
+    pub fn from_graph<G: Graph<NodeIx = K>>(graph: &G) -> Result<Self, CycleCreated> {
+        let mut cycles = Self::new(graph.len_nodes());
+        for ix in graph.node_indices() {
+            cycles.add_node(ix);
+        }
+        for ix in graph.edge_indices() {
+            let [from, to] = graph.endpoints(ix);
+            cycles.try_add_edge(from, to)?;
+        }
+        Ok(cycles)
+    }
+
+    /// Adds a node to `graph` and to this structure.
+    pub fn add_node_to<G: GraphUpdate<NodeIx = K>>(&mut self, graph: &mut G, node: G::Node) -> K {
+        let ix = graph.add_node(node);
+        self.add_node(ix);
+        ix
+    }
+
+    /// Adds an edge to `graph` and to this structure, unless it would close a directed
+    /// cycle.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CycleCreated`] holding `edge` if the edge would close a cycle. Neither
+    /// `graph` nor the structure is changed in that case.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node has not been added to this structure.
+    pub fn add_edge_to<G: GraphUpdate<NodeIx = K>>(
+        &mut self,
+        graph: &mut G,
+        edge: G::Edge,
+        from: K,
+        to: K,
+    ) -> Result<G::EdgeIx, CycleCreated<G::Edge>> {
+        match self.try_add_edge(from, to) {
+            Ok(()) => Ok(graph.add_edge(edge, from, to)),
+            Err(_) => Err(CycleCreated { payload: edge }),
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use crate::prelude::*;
+use crate::Mapping;
+
+/// Iterates over the neighbours of `node` through its outgoing and incoming edges, once
+/// per edge endpoint.
+fn neighbours<G: Graph>(graph: &G, node: G::NodeIx) -> impl Iterator<Item = G::NodeIx> + '_ {
+    graph
+        .outgoing_edge_indices(node)
+        .map(|e| graph.endpoints(e)[1])
+        .chain(
+            graph
+                .incoming_edge_indices(node)
+                .map(|e| graph.endpoints(e)[0]),
+        )
+}
+
+/// Computes the `k`-core of the graph: the largest set of nodes in which every node has
+/// at least `k` edges to other nodes of the set.
+///
+/// Edge directions are ignored, and degrees are counted as in [`Graph::degree`]: every
+/// edge counts at both of its endpoints, so parallel edges count several times and a
+/// self-loop counts twice. The graph is not modified; see
+/// [`VecGraph::retain_k_core`](crate::vec_graph::VecGraph::retain_k_core) to remove the
+/// other nodes.
+///
+/// # Algorithm Details
+///
+/// Nodes of degree less than `k` are queued and peeled off one by one, decrementing the
+/// degree of their neighbours and queueing those that drop below `k`.
+///
+/// - **Time Complexity**: O(V + E) where V is the number of vertices and E is the number of edges
+/// - **Space Complexity**: O(V)
+///
+/// # Returns
+///
+/// The nodes of the `k`-core in the order of `node_indices`.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::k_core;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// let c = graph.add_node("C");
+/// let d = graph.add_node("D");
+/// graph.add_edge((), a, b);
+/// graph.add_edge((), b, c);
+/// graph.add_edge((), c, a);
+/// graph.add_edge((), c, d);
+///
+/// assert_eq!(k_core(&graph, 2), vec![a, b, c]);
+/// ```
+pub fn k_core<G: Graph>(graph: &G, k: usize) -> Vec<G::NodeIx> {
+    let degrees = graph.degree_map();
+    let mut degree = graph.init_node_map(|ix, _| degrees[ix].0 + degrees[ix].1);
+    let mut removed = graph.init_node_map(|_, _| false);
+    let mut queue: Vec<_> = graph.node_indices().filter(|&ix| degree[ix] < k).collect();
+    for &node in &queue {
+        removed[node] = true;
+    }
+
+    while let Some(node) = queue.pop() {
+        for next in neighbours(graph, node) {
+            if !removed[next] {
+                degree[next] -= 1;
+                if degree[next] < k {
+                    removed[next] = true;
+                    queue.push(next);
+                }
+            }
+        }
+    }
+
+    graph.node_indices().filter(|&ix| !removed[ix]).collect()
+}
+
+/// Computes the core number of every node: the largest `k` such that the node belongs to
+/// the `k`-core.
+///
+/// Degrees are counted as in [`k_core`], ignoring edge directions.
+///
+/// # Algorithm Details
+///
+/// Uses the bucket-based peeling of Batagelj and Zaveršnik: nodes are removed in order
+/// of their current degree, which never drops below the degree the peeling has reached.
+///
+/// - **Time Complexity**: O(V + E) where V is the number of vertices and E is the number of edges
+/// - **Space Complexity**: O(V + E) for the buckets
+///
+/// # Returns
+///
+/// A mapping from each node to its core number.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::core_numbers;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// let c = graph.add_node("C");
+/// let d = graph.add_node("D");
+/// graph.add_edge((), a, b);
+/// graph.add_edge((), b, c);
+/// graph.add_edge((), c, a);
+/// graph.add_edge((), c, d);
+///
+/// let cores = core_numbers(&graph);
+/// assert_eq!((cores[a], cores[b], cores[c], cores[d]), (2, 2, 2, 1));
+/// ```
+pub fn core_numbers<G: Graph>(graph: &G) -> impl Mapping<G::NodeIx, usize> + use<'_, G> {
+    let degrees = graph.degree_map();
+    let mut degree = graph.init_node_map(|ix, _| degrees[ix].0 + degrees[ix].1);
+    let mut core = graph.init_node_map(|_, _| None);
+
+    // Nodes by degree, possibly with stale entries for nodes whose degree has decreased
+    let mut buckets: Vec<Vec<G::NodeIx>> = Vec::new();
+    for node in graph.node_indices() {
+        let d = degree[node];
+        if buckets.len() <= d {
+            buckets.resize_with(d + 1, Vec::new);
+        }
+        buckets[d].push(node);
+    }
+
+    let mut level = 0;
+    while level < buckets.len() {
+        let Some(node) = buckets[level].pop() else {
+            level += 1;
+            continue;
+        };
+        if core[node].is_some() || degree[node] != level {
+            continue;
+        }
+        core[node] = Some(level);
+
+        for next in neighbours(graph, node) {
+            if core[next].is_none() && degree[next] > level {
+                degree[next] -= 1;
+                buckets[degree[next]].push(next);
+            }
+        }
+    }
+
+    core.map(|c| c.expect("every node is peeled"))
+}
@@ -0,0 +1,281 @@
+use crate::prelude::*;
+use crate::Mapping;
+use std::collections::VecDeque;
+use std::ops::Add;
+
+/// Error returned by [`longest_path_dag`] and [`longest_path_from`] when the graph is not
+/// a directed acyclic graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError<K> {
+    cycle: Vec<K>,
+}
+
+impl<K> CycleError<K> {
+    /// Returns the nodes of a cycle in the graph, in order. The last node has an edge
+    /// back to the first one.
+    pub fn cycle(&self) -> &[K] {
+        &self.cycle
+    }
+
+    /// Returns the nodes of the cycle, see [`CycleError::cycle`].
+    pub fn into_cycle(self) -> Vec<K> {
+        self.cycle
+    }
+}
+
+impl<K> std::fmt::Display for CycleError<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "graph is not acyclic, it contains a cycle through {} nodes",
+            self.cycle.len()
+        )
+    }
+}
+
+impl<K: std::fmt::Debug> std::error::Error for CycleError<K> {}
+
+/// Finds a maximum-weight path in a directed acyclic graph.
+///
+/// This is the critical path of a PERT chart when nodes are events and edges are tasks
+/// weighted by their duration. Paths may start and end at any node; the empty path has
+/// weight `W::default()`, so with negative weights the result may be empty.
+///
+/// # Algorithm Details
+///
+/// Sorts the nodes topologically with Kahn's algorithm, then relaxes the outgoing edges
+/// of each node in that order, keeping for every node the heaviest path ending there.
+///
+/// - **Time Complexity**: O(V + E) where V is the number of vertices and E is the number of edges
+/// - **Space Complexity**: O(V)
+///
+/// # Returns
+///
+/// The total weight of the path and its edges in order. Among paths of equal weight the
+/// one found first is returned.
+///
+/// # Errors
+///
+/// Returns a [`CycleError`] holding a cycle of the graph if the graph is not acyclic.
+/// Self-loops are cycles.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::longest_path_dag;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, u32> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// let c = graph.add_node("C");
+/// let ab = graph.add_edge(2, a, b);
+/// let bc = graph.add_edge(3, b, c);
+/// graph.add_edge(4, a, c);
+///
+/// let (weight, path) = longest_path_dag(&graph, |_, &w| w).unwrap();
+/// assert_eq!(weight, 5);
+/// assert_eq!(path, vec![ab, bc]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn longest_path_dag<G, W>(
+    graph: &G,
+    weight: impl Fn(G::EdgeIx, &G::Edge) -> W,
+) -> Result<(W, Vec<G::EdgeIx>), CycleError<G::NodeIx>>
+where
+    G: Graph,
+    W: Copy + Ord + Add<Output = W> + Default,
+{
+    let order = topological_order(graph)?;
+    let mut best = graph.init_node_map(|_, _| Some((W::default(), None)));
+    Ok(relax_in_order(graph, &order, &weight, &mut best))
+}
+
+/// Finds a maximum-weight path starting at `source` in a directed acyclic graph.
+///
+/// Like [`longest_path_dag`], but only paths starting at `source` are considered. The
+/// path may end at any node reachable from `source`, or be empty.
+///
+/// # Errors
+///
+/// Returns a [`CycleError`] if the graph is not acyclic, even if the cycle is not
+/// reachable from `source`.
+///
+/// # Panics
+///
+/// Panics if `source` is not a valid node index of `graph`.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::longest_path_from;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, u32> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// let c = graph.add_node("C");
+/// graph.add_edge(10, a, b);
+/// let bc = graph.add_edge(3, b, c);
+///
+/// let (weight, path) = longest_path_from(&graph, b, |_, &w| w).unwrap();
+/// assert_eq!(weight, 3);
+/// assert_eq!(path, vec![bc]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn longest_path_from<G, W>(
+    graph: &G,
+    source: G::NodeIx,
+    weight: impl Fn(G::EdgeIx, &G::Edge) -> W,
+) -> Result<(W, Vec<G::EdgeIx>), CycleError<G::NodeIx>>
+where
+    G: Graph,
+    W: Copy + Ord + Add<Output = W> + Default,
+{
+    assert!(
+        graph.exists_node_index(source),
+        "Source node does not exist in the graph"
+    );
+
+    let order = topological_order(graph)?;
+    let mut best = graph.init_node_map(|_, _| None);
+    best[source] = Some((W::default(), None));
+    Ok(relax_in_order(graph, &order, &weight, &mut best))
+}
+
+/// Computes the weight of the heaviest path ending at each node of a directed acyclic
+/// graph.
+///
+/// Paths may start at any node, so every node gets at least the weight of the empty
+/// path, `W::default()`. This is the earliest start time of each event of a PERT chart
+/// whose edges are weighted by the duration of the tasks. Use [`longest_path_dag`] to
+/// get the edges of the overall heaviest path instead.
+///
+/// # Algorithm Details
+///
+/// Sorts the nodes topologically with Kahn's algorithm, then relaxes the outgoing edges
+/// of each node in that order.
+///
+/// - **Time Complexity**: O(V + E) where V is the number of vertices and E is the number of edges
+/// - **Space Complexity**: O(V)
+///
+/// # Errors
+///
+/// Returns a [`CycleError`] holding a cycle of the graph if the graph is not acyclic.
+/// Self-loops are cycles.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::longest_path;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, u32> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// let c = graph.add_node("C");
+/// graph.add_edge(2, a, b);
+/// graph.add_edge(3, b, c);
+/// graph.add_edge(4, a, c);
+///
+/// let dist = longest_path(&graph, |_, &w| w).unwrap();
+/// assert_eq!((dist[a], dist[b], dist[c]), (0, 2, 5));
+/// ```
+pub fn longest_path<G, F, W>(
+    graph: &G,
+    weight: F,
+) -> Result<impl Mapping<G::NodeIx, W> + use<'_, G, F, W>, CycleError<G::NodeIx>>
+where
+    G: Graph,
+    F: Fn(G::EdgeIx, &G::Edge) -> W,
+    W: Copy + Ord + Add<Output = W> + Default,
+{
+    let order = topological_order(graph)?;
+    let mut dist = graph.init_node_map(|_, _| W::default());
+    for node in order {
+        let w = dist[node];
+        for (edge, data) in graph.outgoing_edge_pairs(node) {
+            let [_, next] = graph.endpoints(edge);
+            let candidate = w + weight(edge, data);
+            if candidate > dist[next] {
+                dist[next] = candidate;
+            }
+        }
+    }
+    Ok(dist)
+}
+
+/// Sorts the nodes with [`Graph::topological_order`], or returns a cycle if there is one
+fn topological_order<G: Graph>(graph: &G) -> Result<Vec<G::NodeIx>, CycleError<G::NodeIx>> {
+    graph.topological_order().map_err(|node| CycleError {
+        cycle: cycle_through(graph, node),
+    })
+}
+
+/// Returns the nodes of a shortest cycle through `start`, beginning with `start`, found
+/// by a breadth-first search back to it. `start` must lie on a cycle.
+fn cycle_through<G: Graph>(graph: &G, start: G::NodeIx) -> Vec<G::NodeIx> {
+    let mut parent = graph.init_node_map(|_, _| None);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        for edge in graph.outgoing_edge_indices(node) {
+            let [_, next] = graph.endpoints(edge);
+            if next == start {
+                let mut cycle = vec![node];
+                while let Some(prev) = parent[*cycle.last().unwrap()] {
+                    cycle.push(prev);
+                }
+                cycle.reverse();
+                return cycle;
+            }
+            if parent[next].is_none() {
+                parent[next] = Some(node);
+                queue.push_back(next);
+            }
+        }
+    }
+    unreachable!("the node returned by `topological_order` lies on a cycle")
+}
+
+/// Relaxes the outgoing edges of each node in topological order, where `best` holds the
+/// weight of the heaviest path ending at each node together with its last edge, and
+/// returns the heaviest path overall
+fn relax_in_order<G, W>(
+    graph: &G,
+    order: &[G::NodeIx],
+    weight: &impl Fn(G::EdgeIx, &G::Edge) -> W,
+    best: &mut impl Mapping<G::NodeIx, Option<(W, Option<G::EdgeIx>)>>,
+) -> (W, Vec<G::EdgeIx>)
+where
+    G: Graph,
+    W: Copy + Ord + Add<Output = W> + Default,
+{
+    let mut end: Option<(W, G::NodeIx)> = None;
+
+    for &node in order {
+        let Some((w, _)) = best[node] else {
+            continue;
+        };
+        if end.map_or(true, |(max, _)| w > max) {
+            end = Some((w, node));
+        }
+        for (edge, data) in graph.outgoing_edge_pairs(node) {
+            let [_, next] = graph.endpoints(edge);
+            let candidate = w + weight(edge, data);
+            if best[next].map_or(true, |(current, _)| candidate > current) {
+                best[next] = Some((candidate, Some(edge)));
+            }
+        }
+    }
+
+    let Some((total, mut node)) = end else {
+        return (W::default(), Vec::new());
+    };
+    let mut path = Vec::new();
+    while let Some((_, Some(edge))) = best[node] {
+        path.push(edge);
+        node = graph.endpoints(edge)[0];
+    }
+    path.reverse();
+    (total, path)
+}
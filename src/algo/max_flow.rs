@@ -0,0 +1,155 @@
+use crate::prelude::*;
+use crate::Mapping;
+use std::collections::VecDeque;
+
+/// Computes a maximum flow from `source` to `sink` using the Edmonds–Karp algorithm.
+///
+/// Each edge can carry at most `capacity(ix, edge)` units of flow in its own direction.
+/// Parallel edges have independent capacities and self-loops never carry flow. The edge
+/// payloads are not modified; the residual network is tracked in an internal mapping.
+///
+/// # Algorithm Details
+///
+/// Repeatedly finds a shortest augmenting path in the residual network with a
+/// breadth-first search and pushes the bottleneck capacity along it, until no augmenting
+/// path remains.
+///
+/// - **Time Complexity**: O(V · E²) where V is the number of vertices and E is the number of edges
+/// - **Space Complexity**: O(V + E)
+///
+/// # Parameters
+///
+/// - `graph`: The flow network
+/// - `source`: The node flow originates from
+/// - `sink`: The node flow is delivered to
+/// - `capacity`: Returns the capacity of an edge
+///
+/// # Returns
+///
+/// The value of the maximum flow, together with a mapping from each edge to the flow it
+/// carries in that maximum flow. If `source == sink` the flow is 0.
+///
+/// # Panics
+///
+/// Panics if `source` or `sink` is not a valid node index of `graph`, or if the value
+/// of the maximum flow does not fit in a `u64`. That can only happen when the capacities
+/// of the edges leaving `source` sum to more than `u64::MAX`; the flow on each edge is
+/// bounded by its capacity and never overflows.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::max_flow;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, u64> = VecGraph::default();
+/// let s = graph.add_node("s");
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// let t = graph.add_node("t");
+/// let sa = graph.add_edge(3, s, a);
+/// graph.add_edge(2, s, b);
+/// graph.add_edge(2, a, t);
+/// graph.add_edge(1, a, b);
+/// graph.add_edge(3, b, t);
+///
+/// let (value, flow) = max_flow(&graph, s, t, |_, &cap| cap);
+/// assert_eq!(value, 5);
+/// assert_eq!(flow[sa], 3);
+/// ```
+pub fn max_flow<G, F>(
+    graph: &G,
+    source: G::NodeIx,
+    sink: G::NodeIx,
+    capacity: F,
+) -> (u64, impl Mapping<G::EdgeIx, u64> + use<'_, G, F>)
+where
+    G: Graph,
+    F: Fn(G::EdgeIx, &G::Edge) -> u64,
+{
+    assert!(
+        graph.exists_node_index(source),
+        "Source node does not exist in the graph"
+    );
+    assert!(
+        graph.exists_node_index(sink),
+        "Sink node does not exist in the graph"
+    );
+
+    let capacities = graph.init_edge_map(|ix, edge| capacity(ix, edge));
+    let mut flow = graph.init_edge_map(|_, _| 0u64);
+    let mut total = 0u64;
+
+    if source == sink {
+        return (total, flow);
+    }
+
+    let mut queue = VecDeque::new();
+    loop {
+        // Edge used to reach each node, and whether it was traversed forwards
+        let mut parent = graph.init_node_map(|_, _| None);
+        let mut visited = graph.init_node_map(|_, _| false);
+        visited[source] = true;
+        queue.clear();
+        queue.push_back(source);
+
+        while let Some(node) = queue.pop_front() {
+            if node == sink {
+                break;
+            }
+            for edge in graph.outgoing_edge_indices(node) {
+                let [from, to] = graph.endpoints(edge);
+                if from != to && !visited[to] && flow[edge] < capacities[edge] {
+                    visited[to] = true;
+                    parent[to] = Some((edge, true));
+                    queue.push_back(to);
+                }
+            }
+            for edge in graph.incoming_edge_indices(node) {
+                let [from, to] = graph.endpoints(edge);
+                if from != to && !visited[from] && flow[edge] > 0 {
+                    visited[from] = true;
+                    parent[from] = Some((edge, false));
+                    queue.push_back(from);
+                }
+            }
+        }
+
+        if !visited[sink] {
+            break;
+        }
+
+        // Find the bottleneck of the augmenting path
+        let mut bottleneck = u64::MAX;
+        let mut node = sink;
+        while let Some((edge, forward)) = parent[node] {
+            let [from, to] = graph.endpoints(edge);
+            if forward {
+                bottleneck = bottleneck.min(capacities[edge] - flow[edge]);
+                node = from;
+            } else {
+                bottleneck = bottleneck.min(flow[edge]);
+                node = to;
+            }
+        }
+
+        // Push the bottleneck along the path
+        let mut node = sink;
+        while let Some((edge, forward)) = parent[node] {
+            let [from, to] = graph.endpoints(edge);
+            if forward {
+                flow[edge] += bottleneck;
+                node = from;
+            } else {
+                flow[edge] -= bottleneck;
+                node = to;
+            }
+        }
+
+        total = total
+            .checked_add(bottleneck)
+            .expect("maximum flow value overflows u64");
+    }
+
+    (total, flow)
+}
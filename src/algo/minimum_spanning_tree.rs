@@ -0,0 +1,71 @@
+use crate::algo::disjoint_set;
+use crate::prelude::*;
+
+/// Finds a minimum spanning forest using Kruskal's algorithm.
+///
+/// Edge directions are ignored, so the graph is treated as undirected. If the graph is
+/// not connected the result is a spanning forest with one tree per weakly connected
+/// component. Self-loops are never part of the result.
+///
+/// Edges with equal weights are considered in order of their edge index, so when
+/// several minimum spanning trees exist the one preferring lower edge indices is
+/// returned.
+///
+/// # Algorithm Details
+///
+/// Edges are sorted by weight and added one at a time unless they would close a cycle,
+/// which is detected with a [`DisjointSet`](crate::algo::DisjointSet) over the nodes.
+///
+/// - **Time Complexity**: O(E log E + V) where V is the number of vertices and E is the number of edges
+/// - **Space Complexity**: O(V + E)
+///
+/// # Parameters
+///
+/// - `graph`: The graph to span
+/// - `weight`: Returns the weight of an edge
+///
+/// # Returns
+///
+/// The edges of the spanning forest, in the order they were chosen (non-decreasing
+/// weight). It contains V - C edges, where C is the number of weakly connected components.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::minimum_spanning_tree;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, u32> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// let c = graph.add_node("C");
+/// let ab = graph.add_edge(1, a, b);
+/// let bc = graph.add_edge(2, b, c);
+/// graph.add_edge(3, c, a);
+///
+/// assert_eq!(minimum_spanning_tree(&graph, |&w| w), vec![ab, bc]);
+/// ```
+pub fn minimum_spanning_tree<G, F, W>(graph: &G, weight: F) -> Vec<G::EdgeIx>
+where
+    G: Graph,
+    F: Fn(&G::Edge) -> W,
+    W: Ord,
+{
+    let mut edges: Vec<_> = graph
+        .edge_pairs()
+        .map(|(ix, edge)| (weight(edge), ix))
+        .collect();
+    edges.sort();
+
+    let mut sets = disjoint_set(graph);
+    let mut tree = Vec::with_capacity(graph.len_nodes().saturating_sub(1));
+
+    for (_, ix) in edges {
+        let [from, to] = graph.endpoints(ix);
+        if sets.union(from, to) {
+            tree.push(ix);
+        }
+    }
+
+    tree
+}
@@ -3,7 +3,64 @@
 //! This module contains various graph algorithms implemented with safe, zero-cost abstractions.
 //! All algorithms work with any type implementing the `Graph` trait.
 
+/// A* shortest path search guided by a heuristic.
+pub mod astar;
+/// Bellman-Ford single-source shortest paths with negative edge weights.
+pub mod bellman_ford;
+/// Betweenness centrality using Brandes' algorithm.
+pub mod betweenness;
+/// Articulation points and bridges of the undirected view of a graph.
+pub mod biconnected;
+/// Bipartiteness check, two-coloring and odd cycle detection.
+pub mod bipartite;
+/// Greedy graph coloring.
+pub mod coloring;
+/// Directed cycle detection.
+pub mod cycle;
+/// Depth-first search driven by visitor callbacks.
+pub mod dfs;
+/// Union-find structure over the nodes of a graph.
+pub mod disjoint_set;
+/// All-pairs shortest paths using the Floyd-Warshall algorithm.
+pub mod floyd_warshall;
+/// Cycle detection while inserting edges one at a time.
+pub mod incremental_cycles;
+/// k-core decomposition.
+pub mod k_core;
+/// Longest (critical) paths in directed acyclic graphs.
+pub mod longest_path;
+/// Maximum flow using the Edmonds–Karp algorithm.
+pub mod max_flow;
+/// Minimum spanning forest using Kruskal's algorithm.
+pub mod minimum_spanning_tree;
+/// PageRank centrality using the power method.
+pub mod pagerank;
+/// Structural equality of graphs up to renumbering of indices.
+pub mod structural_eq;
 /// Tarjan's strongly connected components algorithm.
 pub mod tarjan;
+/// Transitive closure (all-pairs reachability).
+pub mod transitive_closure;
+/// Weakly connected components using a disjoint-set forest.
+pub mod weakly_connected_components;
 
-pub use tarjan::tarjan;
+pub use astar::{astar, astar_until};
+pub use bellman_ford::{bellman_ford, NegativeCycle};
+pub use betweenness::betweenness_centrality;
+pub use biconnected::{articulation_points, bridges};
+pub use bipartite::{bipartite, odd_cycle};
+pub use coloring::greedy_coloring;
+pub use cycle::{find_cycle, has_cycle};
+pub use dfs::{depth_first_search, CycleDetector, DfsVisitor, FinishOrder};
+pub use disjoint_set::{disjoint_set, DisjointSet};
+pub use floyd_warshall::{floyd_warshall, ApspMatrix};
+pub use incremental_cycles::{CycleCreated, IncrementalCycles};
+pub use k_core::{core_numbers, k_core};
+pub use longest_path::{longest_path, longest_path_dag, longest_path_from, CycleError};
+pub use max_flow::max_flow;
+pub use minimum_spanning_tree::minimum_spanning_tree;
+pub use pagerank::pagerank;
+pub use structural_eq::is_structurally_equal;
+pub use tarjan::{tarjan, tarjan_visit};
+pub use transitive_closure::transitive_closure;
+pub use weakly_connected_components::weakly_connected_components;
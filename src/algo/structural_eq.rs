@@ -0,0 +1,254 @@
+use crate::prelude::*;
+use std::collections::HashMap;
+
+/// Dense view of a graph used while searching for a node correspondence.
+struct Indexed<'a, G: Graph> {
+    graph: &'a G,
+    nodes: Vec<G::NodeIx>,
+    // neighbours in either direction (including the node itself for self-loops), deduplicated
+    neighbours: Vec<Vec<usize>>,
+    // edges grouped by their (from, to) position pair
+    edges: HashMap<(usize, usize), Vec<&'a G::Edge>>,
+    // (out-degree, in-degree, self-loops)
+    signatures: Vec<(usize, usize, usize)>,
+}
+
+impl<'a, G: Graph> Indexed<'a, G> {
+    fn new(graph: &'a G) -> Self {
+        let nodes: Vec<_> = graph.node_indices().collect();
+        let position: HashMap<_, _> = nodes.iter().enumerate().map(|(i, &ix)| (ix, i)).collect();
+        let mut neighbours = vec![Vec::new(); nodes.len()];
+        let mut edges: HashMap<(usize, usize), Vec<&G::Edge>> = HashMap::new();
+        let mut signatures = vec![(0, 0, 0); nodes.len()];
+
+        for (edge_ix, edge) in graph.edge_pairs() {
+            let [from, to] = graph.endpoints(edge_ix);
+            let (from, to) = (position[&from], position[&to]);
+            signatures[from].0 += 1;
+            signatures[to].1 += 1;
+            if from == to {
+                signatures[from].2 += 1;
+            }
+            neighbours[from].push(to);
+            neighbours[to].push(from);
+            edges.entry((from, to)).or_default().push(edge);
+        }
+        for list in &mut neighbours {
+            list.sort_unstable();
+            list.dedup();
+        }
+
+        Indexed {
+            graph,
+            nodes,
+            neighbours,
+            edges,
+            signatures,
+        }
+    }
+
+    fn edges_between(&self, from: usize, to: usize) -> &[&'a G::Edge] {
+        self.edges
+            .get(&(from, to))
+            .map_or(&[], |edges| edges.as_slice())
+    }
+}
+
+/// Checks whether two graphs have the same structure and payloads, ignoring how their
+/// indices are assigned.
+///
+/// The graphs are considered equal if there is a one-to-one correspondence between their
+/// nodes such that corresponding nodes satisfy `node_eq`, and for every ordered pair of
+/// nodes the edges between them can be paired up one-to-one so that paired edges satisfy
+/// `edge_eq`. Edge direction and the multiplicity of parallel edges are significant.
+///
+/// This is mainly intended for tests that need to compare graphs after operations which
+/// renumber indices, such as `append` or a round-trip through a serialization format.
+///
+/// # Algorithm Details
+///
+/// Candidate nodes are first restricted to those with the same in-degree, out-degree and
+/// number of self-loops and satisfying `node_eq`. A backtracking search then assigns nodes
+/// one at a time, preferring nodes with the most already-assigned neighbours, and checks
+/// that the edges to every assigned neighbour match.
+///
+/// - **Time Complexity**: O(V² + E) when candidates are unambiguous (e.g. distinct
+///   payloads); exponential in the worst case (large groups of interchangeable nodes in
+///   highly regular graphs)
+/// - **Space Complexity**: O(V + E)
+///
+/// `node_eq` and `edge_eq` are expected to behave like equivalence relations; edges between
+/// the same pair of nodes are paired up greedily.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::is_structurally_equal;
+/// use gotgraph::prelude::*;
+///
+/// let mut a: VecGraph<&str, i32> = VecGraph::default();
+/// let x = a.add_node("x");
+/// let y = a.add_node("y");
+/// a.add_edge(1, x, y);
+///
+/// // Same graph, nodes inserted in the opposite order
+/// let mut b: VecGraph<&str, i32> = VecGraph::default();
+/// let y = b.add_node("y");
+/// let x = b.add_node("x");
+/// b.add_edge(1, x, y);
+///
+/// assert!(is_structurally_equal(&a, &b, |n1, n2| n1 == n2, |e1, e2| e1 == e2));
+/// ```
+pub fn is_structurally_equal<G1: Graph, G2: Graph>(
+    a: &G1,
+    b: &G2,
+    node_eq: impl Fn(&G1::Node, &G2::Node) -> bool,
+    edge_eq: impl Fn(&G1::Edge, &G2::Edge) -> bool,
+) -> bool {
+    if a.len_nodes() != b.len_nodes() || a.len_edges() != b.len_edges() {
+        return false;
+    }
+
+    let a = Indexed::new(a);
+    let b = Indexed::new(b);
+    let n = a.nodes.len();
+
+    // Candidate nodes of `b` for each node of `a`
+    let mut by_signature: HashMap<_, Vec<usize>> = HashMap::new();
+    for (v, &signature) in b.signatures.iter().enumerate() {
+        by_signature.entry(signature).or_default().push(v);
+    }
+    let mut candidates = Vec::with_capacity(n);
+    for u in 0..n {
+        let node = a.graph.node(a.nodes[u]);
+        let list: Vec<usize> = by_signature
+            .get(&a.signatures[u])
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(|&v| node_eq(node, b.graph.node(b.nodes[v])))
+            .collect();
+        if list.is_empty() {
+            return false;
+        }
+        candidates.push(list);
+    }
+
+    let order = search_order(&a.neighbours, &candidates);
+
+    let edges_match = |ea: &[&G1::Edge], eb: &[&G2::Edge]| {
+        if ea.len() != eb.len() {
+            return false;
+        }
+        let mut used = vec![false; eb.len()];
+        ea.iter().all(|&x| {
+            if let Some(j) = (0..eb.len()).find(|&j| !used[j] && edge_eq(x, eb[j])) {
+                used[j] = true;
+                true
+            } else {
+                false
+            }
+        })
+    };
+
+    const UNMAPPED: usize = usize::MAX;
+    let mut mapping = vec![UNMAPPED; n];
+    let mut inverse = vec![UNMAPPED; n];
+
+    // Checks that mapping `u` to `v` is consistent with the nodes mapped so far
+    let consistent = |u: usize, v: usize, mapping: &[usize], inverse: &[usize]| {
+        let mut count_a = 0;
+        for &w in &a.neighbours[u] {
+            let mw = if w == u { v } else { mapping[w] };
+            if mw == UNMAPPED {
+                continue;
+            }
+            let forward = a.edges_between(u, w);
+            if !edges_match(forward, b.edges_between(v, mw)) {
+                return false;
+            }
+            count_a += forward.len();
+            if w != u {
+                let backward = a.edges_between(w, u);
+                if !edges_match(backward, b.edges_between(mw, v)) {
+                    return false;
+                }
+                count_a += backward.len();
+            }
+        }
+
+        // `v` must not have edges to mapped nodes that `u` lacks
+        let mut count_b = 0;
+        for &x in &b.neighbours[v] {
+            if x == v {
+                count_b += b.edges_between(v, v).len();
+            } else if inverse[x] != UNMAPPED {
+                count_b += b.edges_between(v, x).len() + b.edges_between(x, v).len();
+            }
+        }
+        count_a == count_b
+    };
+
+    // Iterative backtracking over `order`
+    let mut cursor = vec![0usize; n];
+    let mut depth = 0;
+    while depth < n {
+        let u = order[depth];
+        if mapping[u] != UNMAPPED {
+            inverse[mapping[u]] = UNMAPPED;
+            mapping[u] = UNMAPPED;
+        }
+
+        let mut found = false;
+        while cursor[depth] < candidates[u].len() {
+            let v = candidates[u][cursor[depth]];
+            cursor[depth] += 1;
+            if inverse[v] == UNMAPPED && consistent(u, v, &mapping, &inverse) {
+                mapping[u] = v;
+                inverse[v] = u;
+                found = true;
+                break;
+            }
+        }
+
+        if found {
+            depth += 1;
+        } else {
+            cursor[depth] = 0;
+            if depth == 0 {
+                return false;
+            }
+            depth -= 1;
+        }
+    }
+
+    true
+}
+
+/// Orders nodes so that each node has as many previously ordered neighbours as possible,
+/// preferring nodes with fewer candidates on ties.
+fn search_order(neighbours: &[Vec<usize>], candidates: &[Vec<usize>]) -> Vec<usize> {
+    let n = neighbours.len();
+    let mut placed = vec![false; n];
+    let mut score = vec![0usize; n];
+    let mut order = Vec::with_capacity(n);
+
+    for _ in 0..n {
+        let u = (0..n)
+            .filter(|&u| !placed[u])
+            .max_by(|&x, &y| {
+                score[x]
+                    .cmp(&score[y])
+                    .then(candidates[y].len().cmp(&candidates[x].len()))
+                    .then(y.cmp(&x))
+            })
+            .expect("unplaced node should exist");
+        placed[u] = true;
+        order.push(u);
+        for &w in &neighbours[u] {
+            score[w] += 1;
+        }
+    }
+
+    order
+}
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use std::ops::ControlFlow;
 
 /// State for a node in Tarjan's algorithm
 #[derive(Debug, Clone)]
@@ -27,7 +28,10 @@ impl Default for TarjanState {
 ///
 /// - **Time Complexity**: O(V + E) where V is the number of vertices and E is the number of edges
 /// - **Space Complexity**: O(V) for the internal state and stack
-/// - **Output Order**: Components are returned in reverse topological order
+/// - **Output Order**: Components are returned in reverse topological order. Ties, and
+///   the order of the nodes within each component, are decided by the order of
+///   `node_indices` and `outgoing_edge_indices` alone, so the output is the same every
+///   time for the same graph
 ///
 /// # Parameters
 ///
@@ -39,6 +43,9 @@ impl Default for TarjanState {
 /// the node indices that form a strongly connected component. The components are
 /// yielded in reverse topological order.
 ///
+/// All components are computed before this function returns, and the iterator does not
+/// borrow the graph, so the graph can be modified while the result is still in use.
+///
 /// # Examples
 ///
 /// ```rust
@@ -81,29 +88,82 @@ impl Default for TarjanState {
 /// - The algorithm handles self-loops correctly
 /// - Empty graphs return no components
 /// - The graph can be any implementation of the `Graph` trait
-pub fn tarjan<G: Graph>(graph: G) -> impl Iterator<Item = Box<[G::NodeIx]>> {
+pub fn tarjan<G: Graph>(graph: &G) -> impl Iterator<Item = Box<[G::NodeIx]>> + use<G> {
     let mut sccs = Vec::new();
+    let _ = tarjan_visit(graph, |scc| {
+        sccs.push(Box::from(scc));
+        ControlFlow::Continue(())
+    });
+    sccs.into_iter()
+}
 
+/// Computes strongly connected components, handing each one to `on_scc` as soon as it
+/// is complete.
+///
+/// Components are visited in the same order, and with their nodes in the same order, as
+/// [`tarjan`] returns them, but nothing is allocated per component: the slice passed to
+/// `on_scc` is only borrowed for the duration of the call. Returning
+/// [`ControlFlow::Break`] stops the search immediately.
+///
+/// # Returns
+///
+/// `ControlFlow::Break(())` if `on_scc` stopped the search, `ControlFlow::Continue(())`
+/// once every component has been visited.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::tarjan_visit;
+/// use gotgraph::prelude::*;
+/// use std::ops::ControlFlow;
+///
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// let c = graph.add_node("C");
+/// graph.add_edge((), a, b);
+/// graph.add_edge((), b, a);
+/// graph.add_edge((), b, c);
+///
+/// // Find the first component with more than one node
+/// let mut found = None;
+/// let flow = tarjan_visit(&graph, |scc| {
+///     if scc.len() > 1 {
+///         found = Some(scc.len());
+///         return ControlFlow::Break(());
+///     }
+///     ControlFlow::Continue(())
+/// });
+/// assert!(flow.is_break());
+/// assert_eq!(found, Some(2));
+/// ```
+pub fn tarjan_visit<G: Graph>(
+    graph: &G,
+    mut on_scc: impl FnMut(&[G::NodeIx]) -> ControlFlow<()>,
+) -> ControlFlow<()> {
     // Single mapping to contain all node state
     let mut node_states = graph.init_node_map(|_, _| TarjanState::default());
     let mut stack = Vec::new();
     let mut index_counter = 0usize;
+    // Reused for every component handed to `on_scc`
+    let mut scc = Vec::new();
 
     // Visit each unvisited node
     for node_ix in graph.node_indices() {
         if node_states[node_ix].index.is_none() {
             visit(
-                &graph,
+                graph,
                 node_ix,
                 &mut node_states,
                 &mut stack,
                 &mut index_counter,
-                &mut sccs,
-            );
+                &mut scc,
+                &mut on_scc,
+            )?;
         }
     }
 
-    sccs.into_iter()
+    ControlFlow::Continue(())
 }
 
 /// Recursive DFS visit function for Tarjan's algorithm
@@ -113,8 +173,9 @@ fn visit<G: Graph>(
     node_states: &mut impl crate::Mapping<G::NodeIx, TarjanState>,
     stack: &mut Vec<G::NodeIx>,
     index_counter: &mut usize,
-    sccs: &mut Vec<Box<[G::NodeIx]>>,
-) {
+    scc: &mut Vec<G::NodeIx>,
+    on_scc: &mut impl FnMut(&[G::NodeIx]) -> ControlFlow<()>,
+) -> ControlFlow<()> {
     // Set the depth index for this node
     node_states[node].index = Some(*index_counter);
     node_states[node].lowlink = *index_counter;
@@ -130,7 +191,15 @@ fn visit<G: Graph>(
 
         if node_states[to_node].index.is_none() {
             // Successor has not yet been visited; recurse on it
-            visit(graph, to_node, node_states, stack, index_counter, sccs);
+            visit(
+                graph,
+                to_node,
+                node_states,
+                stack,
+                index_counter,
+                scc,
+                on_scc,
+            )?;
             // Update lowlink after visiting successor
             node_states[node].lowlink = node_states[node].lowlink.min(node_states[to_node].lowlink);
         } else if node_states[to_node].on_stack {
@@ -142,19 +211,19 @@ fn visit<G: Graph>(
         }
     }
 
-    // If node is a root node, pop the stack and create an SCC
+    // If node is a root node, pop the stack and report the SCC
     if node_states[node].lowlink == node_states[node].index.unwrap() {
-        let mut scc_nodes = Vec::new();
+        scc.clear();
         loop {
             let w = stack.pop().expect("Stack should not be empty");
             node_states[w.clone()].on_stack = false;
-            scc_nodes.push(w.clone());
-            if std::ptr::eq(&w as *const _, &node as *const _)
-                || format!("{:?}", w) == format!("{:?}", node)
-            {
+            scc.push(w.clone());
+            if w == node {
                 break;
             }
         }
-        sccs.push(scc_nodes.into_boxed_slice());
+        on_scc(scc)?;
     }
+
+    ControlFlow::Continue(())
 }
@@ -0,0 +1,89 @@
+use crate::algo::tarjan;
+use crate::prelude::*;
+use crate::Mapping;
+
+/// Computes, for every node, the set of nodes reachable from it via one or more edges.
+///
+/// A node is only included in its own set if it lies on a cycle (including a self-loop).
+///
+/// # Algorithm Details
+///
+/// The strongly connected components are computed first with
+/// [`tarjan`](fn@crate::algo::tarjan). Since they are produced in reverse topological
+/// order, the reachability of every component can be propagated from its successors in a
+/// single pass over the condensation, using one bitset per component.
+///
+/// - **Time Complexity**: O(V + E + E · C / 64 + V²) where C is the number of strongly
+///   connected components; the V² term is the size of the output
+/// - **Space Complexity**: O(C² / 64) for the bitsets plus the size of the output
+///
+/// # Returns
+///
+/// A mapping from each node to the nodes reachable from it, in the order of
+/// `node_indices`.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::transitive_closure;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// let c = graph.add_node("C");
+/// graph.add_edge((), a, b);
+/// graph.add_edge((), b, c);
+///
+/// let closure = transitive_closure(&graph);
+/// assert_eq!(closure[a], vec![b, c]);
+/// assert_eq!(closure[b], vec![c]);
+/// assert!(closure[c].is_empty());
+/// ```
+pub fn transitive_closure<G: Graph>(
+    graph: &G,
+) -> impl Mapping<G::NodeIx, Vec<G::NodeIx>> + use<'_, G> {
+    const UNASSIGNED: usize = usize::MAX;
+
+    let sccs: Vec<_> = tarjan(graph).collect();
+    let words = sccs.len().div_ceil(64);
+
+    let mut component = graph.init_node_map(|_, _| UNASSIGNED);
+    for (c, scc) in sccs.iter().enumerate() {
+        for &node in scc.iter() {
+            component[node] = c;
+        }
+    }
+
+    // Components are in reverse topological order, so every successor of a component
+    // has a smaller number and is complete by the time it is needed
+    let mut reach = vec![0u64; sccs.len() * words];
+    for (c, scc) in sccs.iter().enumerate() {
+        let (done, rest) = reach.split_at_mut(c * words);
+        let current = &mut rest[..words];
+        for &node in scc.iter() {
+            for edge in graph.outgoing_edge_indices(node) {
+                let [_, to] = graph.endpoints(edge);
+                let d = component[to];
+                current[d / 64] |= 1 << (d % 64);
+                if d != c {
+                    for (word, &other) in current.iter_mut().zip(&done[d * words..(d + 1) * words])
+                    {
+                        *word |= other;
+                    }
+                }
+            }
+        }
+    }
+
+    graph.init_node_map(move |node, _| {
+        let bits = &reach[component[node] * words..(component[node] + 1) * words];
+        graph
+            .node_indices()
+            .filter(|&other| {
+                let d = component[other];
+                bits[d / 64] & (1 << (d % 64)) != 0
+            })
+            .collect()
+    })
+}
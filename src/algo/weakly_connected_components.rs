@@ -0,0 +1,64 @@
+use crate::algo::disjoint_set;
+use crate::prelude::*;
+use std::collections::HashMap;
+
+/// Finds the weakly connected components of a graph.
+///
+/// Two nodes are in the same weakly connected component if they are connected by a path
+/// when edge directions are ignored. Unlike running an SCC algorithm on an undirected
+/// view of the graph, this only needs a single pass over the edges.
+///
+/// # Algorithm Details
+///
+/// Every edge merges the sets of its endpoints in a
+/// [`DisjointSet`](crate::algo::DisjointSet), then the nodes are grouped by the
+/// representative of their set.
+///
+/// - **Time Complexity**: O((V + E) · α(V)) plus O(V log V) for sorting the output,
+///   where α is the inverse Ackermann function
+/// - **Space Complexity**: O(V)
+///
+/// # Returns
+///
+/// An iterator over the components. The nodes of each component are sorted by index,
+/// and components are ordered by their smallest node index. Isolated nodes form
+/// components of their own.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::weakly_connected_components;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// let c = graph.add_node("C");
+/// graph.add_edge((), b, a);
+///
+/// let components: Vec<_> = weakly_connected_components(&graph).collect();
+/// assert_eq!(components, vec![vec![a, b].into(), vec![c].into()]);
+/// ```
+pub fn weakly_connected_components<G: Graph>(graph: &G) -> impl Iterator<Item = Box<[G::NodeIx]>> {
+    let mut sets = disjoint_set(graph);
+    for ix in graph.edge_indices() {
+        let [from, to] = graph.endpoints(ix);
+        sets.union(from, to);
+    }
+
+    let mut nodes: Vec<_> = graph.node_indices().collect();
+    nodes.sort_unstable();
+
+    let mut position = HashMap::new();
+    let mut components: Vec<Vec<G::NodeIx>> = Vec::new();
+    for node in nodes {
+        let root = sets.find(node);
+        let i = *position.entry(root).or_insert_with(|| {
+            components.push(Vec::new());
+            components.len() - 1
+        });
+        components[i].push(node);
+    }
+
+    components.into_iter().map(Vec::into_boxed_slice)
+}
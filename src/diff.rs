@@ -0,0 +1,206 @@
+use crate::graph::Graph;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// Identifies an edge independently of its index.
+///
+/// Edges are matched between two graphs by the keys of their endpoints. Parallel edges
+/// between the same pair of nodes are told apart by `occurrence`, which counts the edges
+/// with the same endpoints in the order they are yielded by [`Graph::edge_indices`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EdgeKey<K> {
+    /// Key of the source node.
+    pub source: K,
+    /// Key of the target node.
+    pub target: K,
+    /// Position of this edge among the edges from `source` to `target`, starting at 0.
+    pub occurrence: usize,
+}
+
+/// Differences between two graphs, as computed by [`diff`].
+///
+/// Payloads are borrowed from the graphs that were compared. Removed and modified entries
+/// are listed in the iteration order of the old graph, added entries in the iteration
+/// order of the new graph.
+#[derive(Debug, Clone)]
+pub struct GraphDiff<'a, K, N, E> {
+    /// Nodes that only exist in the new graph.
+    pub added_nodes: Vec<(K, &'a N)>,
+    /// Nodes that only exist in the old graph.
+    pub removed_nodes: Vec<(K, &'a N)>,
+    /// Nodes present in both graphs whose payloads changed, as `(key, old, new)`.
+    pub modified_nodes: Vec<(K, &'a N, &'a N)>,
+    /// Edges that only exist in the new graph.
+    pub added_edges: Vec<(EdgeKey<K>, &'a E)>,
+    /// Edges that only exist in the old graph.
+    pub removed_edges: Vec<(EdgeKey<K>, &'a E)>,
+    /// Edges present in both graphs whose payloads changed, as `(key, old, new)`.
+    pub modified_edges: Vec<(EdgeKey<K>, &'a E, &'a E)>,
+}
+
+impl<K, N, E> GraphDiff<'_, K, N, E> {
+    /// Returns `true` if the two graphs had no differences.
+    pub fn is_empty(&self) -> bool {
+        self.added_nodes.is_empty()
+            && self.removed_nodes.is_empty()
+            && self.modified_nodes.is_empty()
+            && self.added_edges.is_empty()
+            && self.removed_edges.is_empty()
+            && self.modified_edges.is_empty()
+    }
+}
+
+/// Nodes and edges of a graph, keyed so they can be matched against another graph.
+struct Keyed<'a, K, G: Graph> {
+    nodes: Vec<(K, &'a G::Node)>,
+    edges: Vec<(EdgeKey<K>, &'a G::Edge)>,
+}
+
+impl<'a, K: Clone + Eq + Hash, G: Graph> Keyed<'a, K, G> {
+    fn new(graph: &'a G, node_key: &impl Fn(&G::Node) -> K) -> Self {
+        let mut keys = HashMap::with_capacity(graph.len_nodes());
+        let mut seen = HashSet::with_capacity(graph.len_nodes());
+        let mut nodes = Vec::with_capacity(graph.len_nodes());
+        for (ix, node) in graph.node_pairs() {
+            let key = node_key(node);
+            assert!(
+                seen.insert(key.clone()),
+                "Two nodes of the same graph have the same key"
+            );
+            keys.insert(ix, key.clone());
+            nodes.push((key, node));
+        }
+
+        let mut occurrences: HashMap<(K, K), usize> = HashMap::new();
+        let edges = graph
+            .edge_pairs()
+            .map(|(ix, edge)| {
+                let [from, to] = graph.endpoints(ix);
+                let (source, target) = (keys[&from].clone(), keys[&to].clone());
+                let count = occurrences
+                    .entry((source.clone(), target.clone()))
+                    .or_insert(0);
+                let occurrence = *count;
+                *count += 1;
+                let key = EdgeKey {
+                    source,
+                    target,
+                    occurrence,
+                };
+                (key, edge)
+            })
+            .collect();
+
+        Keyed { nodes, edges }
+    }
+}
+
+/// Entries of two graphs matched up by key.
+struct Matched<'a, K, T> {
+    removed: Vec<(K, &'a T)>,
+    modified: Vec<(K, &'a T, &'a T)>,
+    added: Vec<(K, &'a T)>,
+}
+
+/// Matches the entries of `old` and `new` by key.
+fn compare<'a, K: Clone + Eq + Hash, T>(
+    old: Vec<(K, &'a T)>,
+    new: Vec<(K, &'a T)>,
+    changed: impl Fn(&T, &T) -> bool,
+) -> Matched<'a, K, T> {
+    let mut remaining: HashMap<K, &T> = new.iter().map(|(k, v)| (k.clone(), *v)).collect();
+    let mut removed = Vec::new();
+    let mut modified = Vec::new();
+    for (key, before) in old {
+        match remaining.remove(&key) {
+            Some(after) => {
+                if changed(before, after) {
+                    modified.push((key, before, after));
+                }
+            }
+            None => removed.push((key, before)),
+        }
+    }
+    let added = new
+        .into_iter()
+        .filter(|(key, _)| remaining.contains_key(key))
+        .collect();
+    Matched {
+        removed,
+        modified,
+        added,
+    }
+}
+
+/// Computes the differences between two snapshots of a graph.
+///
+/// Indices are generally not stable when a graph is rebuilt, so nodes are matched by the
+/// key returned from `node_key`, which must be unique within each graph. Edges are matched
+/// by an [`EdgeKey`] built from the keys of their endpoints, so a change in the number of
+/// parallel edges between two nodes shows up as added or removed edges.
+///
+/// Nodes and edges that appear in both graphs are reported as modified when
+/// `node_changed` or `edge_changed` returns `true` for their old and new payloads.
+///
+/// # Algorithm Details
+///
+/// - **Time Complexity**: O(V + E) expected, using hash maps keyed by node keys
+/// - **Space Complexity**: O(V + E)
+///
+/// # Panics
+///
+/// Panics if two nodes of the same graph have the same key.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::diff::{diff, EdgeKey};
+/// use gotgraph::prelude::*;
+///
+/// let mut before: VecGraph<&str, f64> = VecGraph::default();
+/// let a = before.add_node("a");
+/// let b = before.add_node("b");
+/// before.add_edge(1.0, a, b);
+///
+/// let mut after: VecGraph<&str, f64> = VecGraph::default();
+/// let b = after.add_node("b");
+/// let a = after.add_node("a");
+/// let c = after.add_node("c");
+/// after.add_edge(2.5, a, b);
+/// after.add_edge(1.0, b, c);
+///
+/// let changes = diff(&before, &after, |&n| n, |x, y| x != y, |x, y| x != y);
+/// assert!(changes.removed_nodes.is_empty());
+/// assert_eq!(changes.added_nodes, vec![("c", &"c")]);
+/// assert_eq!(
+///     changes.modified_edges,
+///     vec![(EdgeKey { source: "a", target: "b", occurrence: 0 }, &1.0, &2.5)]
+/// );
+/// assert_eq!(changes.added_edges.len(), 1);
+/// ```
+pub fn diff<'a, G, K>(
+    a: &'a G,
+    b: &'a G,
+    node_key: impl Fn(&G::Node) -> K,
+    node_changed: impl Fn(&G::Node, &G::Node) -> bool,
+    edge_changed: impl Fn(&G::Edge, &G::Edge) -> bool,
+) -> GraphDiff<'a, K, G::Node, G::Edge>
+where
+    G: Graph,
+    K: Clone + Eq + Hash,
+{
+    let old = Keyed::new(a, &node_key);
+    let new = Keyed::new(b, &node_key);
+
+    let nodes = compare(old.nodes, new.nodes, node_changed);
+    let edges = compare(old.edges, new.edges, edge_changed);
+
+    GraphDiff {
+        added_nodes: nodes.added,
+        removed_nodes: nodes.removed,
+        modified_nodes: nodes.modified,
+        added_edges: edges.added,
+        removed_edges: edges.removed,
+        modified_edges: edges.modified,
+    }
+}
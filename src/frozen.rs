@@ -0,0 +1,209 @@
+use crate::graph::{Graph, GraphMemory, IndexConversion, NotAContext};
+use crate::vec_graph::{EdgeIx, IndexType, NodeIx, VecGraph};
+use crate::Mapping;
+use std::sync::Arc;
+
+/// An immutable `VecGraph` meant to be shared between threads.
+///
+/// Created with [`VecGraph::freeze`], which moves the graph behind an [`Arc`]. A
+/// `FrozenGraph` only implements the read-only [`Graph`] trait and has no interior
+/// mutability of its own, so it is `Send + Sync` whenever the payloads are, and any
+/// number of threads can traverse it, run algorithms on it or open [`Graph::scope`]s
+/// on it at the same time. [`FrozenGraph::thaw`] turns the last handle back into a
+/// mutable `VecGraph`.
+///
+/// Node and edge indices are the ones of the frozen `VecGraph` and stay valid after
+/// thawing.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::frozen::FrozenGraph;
+/// use gotgraph::prelude::*;
+/// use std::sync::Arc;
+///
+/// fn assert_send_sync<T: Send + Sync>() {}
+/// assert_send_sync::<FrozenGraph<i32, i32>>();
+///
+/// let mut graph: VecGraph<i32, i32> = VecGraph::default();
+/// let a = graph.add_node(1);
+/// let b = graph.add_node(2);
+/// graph.add_edge(3, a, b);
+///
+/// let frozen = graph.freeze();
+/// let reader = Arc::clone(&frozen);
+/// let sum = std::thread::spawn(move || reader.nodes().sum::<i32>())
+///     .join()
+///     .unwrap();
+/// assert_eq!(sum, 3);
+///
+/// let mut graph = FrozenGraph::thaw(frozen).unwrap();
+/// graph.add_node(4);
+/// assert_eq!(graph.len_nodes(), 3);
+/// ```
+#[derive(Debug)]
+pub struct FrozenGraph<N, E, Ix = u32>(VecGraph<N, E, Ix>);
+
+unsafe impl<N, E, Ix> NotAContext for FrozenGraph<N, E, Ix> {}
+
+impl<N, E, Ix: IndexType> VecGraph<N, E, Ix> {
+    /// Makes the graph immutable and shareable between threads.
+    ///
+    /// See [`FrozenGraph`].
+    pub fn freeze(self) -> Arc<FrozenGraph<N, E, Ix>> {
+        Arc::new(FrozenGraph(self))
+    }
+}
+
+impl<N, E, Ix> FrozenGraph<N, E, Ix> {
+    /// Returns the mutable graph if `this` is the only handle to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `this` unchanged if other `Arc`s to the graph still exist.
+    pub fn thaw(this: Arc<Self>) -> Result<VecGraph<N, E, Ix>, Arc<Self>> {
+        Arc::try_unwrap(this).map(|frozen| frozen.0)
+    }
+
+    /// Returns the frozen graph, for its read-only `VecGraph` methods.
+    pub fn as_vec_graph(&self) -> &VecGraph<N, E, Ix> {
+        &self.0
+    }
+}
+
+impl<N, E, Ix: IndexType> Graph for FrozenGraph<N, E, Ix> {
+    type Node = N;
+    type Edge = E;
+    type NodeIx = NodeIx<Ix>;
+    type EdgeIx = EdgeIx<Ix>;
+
+    fn exists_node_index(&self, ix: Self::NodeIx) -> bool {
+        self.0.exists_node_index(ix)
+    }
+
+    fn exists_edge_index(&self, ix: Self::EdgeIx) -> bool {
+        self.0.exists_edge_index(ix)
+    }
+
+    unsafe fn node_unchecked(&self, ix: Self::NodeIx) -> &Self::Node {
+        self.0.node_unchecked(ix)
+    }
+
+    unsafe fn edge_unchecked(&self, ix: Self::EdgeIx) -> &Self::Edge {
+        self.0.edge_unchecked(ix)
+    }
+
+    fn node_indices(&self) -> impl Iterator<Item = Self::NodeIx> {
+        self.0.node_indices()
+    }
+
+    fn edge_indices(&self) -> impl Iterator<Item = Self::EdgeIx> {
+        self.0.edge_indices()
+    }
+
+    fn len_nodes(&self) -> usize {
+        self.0.len_nodes()
+    }
+
+    fn len_edges(&self) -> usize {
+        self.0.len_edges()
+    }
+
+    fn memory_footprint(&self) -> GraphMemory {
+        self.0.memory_footprint()
+    }
+
+    fn index_generation(&self) -> Option<u64> {
+        self.0.index_generation()
+    }
+
+    unsafe fn outgoing_edge_indices_unchecked(
+        &self,
+        node: Self::NodeIx,
+    ) -> impl Iterator<Item = Self::EdgeIx> {
+        self.0.outgoing_edge_indices_unchecked(node)
+    }
+
+    unsafe fn incoming_edge_indices_unchecked(
+        &self,
+        node: Self::NodeIx,
+    ) -> impl Iterator<Item = Self::EdgeIx> {
+        self.0.incoming_edge_indices_unchecked(node)
+    }
+
+    unsafe fn endpoints_unchecked(&self, edge: Self::EdgeIx) -> [Self::NodeIx; 2] {
+        self.0.endpoints_unchecked(edge)
+    }
+
+    unsafe fn outgoing_edge_pairs_unchecked(
+        &self,
+        node: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge)> {
+        self.0.outgoing_edge_pairs_unchecked(node)
+    }
+
+    unsafe fn incoming_edge_pairs_unchecked(
+        &self,
+        node: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge)> {
+        self.0.incoming_edge_pairs_unchecked(node)
+    }
+
+    fn outgoing_targets(
+        &self,
+        node: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge, Self::NodeIx, &Self::Node)> {
+        self.0.outgoing_targets(node)
+    }
+
+    fn incoming_sources(
+        &self,
+        node: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge, Self::NodeIx, &Self::Node)> {
+        self.0.incoming_sources(node)
+    }
+
+    fn for_each_outgoing(&self, f: impl FnMut(Self::NodeIx, Self::EdgeIx, &Self::Edge)) {
+        self.0.for_each_outgoing(f)
+    }
+
+    fn fold_edges<B>(
+        &self,
+        init: B,
+        f: impl FnMut(B, Self::NodeIx, Self::EdgeIx, &Self::Edge) -> B,
+    ) -> B {
+        self.0.fold_edges(init, f)
+    }
+
+    fn init_node_map<V>(
+        &self,
+        f: impl FnMut(Self::NodeIx, &Self::Node) -> V,
+    ) -> impl Mapping<Self::NodeIx, V> {
+        self.0.init_node_map(f)
+    }
+
+    fn init_edge_map<V>(
+        &self,
+        f: impl FnMut(Self::EdgeIx, &Self::Edge) -> V,
+    ) -> impl Mapping<Self::EdgeIx, V> {
+        self.0.init_edge_map(f)
+    }
+}
+
+impl<N, E, Ix: IndexType> IndexConversion for FrozenGraph<N, E, Ix> {
+    fn node_to_usize(ix: NodeIx<Ix>) -> usize {
+        VecGraph::<N, E, Ix>::node_to_usize(ix)
+    }
+
+    fn node_from_usize(ix: usize) -> Option<NodeIx<Ix>> {
+        VecGraph::<N, E, Ix>::node_from_usize(ix)
+    }
+
+    fn edge_to_usize(ix: EdgeIx<Ix>) -> usize {
+        VecGraph::<N, E, Ix>::edge_to_usize(ix)
+    }
+
+    fn edge_from_usize(ix: usize) -> Option<EdgeIx<Ix>> {
+        VecGraph::<N, E, Ix>::edge_from_usize(ix)
+    }
+}
@@ -1,11 +1,19 @@
 pub mod context;
+pub mod convert;
+pub mod index;
+pub mod memory;
+pub mod mutable;
 pub mod remove;
 pub mod update;
 
 use crate::Mapping;
-pub use context::{Context, EdgeTag, NodeTag};
+pub use context::{Context, EdgeTag, NodeTag, NotAContext};
+pub use convert::clone_into;
+pub use index::{IndexConversion, InvalidIndex};
+pub use memory::GraphMemory;
+pub use mutable::GraphMut;
 pub use remove::{GraphRemove, GraphRemoveEdge};
-pub use update::GraphUpdate;
+pub use update::{CapacityError, GraphUpdate};
 
 /// The core trait defining the interface for all graph types.
 ///
@@ -91,7 +99,13 @@ pub trait Graph {
 
     /// Returns an iterator over all node indices in the graph.
     ///
-    /// The order of iteration is implementation-defined.
+    /// The order of iteration is implementation-defined, but it must be deterministic:
+    /// calling this again on the same, unmodified graph yields the same sequence, without
+    /// depending on hash seeds or other state outside the graph. The same holds for
+    /// [`Graph::edge_indices`] and the adjacency iterators such as
+    /// [`Graph::outgoing_edge_indices`]. Algorithms in this crate visit nodes and edges
+    /// only in these orders, so their output is reproducible. `VecGraph` yields indices in
+    /// ascending order.
     ///
     /// # Examples
     ///
@@ -111,7 +125,8 @@ pub trait Graph {
 
     /// Returns an iterator over all edge indices in the graph.
     ///
-    /// The order of iteration is implementation-defined.
+    /// The order of iteration is implementation-defined but deterministic, as described
+    /// for [`Graph::node_indices`].
     fn edge_indices(&self) -> impl Iterator<Item = Self::EdgeIx>;
     /// Returns an iterator over the indices of edges originating from the specified node.
     ///
@@ -306,6 +321,68 @@ pub trait Graph {
             .chain(self.incoming_edge_pairs_unchecked(tag))
     }
 
+    /// Returns an iterator over the outgoing edges of a node together with their targets.
+    ///
+    /// Each item holds the index and data of an edge followed by the index and data of the
+    /// node it points to. A self-loop yields the node itself as its target, and parallel
+    /// edges yield the same target once per edge.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node index does not exist in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, u32> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let ab = graph.add_edge(7, a, b);
+    ///
+    /// let targets: Vec<_> = graph.outgoing_targets(a).collect();
+    /// assert_eq!(targets, vec![(ab, &7, b, &"B")]);
+    /// ```
+    fn outgoing_targets(
+        &self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge, Self::NodeIx, &Self::Node)> {
+        assert!(
+            self.exists_node_index(tag),
+            "Node index {:?} does not exist",
+            tag
+        );
+        unsafe { self.outgoing_edge_pairs_unchecked(tag) }.map(move |(ix, edge)| {
+            let [_, to] = unsafe { self.endpoints_unchecked(ix) };
+            (ix, edge, to, unsafe { self.node_unchecked(to) })
+        })
+    }
+
+    /// Returns an iterator over the incoming edges of a node together with their sources.
+    ///
+    /// This is [`Graph::outgoing_targets`] following incoming edges instead: each item
+    /// holds the index and data of an edge followed by the index and data of the node it
+    /// starts at.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node index does not exist in the graph.
+    fn incoming_sources(
+        &self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge, Self::NodeIx, &Self::Node)> {
+        assert!(
+            self.exists_node_index(tag),
+            "Node index {:?} does not exist",
+            tag
+        );
+        unsafe { self.incoming_edge_pairs_unchecked(tag) }.map(move |(ix, edge)| {
+            let [from, _] = unsafe { self.endpoints_unchecked(ix) };
+            (ix, edge, from, unsafe { self.node_unchecked(from) })
+        })
+    }
+
     fn node(&self, tag: Self::NodeIx) -> &Self::Node {
         assert!(
             self.exists_node_index(tag),
@@ -328,6 +405,46 @@ pub trait Graph {
 
     unsafe fn edge_unchecked(&self, tag: Self::EdgeIx) -> &Self::Edge;
 
+    /// Returns a reference to the data of a node, or an error if the index is invalid.
+    ///
+    /// This is the non-panicking counterpart of [`Graph::node`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidIndex`] holding `tag` if the node does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// assert_eq!(graph.try_node(a), Ok(&"A"));
+    /// ```
+    fn try_node(&self, tag: Self::NodeIx) -> Result<&Self::Node, InvalidIndex<Self::NodeIx>> {
+        if self.exists_node_index(tag) {
+            Ok(unsafe { self.node_unchecked(tag) })
+        } else {
+            Err(InvalidIndex::new(tag))
+        }
+    }
+
+    /// Returns a reference to the data of an edge, or an error if the index is invalid.
+    ///
+    /// This is the non-panicking counterpart of [`Graph::edge`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidIndex`] holding `tag` if the edge does not exist.
+    fn try_edge(&self, tag: Self::EdgeIx) -> Result<&Self::Edge, InvalidIndex<Self::EdgeIx>> {
+        if self.exists_edge_index(tag) {
+            Ok(unsafe { self.edge_unchecked(tag) })
+        } else {
+            Err(InvalidIndex::new(tag))
+        }
+    }
+
     fn endpoints(&self, tag: Self::EdgeIx) -> [Self::NodeIx; 2] {
         assert!(
             self.exists_edge_index(tag),
@@ -339,6 +456,60 @@ pub trait Graph {
 
     unsafe fn endpoints_unchecked(&self, ix: Self::EdgeIx) -> [Self::NodeIx; 2];
 
+    /// Returns the payloads of the source and target nodes of an edge.
+    ///
+    /// This is a shorthand for looking up [`Graph::endpoints`] and then calling
+    /// [`Graph::node`] on both of them. For a self-loop both references point to the same
+    /// node, which is fine since they are shared references.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the edge index does not exist in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let ab = graph.add_edge((), a, b);
+    ///
+    /// assert_eq!(graph.endpoint_data(ab), (&"A", &"B"));
+    /// ```
+    fn endpoint_data(&self, tag: Self::EdgeIx) -> (&Self::Node, &Self::Node) {
+        let [from, to] = self.endpoints(tag);
+        unsafe { (self.node_unchecked(from), self.node_unchecked(to)) }
+    }
+
+    /// Returns an edge going from `from` to `to`, if there is one.
+    ///
+    /// The outgoing edges of `from` are scanned in order and the first one ending at
+    /// `to` is returned, so the cost is linear in the out-degree of `from`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` does not exist in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let ab = graph.add_edge((), a, b);
+    ///
+    /// assert_eq!(graph.find_edge(a, b), Some(ab));
+    /// assert_eq!(graph.find_edge(b, a), None);
+    /// ```
+    fn find_edge(&self, from: Self::NodeIx, to: Self::NodeIx) -> Option<Self::EdgeIx> {
+        self.outgoing_edge_indices(from)
+            .find(|&edge| unsafe { self.endpoints_unchecked(edge) }[1] == to)
+    }
+
     fn nodes(&self) -> impl Iterator<Item = &Self::Node> {
         self.node_pairs().map(|(_, node)| node)
     }
@@ -357,6 +528,170 @@ pub trait Graph {
             .map(move |edge_ix| (edge_ix, unsafe { self.edge_unchecked(edge_ix) }))
     }
 
+    /// Returns an iterator over every edge together with its endpoints and data, in the
+    /// order of [`Graph::edge_indices`].
+    ///
+    /// This saves the bounds check of calling [`Graph::endpoints`] on every item of
+    /// [`Graph::edge_pairs`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, u32> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let ab = graph.add_edge(7, a, b);
+    ///
+    /// assert_eq!(graph.edge_triples().collect::<Vec<_>>(), vec![(ab, [a, b], &7)]);
+    /// ```
+    fn edge_triples(&self) -> impl Iterator<Item = (Self::EdgeIx, [Self::NodeIx; 2], &Self::Edge)> {
+        self.edge_indices().map(move |edge_ix| unsafe {
+            (
+                edge_ix,
+                self.endpoints_unchecked(edge_ix),
+                self.edge_unchecked(edge_ix),
+            )
+        })
+    }
+
+    /// Calls `f` with the source node, index and data of every edge.
+    ///
+    /// The visiting order is implementation-defined: the default implementation walks the
+    /// outgoing edges of each node in turn, while `VecGraph` makes a single pass over its
+    /// edge storage, which avoids the per-node adjacency iterators.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<u32, u32> = VecGraph::default();
+    /// let a = graph.add_node(1);
+    /// let b = graph.add_node(2);
+    /// graph.add_edge(10, a, b);
+    /// graph.add_edge(20, b, a);
+    ///
+    /// let mut total = 0;
+    /// graph.for_each_outgoing(|from, _, &edge| total += graph.node(from) * edge);
+    /// assert_eq!(total, 1 * 10 + 2 * 20);
+    /// ```
+    fn for_each_outgoing(&self, mut f: impl FnMut(Self::NodeIx, Self::EdgeIx, &Self::Edge)) {
+        for node in self.node_indices() {
+            for (edge_ix, edge) in unsafe { self.outgoing_edge_pairs_unchecked(node) } {
+                f(node, edge_ix, edge);
+            }
+        }
+    }
+
+    /// Folds every edge into an accumulator, passing its source node, index and data.
+    ///
+    /// Edges are visited in an implementation-defined order, as with
+    /// [`Graph::for_each_outgoing`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<(), u32> = VecGraph::default();
+    /// let a = graph.add_node(());
+    /// let b = graph.add_node(());
+    /// graph.add_edge(3, a, b);
+    /// graph.add_edge(4, a, a);
+    ///
+    /// let from_a = graph.fold_edges(0, |acc, from, _, &w| if from == a { acc + w } else { acc });
+    /// assert_eq!(from_a, 7);
+    /// ```
+    fn fold_edges<B>(
+        &self,
+        init: B,
+        mut f: impl FnMut(B, Self::NodeIx, Self::EdgeIx, &Self::Edge) -> B,
+    ) -> B {
+        self.edge_triples()
+            .fold(init, |acc, (edge_ix, [from, _], edge)| {
+                f(acc, from, edge_ix, edge)
+            })
+    }
+
+    /// Returns an iterator over the indices of the nodes whose data satisfies `f`, in
+    /// the order of [`Graph::node_indices`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<i32, ()> = VecGraph::default();
+    /// let a = graph.add_node(1);
+    /// graph.add_node(-2);
+    /// let c = graph.add_node(3);
+    ///
+    /// let positive: Vec<_> = graph.node_indices_where(|&n| n > 0).collect();
+    /// assert_eq!(positive, vec![a, c]);
+    /// ```
+    fn node_indices_where<F: FnMut(&Self::Node) -> bool>(
+        &self,
+        mut f: F,
+    ) -> impl Iterator<Item = Self::NodeIx> {
+        self.node_pairs()
+            .filter_map(move |(ix, node)| f(node).then_some(ix))
+    }
+
+    /// Returns an iterator over the indices of the edges whose data satisfies `f`, in
+    /// the order of [`Graph::edge_indices`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<(), u32> = VecGraph::default();
+    /// let a = graph.add_node(());
+    /// let b = graph.add_node(());
+    /// graph.add_edge(1, a, b);
+    /// let heavy = graph.add_edge(10, b, a);
+    ///
+    /// let selected: Vec<_> = graph.edge_indices_where(|&w| w > 5).collect();
+    /// assert_eq!(selected, vec![heavy]);
+    /// ```
+    fn edge_indices_where<F: FnMut(&Self::Edge) -> bool>(
+        &self,
+        mut f: F,
+    ) -> impl Iterator<Item = Self::EdgeIx> {
+        self.edge_pairs()
+            .filter_map(move |(ix, edge)| f(edge).then_some(ix))
+    }
+
+    /// Returns the node indices sorted by a key computed from the node data.
+    ///
+    /// The sort is stable, so nodes with equal keys stay in the order of
+    /// [`Graph::node_indices`]. With keys that are unique, the result does not depend on
+    /// how the graph numbers its nodes, which gives algorithms a deterministic order to
+    /// process nodes in. `f` is called once per node.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let c = graph.add_node("C");
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    ///
+    /// assert_eq!(graph.node_indices_sorted_by_key(|&name| name), vec![a, b, c]);
+    /// ```
+    fn node_indices_sorted_by_key<K: Ord, F: FnMut(&Self::Node) -> K>(
+        &self,
+        mut f: F,
+    ) -> Vec<Self::NodeIx> {
+        let mut keyed: Vec<_> = self.node_pairs().map(|(ix, node)| (f(node), ix)).collect();
+        keyed.sort_by(|(a, _), (b, _)| a.cmp(b));
+        keyed.into_iter().map(|(_, ix)| ix).collect()
+    }
+
     fn len_nodes(&self) -> usize {
         self.node_indices().count()
     }
@@ -367,432 +702,944 @@ pub trait Graph {
         self.len_nodes() == 0 && self.len_edges() == 0
     }
 
-    fn scope<
-        'graph,
-        R,
-        F: for<'scope> FnOnce(&crate::graph::context::Context<'scope, &'graph Self>) -> R,
-    >(
-        &'graph self,
-        f: F,
-    ) -> R {
-        use core::marker::PhantomData;
-        f(&crate::graph::context::Context {
-            graph: self,
-            _scope: PhantomData,
-        })
+    /// Reports how much memory the graph's node and edge storage uses.
+    ///
+    /// The default implementation only knows the number of nodes and edges, so it
+    /// assumes there is no unused capacity and estimates the sizes from the payload
+    /// types. Graph types that know their actual allocation override it; see
+    /// [`GraphMemory`] for what is counted.
+    fn memory_footprint(&self) -> GraphMemory {
+        let (len_nodes, len_edges) = (self.len_nodes(), self.len_edges());
+        GraphMemory {
+            nodes_bytes: len_nodes * core::mem::size_of::<Self::Node>(),
+            edges_bytes: len_edges * core::mem::size_of::<Self::Edge>(),
+            nodes_capacity: len_nodes,
+            edges_capacity: len_edges,
+            len_nodes,
+            len_edges,
+        }
     }
 
-    fn node_mut(&mut self, tag: Self::NodeIx) -> &mut Self::Node {
-        assert!(
-            self.exists_node_index(tag),
-            "Node index {:?} does not exist",
-            tag
-        );
-        unsafe { self.node_unchecked_mut(tag) }
+    /// Returns a counter that changes whenever existing indices may have been renumbered
+    /// or freed, or `None` if the graph does not track this.
+    ///
+    /// Adding nodes and edges leaves existing indices alone and does not change the
+    /// counter. [`IndexSnapshot`](crate::graph::context::IndexSnapshot) compares it to
+    /// detect indices that no longer refer to the node or edge they were taken for.
+    fn index_generation(&self) -> Option<u64> {
+        None
     }
 
-    unsafe fn node_unchecked_mut(&mut self, tag: Self::NodeIx) -> &mut Self::Node;
-
-    fn edge_mut(&mut self, tag: Self::EdgeIx) -> &mut Self::Edge {
-        assert!(
-            self.exists_edge_index(tag),
-            "Edge index {:?} does not exist",
-            tag
-        );
-        unsafe { self.edge_unchecked_mut(tag) }
+    /// Returns the number of edges originating from the specified node.
+    ///
+    /// A self-loop counts once. This method panics if the node index is invalid.
+    fn out_degree(&self, ix: Self::NodeIx) -> usize {
+        self.outgoing_edge_indices(ix).count()
     }
 
-    unsafe fn edge_unchecked_mut(&mut self, tag: Self::EdgeIx) -> &mut Self::Edge;
-
-    fn nodes_mut(&mut self) -> impl Iterator<Item = &mut Self::Node> + use<'_, Self>
-    where
-        Self: Sized,
-    {
-        self.node_pairs_mut().map(|(_, node)| node)
+    /// Returns the number of edges pointing to the specified node.
+    ///
+    /// A self-loop counts once. This method panics if the node index is invalid.
+    fn in_degree(&self, ix: Self::NodeIx) -> usize {
+        self.incoming_edge_indices(ix).count()
     }
 
-    fn edges_mut(&mut self) -> impl Iterator<Item = &mut Self::Edge> + use<'_, Self>
-    where
-        Self: Sized,
-    {
-        self.edge_pairs_mut().map(|(_, edge)| edge)
+    /// Returns the total number of edge endpoints at the specified node.
+    ///
+    /// This is the sum of [`Graph::in_degree`] and [`Graph::out_degree`], so a self-loop
+    /// counts twice. This method panics if the node index is invalid.
+    fn degree(&self, ix: Self::NodeIx) -> usize {
+        self.out_degree(ix) + self.in_degree(ix)
     }
 
-    fn node_pairs_mut(
-        &mut self,
-    ) -> impl Iterator<Item = (Self::NodeIx, &mut Self::Node)> + use<'_, Self>
-    where
-        Self: Sized,
-    {
-        struct NodePairsMutIter<'a, G: Graph> {
-            graph: &'a mut G,
-            indices: std::vec::IntoIter<G::NodeIx>,
-        }
-
-        impl<'a, G: Graph> Iterator for NodePairsMutIter<'a, G> {
-            type Item = (G::NodeIx, &'a mut G::Node);
-
-            fn next(&mut self) -> Option<Self::Item> {
-                self.indices.next().map(|ix| unsafe {
-                    let ptr = self.graph.node_unchecked_mut(ix) as *mut G::Node;
-                    (ix, &mut *ptr)
-                })
-            }
-        }
-
-        let indices: Vec<_> = self.node_indices().collect();
-        NodePairsMutIter {
-            graph: self,
-            indices: indices.into_iter(),
+    /// Computes the in-degree and out-degree of every node in a single pass over the edges.
+    ///
+    /// This is O(V + E), which is much cheaper than calling [`Graph::in_degree`] and
+    /// [`Graph::out_degree`] for every node when the adjacency lists are long.
+    ///
+    /// # Returns
+    ///
+    /// A mapping from each node to its `(in_degree, out_degree)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// graph.add_edge((), a, b);
+    /// graph.add_edge((), a, a);
+    ///
+    /// let degrees = graph.degree_map();
+    /// assert_eq!(degrees[a], (1, 2));
+    /// assert_eq!(degrees[b], (1, 0));
+    /// ```
+    fn degree_map(&self) -> impl Mapping<Self::NodeIx, (usize, usize)> {
+        let mut degrees = self.init_node_map(|_, _| (0, 0));
+        for ix in self.edge_indices() {
+            let [from, to] = unsafe { self.endpoints_unchecked(ix) };
+            degrees[from].1 += 1;
+            degrees[to].0 += 1;
         }
+        degrees
     }
 
-    fn edge_pairs_mut(
-        &mut self,
-    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)> + use<'_, Self>
-    where
-        Self: Sized,
-    {
-        struct EdgePairsMutIter<'a, G: Graph> {
-            graph: &'a mut G,
-            indices: std::vec::IntoIter<G::EdgeIx>,
+    /// Counts how many nodes have each degree.
+    ///
+    /// The degree of a node is its in-degree plus its out-degree as computed by
+    /// [`Graph::degree_map`], that is the number of edge endpoints at the node, so a
+    /// self-loop counts twice. Only degrees that occur are present in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let c = graph.add_node("C");
+    /// graph.add_node("D");
+    /// graph.add_edge((), a, b);
+    /// graph.add_edge((), a, c);
+    ///
+    /// let histogram = graph.degree_histogram();
+    /// assert_eq!(histogram.into_iter().collect::<Vec<_>>(), vec![(0, 1), (1, 2), (2, 1)]);
+    /// ```
+    fn degree_histogram(&self) -> std::collections::BTreeMap<usize, usize> {
+        let degrees = self.degree_map();
+        let mut histogram = std::collections::BTreeMap::new();
+        for ix in self.node_indices() {
+            let (in_degree, out_degree) = degrees[ix];
+            *histogram.entry(in_degree + out_degree).or_insert(0) += 1;
         }
+        histogram
+    }
 
-        impl<'a, G: Graph> Iterator for EdgePairsMutIter<'a, G> {
-            type Item = (G::EdgeIx, &'a mut G::Edge);
-
-            fn next(&mut self) -> Option<Self::Item> {
-                self.indices.next().map(|ix| unsafe {
-                    let ptr = self.graph.edge_unchecked_mut(ix) as *mut G::Edge;
-                    (ix, &mut *ptr)
-                })
-            }
-        }
+    /// Returns an iterator over the nodes that have no incoming edges.
+    ///
+    /// A self-loop is an incoming edge, so a node whose only edge is a self-loop is
+    /// neither a source nor a sink. In graphs where every edge is both incoming and
+    /// outgoing, such as [`UnVecGraph`](crate::undirected::UnVecGraph), the sources are
+    /// exactly the isolated nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// graph.add_edge((), a, b);
+    ///
+    /// assert_eq!(graph.source_nodes().collect::<Vec<_>>(), vec![a]);
+    /// ```
+    fn source_nodes(&self) -> impl Iterator<Item = Self::NodeIx> {
+        self.node_indices()
+            .filter(move |&ix| unsafe { self.incoming_edge_indices_unchecked(ix).next().is_none() })
+    }
 
-        let indices: Vec<_> = self.edge_indices().collect();
-        EdgePairsMutIter {
-            graph: self,
-            indices: indices.into_iter(),
-        }
+    /// Returns an iterator over the nodes that have no outgoing edges.
+    ///
+    /// As with [`Graph::source_nodes`], a node with a self-loop is never a sink.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// graph.add_edge((), a, b);
+    ///
+    /// assert_eq!(graph.sink_nodes().collect::<Vec<_>>(), vec![b]);
+    /// ```
+    fn sink_nodes(&self) -> impl Iterator<Item = Self::NodeIx> {
+        self.node_indices()
+            .filter(move |&ix| unsafe { self.outgoing_edge_indices_unchecked(ix).next().is_none() })
     }
 
-    fn outgoing_edges_mut(
-        &mut self,
-        tag: Self::NodeIx,
-    ) -> impl Iterator<Item = &mut Self::Edge> + use<'_, Self>
-    where
-        Self: Sized,
-    {
-        assert!(
-            self.exists_node_index(tag),
-            "Node index {:?} does not exist",
-            tag
-        );
-        unsafe { self.outgoing_edges_unchecked_mut(tag) }
+    /// Returns an iterator over the self-loops, the edges whose source and target are the
+    /// same node.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// graph.add_edge((), a, b);
+    /// let lp = graph.add_edge((), b, b);
+    ///
+    /// assert_eq!(graph.self_loop_edges().collect::<Vec<_>>(), vec![lp]);
+    /// ```
+    fn self_loop_edges(&self) -> impl Iterator<Item = Self::EdgeIx> {
+        self.edge_indices().filter(move |&ix| {
+            let [from, to] = unsafe { self.endpoints_unchecked(ix) };
+            from == to
+        })
     }
 
-    unsafe fn outgoing_edges_unchecked_mut(
-        &mut self,
-        tag: Self::NodeIx,
-    ) -> impl Iterator<Item = &mut Self::Edge> + use<'_, Self>
-    where
-        Self: Sized,
-    {
-        self.outgoing_edge_pairs_unchecked_mut(tag)
-            .map(|(_, edge)| edge)
+    /// Returns `true` if the graph has at least one self-loop.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// assert!(!graph.has_self_loops());
+    /// graph.add_edge((), a, a);
+    /// assert!(graph.has_self_loops());
+    /// ```
+    fn has_self_loops(&self) -> bool {
+        self.self_loop_edges().next().is_some()
     }
 
-    fn outgoing_edge_pairs_mut(
-        &mut self,
-        tag: Self::NodeIx,
-    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)> + use<'_, Self>
-    where
-        Self: Sized,
-    {
-        assert!(
-            self.exists_node_index(tag),
-            "Node index {:?} does not exist",
-            tag
-        );
-        unsafe { self.outgoing_edge_pairs_unchecked_mut(tag) }
+    /// Returns the number of edges that duplicate an edge with a lower index between the
+    /// same ordered pair of nodes.
+    ///
+    /// This is the number of edges that merging parallel edges would remove. Edges in
+    /// opposite directions are not parallel; repeated self-loops on the same node are.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// graph.add_edge((), a, b);
+    /// graph.add_edge((), a, b);
+    /// graph.add_edge((), a, b);
+    /// graph.add_edge((), b, a);
+    ///
+    /// assert_eq!(graph.count_parallel_edges(), 2);
+    /// ```
+    fn count_parallel_edges(&self) -> usize {
+        let mut seen = std::collections::HashSet::new();
+        self.edge_indices()
+            .filter(|&ix| !seen.insert(unsafe { self.endpoints_unchecked(ix) }))
+            .count()
     }
 
-    unsafe fn outgoing_edge_pairs_unchecked_mut(
-        &mut self,
-        tag: Self::NodeIx,
-    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
-    where
-        Self: Sized;
+    /// Returns `true` if the graph also has an edge in the opposite direction of `edge`.
+    ///
+    /// A self-loop is its own reverse edge, so it is always mutual.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the edge index does not exist in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let c = graph.add_node("C");
+    /// let ab = graph.add_edge((), a, b);
+    /// graph.add_edge((), b, a);
+    /// let bc = graph.add_edge((), b, c);
+    ///
+    /// assert!(graph.is_mutual(ab));
+    /// assert!(!graph.is_mutual(bc));
+    /// ```
+    fn is_mutual(&self, edge: Self::EdgeIx) -> bool {
+        let [from, to] = self.endpoints(edge);
+        self.find_edge(to, from).is_some()
+    }
 
-    fn incoming_edges_mut(
-        &mut self,
-        tag: Self::NodeIx,
-    ) -> impl Iterator<Item = &mut Self::Edge> + use<'_, Self>
-    where
-        Self: Sized,
-    {
+    /// Returns an iterator over the nodes that both `a` and `b` have an outgoing edge to.
+    ///
+    /// Every such node is yielded once, in the order of the outgoing edges of `b`, even if
+    /// there are parallel edges. Self-loops count, so `a` itself is included if it has a
+    /// self-loop and an edge from `b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node index does not exist in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let c = graph.add_node("C");
+    /// let d = graph.add_node("D");
+    /// graph.add_edge((), a, c);
+    /// graph.add_edge((), a, d);
+    /// graph.add_edge((), b, c);
+    ///
+    /// assert_eq!(graph.common_successors(a, b).collect::<Vec<_>>(), vec![c]);
+    /// ```
+    fn common_successors(
+        &self,
+        a: Self::NodeIx,
+        b: Self::NodeIx,
+    ) -> impl Iterator<Item = Self::NodeIx> {
+        let mut successors: std::collections::HashSet<_> = self
+            .outgoing_edge_indices(a)
+            .map(|edge| unsafe { self.endpoints_unchecked(edge) }[1])
+            .collect();
+        self.outgoing_edge_indices(b)
+            .map(move |edge| unsafe { self.endpoints_unchecked(edge) }[1])
+            .filter(move |next| successors.remove(next))
+    }
+
+    /// Returns an iterator over the nodes that have an outgoing edge to both `a` and `b`.
+    ///
+    /// This is [`Graph::common_successors`] following incoming edges instead: every such
+    /// node is yielded once, in the order of the incoming edges of `b`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node index does not exist in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let c = graph.add_node("C");
+    /// graph.add_edge((), c, a);
+    /// graph.add_edge((), c, b);
+    /// graph.add_edge((), a, b);
+    ///
+    /// assert_eq!(graph.common_predecessors(a, b).collect::<Vec<_>>(), vec![c]);
+    /// ```
+    fn common_predecessors(
+        &self,
+        a: Self::NodeIx,
+        b: Self::NodeIx,
+    ) -> impl Iterator<Item = Self::NodeIx> {
+        let mut predecessors: std::collections::HashSet<_> = self
+            .incoming_edge_indices(a)
+            .map(|edge| unsafe { self.endpoints_unchecked(edge) }[0])
+            .collect();
+        self.incoming_edge_indices(b)
+            .map(move |edge| unsafe { self.endpoints_unchecked(edge) }[0])
+            .filter(move |prev| predecessors.remove(prev))
+    }
+
+    /// Returns an iterator over the nodes reachable from `node` through outgoing edges.
+    ///
+    /// Every reachable node is yielded exactly once, in depth-first order. The starting
+    /// node itself is only included if it lies on a cycle, i.e. if some path (possibly a
+    /// self-loop) leads back to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node index does not exist in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let c = graph.add_node("C");
+    /// graph.add_edge((), a, b);
+    /// graph.add_edge((), b, c);
+    ///
+    /// let mut reachable: Vec<_> = graph.descendants(a).collect();
+    /// reachable.sort();
+    /// assert_eq!(reachable, vec![b, c]);
+    /// assert_eq!(graph.descendants(c).count(), 0);
+    /// ```
+    fn descendants(&self, node: Self::NodeIx) -> impl Iterator<Item = Self::NodeIx> {
         assert!(
-            self.exists_node_index(tag),
+            self.exists_node_index(node),
             "Node index {:?} does not exist",
-            tag
+            node
         );
-        unsafe { self.incoming_edges_unchecked_mut(tag) }
-    }
-
-    unsafe fn incoming_edges_unchecked_mut(
-        &mut self,
-        tag: Self::NodeIx,
-    ) -> impl Iterator<Item = &mut Self::Edge> + use<'_, Self>
-    where
-        Self: Sized,
-    {
-        self.incoming_edge_pairs_unchecked_mut(tag)
-            .map(|(_, edge)| edge)
+        let mut visited = self.init_node_map(|_, _| false);
+        let mut worklist = vec![node];
+        let mut start = true;
+        core::iter::from_fn(move || {
+            while let Some(current) = worklist.pop() {
+                for edge in unsafe { self.outgoing_edge_indices_unchecked(current) } {
+                    let [_, next] = unsafe { self.endpoints_unchecked(edge) };
+                    if !core::mem::replace(&mut visited[next], true) {
+                        worklist.push(next);
+                    }
+                }
+                // The start node is only expanded, not yielded, unless it is reached again
+                if !core::mem::take(&mut start) {
+                    return Some(current);
+                }
+            }
+            None
+        })
     }
 
-    fn incoming_edge_pairs_mut(
-        &mut self,
-        tag: Self::NodeIx,
-    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)> + use<'_, Self>
-    where
-        Self: Sized,
-    {
+    /// Returns an iterator over the nodes that can reach `node` through outgoing edges.
+    ///
+    /// This is [`Graph::descendants`] following incoming edges instead: every such node is
+    /// yielded exactly once, and the starting node is only included if it lies on a cycle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node index does not exist in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let c = graph.add_node("C");
+    /// graph.add_edge((), a, b);
+    /// graph.add_edge((), b, c);
+    /// graph.add_edge((), c, c);
+    ///
+    /// let mut reaching: Vec<_> = graph.ancestors(c).collect();
+    /// reaching.sort();
+    /// assert_eq!(reaching, vec![a, b, c]);
+    /// ```
+    fn ancestors(&self, node: Self::NodeIx) -> impl Iterator<Item = Self::NodeIx> {
         assert!(
-            self.exists_node_index(tag),
+            self.exists_node_index(node),
             "Node index {:?} does not exist",
-            tag
+            node
         );
-        unsafe { self.incoming_edge_pairs_unchecked_mut(tag) }
+        let mut visited = self.init_node_map(|_, _| false);
+        let mut worklist = vec![node];
+        let mut start = true;
+        core::iter::from_fn(move || {
+            while let Some(current) = worklist.pop() {
+                for edge in unsafe { self.incoming_edge_indices_unchecked(current) } {
+                    let [prev, _] = unsafe { self.endpoints_unchecked(edge) };
+                    if !core::mem::replace(&mut visited[prev], true) {
+                        worklist.push(prev);
+                    }
+                }
+                // The start node is only expanded, not yielded, unless it is reached again
+                if !core::mem::take(&mut start) {
+                    return Some(current);
+                }
+            }
+            None
+        })
     }
 
-    unsafe fn incoming_edge_pairs_unchecked_mut(
-        &mut self,
-        tag: Self::NodeIx,
-    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
-    where
-        Self: Sized;
+    /// Returns a mapping telling for every node whether it can be reached from any of
+    /// `sources` through outgoing edges.
+    ///
+    /// This is a breadth-first search started from all sources at once, so each node is
+    /// visited at most once however many sources reach it. The sources themselves are
+    /// always marked reachable, and listing a source more than once has no effect.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the sources does not exist in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let c = graph.add_node("C");
+    /// let d = graph.add_node("D");
+    /// graph.add_edge((), a, c);
+    /// graph.add_edge((), b, c);
+    ///
+    /// let reachable = graph.reachable_from([a, a]);
+    /// assert!(reachable[a] && reachable[c]);
+    /// assert!(!reachable[b] && !reachable[d]);
+    /// ```
+    fn reachable_from<I: IntoIterator<Item = Self::NodeIx>>(
+        &self,
+        sources: I,
+    ) -> impl Mapping<Self::NodeIx, bool> {
+        let mut reachable = self.init_node_map(|_, _| false);
+        let mut queue = std::collections::VecDeque::new();
+        for source in sources {
+            assert!(
+                self.exists_node_index(source),
+                "Node index {:?} does not exist",
+                source
+            );
+            if !core::mem::replace(&mut reachable[source], true) {
+                queue.push_back(source);
+            }
+        }
+        while let Some(current) = queue.pop_front() {
+            for edge in unsafe { self.outgoing_edge_indices_unchecked(current) } {
+                let [_, next] = unsafe { self.endpoints_unchecked(edge) };
+                if !core::mem::replace(&mut reachable[next], true) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        reachable
+    }
 
-    fn connecting_edges_mut(
-        &mut self,
-        tag: Self::NodeIx,
-    ) -> impl Iterator<Item = &mut Self::Edge> + use<'_, Self>
+    /// Copies the subgraph induced by `nodes` into a new `VecGraph`.
+    ///
+    /// The new graph has a copy of every listed node, in the order they are listed
+    /// (duplicates are ignored), and a copy of every edge whose endpoints are both
+    /// listed, including self-loops and parallel edges, in the order of
+    /// [`Graph::edge_indices`]. Inside a scope the nodes are given as `NodeTag`s, and the
+    /// result is still a plain `VecGraph` independent of the scope.
+    ///
+    /// # Returns
+    ///
+    /// The new graph, together with a mapping from each node index of `self` to the
+    /// index of its copy, or `None` for nodes that were not listed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the nodes does not exist in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, i32> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let c = graph.add_node("C");
+    /// graph.add_edge(1, a, b);
+    /// graph.add_edge(2, b, c);
+    ///
+    /// let (sub, remap) = graph.extract_subgraph([b, c]);
+    /// assert_eq!(sub.nodes().collect::<Vec<_>>(), vec![&"B", &"C"]);
+    /// assert_eq!(sub.edges().collect::<Vec<_>>(), vec![&2]);
+    /// assert_eq!(remap[a], None);
+    /// assert_eq!(sub.node(remap[c].unwrap()), &"C");
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn extract_subgraph(
+        &self,
+        nodes: impl IntoIterator<Item = Self::NodeIx>,
+    ) -> (
+        crate::vec_graph::VecGraph<Self::Node, Self::Edge>,
+        impl Mapping<Self::NodeIx, Option<crate::vec_graph::NodeIx>>,
+    )
     where
-        Self: Sized,
+        Self::Node: Clone,
+        Self::Edge: Clone,
     {
-        assert!(
-            self.exists_node_index(tag),
-            "Node index {:?} does not exist",
-            tag
-        );
-        unsafe { self.connecting_edges_unchecked_mut(tag) }
+        let mut subgraph = crate::vec_graph::VecGraph::default();
+        let mut remap = self.init_node_map(|_, _| None);
+        for node in nodes {
+            if remap[node].is_none() {
+                remap[node] = Some(subgraph.add_node(self.node(node).clone()));
+            }
+        }
+        for (edge, data) in self.edge_pairs() {
+            let [from, to] = unsafe { self.endpoints_unchecked(edge) };
+            if let (Some(new_from), Some(new_to)) = (remap[from], remap[to]) {
+                subgraph.add_edge(data.clone(), new_from, new_to);
+            }
+        }
+        (subgraph, remap)
     }
 
-    unsafe fn connecting_edges_unchecked_mut(
-        &mut self,
-        tag: Self::NodeIx,
-    ) -> impl Iterator<Item = &mut Self::Edge> + use<'_, Self>
+    /// Copies the subgraph induced by the nodes reachable from `start` into a new
+    /// `VecGraph`.
+    ///
+    /// Reachability follows outgoing edges as in [`Graph::reachable_from`], and `start`
+    /// itself is always included. The copy is made as with [`Graph::extract_subgraph`],
+    /// keeping the reachable nodes in the order of [`Graph::node_indices`].
+    ///
+    /// # Returns
+    ///
+    /// The new graph, together with a mapping from each node index of `self` to the
+    /// index of its copy, or `None` for unreachable nodes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` does not exist in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, i32> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let c = graph.add_node("C");
+    /// graph.add_edge(1, a, b);
+    /// graph.add_edge(2, c, b);
+    ///
+    /// let (sub, remap) = graph.reachable_subgraph(a);
+    /// assert_eq!(sub.nodes().collect::<Vec<_>>(), vec![&"A", &"B"]);
+    /// assert_eq!(sub.edges().collect::<Vec<_>>(), vec![&1]);
+    /// assert_eq!(remap[c], None);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn reachable_subgraph(
+        &self,
+        start: Self::NodeIx,
+    ) -> (
+        crate::vec_graph::VecGraph<Self::Node, Self::Edge>,
+        impl Mapping<Self::NodeIx, Option<crate::vec_graph::NodeIx>>,
+    )
     where
-        Self: Sized,
+        Self::Node: Clone,
+        Self::Edge: Clone,
     {
-        self.connecting_edge_pairs_unchecked_mut(tag)
-            .map(|(_, edge)| edge)
+        let reachable = self.reachable_from([start]);
+        let nodes: Vec<_> = self.node_indices().filter(|&ix| reachable[ix]).collect();
+        self.extract_subgraph(nodes)
     }
 
-    fn connecting_edge_pairs_mut(
-        &mut self,
-        tag: Self::NodeIx,
-    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)> + use<'_, Self>
-    where
-        Self: Sized,
-    {
-        assert!(
-            self.exists_node_index(tag),
-            "Node index {:?} does not exist",
-            tag
-        );
-        unsafe { self.connecting_edge_pairs_unchecked_mut(tag) }
+    /// Returns the nodes in a topological order, where every edge goes from an earlier
+    /// node to a later one.
+    ///
+    /// Uses Kahn's algorithm: nodes without remaining incoming edges are taken in the
+    /// order they become ready, starting with those of [`Graph::node_indices`]. Inside a
+    /// scope the order consists of `NodeTag`s that can be used with the context directly.
+    ///
+    /// # Errors
+    ///
+    /// Returns a node lying on a directed cycle if the graph is not acyclic. Self-loops
+    /// are cycles.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let c = graph.add_node("C");
+    /// graph.add_edge((), c, a);
+    /// graph.add_edge((), a, b);
+    ///
+    /// graph.scope(|ctx| {
+    ///     let order = ctx.topological_order().unwrap();
+    ///     let names: Vec<_> = order.iter().map(|&n| *ctx.node(n)).collect();
+    ///     assert_eq!(names, vec!["C", "A", "B"]);
+    /// });
+    ///
+    /// graph.add_edge((), b, c);
+    /// assert!(graph.topological_order().is_err());
+    /// ```
+    fn topological_order(&self) -> Result<Vec<Self::NodeIx>, Self::NodeIx> {
+        let mut in_degree =
+            self.init_node_map(|ix, _| unsafe { self.incoming_edge_indices_unchecked(ix).count() });
+        let mut order: Vec<_> = self
+            .node_indices()
+            .filter(|&ix| in_degree[ix] == 0)
+            .collect();
+
+        let mut i = 0;
+        while let Some(&node) = order.get(i) {
+            i += 1;
+            for edge in unsafe { self.outgoing_edge_indices_unchecked(node) } {
+                let [_, next] = unsafe { self.endpoints_unchecked(edge) };
+                in_degree[next] -= 1;
+                if in_degree[next] == 0 {
+                    order.push(next);
+                }
+            }
+        }
+
+        if order.len() == self.len_nodes() {
+            Ok(order)
+        } else {
+            Err(node_on_cycle(self, &in_degree))
+        }
     }
 
-    unsafe fn connecting_edge_pairs_unchecked_mut(
-        &mut self,
-        tag: Self::NodeIx,
-    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
-    where
-        Self: Sized;
+    /// Returns the nodes in a topological order, taking the ready node that comes first
+    /// according to `tie_break` at each step.
+    ///
+    /// Among the nodes whose predecessors have all been taken, the smallest by
+    /// `tie_break` is taken next, so independent nodes come out in priority order as far
+    /// as the edges allow. Nodes comparing equal are taken in the order they became
+    /// ready. See [`Graph::topological_order`] for the rest.
+    ///
+    /// # Errors
+    ///
+    /// Returns a node lying on a directed cycle if the graph is not acyclic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// // Tasks with priorities, where a lower number runs first
+    /// let mut graph: VecGraph<(&str, u32), ()> = VecGraph::default();
+    /// let build = graph.add_node(("build", 2));
+    /// let lint = graph.add_node(("lint", 3));
+    /// let fetch = graph.add_node(("fetch", 1));
+    /// let test = graph.add_node(("test", 0));
+    /// graph.add_edge((), fetch, build);
+    /// graph.add_edge((), build, test);
+    ///
+    /// let order = graph
+    ///     .topological_order_by(|a, b| a.1.cmp(&b.1))
+    ///     .unwrap();
+    /// assert_eq!(order, vec![fetch, build, test, lint]);
+    /// ```
+    fn topological_order_by(
+        &self,
+        mut tie_break: impl FnMut(&Self::Node, &Self::Node) -> core::cmp::Ordering,
+    ) -> Result<Vec<Self::NodeIx>, Self::NodeIx> {
+        let mut in_degree =
+            self.init_node_map(|ix, _| unsafe { self.incoming_edge_indices_unchecked(ix).count() });
+        // Ready nodes, sorted so that the next one to take is last
+        let mut ready: Vec<Self::NodeIx> = Vec::new();
+        let mut push_ready = |ready: &mut Vec<Self::NodeIx>, ix| {
+            let node = unsafe { self.node_unchecked(ix) };
+            let at = ready.partition_point(|&other| {
+                tie_break(unsafe { self.node_unchecked(other) }, node).is_gt()
+            });
+            ready.insert(at, ix);
+        };
+        for ix in self.node_indices() {
+            if in_degree[ix] == 0 {
+                push_ready(&mut ready, ix);
+            }
+        }
+
+        let mut order = Vec::with_capacity(self.len_nodes());
+        while let Some(node) = ready.pop() {
+            order.push(node);
+            for edge in unsafe { self.outgoing_edge_indices_unchecked(node) } {
+                let [_, next] = unsafe { self.endpoints_unchecked(edge) };
+                in_degree[next] -= 1;
+                if in_degree[next] == 0 {
+                    push_ready(&mut ready, next);
+                }
+            }
+        }
+
+        if order.len() == self.len_nodes() {
+            Ok(order)
+        } else {
+            Err(node_on_cycle(self, &in_degree))
+        }
+    }
 
-    fn scope_mut<
+    /// Runs `f` with a read-only [`Context`] over the graph.
+    ///
+    /// Indices handed out by the context are tags tied to this call, so they cannot be
+    /// used with another graph or after the scope ends. See the
+    /// [module documentation](crate::graph::context) for details.
+    ///
+    /// # Thread Safety
+    ///
+    /// The context only holds a shared reference to the graph, so it is `Send` and
+    /// `Sync` whenever the graph is `Sync`. Threads spawned with [`std::thread::scope`]
+    /// inside `f` can read the graph through the same context and exchange its tags.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<u32, ()> = VecGraph::default();
+    /// graph.add_nodes(0..100);
+    ///
+    /// let total = graph.scope(|ctx| {
+    ///     let nodes: Vec<_> = ctx.node_indices().collect();
+    ///     std::thread::scope(|s| {
+    ///         let handles: Vec<_> = nodes
+    ///             .chunks(25)
+    ///             .map(|chunk| s.spawn(move || chunk.iter().map(|&n| ctx.node(n)).sum::<u32>()))
+    ///             .collect();
+    ///         handles.into_iter().map(|h| h.join().unwrap()).sum::<u32>()
+    ///     })
+    /// });
+    /// assert_eq!(total, 4950);
+    /// ```
+    fn scope<
         'graph,
         R,
-        F: for<'scope> FnOnce(crate::graph::context::Context<'scope, &'graph mut Self>) -> R,
+        F: for<'scope> FnOnce(&crate::graph::context::Context<'scope, &'graph Self>) -> R,
     >(
-        &'graph mut self,
+        &'graph self,
         f: F,
     ) -> R
     where
-        Self: Sized + crate::graph::GraphUpdate,
+        Self: NotAContext,
     {
         use core::marker::PhantomData;
-        f(crate::graph::context::Context {
+        f(&crate::graph::context::Context {
             graph: self,
             _scope: PhantomData,
         })
     }
 
-    fn reverse(&mut self)
-    where
-        Self: Sized,
-    {
-        let edges: Vec<_> = self.edge_indices().collect();
-        for edge_ix in edges {
-            let [from, to] = unsafe { self.endpoints_unchecked(edge_ix) };
-            unsafe {
-                self.reverse_edge_unchecked(edge_ix, to, from);
-            }
-        }
-    }
-
-    unsafe fn reverse_edge_unchecked(&mut self, edge_ix: Self::EdgeIx, new_from: Self::NodeIx, new_to: Self::NodeIx)
-    where
-        Self: Sized;
-
+    /// Creates a mapping from every edge index to a value computed by `f`.
+    ///
+    /// `f` is called once per edge, in the order of [`Graph::edge_indices`], and the
+    /// mapping's [`iter`](Mapping::iter) and `into_iter` yield the values in that order.
+    /// Implementations may override this with a faster mapping that keeps the same order.
     fn init_edge_map<V>(
         &self,
         mut f: impl FnMut(Self::EdgeIx, &Self::Edge) -> V,
     ) -> impl Mapping<Self::EdgeIx, V> {
-        #[derive(Debug)]
-        struct DefaultEdgeMap<K, V>(std::collections::HashMap<K, V>);
-
-        impl<K: Eq + std::hash::Hash, V> std::ops::Index<K> for DefaultEdgeMap<K, V> {
-            type Output = V;
+        self.edge_pairs()
+            .map(|(edge_ix, edge)| (edge_ix, f(edge_ix, edge)))
+            .collect::<DefaultMap<_, _>>()
+    }
 
-            fn index(&self, key: K) -> &Self::Output {
-                &self.0[&key]
-            }
-        }
+    /// Creates a mapping from every node index to a value computed by `f`.
+    ///
+    /// `f` is called once per node, in the order of [`Graph::node_indices`], and the
+    /// mapping's [`iter`](Mapping::iter) and `into_iter` yield the values in that order.
+    /// Implementations may override this with a faster mapping that keeps the same order.
+    fn init_node_map<V>(
+        &self,
+        mut f: impl FnMut(Self::NodeIx, &Self::Node) -> V,
+    ) -> impl Mapping<Self::NodeIx, V> {
+        self.node_pairs()
+            .map(|(node_ix, node)| (node_ix, f(node_ix, node)))
+            .collect::<DefaultMap<_, _>>()
+    }
+}
 
-        impl<K: Eq + std::hash::Hash, V> std::ops::IndexMut<K> for DefaultEdgeMap<K, V> {
-            fn index_mut(&mut self, key: K) -> &mut Self::Output {
-                self.0.get_mut(&key).expect("Key not found in mapping")
-            }
-        }
+/// Finds a node on a cycle among the nodes Kahn's algorithm could not take.
+///
+/// Those are the nodes with a positive remaining in-degree, and each of them has an
+/// incoming edge from another one, so walking such edges backward from any of them
+/// eventually repeats a node, which lies on a cycle.
+fn node_on_cycle<G: Graph + ?Sized>(
+    graph: &G,
+    in_degree: &impl Mapping<G::NodeIx, usize>,
+) -> G::NodeIx {
+    let mut visited = graph.init_node_map(|_, _| false);
+    let mut current = graph
+        .node_indices()
+        .find(|&ix| in_degree[ix] > 0)
+        .expect("some node was left unsorted");
+    while !core::mem::replace(&mut visited[current], true) {
+        current = unsafe { graph.incoming_edge_indices_unchecked(current) }
+            .map(|edge| unsafe { graph.endpoints_unchecked(edge) }[0])
+            .find(|&prev| in_degree[prev] > 0)
+            .expect("unsorted nodes have an unsorted predecessor");
+    }
+    current
+}
 
-        impl<K: Eq + std::hash::Hash, V> IntoIterator for DefaultEdgeMap<K, V> {
-            type Item = V;
-            type IntoIter = std::collections::hash_map::IntoValues<K, V>;
+/// Mapping used by the default [`Graph::init_node_map`] and [`Graph::init_edge_map`].
+///
+/// Values are stored in insertion order, which is the order of `node_indices` or
+/// `edge_indices`, so iteration does not depend on hashing.
+#[derive(Debug)]
+pub(crate) struct DefaultMap<K, V> {
+    positions: std::collections::HashMap<K, usize>,
+    keys: Vec<K>,
+    values: Vec<V>,
+}
 
-            fn into_iter(self) -> Self::IntoIter {
-                self.0.into_values()
-            }
+impl<K: Copy + Eq + std::hash::Hash, V> FromIterator<(K, V)> for DefaultMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = DefaultMap {
+            positions: std::collections::HashMap::new(),
+            keys: Vec::new(),
+            values: Vec::new(),
+        };
+        for (key, value) in iter {
+            map.positions.insert(key, map.values.len());
+            map.keys.push(key);
+            map.values.push(value);
         }
+        map
+    }
+}
 
-        impl<K: Eq + std::hash::Hash, V> Mapping<K, V> for DefaultEdgeMap<K, V> {
-            fn map<VV>(self, mut f: impl FnMut(V) -> VV) -> impl Mapping<K, VV> {
-                DefaultEdgeMap(
-                    self.0
-                        .into_iter()
-                        .map(|(k, v)| (k, f(v)))
-                        .collect::<std::collections::HashMap<K, VV>>(),
-                )
-            }
-
-            fn iter<'a>(&'a self) -> impl Iterator<Item = &'a V>
-            where
-                V: 'a,
-            {
-                self.0.values()
-            }
-
-            fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut V>
-            where
-                V: 'a,
-            {
-                self.0.values_mut()
-            }
-
-            unsafe fn get_unchecked(&self, key: K) -> &V {
-                self.0.get(&key).unwrap_unchecked()
-            }
-
-            unsafe fn get_unchecked_mut(&mut self, key: K) -> &mut V {
-                self.0.get_mut(&key).unwrap_unchecked()
-            }
-        }
+impl<K: Eq + std::hash::Hash, V> std::ops::Index<K> for DefaultMap<K, V> {
+    type Output = V;
 
-        let mut map = std::collections::HashMap::new();
-        for (edge_ix, edge) in self.edge_pairs() {
-            map.insert(edge_ix, f(edge_ix, edge));
-        }
-        DefaultEdgeMap(map)
+    fn index(&self, key: K) -> &Self::Output {
+        &self.values[*self.positions.get(&key).expect("Key not found in mapping")]
     }
+}
 
-    fn init_node_map<V>(
-        &self,
-        mut f: impl FnMut(Self::NodeIx, &Self::Node) -> V,
-    ) -> impl Mapping<Self::NodeIx, V> {
-        #[derive(Debug)]
-        struct DefaultNodeMap<K, V>(std::collections::HashMap<K, V>);
+impl<K: Eq + std::hash::Hash, V> std::ops::IndexMut<K> for DefaultMap<K, V> {
+    fn index_mut(&mut self, key: K) -> &mut Self::Output {
+        &mut self.values[*self.positions.get(&key).expect("Key not found in mapping")]
+    }
+}
 
-        impl<K: Eq + std::hash::Hash, V> std::ops::Index<K> for DefaultNodeMap<K, V> {
-            type Output = V;
+impl<K, V> IntoIterator for DefaultMap<K, V> {
+    type Item = V;
+    type IntoIter = std::vec::IntoIter<V>;
 
-            fn index(&self, key: K) -> &Self::Output {
-                &self.0[&key]
-            }
-        }
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
 
-        impl<K: Eq + std::hash::Hash, V> std::ops::IndexMut<K> for DefaultNodeMap<K, V> {
-            fn index_mut(&mut self, key: K) -> &mut Self::Output {
-                self.0.get_mut(&key).expect("Key not found in mapping")
-            }
+impl<K: Copy + Eq + std::hash::Hash, V> Mapping<K, V> for DefaultMap<K, V> {
+    fn map<VV>(self, f: impl FnMut(V) -> VV) -> impl Mapping<K, VV> {
+        DefaultMap {
+            positions: self.positions,
+            keys: self.keys,
+            values: self.values.into_iter().map(f).collect(),
         }
+    }
 
-        impl<K: Eq + std::hash::Hash, V> IntoIterator for DefaultNodeMap<K, V> {
-            type Item = V;
-            type IntoIter = std::collections::hash_map::IntoValues<K, V>;
-
-            fn into_iter(self) -> Self::IntoIter {
-                self.0.into_values()
-            }
-        }
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a V>
+    where
+        V: 'a,
+    {
+        self.values.iter()
+    }
 
-        impl<K: Eq + std::hash::Hash, V> Mapping<K, V> for DefaultNodeMap<K, V> {
-            fn map<VV>(self, mut f: impl FnMut(V) -> VV) -> impl Mapping<K, VV> {
-                DefaultNodeMap(
-                    self.0
-                        .into_iter()
-                        .map(|(k, v)| (k, f(v)))
-                        .collect::<std::collections::HashMap<K, VV>>(),
-                )
-            }
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut V>
+    where
+        V: 'a,
+    {
+        self.values.iter_mut()
+    }
 
-            fn iter<'a>(&'a self) -> impl Iterator<Item = &'a V>
-            where
-                V: 'a,
-            {
-                self.0.values()
-            }
+    fn keys(&self) -> impl Iterator<Item = K> {
+        self.keys.iter().copied()
+    }
 
-            fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut V>
-            where
-                V: 'a,
-            {
-                self.0.values_mut()
-            }
+    fn pairs<'a>(&'a self) -> impl Iterator<Item = (K, &'a V)>
+    where
+        V: 'a,
+    {
+        self.keys.iter().copied().zip(&self.values)
+    }
 
-            unsafe fn get_unchecked(&self, key: K) -> &V {
-                self.0.get(&key).unwrap_unchecked()
-            }
+    fn into_pairs(self) -> impl Iterator<Item = (K, V)> {
+        self.keys.into_iter().zip(self.values)
+    }
 
-            unsafe fn get_unchecked_mut(&mut self, key: K) -> &mut V {
-                self.0.get_mut(&key).unwrap_unchecked()
-            }
-        }
+    unsafe fn get_unchecked(&self, key: K) -> &V {
+        self.values
+            .get_unchecked(*self.positions.get(&key).unwrap_unchecked())
+    }
 
-        let mut map = std::collections::HashMap::new();
-        for (node_ix, node) in self.node_pairs() {
-            map.insert(node_ix, f(node_ix, node));
-        }
-        DefaultNodeMap(map)
+    unsafe fn get_unchecked_mut(&mut self, key: K) -> &mut V {
+        self.values
+            .get_unchecked_mut(*self.positions.get(&key).unwrap_unchecked())
     }
 }
 
@@ -878,49 +1725,53 @@ impl<T: Graph> Graph for &T {
         (*self).incoming_edge_pairs_unchecked(tag)
     }
 
-    unsafe fn node_unchecked_mut(&mut self, _tag: Self::NodeIx) -> &mut Self::Node {
-        panic!("&T does not support mutable access")
+    unsafe fn connecting_edge_pairs_unchecked(
+        &self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge)> {
+        (*self).connecting_edge_pairs_unchecked(tag)
     }
 
-    unsafe fn edge_unchecked_mut(&mut self, _tag: Self::EdgeIx) -> &mut Self::Edge {
-        panic!("&T does not support mutable access")
+    fn outgoing_targets(
+        &self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge, Self::NodeIx, &Self::Node)> {
+        (*self).outgoing_targets(tag)
     }
 
-    unsafe fn outgoing_edge_pairs_unchecked_mut(
-        &mut self,
-        _tag: Self::NodeIx,
-    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
-    where
-        Self: Sized,
-    {
-        std::iter::empty()
+    fn incoming_sources(
+        &self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge, Self::NodeIx, &Self::Node)> {
+        (*self).incoming_sources(tag)
     }
 
-    unsafe fn incoming_edge_pairs_unchecked_mut(
-        &mut self,
-        _tag: Self::NodeIx,
-    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
-    where
-        Self: Sized,
-    {
-        std::iter::empty()
+    fn for_each_outgoing(&self, f: impl FnMut(Self::NodeIx, Self::EdgeIx, &Self::Edge)) {
+        (*self).for_each_outgoing(f)
     }
 
-    unsafe fn connecting_edge_pairs_unchecked_mut(
-        &mut self,
-        _tag: Self::NodeIx,
-    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
-    where
-        Self: Sized,
-    {
-        std::iter::empty()
+    fn fold_edges<B>(
+        &self,
+        init: B,
+        f: impl FnMut(B, Self::NodeIx, Self::EdgeIx, &Self::Edge) -> B,
+    ) -> B {
+        (*self).fold_edges(init, f)
     }
 
-    unsafe fn reverse_edge_unchecked(&mut self, _edge_ix: Self::EdgeIx, _new_from: Self::NodeIx, _new_to: Self::NodeIx)
-    where
-        Self: Sized,
-    {
-        panic!("&T does not support mutable access")
+    fn len_nodes(&self) -> usize {
+        (*self).len_nodes()
+    }
+
+    fn len_edges(&self) -> usize {
+        (*self).len_edges()
+    }
+
+    fn memory_footprint(&self) -> GraphMemory {
+        (*self).memory_footprint()
+    }
+
+    fn index_generation(&self) -> Option<u64> {
+        (*self).index_generation()
     }
 }
 
@@ -1006,48 +1857,52 @@ impl<T: Graph> Graph for &mut T {
         (**self).incoming_edge_pairs_unchecked(tag)
     }
 
-    unsafe fn node_unchecked_mut(&mut self, tag: Self::NodeIx) -> &mut Self::Node {
-        (**self).node_unchecked_mut(tag)
+    unsafe fn connecting_edge_pairs_unchecked(
+        &self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge)> {
+        (**self).connecting_edge_pairs_unchecked(tag)
     }
 
-    unsafe fn edge_unchecked_mut(&mut self, tag: Self::EdgeIx) -> &mut Self::Edge {
-        (**self).edge_unchecked_mut(tag)
+    fn outgoing_targets(
+        &self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge, Self::NodeIx, &Self::Node)> {
+        (**self).outgoing_targets(tag)
     }
 
-    unsafe fn outgoing_edge_pairs_unchecked_mut(
-        &mut self,
+    fn incoming_sources(
+        &self,
         tag: Self::NodeIx,
-    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
-    where
-        Self: Sized,
-    {
-        (**self).outgoing_edge_pairs_unchecked_mut(tag)
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge, Self::NodeIx, &Self::Node)> {
+        (**self).incoming_sources(tag)
     }
 
-    unsafe fn incoming_edge_pairs_unchecked_mut(
-        &mut self,
-        tag: Self::NodeIx,
-    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
-    where
-        Self: Sized,
-    {
-        (**self).incoming_edge_pairs_unchecked_mut(tag)
+    fn for_each_outgoing(&self, f: impl FnMut(Self::NodeIx, Self::EdgeIx, &Self::Edge)) {
+        (**self).for_each_outgoing(f)
     }
 
-    unsafe fn connecting_edge_pairs_unchecked_mut(
-        &mut self,
-        tag: Self::NodeIx,
-    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
-    where
-        Self: Sized,
-    {
-        (**self).connecting_edge_pairs_unchecked_mut(tag)
+    fn fold_edges<B>(
+        &self,
+        init: B,
+        f: impl FnMut(B, Self::NodeIx, Self::EdgeIx, &Self::Edge) -> B,
+    ) -> B {
+        (**self).fold_edges(init, f)
     }
 
-    unsafe fn reverse_edge_unchecked(&mut self, edge_ix: Self::EdgeIx, new_from: Self::NodeIx, new_to: Self::NodeIx)
-    where
-        Self: Sized,
-    {
-        (**self).reverse_edge_unchecked(edge_ix, new_from, new_to)
+    fn len_nodes(&self) -> usize {
+        (**self).len_nodes()
+    }
+
+    fn len_edges(&self) -> usize {
+        (**self).len_edges()
+    }
+
+    fn memory_footprint(&self) -> GraphMemory {
+        (**self).memory_footprint()
+    }
+
+    fn index_generation(&self) -> Option<u64> {
+        (**self).index_generation()
     }
 }
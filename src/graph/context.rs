@@ -1,6 +1,6 @@
 use core::marker::PhantomData;
 
-use crate::graph::{Graph, GraphRemove, GraphUpdate};
+use crate::graph::{CapacityError, Graph, GraphMut, GraphRemove, GraphRemoveEdge, GraphUpdate};
 
 /// A scoped mapping from node tags to values.
 ///
@@ -57,8 +57,103 @@ pub struct ContextEdgeMap<'scope, K, V, M> {
     inner: M,
 }
 
+/// A node mapping detached from the scope it was created in.
+///
+/// Obtained from [`ContextNodeMap::into_raw`], it is keyed by the underlying node
+/// indices instead of `NodeTag`s, so it can be carried from one `scope`/`scope_mut`
+/// call to the next and re-attached with [`Context::adopt_node_map`].
+///
+/// # Type Parameters
+///
+/// - `K`: The underlying key type (typically a node index)
+/// - `V`: The value type stored in the mapping
+#[derive(Debug)]
+pub struct RawNodeMap<K, V> {
+    inner: std::collections::HashMap<K, V>,
+}
+
+/// An edge mapping detached from the scope it was created in.
+///
+/// Obtained from [`ContextEdgeMap::into_raw`], it is keyed by the underlying edge
+/// indices instead of `EdgeTag`s, so it can be carried from one `scope`/`scope_mut`
+/// call to the next and re-attached with [`Context::adopt_edge_map`].
+///
+/// # Type Parameters
+///
+/// - `K`: The underlying key type (typically an edge index)
+/// - `V`: The value type stored in the mapping
+#[derive(Debug)]
+pub struct RawEdgeMap<K, V> {
+    inner: std::collections::HashMap<K, V>,
+}
+
+/// A node mapping backed by a [`BTreeMap`](std::collections::BTreeMap).
+///
+/// Obtained from [`Context::init_node_btreemap`] wrapped in a [`ContextNodeMap`]. Unlike
+/// the dense mapping returned by [`Context::init_node_map`], iteration visits the keys
+/// in ascending index order regardless of how the graph stores its nodes.
+///
+/// # Type Parameters
+///
+/// - `K`: The underlying key type (typically a node index)
+/// - `V`: The value type stored in the mapping
+#[derive(Debug)]
+pub struct BTreeNodeMap<K, V> {
+    inner: std::collections::BTreeMap<K, V>,
+}
+
+/// An edge mapping backed by a [`BTreeMap`](std::collections::BTreeMap).
+///
+/// Obtained from [`Context::init_edge_btreemap`] wrapped in a [`ContextEdgeMap`].
+/// Iteration visits the keys in ascending index order.
+///
+/// # Type Parameters
+///
+/// - `K`: The underlying key type (typically an edge index)
+/// - `V`: The value type stored in the mapping
+#[derive(Debug)]
+pub struct BTreeEdgeMap<K, V> {
+    inner: std::collections::BTreeMap<K, V>,
+}
+
 macro_rules! impl_context_map {
-    ($map_type:ident, $tag_type:ident) => {
+    ($map_type:ident, $tag_type:ident, $raw_type:ident, $ix_type:ident, $indices:ident, $len:ident) => {
+        impl<'scope, K, V, M: crate::Mapping<K, V>> $map_type<'scope, K, V, M> {
+            /// Detaches this mapping from its scope.
+            ///
+            /// The returned mapping is indexed by the underlying indices and can outlive
+            /// the scope. Use the matching `adopt` method of a later [`Context`] to
+            /// index it with tags again.
+            ///
+            /// # Panics
+            ///
+            /// Panics if elements were added to the graph after this mapping was created.
+            pub fn into_raw<G>(self, ctx: &Context<'scope, G>) -> $raw_type<K, V>
+            where
+                G: Graph<$ix_type = K>,
+                K: Copy + Eq + std::hash::Hash,
+            {
+                let len = self.inner.iter().count();
+                assert!(
+                    len == ctx.graph.$len(),
+                    "Mapping has {} entries, but the graph has {} elements",
+                    len,
+                    ctx.graph.$len()
+                );
+                let mut inner = self.inner.map(Some);
+                $raw_type {
+                    inner: ctx
+                        .graph
+                        .$indices()
+                        .map(|ix| {
+                            let value = inner[ix].take().expect("index visited twice");
+                            (ix, value)
+                        })
+                        .collect(),
+                }
+            }
+        }
+
         impl<'scope, K, V, M: crate::Mapping<K, V>> std::ops::Index<$tag_type<'scope, K>>
             for $map_type<'scope, K, V, M>
         {
@@ -115,6 +210,25 @@ macro_rules! impl_context_map {
                 self.inner.iter_mut()
             }
 
+            fn keys(&self) -> impl Iterator<Item = $tag_type<'scope, K>> {
+                self.inner.keys().map(|key| $tag_type(PhantomData, key))
+            }
+
+            fn pairs<'a>(&'a self) -> impl Iterator<Item = ($tag_type<'scope, K>, &'a V)>
+            where
+                V: 'a,
+            {
+                self.inner
+                    .pairs()
+                    .map(|(key, value)| ($tag_type(PhantomData, key), value))
+            }
+
+            fn into_pairs(self) -> impl Iterator<Item = ($tag_type<'scope, K>, V)> {
+                self.inner
+                    .into_pairs()
+                    .map(|(key, value)| ($tag_type(PhantomData, key), value))
+            }
+
             unsafe fn get_unchecked(&self, $tag_type(_, key): $tag_type<'scope, K>) -> &V {
                 self.inner.get_unchecked(key)
             }
@@ -129,8 +243,329 @@ macro_rules! impl_context_map {
     };
 }
 
-impl_context_map!(ContextNodeMap, NodeTag);
-impl_context_map!(ContextEdgeMap, EdgeTag);
+impl_context_map!(
+    ContextNodeMap,
+    NodeTag,
+    RawNodeMap,
+    NodeIx,
+    node_indices,
+    len_nodes
+);
+impl_context_map!(
+    ContextEdgeMap,
+    EdgeTag,
+    RawEdgeMap,
+    EdgeIx,
+    edge_indices,
+    len_edges
+);
+
+macro_rules! impl_raw_map {
+    ($raw_type:ident) => {
+        impl<K: Eq + std::hash::Hash, V> std::ops::Index<K> for $raw_type<K, V> {
+            type Output = V;
+
+            fn index(&self, key: K) -> &Self::Output {
+                &self.inner[&key]
+            }
+        }
+
+        impl<K: Eq + std::hash::Hash, V> std::ops::IndexMut<K> for $raw_type<K, V> {
+            fn index_mut(&mut self, key: K) -> &mut Self::Output {
+                self.inner.get_mut(&key).expect("Key not found in mapping")
+            }
+        }
+
+        impl<K: Eq + std::hash::Hash, V> IntoIterator for $raw_type<K, V> {
+            type Item = V;
+            type IntoIter = std::collections::hash_map::IntoValues<K, V>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.inner.into_values()
+            }
+        }
+
+        impl<K: Copy + Eq + std::hash::Hash, V> crate::Mapping<K, V> for $raw_type<K, V> {
+            fn map<VV>(self, mut f: impl FnMut(V) -> VV) -> impl crate::Mapping<K, VV> {
+                $raw_type {
+                    inner: self
+                        .inner
+                        .into_iter()
+                        .map(|(k, v)| (k, f(v)))
+                        .collect::<std::collections::HashMap<K, VV>>(),
+                }
+            }
+
+            fn iter<'a>(&'a self) -> impl Iterator<Item = &'a V>
+            where
+                V: 'a,
+            {
+                self.inner.values()
+            }
+
+            fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut V>
+            where
+                V: 'a,
+            {
+                self.inner.values_mut()
+            }
+
+            fn keys(&self) -> impl Iterator<Item = K> {
+                self.inner.keys().copied()
+            }
+
+            fn pairs<'a>(&'a self) -> impl Iterator<Item = (K, &'a V)>
+            where
+                V: 'a,
+            {
+                self.inner.iter().map(|(&key, value)| (key, value))
+            }
+
+            fn into_pairs(self) -> impl Iterator<Item = (K, V)> {
+                self.inner.into_iter()
+            }
+
+            unsafe fn get_unchecked(&self, key: K) -> &V {
+                self.inner.get(&key).unwrap_unchecked()
+            }
+
+            unsafe fn get_unchecked_mut(&mut self, key: K) -> &mut V {
+                self.inner.get_mut(&key).unwrap_unchecked()
+            }
+        }
+    };
+}
+
+impl_raw_map!(RawNodeMap);
+impl_raw_map!(RawEdgeMap);
+
+macro_rules! impl_btree_map {
+    ($btree_type:ident) => {
+        impl<K: Ord, V> std::ops::Index<K> for $btree_type<K, V> {
+            type Output = V;
+
+            fn index(&self, key: K) -> &Self::Output {
+                &self.inner[&key]
+            }
+        }
+
+        impl<K: Ord, V> std::ops::IndexMut<K> for $btree_type<K, V> {
+            fn index_mut(&mut self, key: K) -> &mut Self::Output {
+                self.inner.get_mut(&key).expect("Key not found in mapping")
+            }
+        }
+
+        impl<K: Ord, V> IntoIterator for $btree_type<K, V> {
+            type Item = V;
+            type IntoIter = std::collections::btree_map::IntoValues<K, V>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.inner.into_values()
+            }
+        }
+
+        impl<K: Copy + Ord, V> crate::Mapping<K, V> for $btree_type<K, V> {
+            fn map<VV>(self, mut f: impl FnMut(V) -> VV) -> impl crate::Mapping<K, VV> {
+                $btree_type {
+                    inner: self
+                        .inner
+                        .into_iter()
+                        .map(|(k, v)| (k, f(v)))
+                        .collect::<std::collections::BTreeMap<K, VV>>(),
+                }
+            }
+
+            fn iter<'a>(&'a self) -> impl Iterator<Item = &'a V>
+            where
+                V: 'a,
+            {
+                self.inner.values()
+            }
+
+            fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut V>
+            where
+                V: 'a,
+            {
+                self.inner.values_mut()
+            }
+
+            fn keys(&self) -> impl Iterator<Item = K> {
+                self.inner.keys().copied()
+            }
+
+            fn pairs<'a>(&'a self) -> impl Iterator<Item = (K, &'a V)>
+            where
+                V: 'a,
+            {
+                self.inner.iter().map(|(&key, value)| (key, value))
+            }
+
+            fn into_pairs(self) -> impl Iterator<Item = (K, V)> {
+                self.inner.into_iter()
+            }
+
+            unsafe fn get_unchecked(&self, key: K) -> &V {
+                self.inner.get(&key).unwrap_unchecked()
+            }
+
+            unsafe fn get_unchecked_mut(&mut self, key: K) -> &mut V {
+                self.inner.get_mut(&key).unwrap_unchecked()
+            }
+        }
+    };
+}
+
+impl_btree_map!(BTreeNodeMap);
+impl_btree_map!(BTreeEdgeMap);
+
+/// The node and edge indices of a graph at the time of [`Context::freeze`].
+///
+/// Unlike tags, a snapshot is not tied to a scope and can be kept for as long as needed.
+/// Instead of trusting the indices blindly like the vectors returned by
+/// [`Context::snapshot`], it checks them against the graph on every access, so indices
+/// invalidated by a later removal are reported instead of silently reused.
+///
+/// Removal in [`VecGraph`](crate::vec_graph::VecGraph) moves the last node or edge into
+/// the freed slot and new nodes reuse freed slots, so an index that still exists may
+/// refer to another node or edge. The snapshot therefore records the
+/// [`Graph::index_generation`] of the graph and treats all of its indices as invalid
+/// once the generation has changed, that is after any removal or renumbering. Adding
+/// nodes and edges keeps the indices valid.
+///
+/// For graphs that do not track a generation, a node index is considered valid while it
+/// still exists, and an edge index while it exists and still connects the same two
+/// nodes, which cannot detect a node moved into a freed slot.
+///
+/// # Type Parameters
+///
+/// - `NI`: The underlying node index type
+/// - `EI`: The underlying edge index type
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// let ab = graph.add_edge((), a, b);
+///
+/// let snapshot = graph.scope(|ctx| ctx.freeze());
+/// assert_eq!(snapshot.node(&graph, b), Some(&"B"));
+///
+/// graph.add_node("C");
+/// assert!(snapshot.is_edge_valid(&graph, ab));
+///
+/// // `C` moves into the slot of `A`
+/// graph.remove_node(a);
+/// assert_eq!(snapshot.node(&graph, a), None);
+/// assert!(!snapshot.is_node_valid(&graph, b));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexSnapshot<NI, EI> {
+    // Sorted by index
+    nodes: Vec<NI>,
+    edges: Vec<(EI, [NI; 2])>,
+    generation: Option<u64>,
+}
+
+impl<NI: Copy + Ord, EI: Copy + Ord> IndexSnapshot<NI, EI> {
+    /// Returns the node indices the graph had, in ascending order.
+    pub fn nodes(&self) -> impl Iterator<Item = NI> + '_ {
+        self.nodes.iter().copied()
+    }
+
+    /// Returns the edge indices the graph had, in ascending order.
+    pub fn edges(&self) -> impl Iterator<Item = EI> + '_ {
+        self.edges.iter().map(|&(ix, _)| ix)
+    }
+
+    /// Returns whether the graph had the node when the snapshot was taken.
+    pub fn contains_node(&self, ix: NI) -> bool {
+        self.nodes.binary_search(&ix).is_ok()
+    }
+
+    /// Returns whether the graph had the edge when the snapshot was taken.
+    pub fn contains_edge(&self, ix: EI) -> bool {
+        self.endpoints(ix).is_some()
+    }
+
+    /// Returns the endpoints the edge had when the snapshot was taken.
+    pub fn endpoints(&self, ix: EI) -> Option<[NI; 2]> {
+        self.edges
+            .binary_search_by_key(&ix, |&(edge, _)| edge)
+            .ok()
+            .map(|i| self.edges[i].1)
+    }
+
+    /// Returns whether the indices of `graph` have not been renumbered since the
+    /// snapshot was taken.
+    ///
+    /// Always `true` for graphs that do not track [`Graph::index_generation`].
+    pub fn is_current<G>(&self, graph: &G) -> bool
+    where
+        G: Graph<NodeIx = NI, EdgeIx = EI>,
+    {
+        graph.index_generation() == self.generation
+    }
+
+    /// Returns whether the node is in the snapshot and still valid in `graph`.
+    pub fn is_node_valid<G>(&self, graph: &G, ix: NI) -> bool
+    where
+        G: Graph<NodeIx = NI, EdgeIx = EI>,
+    {
+        self.is_current(graph) && self.contains_node(ix) && graph.exists_node_index(ix)
+    }
+
+    /// Returns whether the edge is in the snapshot and still valid in `graph`.
+    pub fn is_edge_valid<G>(&self, graph: &G, ix: EI) -> bool
+    where
+        G: Graph<NodeIx = NI, EdgeIx = EI>,
+    {
+        let Some([from, to]) = self.endpoints(ix) else {
+            return false;
+        };
+        graph.exists_edge_index(ix)
+            && self.is_node_valid(graph, from)
+            && self.is_node_valid(graph, to)
+            && graph.endpoints(ix) == [from, to]
+    }
+
+    /// Returns the data of the node if it is still valid in `graph`.
+    pub fn node<'g, G>(&self, graph: &'g G, ix: NI) -> Option<&'g G::Node>
+    where
+        G: Graph<NodeIx = NI, EdgeIx = EI>,
+    {
+        self.is_node_valid(graph, ix).then(|| graph.node(ix))
+    }
+
+    /// Returns the data of the edge if it is still valid in `graph`.
+    pub fn edge<'g, G>(&self, graph: &'g G, ix: EI) -> Option<&'g G::Edge>
+    where
+        G: Graph<NodeIx = NI, EdgeIx = EI>,
+    {
+        self.is_edge_valid(graph, ix).then(|| graph.edge(ix))
+    }
+
+    /// Returns the nodes of the snapshot that are still valid in `graph`.
+    pub fn valid_nodes<'a, G>(&'a self, graph: &'a G) -> impl Iterator<Item = NI> + 'a
+    where
+        G: Graph<NodeIx = NI, EdgeIx = EI>,
+    {
+        self.nodes()
+            .filter(move |&ix| self.is_node_valid(graph, ix))
+    }
+
+    /// Returns the edges of the snapshot that are still valid in `graph`.
+    pub fn valid_edges<'a, G>(&'a self, graph: &'a G) -> impl Iterator<Item = EI> + 'a
+    where
+        G: Graph<NodeIx = NI, EdgeIx = EI>,
+    {
+        self.edges()
+            .filter(move |&ix| self.is_edge_valid(graph, ix))
+    }
+}
 
 /// A lifetime-parameterized wrapper around node indices.
 ///
@@ -273,6 +708,32 @@ pub struct Context<'scope, G> {
     pub(crate) _scope: crate::Invariant<'scope>,
 }
 
+/// Marker for graphs that a scope can be opened on with [`Graph::scope`] or
+/// [`GraphMut::scope_mut`].
+///
+/// Opening a scope on a [`Context`] would create an inner scope that can remove nodes and
+/// edges while the outer scope still holds tags to them. `Context` does not implement
+/// this trait, so calling `scope` or `scope_mut` inside a scope fails to compile:
+///
+/// ```compile_fail
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<i32, ()> = VecGraph::default();
+/// graph.scope_mut(|mut ctx| {
+///     ctx.scope_mut(|_inner| {});
+/// });
+/// ```
+///
+/// # Safety
+///
+/// Implementors must not be a `Context` and must not give access to one through their
+/// `Graph` implementation.
+pub unsafe trait NotAContext {}
+
+unsafe impl<T: NotAContext + ?Sized> NotAContext for &T {}
+
+unsafe impl<T: NotAContext + ?Sized> NotAContext for &mut T {}
+
 impl<'scope, G: Graph> Graph for Context<'scope, G> {
     type Node = G::Node;
     type Edge = G::Edge;
@@ -345,6 +806,128 @@ impl<'scope, G: Graph> Graph for Context<'scope, G> {
             .map(|(edge_ix, edge)| (EdgeTag(PhantomData, edge_ix), edge))
     }
 
+    unsafe fn connecting_edge_pairs_unchecked(
+        &self,
+        NodeTag(_, ix): Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge)> {
+        self.graph
+            .connecting_edge_pairs_unchecked(ix)
+            .map(|(edge_ix, edge)| (EdgeTag(PhantomData, edge_ix), edge))
+    }
+
+    fn init_node_map<V>(
+        &self,
+        f: impl FnMut(Self::NodeIx, &Self::Node) -> V,
+    ) -> impl crate::Mapping<Self::NodeIx, V> {
+        Context::init_node_map(self, f)
+    }
+
+    fn init_edge_map<V>(
+        &self,
+        f: impl FnMut(Self::EdgeIx, &Self::Edge) -> V,
+    ) -> impl crate::Mapping<Self::EdgeIx, V> {
+        Context::init_edge_map(self, f)
+    }
+
+    fn edge_triples(&self) -> impl Iterator<Item = (Self::EdgeIx, [Self::NodeIx; 2], &Self::Edge)> {
+        self.graph.edge_triples().map(|(ix, endpoints, edge)| {
+            (
+                EdgeTag(PhantomData, ix),
+                endpoints.map(|ix| NodeTag(PhantomData, ix)),
+                edge,
+            )
+        })
+    }
+
+    fn outgoing_targets(
+        &self,
+        NodeTag(_, ix): Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge, Self::NodeIx, &Self::Node)> {
+        self.graph
+            .outgoing_targets(ix)
+            .map(|(edge_ix, edge, to, node)| {
+                (
+                    EdgeTag(PhantomData, edge_ix),
+                    edge,
+                    NodeTag(PhantomData, to),
+                    node,
+                )
+            })
+    }
+
+    fn incoming_sources(
+        &self,
+        NodeTag(_, ix): Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge, Self::NodeIx, &Self::Node)> {
+        self.graph
+            .incoming_sources(ix)
+            .map(|(edge_ix, edge, from, node)| {
+                (
+                    EdgeTag(PhantomData, edge_ix),
+                    edge,
+                    NodeTag(PhantomData, from),
+                    node,
+                )
+            })
+    }
+
+    fn for_each_outgoing(&self, mut f: impl FnMut(Self::NodeIx, Self::EdgeIx, &Self::Edge)) {
+        self.graph.for_each_outgoing(|from, ix, edge| {
+            f(NodeTag(PhantomData, from), EdgeTag(PhantomData, ix), edge)
+        })
+    }
+
+    fn fold_edges<B>(
+        &self,
+        init: B,
+        mut f: impl FnMut(B, Self::NodeIx, Self::EdgeIx, &Self::Edge) -> B,
+    ) -> B {
+        self.graph.fold_edges(init, |acc, from, ix, edge| {
+            f(
+                acc,
+                NodeTag(PhantomData, from),
+                EdgeTag(PhantomData, ix),
+                edge,
+            )
+        })
+    }
+
+    fn node_indices_where<F: FnMut(&Self::Node) -> bool>(
+        &self,
+        f: F,
+    ) -> impl Iterator<Item = Self::NodeIx> {
+        self.graph
+            .node_indices_where(f)
+            .map(|ix| NodeTag(PhantomData, ix))
+    }
+
+    fn edge_indices_where<F: FnMut(&Self::Edge) -> bool>(
+        &self,
+        f: F,
+    ) -> impl Iterator<Item = Self::EdgeIx> {
+        self.graph
+            .edge_indices_where(f)
+            .map(|ix| EdgeTag(PhantomData, ix))
+    }
+
+    fn len_nodes(&self) -> usize {
+        self.graph.len_nodes()
+    }
+
+    fn len_edges(&self) -> usize {
+        self.graph.len_edges()
+    }
+
+    fn memory_footprint(&self) -> crate::graph::GraphMemory {
+        self.graph.memory_footprint()
+    }
+
+    fn index_generation(&self) -> Option<u64> {
+        self.graph.index_generation()
+    }
+}
+
+impl<'scope, G: GraphMut> GraphMut for Context<'scope, G> {
     unsafe fn node_unchecked_mut(&mut self, NodeTag(_, ix): Self::NodeIx) -> &mut Self::Node {
         self.graph.node_unchecked_mut(ix)
     }
@@ -353,6 +936,22 @@ impl<'scope, G: Graph> Graph for Context<'scope, G> {
         self.graph.edge_unchecked_mut(ix)
     }
 
+    fn get_node_disjoint_mut<const K: usize>(
+        &mut self,
+        ixs: [Self::NodeIx; K],
+    ) -> Option<[&mut Self::Node; K]> {
+        self.graph
+            .get_node_disjoint_mut(ixs.map(|NodeTag(_, ix)| ix))
+    }
+
+    fn get_edge_disjoint_mut<const K: usize>(
+        &mut self,
+        ixs: [Self::EdgeIx; K],
+    ) -> Option<[&mut Self::Edge; K]> {
+        self.graph
+            .get_edge_disjoint_mut(ixs.map(|EdgeTag(_, ix)| ix))
+    }
+
     unsafe fn outgoing_edge_pairs_unchecked_mut(
         &mut self,
         NodeTag(_, ix): Self::NodeIx,
@@ -389,10 +988,32 @@ impl<'scope, G: Graph> Graph for Context<'scope, G> {
             .map(|(edge_ix, edge)| (EdgeTag(PhantomData, edge_ix), edge))
     }
 
-    fn init_node_map<V>(
+    unsafe fn reverse_edge_unchecked(&mut self, EdgeTag(_, edge_ix): Self::EdgeIx, NodeTag(_, new_from): Self::NodeIx, NodeTag(_, new_to): Self::NodeIx)
+    where
+        Self: Sized,
+    {
+        self.graph.reverse_edge_unchecked(edge_ix, new_from, new_to)
+    }
+}
+
+impl<'scope, G: Graph> Context<'scope, G> {
+    /// Creates a scoped mapping from every node to a value.
+    ///
+    /// This is the same as [`Graph::init_node_map`], but returns the concrete
+    /// [`ContextNodeMap`] so that the mapping can be detached with
+    /// [`ContextNodeMap::into_raw`].
+    pub fn init_node_map<V, F>(
         &self,
-        mut f: impl FnMut(Self::NodeIx, &Self::Node) -> V,
-    ) -> impl crate::Mapping<Self::NodeIx, V> {
+        mut f: F,
+    ) -> ContextNodeMap<
+        'scope,
+        G::NodeIx,
+        V,
+        impl crate::Mapping<G::NodeIx, V> + use<'_, 'scope, G, V, F>,
+    >
+    where
+        F: FnMut(NodeTag<'scope, G::NodeIx>, &G::Node) -> V,
+    {
         ContextNodeMap {
             _scope: PhantomData,
             _key: core::marker::PhantomData,
@@ -403,10 +1024,23 @@ impl<'scope, G: Graph> Graph for Context<'scope, G> {
         }
     }
 
-    fn init_edge_map<V>(
+    /// Creates a scoped mapping from every edge to a value.
+    ///
+    /// This is the same as [`Graph::init_edge_map`], but returns the concrete
+    /// [`ContextEdgeMap`] so that the mapping can be detached with
+    /// [`ContextEdgeMap::into_raw`].
+    pub fn init_edge_map<V, F>(
         &self,
-        mut f: impl FnMut(Self::EdgeIx, &Self::Edge) -> V,
-    ) -> impl crate::Mapping<Self::EdgeIx, V> {
+        mut f: F,
+    ) -> ContextEdgeMap<
+        'scope,
+        G::EdgeIx,
+        V,
+        impl crate::Mapping<G::EdgeIx, V> + use<'_, 'scope, G, V, F>,
+    >
+    where
+        F: FnMut(EdgeTag<'scope, G::EdgeIx>, &G::Edge) -> V,
+    {
         ContextEdgeMap {
             _scope: PhantomData,
             _key: core::marker::PhantomData,
@@ -417,11 +1051,333 @@ impl<'scope, G: Graph> Graph for Context<'scope, G> {
         }
     }
 
-    unsafe fn reverse_edge_unchecked(&mut self, EdgeTag(_, edge_ix): Self::EdgeIx, NodeTag(_, new_from): Self::NodeIx, NodeTag(_, new_to): Self::NodeIx)
+    /// Creates a scoped mapping from every node to a value, backed by a
+    /// [`BTreeMap`](std::collections::BTreeMap).
+    ///
+    /// [`Mapping::iter`](crate::Mapping::iter), [`Mapping::pairs`](crate::Mapping::pairs)
+    /// and friends visit the nodes in ascending order of their tags, which are ordered
+    /// like the underlying indices. Indexing costs `O(log n)` instead of the `O(1)` of
+    /// [`Context::init_node_map`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    /// use gotgraph::Mapping;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// graph.add_node("a");
+    /// graph.add_node("b");
+    ///
+    /// graph.scope(|ctx| {
+    ///     let names = ctx.init_node_btreemap(|_, &name| name.to_uppercase());
+    ///     let tags: Vec<_> = names.keys().collect();
+    ///     assert!(tags.windows(2).all(|w| w[0] < w[1]));
+    ///     assert_eq!(names[tags[1]], "B");
+    /// });
+    /// ```
+    pub fn init_node_btreemap<V, F>(
+        &self,
+        mut f: F,
+    ) -> ContextNodeMap<'scope, G::NodeIx, V, BTreeNodeMap<G::NodeIx, V>>
     where
-        Self: Sized,
+        F: FnMut(NodeTag<'scope, G::NodeIx>, &G::Node) -> V,
     {
-        self.graph.reverse_edge_unchecked(edge_ix, new_from, new_to)
+        ContextNodeMap {
+            _scope: PhantomData,
+            _key: PhantomData,
+            _value: PhantomData,
+            inner: BTreeNodeMap {
+                inner: self
+                    .graph
+                    .node_pairs()
+                    .map(|(ix, node)| (ix, f(NodeTag(PhantomData, ix), node)))
+                    .collect(),
+            },
+        }
+    }
+
+    /// Creates a scoped mapping from every edge to a value, backed by a
+    /// [`BTreeMap`](std::collections::BTreeMap).
+    ///
+    /// See [`Context::init_node_btreemap`]; the edges are visited in ascending order of
+    /// their tags.
+    pub fn init_edge_btreemap<V, F>(
+        &self,
+        mut f: F,
+    ) -> ContextEdgeMap<'scope, G::EdgeIx, V, BTreeEdgeMap<G::EdgeIx, V>>
+    where
+        F: FnMut(EdgeTag<'scope, G::EdgeIx>, &G::Edge) -> V,
+    {
+        ContextEdgeMap {
+            _scope: PhantomData,
+            _key: PhantomData,
+            _value: PhantomData,
+            inner: BTreeEdgeMap {
+                inner: self
+                    .graph
+                    .edge_pairs()
+                    .map(|(ix, edge)| (ix, f(EdgeTag(PhantomData, ix), edge)))
+                    .collect(),
+            },
+        }
+    }
+
+    /// Re-attaches a node mapping detached with [`ContextNodeMap::into_raw`] to this scope.
+    ///
+    /// Scoped mappings are indexed without bounds checks, which is sound because a
+    /// `NodeTag` of this scope always refers to a node the mapping has an entry for.
+    /// A detached mapping only has entries for the nodes the graph had when it was
+    /// detached, so this method checks that it has an entry for every node currently in
+    /// the graph before re-attaching it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node set of the graph has changed since the mapping was detached.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<i32, ()> = VecGraph::default();
+    /// graph.scope_mut(|mut ctx| {
+    ///     let a = ctx.add_node(1);
+    ///     let b = ctx.add_node(2);
+    ///     ctx.add_edge((), a, b);
+    /// });
+    ///
+    /// // Compute something in one scope...
+    /// let degrees = graph.scope(|ctx| {
+    ///     ctx.init_node_map(|tag, _| ctx.outgoing_edge_indices(tag).count())
+    ///         .into_raw(ctx)
+    /// });
+    ///
+    /// // ...and use it in the next one
+    /// graph.scope_mut(|mut ctx| {
+    ///     let degrees = ctx.adopt_node_map(degrees);
+    ///     let tags: Vec<_> = ctx.node_indices().collect();
+    ///     for tag in tags {
+    ///         *ctx.node_mut(tag) += degrees[tag] as i32;
+    ///     }
+    /// });
+    /// assert_eq!(graph.nodes().copied().collect::<Vec<_>>(), vec![2, 2]);
+    /// ```
+    pub fn adopt_node_map<V>(
+        &self,
+        raw: RawNodeMap<G::NodeIx, V>,
+    ) -> ContextNodeMap<'scope, G::NodeIx, V, RawNodeMap<G::NodeIx, V>> {
+        let len = self.graph.len_nodes();
+        assert!(
+            raw.inner.len() == len
+                && self
+                    .graph
+                    .node_indices()
+                    .all(|ix| raw.inner.contains_key(&ix)),
+            "Node map was created for {} nodes, but the graph now has {} nodes",
+            raw.inner.len(),
+            len
+        );
+        ContextNodeMap {
+            _scope: PhantomData,
+            _key: PhantomData,
+            _value: PhantomData,
+            inner: raw,
+        }
+    }
+
+    /// Re-attaches an edge mapping detached with [`ContextEdgeMap::into_raw`] to this scope.
+    ///
+    /// See [`Context::adopt_node_map`] for why this is sound; the same reasoning applies
+    /// to edges.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the edge set of the graph has changed since the mapping was detached.
+    pub fn adopt_edge_map<V>(
+        &self,
+        raw: RawEdgeMap<G::EdgeIx, V>,
+    ) -> ContextEdgeMap<'scope, G::EdgeIx, V, RawEdgeMap<G::EdgeIx, V>> {
+        let len = self.graph.len_edges();
+        assert!(
+            raw.inner.len() == len
+                && self
+                    .graph
+                    .edge_indices()
+                    .all(|ix| raw.inner.contains_key(&ix)),
+            "Edge map was created for {} edges, but the graph now has {} edges",
+            raw.inner.len(),
+            len
+        );
+        ContextEdgeMap {
+            _scope: PhantomData,
+            _key: PhantomData,
+            _value: PhantomData,
+            inner: raw,
+        }
+    }
+
+    /// Opens a read-only scope on the same graph with a fresh lifetime.
+    ///
+    /// This is the sanctioned alternative to calling [`Graph::scope`] inside a scope,
+    /// which does not compile. The inner context only borrows this one immutably, so
+    /// nothing can be added or removed while it is alive, and its tags are branded with
+    /// their own lifetime: they cannot be mixed with the outer tags or returned from `f`.
+    /// Mappings created inside `f` are likewise private to the subscope, which makes it
+    /// a convenient place for scratch state of a nested analysis.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::graph::Context;
+    /// use gotgraph::prelude::*;
+    ///
+    /// fn count_sinks<G: Graph>(ctx: &Context<'_, G>) -> usize {
+    ///     ctx.node_indices()
+    ///         .filter(|&n| ctx.outgoing_edge_indices(n).next().is_none())
+    ///         .count()
+    /// }
+    ///
+    /// let mut graph: VecGraph<i32, ()> = VecGraph::default();
+    /// graph.scope_mut(|mut ctx| {
+    ///     let a = ctx.add_node(1);
+    ///     let b = ctx.add_node(2);
+    ///     ctx.add_edge((), a, b);
+    ///
+    ///     assert_eq!(ctx.subscope(|sub| count_sinks(sub)), 1);
+    ///
+    ///     // The outer context is usable again once the subscope has ended
+    ///     ctx.add_node(3);
+    /// });
+    /// ```
+    pub fn subscope<R>(&self, f: impl for<'sub> FnOnce(&Context<'sub, &G>) -> R) -> R {
+        f(&Context {
+            graph: &self.graph,
+            _scope: PhantomData,
+        })
+    }
+
+    /// Returns the raw indices of all nodes, without the scope's lifetime branding.
+    ///
+    /// This is the same as collecting [`NodeTag::inner`] of every tag from
+    /// `node_indices`. Unlike tags, the returned indices can be kept after the scope
+    /// ends and used with the bare graph, but nothing checks them anymore: they are
+    /// only valid until the next structural mutation (adding or removing nodes or
+    /// edges) of the graph, which may renumber or invalidate them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let nodes = graph.scope_mut(|mut ctx| {
+    ///     ctx.add_node("A");
+    ///     ctx.add_node("B");
+    ///     ctx.snapshot_nodes()
+    /// });
+    /// assert_eq!(graph.node(nodes[1]), &"B");
+    /// ```
+    pub fn snapshot_nodes(&self) -> Vec<G::NodeIx> {
+        self.graph.node_indices().collect()
+    }
+
+    /// Returns the raw indices of all edges, without the scope's lifetime branding.
+    ///
+    /// See [`Context::snapshot_nodes`]; the indices are only valid until the next
+    /// structural mutation of the graph.
+    pub fn snapshot_edges(&self) -> Vec<G::EdgeIx> {
+        self.graph.edge_indices().collect()
+    }
+
+    /// Returns the raw indices of all nodes and all edges.
+    ///
+    /// This combines [`Context::snapshot_nodes`] and [`Context::snapshot_edges`], with
+    /// the same caveat: the indices are only valid until the next structural mutation
+    /// of the graph.
+    pub fn snapshot(&self) -> (Vec<G::NodeIx>, Vec<G::EdgeIx>) {
+        (self.snapshot_nodes(), self.snapshot_edges())
+    }
+
+    /// Takes a snapshot of the raw indices of all nodes and edges.
+    ///
+    /// The returned [`IndexSnapshot`] outlives the scope and checks its indices against
+    /// the graph on every access, which makes it a safer way to carry indices out of a
+    /// scope than [`Context::snapshot`].
+    pub fn freeze(&self) -> IndexSnapshot<G::NodeIx, G::EdgeIx> {
+        let mut nodes: Vec<_> = self.graph.node_indices().collect();
+        nodes.sort_unstable();
+        let mut edges: Vec<_> = self
+            .graph
+            .edge_indices()
+            .map(|ix| (ix, self.graph.endpoints(ix)))
+            .collect();
+        edges.sort_unstable_by_key(|&(ix, _)| ix);
+        IndexSnapshot {
+            nodes,
+            edges,
+            generation: self.graph.index_generation(),
+        }
+    }
+
+    /// Brands a raw node index with the lifetime of this scope.
+    ///
+    /// This re-imports indices computed outside the scope, such as the result of an
+    /// algorithm run on the bare graph or a [`Context::snapshot_nodes`] of a previous
+    /// scope.
+    ///
+    /// This is sound because the index is checked against the current state of the
+    /// graph: nodes are never removed while a context exists (removal consumes the
+    /// context), so an index that exists now stays valid for the rest of the scope.
+    ///
+    /// # Returns
+    ///
+    /// The tag, or `None` if the index does not exist in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// graph.remove_node(b);
+    ///
+    /// graph.scope(|ctx| {
+    ///     let tag = ctx.tag_node(a).unwrap();
+    ///     assert_eq!(ctx.node(tag), &"A");
+    ///     assert!(ctx.tag_node(b).is_none());
+    /// });
+    /// ```
+    pub fn tag_node(&self, ix: G::NodeIx) -> Option<NodeTag<'scope, G::NodeIx>> {
+        self.graph
+            .exists_node_index(ix)
+            .then_some(NodeTag(PhantomData, ix))
+    }
+
+    /// Brands a raw edge index with the lifetime of this scope.
+    ///
+    /// See [`Context::tag_node`], which this mirrors for edges.
+    ///
+    /// # Returns
+    ///
+    /// The tag, or `None` if the index does not exist in the graph.
+    pub fn tag_edge(&self, ix: G::EdgeIx) -> Option<EdgeTag<'scope, G::EdgeIx>> {
+        self.graph
+            .exists_edge_index(ix)
+            .then_some(EdgeTag(PhantomData, ix))
+    }
+
+    /// Returns the raw indices of the given node tags, in order.
+    ///
+    /// This is [`NodeTag::inner`] applied to every tag; the indices are only valid until
+    /// the next structural mutation of the graph, as with [`Context::snapshot_nodes`].
+    pub fn untag_nodes(
+        &self,
+        tags: impl IntoIterator<Item = NodeTag<'scope, G::NodeIx>>,
+    ) -> Vec<G::NodeIx> {
+        tags.into_iter().map(NodeTag::inner).collect()
     }
 }
 
@@ -430,6 +1386,54 @@ impl<'scope, G: GraphUpdate> GraphUpdate for Context<'scope, G> {
         NodeTag(PhantomData, self.graph.add_node(node))
     }
 
+    fn try_add_node(
+        &mut self,
+        node: Self::Node,
+    ) -> Result<Self::NodeIx, CapacityError<Self::Node>> {
+        self.graph
+            .try_add_node(node)
+            .map(|ix| NodeTag(PhantomData, ix))
+    }
+
+    fn try_add_edge(
+        &mut self,
+        edge: Self::Edge,
+        NodeTag(_, from): Self::NodeIx,
+        NodeTag(_, to): Self::NodeIx,
+    ) -> Result<Self::EdgeIx, CapacityError<Self::Edge>> {
+        self.graph
+            .try_add_edge(edge, from, to)
+            .map(|ix| EdgeTag(PhantomData, ix))
+    }
+
+    fn add_node_with(&mut self, f: impl FnOnce(Self::NodeIx) -> Self::Node) -> Self::NodeIx {
+        NodeTag(
+            PhantomData,
+            self.graph.add_node_with(|ix| f(NodeTag(PhantomData, ix))),
+        )
+    }
+
+    fn add_edge_with(
+        &mut self,
+        NodeTag(_, from): Self::NodeIx,
+        NodeTag(_, to): Self::NodeIx,
+        f: impl FnOnce(Self::EdgeIx) -> Self::Edge,
+    ) -> Self::EdgeIx {
+        EdgeTag(
+            PhantomData,
+            self.graph
+                .add_edge_with(from, to, |ix| f(EdgeTag(PhantomData, ix))),
+        )
+    }
+
+    fn add_nodes<I: IntoIterator<Item = Self::Node>>(&mut self, nodes: I) -> Vec<Self::NodeIx> {
+        self.graph
+            .add_nodes(nodes)
+            .into_iter()
+            .map(|ix| NodeTag(PhantomData, ix))
+            .collect()
+    }
+
     unsafe fn add_edge_unchecked(
         &mut self,
         edge: Self::Edge,
@@ -440,6 +1444,123 @@ impl<'scope, G: GraphUpdate> GraphUpdate for Context<'scope, G> {
     }
 }
 
+impl<'scope, G: GraphRemoveEdge> Context<'scope, G> {
+    /// Removes every edge from `from` to `to`, ending the scope.
+    ///
+    /// Removing edges may renumber the remaining ones, so like
+    /// [`Context::remove_nodes_edges`] this consumes the context. See
+    /// [`GraphRemoveEdge::remove_edges_between`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<(), i32> = VecGraph::default();
+    /// let removed = graph.scope_mut(|mut ctx| {
+    ///     let a = ctx.add_node(());
+    ///     let b = ctx.add_node(());
+    ///     ctx.add_edge(1, a, b);
+    ///     ctx.add_edge(2, a, b);
+    ///     ctx.remove_edges_between(a, b)
+    /// });
+    /// assert_eq!(removed, vec![1, 2]);
+    /// assert_eq!(graph.len_edges(), 0);
+    /// ```
+    pub fn remove_edges_between(
+        mut self,
+        NodeTag(_, from): NodeTag<'scope, G::NodeIx>,
+        NodeTag(_, to): NodeTag<'scope, G::NodeIx>,
+    ) -> Vec<G::Edge> {
+        self.graph.remove_edges_between(from, to)
+    }
+
+    /// Removes one edge from `from` to `to`, ending the scope.
+    ///
+    /// See [`Context::remove_edges_between`] and
+    /// [`GraphRemoveEdge::remove_edge_between`].
+    pub fn remove_edge_between(
+        mut self,
+        NodeTag(_, from): NodeTag<'scope, G::NodeIx>,
+        NodeTag(_, to): NodeTag<'scope, G::NodeIx>,
+    ) -> Option<G::Edge> {
+        self.graph.remove_edge_between(from, to)
+    }
+
+    /// Removes `edge` and continues with a new scope over the same graph.
+    ///
+    /// Removing an edge may renumber the remaining ones, so the context is consumed and
+    /// `cont` receives a re-branded context: tags obtained before the removal cannot be
+    /// used with it, and the edges have to be looked up again. This allows removing edges
+    /// one at a time while iterating, by restarting the iteration in the continuation.
+    ///
+    /// # Returns
+    ///
+    /// The data of the removed edge and the result of `cont`.
+    ///
+    /// # Examples
+    ///
+    /// Removing every edge heavier than a threshold, one at a time:
+    ///
+    /// ```rust
+    /// use gotgraph::graph::Context;
+    /// use gotgraph::prelude::*;
+    ///
+    /// fn prune(ctx: Context<'_, &mut VecGraph<(), u32>>, threshold: u32) -> Vec<u32> {
+    ///     let heavy = ctx.edge_pairs().find(|(_, &w)| w > threshold).map(|(e, _)| e);
+    ///     match heavy {
+    ///         Some(edge) => {
+    ///             let (w, mut removed) =
+    ///                 ctx.remove_edge_and_continue(edge, |ctx| prune(ctx, threshold));
+    ///             removed.push(w);
+    ///             removed
+    ///         }
+    ///         None => Vec::new(),
+    ///     }
+    /// }
+    ///
+    /// let mut graph: VecGraph<(), u32> = VecGraph::default();
+    /// let a = graph.add_node(());
+    /// let b = graph.add_node(());
+    /// for w in [5, 12, 3, 20, 8] {
+    ///     graph.add_edge(w, a, b);
+    /// }
+    ///
+    /// let mut removed = graph.scope_mut(|ctx| prune(ctx, 10));
+    /// removed.sort();
+    /// assert_eq!(removed, vec![12, 20]);
+    /// assert_eq!(graph.len_edges(), 3);
+    /// ```
+    ///
+    /// Tags of the previous scope are rejected by the new context:
+    ///
+    /// ```compile_fail
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<(), ()> = VecGraph::default();
+    /// graph.scope_mut(|mut ctx| {
+    ///     let a = ctx.add_node(());
+    ///     let e1 = ctx.add_edge((), a, a);
+    ///     let e2 = ctx.add_edge((), a, a);
+    ///     ctx.remove_edge_and_continue(e1, |ctx| {
+    ///         ctx.edge(e2); // `e2` belongs to the consumed scope
+    ///     });
+    /// });
+    /// ```
+    pub fn remove_edge_and_continue<R>(
+        mut self,
+        EdgeTag(_, edge): EdgeTag<'scope, G::EdgeIx>,
+        cont: impl for<'n> FnOnce(Context<'n, G>) -> R,
+    ) -> (G::Edge, R) {
+        let data = unsafe { self.graph.remove_edge_unchecked(edge) };
+        let result = cont(Context {
+            graph: self.graph,
+            _scope: PhantomData,
+        });
+        (data, result)
+    }
+}
+
 impl<'scope, G: GraphRemove> Context<'scope, G> {
     pub fn remove_nodes_edges<CN, CE>(
         mut self,
@@ -457,4 +1578,46 @@ impl<'scope, G: GraphRemove> Context<'scope, G> {
                 .remove_nodes_edges_unchecked(node_indices, edge_indices)
         }
     }
+
+    /// Removes `node` together with its edges and continues with a new scope over the
+    /// same graph.
+    ///
+    /// This is the node analogue of [`Context::remove_edge_and_continue`]: removing a node
+    /// may renumber other nodes and edges, so `cont` receives a re-branded context that
+    /// does not accept tags of the consumed one.
+    ///
+    /// # Returns
+    ///
+    /// The data of the removed node and the result of `cont`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let (removed, left) = graph.scope_mut(|mut ctx| {
+    ///     let a = ctx.add_node("A");
+    ///     let b = ctx.add_node("B");
+    ///     ctx.add_edge((), a, b);
+    ///     ctx.remove_node_and_continue(a, |ctx| {
+    ///         ctx.nodes().copied().collect::<Vec<_>>()
+    ///     })
+    /// });
+    /// assert_eq!(removed, "A");
+    /// assert_eq!(left, vec!["B"]);
+    /// assert_eq!(graph.len_edges(), 0);
+    /// ```
+    pub fn remove_node_and_continue<R>(
+        mut self,
+        NodeTag(_, node): NodeTag<'scope, G::NodeIx>,
+        cont: impl for<'n> FnOnce(Context<'n, G>) -> R,
+    ) -> (G::Node, R) {
+        let data = unsafe { self.graph.remove_node_unchecked(node) };
+        let result = cont(Context {
+            graph: self.graph,
+            _scope: PhantomData,
+        });
+        (data, result)
+    }
 }
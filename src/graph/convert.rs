@@ -0,0 +1,59 @@
+use super::{Graph, GraphUpdate};
+use crate::Mapping;
+use std::collections::HashMap;
+
+/// Copies a graph into a new graph of a possibly different type.
+///
+/// Nodes are added to the new graph in [`Graph::node_indices`] order, followed by the
+/// edges in [`Graph::edge_indices`] order, so self-loops and parallel edges are preserved.
+/// Payloads are cloned and converted with `From`, which also allows changing the payload
+/// types along the way.
+///
+/// # Returns
+///
+/// The new graph, together with a mapping from each node index of `src` to the index of
+/// the corresponding node in the new graph.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::graph::clone_into;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<u8, u8> = VecGraph::default();
+/// let a = graph.add_node(1);
+/// let b = graph.add_node(2);
+/// graph.add_edge(3, a, b);
+///
+/// let (copy, remap): (VecGraph<u32, u64>, _) = clone_into(&graph);
+/// assert_eq!(*copy.node(remap[b]), 2u32);
+/// assert_eq!(copy.len_edges(), 1);
+/// ```
+pub fn clone_into<Src, Dst>(
+    src: &Src,
+) -> (
+    Dst,
+    impl Mapping<Src::NodeIx, Dst::NodeIx> + use<'_, Src, Dst>,
+)
+where
+    Src: Graph,
+    Dst: GraphUpdate + Default,
+    Src::Node: Clone,
+    Src::Edge: Clone,
+    Dst::Node: From<Src::Node>,
+    Dst::Edge: From<Src::Edge>,
+{
+    let mut dst = Dst::default();
+    let new_ix: HashMap<_, _> = src
+        .node_indices()
+        .map(|ix| (ix, dst.add_node(src.node(ix).clone().into())))
+        .collect();
+
+    for (ix, edge) in src.edge_pairs() {
+        let [from, to] = src.endpoints(ix);
+        dst.add_edge(edge.clone().into(), new_ix[&from], new_ix[&to]);
+    }
+
+    let remap = src.init_node_map(move |ix, _| new_ix[&ix]);
+    (dst, remap)
+}
@@ -0,0 +1,120 @@
+use super::Graph;
+
+/// Error returned when an index does not refer to a node or edge of the graph.
+///
+/// The offending index is kept so that it can be reported or inspected.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// graph.remove_node(a);
+///
+/// let err = graph.try_node(a).unwrap_err();
+/// assert_eq!(err.index(), a);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct InvalidIndex<I> {
+    index: I,
+}
+
+impl<I> InvalidIndex<I> {
+    /// Creates an error for the given index.
+    pub fn new(index: I) -> Self {
+        InvalidIndex { index }
+    }
+
+    /// Returns the index that was not found.
+    pub fn index(self) -> I {
+        self.index
+    }
+}
+
+impl<I: core::fmt::Debug> core::fmt::Display for InvalidIndex<I> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "index {:?} does not exist in the graph", self.index)
+    }
+}
+
+impl<I: core::fmt::Debug> std::error::Error for InvalidIndex<I> {}
+
+/// Trait for graphs whose indices can be converted to and from plain integers.
+///
+/// This is useful for storing indices compactly in external arrays or passing them
+/// across an FFI boundary. The conversions are only meaningful for indices obtained from
+/// the same graph: an integer turned back into an index is not checked against the
+/// graph's contents, so it may refer to a node or edge that does not exist (or to a
+/// different one after the graph has been modified). Checked graph methods still
+/// validate such indices, so this can lead to panics but never to unsoundness.
+///
+/// Scoped graphs ([`Context`](super::Context)) do not implement this trait, since their
+/// tags guarantee that an index is valid and cannot be forged from an integer.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::graph::IndexConversion;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let a = graph.add_node("A");
+///
+/// let raw = VecGraph::<&str, ()>::node_to_usize(a);
+/// let back = VecGraph::<&str, ()>::node_from_usize(raw).unwrap();
+/// assert_eq!(back, a);
+/// assert!(graph.exists_node_index(back));
+/// ```
+pub trait IndexConversion: Graph {
+    /// Converts a node index to an integer.
+    fn node_to_usize(ix: Self::NodeIx) -> usize;
+
+    /// Converts an integer to a node index, or returns `None` if it cannot be
+    /// represented by the index type.
+    fn node_from_usize(ix: usize) -> Option<Self::NodeIx>;
+
+    /// Converts an edge index to an integer.
+    fn edge_to_usize(ix: Self::EdgeIx) -> usize;
+
+    /// Converts an integer to an edge index, or returns `None` if it cannot be
+    /// represented by the index type.
+    fn edge_from_usize(ix: usize) -> Option<Self::EdgeIx>;
+}
+
+impl<T: IndexConversion> IndexConversion for &T {
+    fn node_to_usize(ix: Self::NodeIx) -> usize {
+        T::node_to_usize(ix)
+    }
+
+    fn node_from_usize(ix: usize) -> Option<Self::NodeIx> {
+        T::node_from_usize(ix)
+    }
+
+    fn edge_to_usize(ix: Self::EdgeIx) -> usize {
+        T::edge_to_usize(ix)
+    }
+
+    fn edge_from_usize(ix: usize) -> Option<Self::EdgeIx> {
+        T::edge_from_usize(ix)
+    }
+}
+
+impl<T: IndexConversion> IndexConversion for &mut T {
+    fn node_to_usize(ix: Self::NodeIx) -> usize {
+        T::node_to_usize(ix)
+    }
+
+    fn node_from_usize(ix: usize) -> Option<Self::NodeIx> {
+        T::node_from_usize(ix)
+    }
+
+    fn edge_to_usize(ix: Self::EdgeIx) -> usize {
+        T::edge_to_usize(ix)
+    }
+
+    fn edge_from_usize(ix: usize) -> Option<Self::EdgeIx> {
+        T::edge_from_usize(ix)
+    }
+}
@@ -0,0 +1,44 @@
+/// Report of the memory used by a graph's node and edge storage.
+///
+/// Returned by [`Graph::memory_footprint`](super::Graph::memory_footprint). The byte
+/// counts cover the storage the graph allocates for its nodes and edges, including unused
+/// capacity, but not heap memory owned by the payloads themselves (such as the contents
+/// of a `String` node).
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<u64, ()> = VecGraph::default();
+/// let nodes: Vec<_> = (0..100).map(|i| graph.add_node(i)).collect();
+/// for &ix in nodes[10..].iter().rev() {
+///     graph.remove_node(ix);
+/// }
+///
+/// let memory = graph.memory_footprint();
+/// assert_eq!(memory.len_nodes, 10);
+/// assert!(memory.nodes_capacity >= 100);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct GraphMemory {
+    /// Bytes allocated for node storage.
+    pub nodes_bytes: usize,
+    /// Bytes allocated for edge storage.
+    pub edges_bytes: usize,
+    /// Number of nodes that fit in the allocated storage.
+    pub nodes_capacity: usize,
+    /// Number of edges that fit in the allocated storage.
+    pub edges_capacity: usize,
+    /// Number of nodes in the graph.
+    pub len_nodes: usize,
+    /// Number of edges in the graph.
+    pub len_edges: usize,
+}
+
+impl GraphMemory {
+    /// Returns the total number of bytes allocated for nodes and edges.
+    pub fn total_bytes(&self) -> usize {
+        self.nodes_bytes + self.edges_bytes
+    }
+}
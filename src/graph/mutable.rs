@@ -0,0 +1,525 @@
+use super::{Graph, GraphUpdate, NotAContext};
+
+/// Trait for graphs that allow their node and edge payloads to be modified.
+///
+/// This trait extends the base `Graph` trait with mutable accessors. It is implemented
+/// for owned graphs, for `&mut T` and for contexts created by `scope_mut`, but not for
+/// `&T`, so a context created by `scope` only provides read-only access and attempts to
+/// modify the graph through it fail to compile.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<i32, &str> = VecGraph::default();
+///
+/// graph.scope_mut(|mut ctx| {
+///     let node = ctx.add_node(1);
+///     *ctx.node_mut(node) += 41;
+///     assert_eq!(*ctx.node(node), 42);
+/// });
+/// ```
+///
+/// ```compile_fail
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<i32, &str> = VecGraph::default();
+/// let node = graph.add_node(1);
+///
+/// graph.scope(|ctx| {
+///     let tag = ctx.node_indices().next().unwrap();
+///     *ctx.node_mut(tag) += 41; // `&VecGraph` is not `GraphMut`
+/// });
+/// ```
+pub trait GraphMut: Graph {
+    fn node_mut(&mut self, tag: Self::NodeIx) -> &mut Self::Node {
+        assert!(
+            self.exists_node_index(tag),
+            "Node index {:?} does not exist",
+            tag
+        );
+        unsafe { self.node_unchecked_mut(tag) }
+    }
+
+    unsafe fn node_unchecked_mut(&mut self, tag: Self::NodeIx) -> &mut Self::Node;
+
+    fn edge_mut(&mut self, tag: Self::EdgeIx) -> &mut Self::Edge {
+        assert!(
+            self.exists_edge_index(tag),
+            "Edge index {:?} does not exist",
+            tag
+        );
+        unsafe { self.edge_unchecked_mut(tag) }
+    }
+
+    unsafe fn edge_unchecked_mut(&mut self, tag: Self::EdgeIx) -> &mut Self::Edge;
+
+    /// Returns mutable references to the data of several nodes at once.
+    ///
+    /// [`GraphMut::node_mut`] borrows the whole graph, so it cannot give out two
+    /// references at the same time. This method checks that the indices are distinct
+    /// instead, so that the references can coexist.
+    ///
+    /// There is no provided implementation: distinct indices are not guaranteed to refer
+    /// to distinct payloads in general, so every implementation has to establish that the
+    /// returned references do not alias.
+    ///
+    /// # Returns
+    ///
+    /// The references in the order of `ixs`, or `None` if an index does not exist or
+    /// appears more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<u32, ()> = VecGraph::default();
+    /// let a = graph.add_node(10);
+    /// let b = graph.add_node(0);
+    ///
+    /// let [from, to] = graph.get_node_disjoint_mut([a, b]).unwrap();
+    /// core::mem::swap(from, to);
+    /// assert_eq!((*graph.node(a), *graph.node(b)), (0, 10));
+    /// assert!(graph.get_node_disjoint_mut([a, a]).is_none());
+    /// ```
+    fn get_node_disjoint_mut<const K: usize>(
+        &mut self,
+        ixs: [Self::NodeIx; K],
+    ) -> Option<[&mut Self::Node; K]>;
+
+    /// Returns mutable references to the data of several edges at once.
+    ///
+    /// This is the edge analogue of [`GraphMut::get_node_disjoint_mut`]: the references
+    /// are in the order of `ixs`, or `None` is returned if an index does not exist or
+    /// appears more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<(), u32> = VecGraph::default();
+    /// let a = graph.add_node(());
+    /// let b = graph.add_node(());
+    /// let ab = graph.add_edge(5, a, b);
+    /// let ba = graph.add_edge(3, b, a);
+    ///
+    /// let [x, y] = graph.get_edge_disjoint_mut([ab, ba]).unwrap();
+    /// *x += *y;
+    /// assert_eq!(*graph.edge(ab), 8);
+    /// ```
+    fn get_edge_disjoint_mut<const K: usize>(
+        &mut self,
+        ixs: [Self::EdgeIx; K],
+    ) -> Option<[&mut Self::Edge; K]>;
+
+    fn nodes_mut(&mut self) -> impl Iterator<Item = &mut Self::Node> + use<'_, Self>
+    where
+        Self: Sized,
+    {
+        self.node_pairs_mut().map(|(_, node)| node)
+    }
+
+    fn edges_mut(&mut self) -> impl Iterator<Item = &mut Self::Edge> + use<'_, Self>
+    where
+        Self: Sized,
+    {
+        self.edge_pairs_mut().map(|(_, edge)| edge)
+    }
+
+    fn node_pairs_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (Self::NodeIx, &mut Self::Node)> + use<'_, Self>
+    where
+        Self: Sized,
+    {
+        struct NodePairsMutIter<'a, G: GraphMut> {
+            graph: &'a mut G,
+            indices: std::vec::IntoIter<G::NodeIx>,
+        }
+
+        impl<'a, G: GraphMut> Iterator for NodePairsMutIter<'a, G> {
+            type Item = (G::NodeIx, &'a mut G::Node);
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.indices.next().map(|ix| unsafe {
+                    let ptr = self.graph.node_unchecked_mut(ix) as *mut G::Node;
+                    (ix, &mut *ptr)
+                })
+            }
+        }
+
+        let indices: Vec<_> = self.node_indices().collect();
+        NodePairsMutIter {
+            graph: self,
+            indices: indices.into_iter(),
+        }
+    }
+
+    fn edge_pairs_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)> + use<'_, Self>
+    where
+        Self: Sized,
+    {
+        struct EdgePairsMutIter<'a, G: GraphMut> {
+            graph: &'a mut G,
+            indices: std::vec::IntoIter<G::EdgeIx>,
+        }
+
+        impl<'a, G: GraphMut> Iterator for EdgePairsMutIter<'a, G> {
+            type Item = (G::EdgeIx, &'a mut G::Edge);
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.indices.next().map(|ix| unsafe {
+                    let ptr = self.graph.edge_unchecked_mut(ix) as *mut G::Edge;
+                    (ix, &mut *ptr)
+                })
+            }
+        }
+
+        let indices: Vec<_> = self.edge_indices().collect();
+        EdgePairsMutIter {
+            graph: self,
+            indices: indices.into_iter(),
+        }
+    }
+
+    fn outgoing_edges_mut(
+        &mut self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = &mut Self::Edge> + use<'_, Self>
+    where
+        Self: Sized,
+    {
+        assert!(
+            self.exists_node_index(tag),
+            "Node index {:?} does not exist",
+            tag
+        );
+        unsafe { self.outgoing_edges_unchecked_mut(tag) }
+    }
+
+    unsafe fn outgoing_edges_unchecked_mut(
+        &mut self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = &mut Self::Edge> + use<'_, Self>
+    where
+        Self: Sized,
+    {
+        self.outgoing_edge_pairs_unchecked_mut(tag)
+            .map(|(_, edge)| edge)
+    }
+
+    fn outgoing_edge_pairs_mut(
+        &mut self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)> + use<'_, Self>
+    where
+        Self: Sized,
+    {
+        assert!(
+            self.exists_node_index(tag),
+            "Node index {:?} does not exist",
+            tag
+        );
+        unsafe { self.outgoing_edge_pairs_unchecked_mut(tag) }
+    }
+
+    unsafe fn outgoing_edge_pairs_unchecked_mut(
+        &mut self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
+    where
+        Self: Sized;
+
+    fn incoming_edges_mut(
+        &mut self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = &mut Self::Edge> + use<'_, Self>
+    where
+        Self: Sized,
+    {
+        assert!(
+            self.exists_node_index(tag),
+            "Node index {:?} does not exist",
+            tag
+        );
+        unsafe { self.incoming_edges_unchecked_mut(tag) }
+    }
+
+    unsafe fn incoming_edges_unchecked_mut(
+        &mut self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = &mut Self::Edge> + use<'_, Self>
+    where
+        Self: Sized,
+    {
+        self.incoming_edge_pairs_unchecked_mut(tag)
+            .map(|(_, edge)| edge)
+    }
+
+    fn incoming_edge_pairs_mut(
+        &mut self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)> + use<'_, Self>
+    where
+        Self: Sized,
+    {
+        assert!(
+            self.exists_node_index(tag),
+            "Node index {:?} does not exist",
+            tag
+        );
+        unsafe { self.incoming_edge_pairs_unchecked_mut(tag) }
+    }
+
+    unsafe fn incoming_edge_pairs_unchecked_mut(
+        &mut self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
+    where
+        Self: Sized;
+
+    fn connecting_edges_mut(
+        &mut self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = &mut Self::Edge> + use<'_, Self>
+    where
+        Self: Sized,
+    {
+        assert!(
+            self.exists_node_index(tag),
+            "Node index {:?} does not exist",
+            tag
+        );
+        unsafe { self.connecting_edges_unchecked_mut(tag) }
+    }
+
+    unsafe fn connecting_edges_unchecked_mut(
+        &mut self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = &mut Self::Edge> + use<'_, Self>
+    where
+        Self: Sized,
+    {
+        self.connecting_edge_pairs_unchecked_mut(tag)
+            .map(|(_, edge)| edge)
+    }
+
+    fn connecting_edge_pairs_mut(
+        &mut self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)> + use<'_, Self>
+    where
+        Self: Sized,
+    {
+        assert!(
+            self.exists_node_index(tag),
+            "Node index {:?} does not exist",
+            tag
+        );
+        unsafe { self.connecting_edge_pairs_unchecked_mut(tag) }
+    }
+
+    unsafe fn connecting_edge_pairs_unchecked_mut(
+        &mut self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
+    where
+        Self: Sized;
+
+    /// Runs `f` with a [`Context`](crate::graph::Context) that can modify the graph.
+    ///
+    /// # Thread Safety
+    ///
+    /// The context holds the exclusive borrow of the graph. It is `Send` whenever the
+    /// graph is, so it can be moved to another thread, but it cannot be shared: no other
+    /// thread can read the graph while the scope is running. Use [`Graph::scope`] for
+    /// parallel read-only access.
+    fn scope_mut<
+        'graph,
+        R,
+        F: for<'scope> FnOnce(crate::graph::context::Context<'scope, &'graph mut Self>) -> R,
+    >(
+        &'graph mut self,
+        f: F,
+    ) -> R
+    where
+        Self: Sized + GraphUpdate + NotAContext,
+    {
+        use core::marker::PhantomData;
+        f(crate::graph::context::Context {
+            graph: self,
+            _scope: PhantomData,
+        })
+    }
+
+    fn reverse(&mut self)
+    where
+        Self: Sized,
+    {
+        let edges: Vec<_> = self.edge_indices().collect();
+        for edge_ix in edges {
+            let [from, to] = unsafe { self.endpoints_unchecked(edge_ix) };
+            unsafe {
+                self.reverse_edge_unchecked(edge_ix, to, from);
+            }
+        }
+    }
+
+    /// Reverses the direction of an edge, swapping its source and target.
+    ///
+    /// The edge keeps its index and data. A self-loop is left as it is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the edge index does not exist in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let e = graph.add_edge((), a, b);
+    ///
+    /// graph.reverse_edge(e);
+    /// assert_eq!(graph.endpoints(e), [b, a]);
+    /// assert_eq!(graph.outgoing_edge_indices(b).collect::<Vec<_>>(), vec![e]);
+    /// assert_eq!(graph.outgoing_edge_indices(a).count(), 0);
+    /// ```
+    fn reverse_edge(&mut self, edge_ix: Self::EdgeIx)
+    where
+        Self: Sized,
+    {
+        let [from, to] = self.endpoints(edge_ix);
+        unsafe { self.reverse_edge_unchecked(edge_ix, to, from) }
+    }
+
+    /// Moves an edge so that it goes from `new_from` to `new_to`.
+    ///
+    /// The edge keeps its index and data, and is removed from the adjacency of its old
+    /// endpoints and added to that of the new ones.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the edge index or either node index does not exist in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let c = graph.add_node("C");
+    /// let e = graph.add_edge((), a, b);
+    ///
+    /// graph.rewire_edge(e, b, c);
+    /// assert_eq!(graph.endpoints(e), [b, c]);
+    /// assert_eq!(graph.incoming_edge_indices(c).collect::<Vec<_>>(), vec![e]);
+    /// assert_eq!(graph.incoming_edge_indices(b).count(), 0);
+    /// ```
+    fn rewire_edge(&mut self, edge_ix: Self::EdgeIx, new_from: Self::NodeIx, new_to: Self::NodeIx)
+    where
+        Self: Sized,
+    {
+        assert!(
+            self.exists_edge_index(edge_ix),
+            "Edge index {:?} does not exist",
+            edge_ix
+        );
+        assert!(
+            self.exists_node_index(new_from),
+            "Node index {:?} does not exist",
+            new_from
+        );
+        assert!(
+            self.exists_node_index(new_to),
+            "Node index {:?} does not exist",
+            new_to
+        );
+        unsafe { self.reverse_edge_unchecked(edge_ix, new_from, new_to) }
+    }
+
+    /// Moves an edge so that it goes from `new_from` to `new_to`, without bounds
+    /// checking.
+    ///
+    /// This is the unchecked counterpart of [`GraphMut::rewire_edge`]; the adjacency of
+    /// the old and new endpoints is updated in the same way.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `edge_ix`, `new_from` and `new_to` all exist in the
+    /// graph.
+    unsafe fn reverse_edge_unchecked(&mut self, edge_ix: Self::EdgeIx, new_from: Self::NodeIx, new_to: Self::NodeIx)
+    where
+        Self: Sized;
+}
+
+impl<T: GraphMut> GraphMut for &mut T {
+    unsafe fn node_unchecked_mut(&mut self, tag: Self::NodeIx) -> &mut Self::Node {
+        (**self).node_unchecked_mut(tag)
+    }
+
+    unsafe fn edge_unchecked_mut(&mut self, tag: Self::EdgeIx) -> &mut Self::Edge {
+        (**self).edge_unchecked_mut(tag)
+    }
+
+    fn get_node_disjoint_mut<const K: usize>(
+        &mut self,
+        ixs: [Self::NodeIx; K],
+    ) -> Option<[&mut Self::Node; K]> {
+        (**self).get_node_disjoint_mut(ixs)
+    }
+
+    fn get_edge_disjoint_mut<const K: usize>(
+        &mut self,
+        ixs: [Self::EdgeIx; K],
+    ) -> Option<[&mut Self::Edge; K]> {
+        (**self).get_edge_disjoint_mut(ixs)
+    }
+
+    unsafe fn outgoing_edge_pairs_unchecked_mut(
+        &mut self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
+    where
+        Self: Sized,
+    {
+        (**self).outgoing_edge_pairs_unchecked_mut(tag)
+    }
+
+    unsafe fn incoming_edge_pairs_unchecked_mut(
+        &mut self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
+    where
+        Self: Sized,
+    {
+        (**self).incoming_edge_pairs_unchecked_mut(tag)
+    }
+
+    unsafe fn connecting_edge_pairs_unchecked_mut(
+        &mut self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
+    where
+        Self: Sized,
+    {
+        (**self).connecting_edge_pairs_unchecked_mut(tag)
+    }
+
+    unsafe fn reverse_edge_unchecked(&mut self, edge_ix: Self::EdgeIx, new_from: Self::NodeIx, new_to: Self::NodeIx)
+    where
+        Self: Sized,
+    {
+        (**self).reverse_edge_unchecked(edge_ix, new_from, new_to)
+    }
+}
@@ -109,6 +109,133 @@ pub trait GraphRemoveEdge: Graph {
             }
         })
     }
+
+    /// Removes a batch of edges and collects their data.
+    ///
+    /// Removing an edge may renumber other edges (`VecGraph` moves the last edge into the
+    /// freed slot), so removing the indices one by one in the given order could remove
+    /// the wrong edges. This method removes them in descending index order instead, so
+    /// every index in the batch refers to the edge it referred to before the call.
+    ///
+    /// Indices that do not exist and repeated indices are skipped.
+    ///
+    /// # Returns
+    ///
+    /// The data of the removed edges, in the order their indices were given.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<(), &str> = VecGraph::default();
+    /// let a = graph.add_node(());
+    /// let b = graph.add_node(());
+    /// let e0 = graph.add_edge("e0", a, b);
+    /// let e1 = graph.add_edge("e1", a, b);
+    /// let e2 = graph.add_edge("e2", b, a);
+    ///
+    /// let removed: Vec<_> = graph.remove_edges([e0, e2]);
+    /// assert_eq!(removed, vec!["e0", "e2"]);
+    /// assert_eq!(graph.edges().collect::<Vec<_>>(), vec![&"e1"]);
+    /// ```
+    fn remove_edges<I, C>(&mut self, edges: I) -> C
+    where
+        I: IntoIterator<Item = Self::EdgeIx>,
+        C: Default + Extend<Self::Edge>,
+    {
+        let mut order: Vec<_> = edges
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, ix)| self.exists_edge_index(ix))
+            .collect();
+        // Highest index first; among repeats, keep the first occurrence
+        order.sort_unstable_by(|(i, a), (j, b)| b.cmp(a).then(i.cmp(j)));
+        order.dedup_by_key(|&mut (_, ix)| ix);
+
+        let mut removed: Vec<_> = order
+            .into_iter()
+            .map(|(pos, ix)| (pos, unsafe { self.remove_edge_unchecked(ix) }))
+            .collect();
+        removed.sort_unstable_by_key(|&(pos, _)| pos);
+
+        let mut result = C::default();
+        result.extend(removed.into_iter().map(|(_, edge)| edge));
+        result
+    }
+
+    /// Removes every edge from `from` to `to` and returns their data.
+    ///
+    /// Edges in the opposite direction are kept. The matching edges are collected first
+    /// and then removed with [`GraphRemoveEdge::remove_edges`], so the renumbering done
+    /// by each removal never makes another matching edge be missed.
+    ///
+    /// # Returns
+    ///
+    /// The data of the removed edges, in the order of their indices before the call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` or `to` does not exist in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<(), &str> = VecGraph::default();
+    /// let a = graph.add_node(());
+    /// let b = graph.add_node(());
+    /// graph.add_edge("a-b 1", a, b);
+    /// graph.add_edge("b-a", b, a);
+    /// graph.add_edge("a-b 2", a, b);
+    ///
+    /// assert_eq!(graph.remove_edges_between(a, b), vec!["a-b 1", "a-b 2"]);
+    /// assert_eq!(graph.edges().collect::<Vec<_>>(), vec![&"b-a"]);
+    /// ```
+    fn remove_edges_between(&mut self, from: Self::NodeIx, to: Self::NodeIx) -> Vec<Self::Edge> {
+        assert!(
+            self.exists_node_index(to),
+            "Node index {:?} does not exist",
+            to
+        );
+        let mut matching: Vec<_> = self
+            .outgoing_edge_indices(from)
+            .filter(|&ix| unsafe { self.endpoints_unchecked(ix) }[1] == to)
+            .collect();
+        matching.sort_unstable();
+        self.remove_edges(matching)
+    }
+
+    /// Removes one edge from `from` to `to` and returns its data.
+    ///
+    /// The removed edge is the one [`Graph::find_edge`] returns.
+    ///
+    /// # Returns
+    ///
+    /// The data of the removed edge, or `None` if there is no edge from `from` to `to`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` does not exist in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<(), &str> = VecGraph::default();
+    /// let a = graph.add_node(());
+    /// let b = graph.add_node(());
+    /// graph.add_edge("a-b", a, b);
+    ///
+    /// assert_eq!(graph.remove_edge_between(a, b), Some("a-b"));
+    /// assert_eq!(graph.remove_edge_between(a, b), None);
+    /// ```
+    fn remove_edge_between(&mut self, from: Self::NodeIx, to: Self::NodeIx) -> Option<Self::Edge> {
+        let edge = self.find_edge(from, to)?;
+        Some(unsafe { self.remove_edge_unchecked(edge) })
+    }
 }
 
 pub trait GraphRemove: GraphUpdate + GraphRemoveEdge {
@@ -204,6 +331,190 @@ pub trait GraphRemove: GraphUpdate + GraphRemoveEdge {
             }
         })
     }
+
+    /// Contracts an edge, merging its target node into its source node.
+    ///
+    /// The edge itself is removed and dropped. Every other edge connected to the target
+    /// node is rewired to the source node, so edges that ran between the two endpoints
+    /// (and self-loops on the target) become self-loops on the source node. The target
+    /// node is then removed.
+    ///
+    /// Rewired edges are re-inserted, so they get new indices. As with
+    /// [`GraphRemove::remove_node`], removing the target node may also change the
+    /// indices of other nodes, including the source node.
+    ///
+    /// # Returns
+    ///
+    /// The index the source node has after the contraction, and the payload of the target
+    /// node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the edge index does not exist or if the edge is a self-loop.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let c = graph.add_node("C");
+    /// let ab = graph.add_edge((), a, b);
+    /// graph.add_edge((), b, c);
+    ///
+    /// let (a, removed) = graph.contract_edge(ab);
+    /// assert_eq!(removed, "B");
+    /// assert_eq!(graph.len_nodes(), 2);
+    /// let [from, to] = graph.endpoints(graph.edge_indices().next().unwrap());
+    /// assert_eq!(from, a);
+    /// assert_eq!((*graph.node(from), *graph.node(to)), ("A", "C"));
+    /// ```
+    fn contract_edge(&mut self, edge: Self::EdgeIx) -> (Self::NodeIx, Self::Node)
+    where
+        Self: Sized,
+    {
+        let [from, to] = self.endpoints(edge);
+        assert!(from != to, "Cannot contract self-loop {:?}", edge);
+        self.remove_edge(edge);
+        let (from, mut merged) = self.merge_nodes(from, [to]);
+        (from, merged.pop().expect("one node was merged"))
+    }
+
+    /// Merges nodes into `keep`, generalizing [`GraphRemove::contract_edge`] to any set of
+    /// nodes.
+    ///
+    /// Every edge of a merged node is rewired to `keep`, so edges between `keep` and the
+    /// merged nodes, edges among the merged nodes and their self-loops all become
+    /// self-loops on `keep`; no edge is dropped. The merged nodes are then removed.
+    ///
+    /// Rewired edges are re-inserted, so they get new indices. As with
+    /// [`GraphRemove::remove_node`], removing the merged nodes may also change the indices
+    /// of other nodes, including `keep`.
+    ///
+    /// Because of that renumbering the new index of `keep` is returned along with the
+    /// payloads: graphs like `VecGraph` remove nodes with `swap_remove`, which moves the
+    /// last node into each freed slot, and `keep` may be that node.
+    ///
+    /// The provided implementation removes the merged nodes one at a time with
+    /// [`GraphRemove::remove_node`] and assumes it works that way: when the last node of
+    /// [`Graph::node_indices`] no longer exists after a removal, it is taken to have moved
+    /// into the freed slot. Graphs that renumber their nodes differently must override
+    /// this method.
+    ///
+    /// # Returns
+    ///
+    /// The index `keep` has after the merge, and the payloads of the merged nodes in the
+    /// order they were given.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the nodes does not exist, if a node is given twice or if `keep` is
+    /// among the merged nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let c = graph.add_node("C");
+    /// let d = graph.add_node("D");
+    /// graph.add_edge((), a, b);
+    /// graph.add_edge((), b, d);
+    /// graph.add_edge((), d, c);
+    ///
+    /// let (d, removed) = graph.merge_nodes(d, [b, c]);
+    /// assert_eq!(removed, vec!["B", "C"]);
+    /// assert_eq!(graph.len_nodes(), 2);
+    /// assert_eq!(*graph.node(d), "D");
+    /// assert_eq!(graph.len_edges(), 3);
+    /// assert_eq!(graph.self_loop_edges().count(), 2);
+    /// ```
+    fn merge_nodes(
+        &mut self,
+        keep: Self::NodeIx,
+        merge: impl IntoIterator<Item = Self::NodeIx>,
+    ) -> (Self::NodeIx, Vec<Self::Node>)
+    where
+        Self: Sized,
+    {
+        let mut pending = rewire_merged_edges(self, keep, merge);
+        let mut keep = keep;
+        let mut nodes = Vec::with_capacity(pending.len());
+        for i in 0..pending.len() {
+            let node = pending[i];
+            let last = self
+                .node_indices()
+                .last()
+                .expect("the node to remove exists");
+            nodes.push(self.remove_node(node));
+            if last != node && !self.exists_node_index(last) {
+                // The last node moved into the freed slot
+                if keep == last {
+                    keep = node;
+                }
+                for later in &mut pending[i + 1..] {
+                    if *later == last {
+                        *later = node;
+                    }
+                }
+            }
+        }
+        (keep, nodes)
+    }
+}
+
+/// Checks the arguments of [`GraphRemove::merge_nodes`] and moves every edge of the
+/// merged nodes onto `keep`, returning the merged nodes, which are left without edges.
+pub(crate) fn rewire_merged_edges<G: GraphRemove>(
+    graph: &mut G,
+    keep: G::NodeIx,
+    merge: impl IntoIterator<Item = G::NodeIx>,
+) -> Vec<G::NodeIx> {
+    assert!(
+        graph.exists_node_index(keep),
+        "Node index {:?} does not exist",
+        keep
+    );
+    let merge: Vec<_> = merge.into_iter().collect();
+    let mut seen = std::collections::HashSet::with_capacity(merge.len());
+    for &node in &merge {
+        assert!(
+            graph.exists_node_index(node),
+            "Node index {:?} does not exist",
+            node
+        );
+        assert!(node != keep, "Cannot merge node {:?} into itself", node);
+        assert!(seen.insert(node), "Node {:?} is merged twice", node);
+    }
+
+    // Indices are looked up again after every removal, since removing an edge may
+    // renumber the remaining ones. Edges towards nodes merged later are rewired again
+    // when their turn comes.
+    for &node in &merge {
+        loop {
+            let Some(ix) = graph.outgoing_edge_indices(node).next() else {
+                break;
+            };
+            let [_, target] = graph.endpoints(ix);
+            let target = if target == node { keep } else { target };
+            let data = graph.remove_edge(ix);
+            graph.add_edge(data, keep, target);
+        }
+        loop {
+            let Some(ix) = graph.incoming_edge_indices(node).next() else {
+                break;
+            };
+            let [source, _] = graph.endpoints(ix);
+            let data = graph.remove_edge(ix);
+            graph.add_edge(data, source, keep);
+        }
+    }
+    merge
 }
 
 impl<T: GraphRemoveEdge> GraphRemoveEdge for &mut T {
@@ -245,4 +556,12 @@ impl<T: GraphRemove> GraphRemove for &mut T {
     {
         (**self).remove_nodes_edges_unchecked(nodes, edges)
     }
+
+    fn merge_nodes(
+        &mut self,
+        keep: Self::NodeIx,
+        merge: impl IntoIterator<Item = Self::NodeIx>,
+    ) -> (Self::NodeIx, Vec<Self::Node>) {
+        (**self).merge_nodes(keep, merge)
+    }
 }
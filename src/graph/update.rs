@@ -1,8 +1,51 @@
-use super::Graph;
+use super::GraphMut;
+
+/// Error returned when a graph cannot hold any more nodes or edges.
+///
+/// The payload that could not be inserted is handed back, so no data is lost.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::graph::CapacityError;
+///
+/// let err = CapacityError::new("payload");
+/// assert_eq!(err.into_inner(), "payload");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError<T> {
+    payload: T,
+}
+
+impl<T> CapacityError<T> {
+    /// Creates an error carrying the payload that could not be inserted.
+    pub fn new(payload: T) -> Self {
+        CapacityError { payload }
+    }
+
+    /// Returns the payload that could not be inserted.
+    pub fn into_inner(self) -> T {
+        self.payload
+    }
+}
+
+impl<T> core::fmt::Debug for CapacityError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CapacityError").finish_non_exhaustive()
+    }
+}
+
+impl<T> core::fmt::Display for CapacityError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "graph has reached its maximum capacity")
+    }
+}
+
+impl<T> std::error::Error for CapacityError<T> {}
 
 /// Trait for graphs that support adding nodes and edges.
 ///
-/// This trait extends the base `Graph` trait with mutation operations for adding
+/// This trait extends the `GraphMut` trait with mutation operations for adding
 /// new nodes and edges to the graph. It provides both checked and unchecked variants
 /// for performance-critical scenarios.
 ///
@@ -22,7 +65,7 @@ use super::Graph;
 ///              ctx.node(node1), ctx.node(node2));
 /// });
 /// ```
-pub trait GraphUpdate: Graph {
+pub trait GraphUpdate: GraphMut {
     /// Adds a new node to the graph with the given data.
     ///
     /// # Parameters
@@ -81,6 +124,111 @@ pub trait GraphUpdate: Graph {
         unsafe { self.add_edge_unchecked(edge, from, to) }
     }
 
+    /// Adds a new node to the graph, or returns the payload back if the graph is full.
+    ///
+    /// The default implementation never fails and simply calls [`GraphUpdate::add_node`];
+    /// graphs with a hard limit on the number of nodes override it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CapacityError`] holding `node` if no more nodes can be added.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let node = graph.try_add_node("Alice").unwrap();
+    /// assert_eq!(*graph.node(node), "Alice");
+    /// ```
+    fn try_add_node(
+        &mut self,
+        node: Self::Node,
+    ) -> Result<Self::NodeIx, CapacityError<Self::Node>> {
+        Ok(self.add_node(node))
+    }
+
+    /// Adds a new edge to the graph, or returns the payload back if the graph is full.
+    ///
+    /// The default implementation never fails and simply calls [`GraphUpdate::add_edge`];
+    /// graphs with a hard limit on the number of edges override it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CapacityError`] holding `edge` if no more edges can be added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either `from` or `to` node indices don't exist in the graph.
+    fn try_add_edge(
+        &mut self,
+        edge: Self::Edge,
+        from: Self::NodeIx,
+        to: Self::NodeIx,
+    ) -> Result<Self::EdgeIx, CapacityError<Self::Edge>> {
+        Ok(self.add_edge(edge, from, to))
+    }
+
+    /// Adds a new node whose data is computed from its own index.
+    ///
+    /// `f` is called exactly once with the index the node will have, which is useful
+    /// for payloads that need to refer to themselves.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no more nodes can be added to the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<String, ()> = VecGraph::default();
+    /// graph.scope_mut(|mut ctx| {
+    ///     let node = ctx.add_node_with(|ix| format!("{:?}", ix.inner()));
+    ///     assert_eq!(*ctx.node(node), format!("{:?}", node.inner()));
+    /// });
+    /// ```
+    fn add_node_with(&mut self, f: impl FnOnce(Self::NodeIx) -> Self::Node) -> Self::NodeIx;
+
+    /// Adds a new edge whose data is computed from its own index.
+    ///
+    /// `f` is called exactly once with the index the edge will have, after the endpoints
+    /// have been checked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either `from` or `to` node indices don't exist in the graph, or if no
+    /// more edges can be added.
+    fn add_edge_with(
+        &mut self,
+        from: Self::NodeIx,
+        to: Self::NodeIx,
+        f: impl FnOnce(Self::EdgeIx) -> Self::Edge,
+    ) -> Self::EdgeIx;
+
+    /// Adds a batch of nodes and returns their indices, in iteration order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no more nodes can be added to the graph. Nodes added before that point
+    /// are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let nodes = graph.add_nodes(["A", "B", "C"]);
+    /// graph.add_edge((), nodes[0], nodes[2]);
+    /// assert_eq!(*graph.node(nodes[1]), "B");
+    /// ```
+    fn add_nodes<I: IntoIterator<Item = Self::Node>>(&mut self, nodes: I) -> Vec<Self::NodeIx> {
+        nodes.into_iter().map(|node| self.add_node(node)).collect()
+    }
+
     /// Adds a new edge to the graph between two nodes without bounds checking.
     ///
     /// # Safety
@@ -107,6 +255,129 @@ pub trait GraphUpdate: Graph {
         self.add_edge(edge, from, to)
     }
 
+    /// Replaces the data of a node, returning the old data.
+    ///
+    /// The node keeps its index and edges.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node index does not exist in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let node = graph.add_node("old");
+    /// assert_eq!(graph.replace_node(node, "new"), "old");
+    /// assert_eq!(*graph.node(node), "new");
+    /// ```
+    fn replace_node(&mut self, ix: Self::NodeIx, node: Self::Node) -> Self::Node {
+        core::mem::replace(self.node_mut(ix), node)
+    }
+
+    /// Replaces the data of a node without bounds checking, returning the old data.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the node index exists in the graph.
+    unsafe fn replace_node_unchecked(&mut self, ix: Self::NodeIx, node: Self::Node) -> Self::Node {
+        core::mem::replace(self.node_unchecked_mut(ix), node)
+    }
+
+    /// Replaces the data of an edge, returning the old data.
+    ///
+    /// The edge keeps its index and endpoints.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the edge index does not exist in the graph.
+    fn replace_edge(&mut self, ix: Self::EdgeIx, edge: Self::Edge) -> Self::Edge {
+        core::mem::replace(self.edge_mut(ix), edge)
+    }
+
+    /// Replaces the data of an edge without bounds checking, returning the old data.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the edge index exists in the graph.
+    unsafe fn replace_edge_unchecked(&mut self, ix: Self::EdgeIx, edge: Self::Edge) -> Self::Edge {
+        core::mem::replace(self.edge_unchecked_mut(ix), edge)
+    }
+
+    /// Moves the data out of a node, leaving `Default::default()` in its place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node index does not exist in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<Vec<u32>, ()> = VecGraph::default();
+    /// let node = graph.add_node(vec![1, 2]);
+    ///
+    /// let mut state = graph.take_node(node);
+    /// assert!(graph.node(node).is_empty());
+    /// state.push(3);
+    /// graph.replace_node(node, state);
+    /// assert_eq!(*graph.node(node), vec![1, 2, 3]);
+    /// ```
+    fn take_node(&mut self, ix: Self::NodeIx) -> Self::Node
+    where
+        Self::Node: Default,
+    {
+        core::mem::take(self.node_mut(ix))
+    }
+
+    /// Swaps the data of two nodes.
+    ///
+    /// Only the payloads move: every edge stays attached to the same node index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node index does not exist in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let e = graph.add_edge((), a, b);
+    ///
+    /// graph.swap_nodes(a, b);
+    /// assert_eq!((*graph.node(a), *graph.node(b)), ("B", "A"));
+    /// assert_eq!(graph.endpoints(e), [a, b]);
+    /// ```
+    fn swap_nodes(&mut self, a: Self::NodeIx, b: Self::NodeIx) {
+        assert!(
+            self.exists_node_index(a),
+            "Node index {:?} does not exist",
+            a
+        );
+        assert!(
+            self.exists_node_index(b),
+            "Node index {:?} does not exist",
+            b
+        );
+        if a == b {
+            return;
+        }
+        // Only one mutable borrow of the graph can exist at a time, so move the data of
+        // `a` out bitwise and write the data of `b` back over it
+        unsafe {
+            let data_a = core::ptr::read(self.node_unchecked_mut(a));
+            let data_b = self.replace_node_unchecked(b, data_a);
+            core::ptr::write(self.node_unchecked_mut(a), data_b);
+        }
+    }
+
     fn append<G>(&mut self, mut other: G)
     where
         Self: Sized,
@@ -156,6 +427,39 @@ impl<T: GraphUpdate> GraphUpdate for &mut T {
         (**self).add_edge(edge, from, to)
     }
 
+    fn try_add_node(
+        &mut self,
+        node: Self::Node,
+    ) -> Result<Self::NodeIx, CapacityError<Self::Node>> {
+        (**self).try_add_node(node)
+    }
+
+    fn try_add_edge(
+        &mut self,
+        edge: Self::Edge,
+        from: Self::NodeIx,
+        to: Self::NodeIx,
+    ) -> Result<Self::EdgeIx, CapacityError<Self::Edge>> {
+        (**self).try_add_edge(edge, from, to)
+    }
+
+    fn add_node_with(&mut self, f: impl FnOnce(Self::NodeIx) -> Self::Node) -> Self::NodeIx {
+        (**self).add_node_with(f)
+    }
+
+    fn add_edge_with(
+        &mut self,
+        from: Self::NodeIx,
+        to: Self::NodeIx,
+        f: impl FnOnce(Self::EdgeIx) -> Self::Edge,
+    ) -> Self::EdgeIx {
+        (**self).add_edge_with(from, to, f)
+    }
+
+    fn add_nodes<I: IntoIterator<Item = Self::Node>>(&mut self, nodes: I) -> Vec<Self::NodeIx> {
+        (**self).add_nodes(nodes)
+    }
+
     unsafe fn add_edge_unchecked(
         &mut self,
         edge: Self::Edge,
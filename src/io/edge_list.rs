@@ -0,0 +1,114 @@
+use crate::prelude::*;
+use crate::vec_graph::{NodeIx, ParseError};
+use std::fmt::Write;
+
+/// Parses a weighted graph from a comma-separated edge list.
+///
+/// Every line holds one edge as `from,to,weight`, where `from` and `to` are node
+/// indices and `weight` is a floating point number; whitespace around the fields is
+/// ignored. Blank lines and lines starting with `#` are skipped, as is anything after a
+/// `#` on an edge line. The graph has one node for every index up to the largest one
+/// seen, with the index as payload, and edges are added in line order.
+///
+/// # Errors
+///
+/// Returns [`ParseError::FieldCount`] for a row without exactly three fields, and
+/// [`ParseError::InvalidIndex`] or [`ParseError::InvalidWeight`] for a field that cannot
+/// be parsed, all with the 1-based line number.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::io::from_edge_list_str;
+/// use gotgraph::prelude::*;
+/// use gotgraph::vec_graph::ParseError;
+///
+/// let graph = from_edge_list_str("# from,to,weight\n0,1,0.5\n1,3,2\n").unwrap();
+/// assert_eq!(graph.nodes().copied().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+/// assert_eq!(graph.edges().copied().collect::<Vec<_>>(), vec![0.5, 2.0]);
+///
+/// let err = from_edge_list_str("0,1,0.5\n1,2,heavy\n").unwrap_err();
+/// assert_eq!(err.to_string(), r#"line 2: "heavy" is not a valid weight"#);
+/// ```
+pub fn from_edge_list_str(s: &str) -> Result<VecGraph<u32, f64>, ParseError> {
+    let mut edges = Vec::new();
+    for (i, line) in s.lines().enumerate() {
+        let line_number = i + 1;
+        let content = line.split('#').next().unwrap_or_default().trim();
+        if content.is_empty() {
+            continue;
+        }
+        let fields: Vec<_> = content.split(',').map(str::trim).collect();
+        let [from, to, weight] = fields[..] else {
+            return Err(ParseError::FieldCount {
+                line: line_number,
+                fields: fields.len(),
+            });
+        };
+        let index = |token: &str| {
+            token
+                .parse::<u32>()
+                .ok()
+                .filter(|&ix| ix != u32::MAX)
+                .ok_or_else(|| ParseError::InvalidIndex {
+                    line: line_number,
+                    token: token.to_string(),
+                })
+        };
+        let weight = weight
+            .parse::<f64>()
+            .map_err(|_| ParseError::InvalidWeight {
+                line: line_number,
+                token: weight.to_string(),
+            })?;
+        edges.push((index(from)?, index(to)?, weight));
+    }
+
+    let len = edges
+        .iter()
+        .map(|&(from, to, _)| from.max(to) + 1)
+        .max()
+        .unwrap_or(0);
+    let mut graph = VecGraph::default();
+    graph.add_nodes_range(0..len);
+    graph.extend(edges.into_iter().map(|(from, to, weight)| {
+        let node = |ix: u32| NodeIx::try_from(ix as usize).unwrap();
+        (node(from), node(to), weight)
+    }));
+    Ok(graph)
+}
+
+/// Writes the edges of a weighted graph as a comma-separated edge list.
+///
+/// Nodes are numbered `0`, `1`, ... in the order of `node_indices`, and every edge is
+/// written as a `from,to,weight` line in the order of `edge_indices`. Weights are
+/// written so that [`from_edge_list_str`] reads back the same values. Nodes after the
+/// last one with an edge are not recorded, since an edge list only describes nodes
+/// through their edges.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::io::to_edge_list_str;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, f64> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// graph.add_edge(1.5, a, b);
+/// graph.add_edge(-2.0, b, b);
+///
+/// assert_eq!(to_edge_list_str(&graph), "0,1,1.5\n1,1,-2\n");
+/// ```
+pub fn to_edge_list_str<G: Graph<Edge = f64>>(graph: &G) -> String {
+    let mut ids = graph.init_node_map(|_, _| 0);
+    for (i, ix) in graph.node_indices().enumerate() {
+        ids[ix] = i;
+    }
+    let mut out = String::new();
+    for (ix, weight) in graph.edge_pairs() {
+        let [from, to] = graph.endpoints(ix);
+        writeln!(out, "{},{},{}", ids[from], ids[to], weight).unwrap();
+    }
+    out
+}
@@ -0,0 +1,539 @@
+use super::{Attributes, ReadError, ReadErrorKind};
+use crate::prelude::*;
+use quick_xml::escape::escape;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::collections::{BTreeSet, HashMap};
+use std::io::{Read, Write};
+
+/// Writes a graph as a GraphML document.
+///
+/// Nodes get the ids `n0`, `n1`, ... in the order of `node_indices`, and edges are
+/// written in the order of `edge_indices`, so self-loops and parallel edges are kept.
+/// Every attribute name returned by `node_attrs` or `edge_attrs` is declared as a
+/// `<key>` of type `string`, and each attribute becomes a `<data>` element.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::io::{write_graphml, Attributes};
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, u32> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// graph.add_edge(5, a, b);
+///
+/// let mut out = Vec::new();
+/// write_graphml(
+///     &graph,
+///     &mut out,
+///     |name| Attributes::from([("name".to_string(), name.to_string())]),
+///     |weight| Attributes::from([("weight".to_string(), weight.to_string())]),
+/// )
+/// .unwrap();
+///
+/// let xml = String::from_utf8(out).unwrap();
+/// assert!(xml.contains(r#"<edge source="n0" target="n1">"#));
+/// ```
+pub fn write_graphml<G: Graph>(
+    graph: &G,
+    mut writer: impl Write,
+    mut node_attrs: impl FnMut(&G::Node) -> Attributes,
+    mut edge_attrs: impl FnMut(&G::Edge) -> Attributes,
+) -> std::io::Result<()> {
+    let nodes: Vec<_> = graph
+        .node_pairs()
+        .map(|(ix, node)| (ix, node_attrs(node)))
+        .collect();
+    let edges: Vec<_> = graph
+        .edge_pairs()
+        .map(|(ix, edge)| (graph.endpoints(ix), edge_attrs(edge)))
+        .collect();
+    let mut ids = graph.init_node_map(|_, _| 0);
+    for (i, &(ix, _)) in nodes.iter().enumerate() {
+        ids[ix] = i;
+    }
+
+    // Key ids are assigned in sorted attribute name order
+    let node_keys: BTreeSet<_> = nodes.iter().flat_map(|(_, attrs)| attrs.keys()).collect();
+    let edge_keys: BTreeSet<_> = edges.iter().flat_map(|(_, attrs)| attrs.keys()).collect();
+    let node_keys: HashMap<_, _> = node_keys
+        .into_iter()
+        .enumerate()
+        .map(|(i, name)| (name, format!("dn{}", i)))
+        .collect();
+    let edge_keys: HashMap<_, _> = edge_keys
+        .into_iter()
+        .enumerate()
+        .map(|(i, name)| (name, format!("de{}", i)))
+        .collect();
+
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        writer,
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#
+    )?;
+    for (domain, keys) in [("node", &node_keys), ("edge", &edge_keys)] {
+        let mut keys: Vec<_> = keys.iter().collect();
+        keys.sort_by_key(|&(name, _)| *name);
+        for (name, id) in keys {
+            writeln!(
+                writer,
+                r#"  <key id="{}" for="{}" attr.name="{}" attr.type="string"/>"#,
+                id,
+                domain,
+                escape(name.as_str())
+            )?;
+        }
+    }
+    writeln!(writer, r#"  <graph edgedefault="directed">"#)?;
+    for (i, (_, attrs)) in nodes.iter().enumerate() {
+        write_element(
+            &mut writer,
+            format_args!(r#"node id="n{}""#, i),
+            "node",
+            attrs,
+            &node_keys,
+        )?;
+    }
+    for ([from, to], attrs) in &edges {
+        write_element(
+            &mut writer,
+            format_args!(r#"edge source="n{}" target="n{}""#, ids[*from], ids[*to]),
+            "edge",
+            attrs,
+            &edge_keys,
+        )?;
+    }
+    writeln!(writer, "  </graph>")?;
+    writeln!(writer, "</graphml>")?;
+    Ok(())
+}
+
+/// Returns a graph as a GraphML document.
+///
+/// This is [`write_graphml`] writing into a `String`; see there for the layout of the
+/// document.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::io::{to_graphml, Attributes};
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let a = graph.add_node("A & B");
+/// let b = graph.add_node("C");
+/// graph.add_edge((), a, b);
+///
+/// let xml = to_graphml(
+///     &graph,
+///     |name| Attributes::from([("label".to_string(), name.to_string())]),
+///     |_| Attributes::new(),
+/// );
+/// assert!(xml.contains(r#"<graph edgedefault="directed">"#));
+/// assert!(xml.contains("A &amp; B"));
+/// assert!(xml.contains(r#"<edge source="n0" target="n1"/>"#));
+/// ```
+pub fn to_graphml<G, FN, FE>(graph: &G, node_attr: FN, edge_attr: FE) -> String
+where
+    G: Graph,
+    FN: FnMut(&G::Node) -> Attributes,
+    FE: FnMut(&G::Edge) -> Attributes,
+{
+    let mut out = Vec::new();
+    write_graphml(graph, &mut out, node_attr, edge_attr).expect("writing to a Vec cannot fail");
+    String::from_utf8(out).expect("GraphML output is UTF-8")
+}
+
+fn write_element(
+    writer: &mut impl Write,
+    open: core::fmt::Arguments<'_>,
+    name: &str,
+    attrs: &Attributes,
+    keys: &HashMap<&String, String>,
+) -> std::io::Result<()> {
+    if attrs.is_empty() {
+        return writeln!(writer, "    <{}/>", open);
+    }
+    writeln!(writer, "    <{}>", open)?;
+    for (key, value) in attrs {
+        writeln!(
+            writer,
+            r#"      <data key="{}">{}</data>"#,
+            keys[key],
+            escape(value.as_str())
+        )?;
+    }
+    writeln!(writer, "    </{}>", name)
+}
+
+/// A `<key>` declaration.
+struct Key {
+    name: String,
+    domain: String,
+    default: Option<String>,
+}
+
+impl Key {
+    fn applies_to(&self, element: &str) -> bool {
+        self.domain == element || self.domain == "all"
+    }
+}
+
+/// A `<node>` or `<edge>` element whose end tag has not been read yet.
+struct Element {
+    line: usize,
+    description: String,
+    kind: ElementKind,
+    attrs: Attributes,
+}
+
+enum ElementKind {
+    Node(String),
+    Edge(String, String),
+}
+
+/// Reads a graph from a GraphML document.
+///
+/// Nodes and edges are added in document order, so the `i`-th `<node>` element gets
+/// the `NodeIx` with `index() == i`. Edges may refer to nodes declared after them.
+/// The `<data>` children of each element are collected into [`Attributes`] keyed by the
+/// `attr.name` of their `<key>` (or the key id if it has none), with `<default>` values
+/// filled in, and passed to `node` or `edge`. Errors returned by these closures are
+/// reported as [`ReadErrorKind::Payload`].
+///
+/// Every edge is read as directed from `source` to `target`, regardless of
+/// `edgedefault`. Nested graphs, hyperedges and ports are not supported: nested
+/// graphs are rejected and the other elements are ignored.
+///
+/// # Errors
+///
+/// Returns a [`ReadError`] with the line and element of the problem if the document is
+/// not well-formed XML, if a node has no `id` or an edge no `source`/`target`, if node
+/// ids repeat, if an edge refers to an unknown node, if a `<data>` element refers to an
+/// undeclared key, or if a payload closure fails.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::io::read_graphml;
+/// use gotgraph::prelude::*;
+///
+/// let xml = r#"<graphml>
+///   <key id="w" for="edge" attr.name="weight"/>
+///   <graph edgedefault="directed">
+///     <node id="a"/>
+///     <node id="b"/>
+///     <edge source="a" target="b"><data key="w">2.5</data></edge>
+///   </graph>
+/// </graphml>"#;
+///
+/// let graph = read_graphml(
+///     xml.as_bytes(),
+///     |_| Ok(()),
+///     |attrs| attrs["weight"].parse::<f64>().map_err(|e| e.to_string()),
+/// )
+/// .unwrap();
+/// assert_eq!(graph.edges().collect::<Vec<_>>(), vec![&2.5]);
+///
+/// let err = read_graphml(
+///     r#"<graphml><graph><edge source="a" target="b"/></graph></graphml>"#.as_bytes(),
+///     |_| Ok(()),
+///     |_| Ok(()),
+/// )
+/// .unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     r#"line 1: <edge source="a" target="b">: edge refers to unknown node "a""#
+/// );
+/// ```
+pub fn read_graphml<N, E>(
+    mut reader: impl Read,
+    mut node: impl FnMut(&Attributes) -> Result<N, String>,
+    mut edge: impl FnMut(&Attributes) -> Result<E, String>,
+) -> Result<VecGraph<N, E>, ReadError> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    let line_at = |pos: u64| {
+        let pos = (pos as usize).min(input.len());
+        input.as_bytes()[..pos]
+            .iter()
+            .filter(|&&b| b == b'\n')
+            .count()
+            + 1
+    };
+
+    let mut xml = Reader::from_str(&input);
+    xml.config_mut().trim_text(true);
+
+    let mut graph = VecGraph::default();
+    let mut ids = HashMap::new();
+    let mut edges = Vec::new();
+    let mut keys: HashMap<String, Key> = HashMap::new();
+    let mut seen_root = false;
+    let mut in_graph = false;
+    // Innermost open `<key>`, `<node>`/`<edge>` and `<data>`/`<default>` elements
+    let mut current_key: Option<String> = None;
+    let mut current: Option<Element> = None;
+    let mut text: Option<(String, String)> = None;
+
+    loop {
+        let event = xml.read_event().map_err(|err| {
+            ReadError::new(ReadErrorKind::Syntax(err.to_string()))
+                .at_line(line_at(xml.error_position()))
+        })?;
+        let line = line_at(xml.buffer_position());
+        let syntax = |message: &str| {
+            ReadError::new(ReadErrorKind::Syntax(message.to_string())).at_line(line)
+        };
+
+        let (start, is_empty) = match event {
+            Event::Start(start) => (start, false),
+            Event::Empty(start) => (start, true),
+            Event::End(end) => {
+                match end.local_name().as_ref() {
+                    b"graph" => in_graph = false,
+                    b"key" => current_key = None,
+                    b"default" => {
+                        let key = current_key.as_ref().and_then(|id| keys.get_mut(id));
+                        if let (Some((_, value)), Some(key)) = (text.take(), key) {
+                            key.default = Some(value);
+                        }
+                    }
+                    b"data" => {
+                        if let (Some((name, value)), Some(element)) = (text.take(), &mut current) {
+                            element.attrs.insert(name, value);
+                        }
+                    }
+                    b"node" | b"edge" => {
+                        if let Some(element) = current.take() {
+                            finish_element(
+                                element, &keys, &mut graph, &mut ids, &mut edges, &mut node,
+                            )?;
+                        }
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+            Event::Text(content) => {
+                if let Some((_, value)) = &mut text {
+                    let content = content.unescape().map_err(|err| syntax(&err.to_string()))?;
+                    value.push_str(&content);
+                }
+                continue;
+            }
+            Event::CData(content) => {
+                if let Some((_, value)) = &mut text {
+                    let content = content.decode().map_err(|err| syntax(&err.to_string()))?;
+                    value.push_str(&content);
+                }
+                continue;
+            }
+            Event::Eof => break,
+            _ => continue,
+        };
+
+        let tag = start.local_name();
+        if !seen_root {
+            if tag.as_ref() != b"graphml" {
+                return Err(syntax("the root element is not <graphml>"));
+            }
+            seen_root = true;
+            continue;
+        }
+        match tag.as_ref() {
+            b"key" => {
+                let description = describe(&start);
+                let id = attribute(&start, "id", line, &description)?
+                    .ok_or_else(|| missing("id", line, &description))?;
+                let name = attribute(&start, "attr.name", line, &description)?
+                    .unwrap_or_else(|| id.clone());
+                let domain = attribute(&start, "for", line, &description)?
+                    .unwrap_or_else(|| "all".to_string());
+                keys.insert(
+                    id.clone(),
+                    Key {
+                        name,
+                        domain,
+                        default: None,
+                    },
+                );
+                if !is_empty {
+                    current_key = Some(id);
+                }
+            }
+            b"default" => {
+                let key = current_key.as_ref().and_then(|id| keys.get_mut(id));
+                match key {
+                    Some(key) if is_empty => key.default = Some(String::new()),
+                    Some(_) => text = Some((String::new(), String::new())),
+                    None => {}
+                }
+            }
+            b"graph" => {
+                if in_graph || current.is_some() {
+                    return Err(syntax("nested graphs are not supported"));
+                }
+                in_graph = !is_empty;
+            }
+            b"node" | b"edge" if in_graph => {
+                let description = describe(&start);
+                let kind = if tag.as_ref() == b"node" {
+                    let id = attribute(&start, "id", line, &description)?
+                        .ok_or_else(|| missing("id", line, &description))?;
+                    ElementKind::Node(id)
+                } else {
+                    let source = attribute(&start, "source", line, &description)?
+                        .ok_or_else(|| missing("source", line, &description))?;
+                    let target = attribute(&start, "target", line, &description)?
+                        .ok_or_else(|| missing("target", line, &description))?;
+                    ElementKind::Edge(source, target)
+                };
+                let element = Element {
+                    line,
+                    description,
+                    kind,
+                    attrs: Attributes::new(),
+                };
+                if is_empty {
+                    finish_element(element, &keys, &mut graph, &mut ids, &mut edges, &mut node)?;
+                } else {
+                    current = Some(element);
+                }
+            }
+            b"data" => {
+                let description = describe(&start);
+                let key = attribute(&start, "key", line, &description)?
+                    .ok_or_else(|| missing("key", line, &description))?;
+                let name = match keys.get(&key) {
+                    Some(key) => key.name.clone(),
+                    None => {
+                        return Err(ReadError::new(ReadErrorKind::UnknownKey(key))
+                            .at_line(line)
+                            .in_element(description))
+                    }
+                };
+                match &mut current {
+                    Some(element) if is_empty => {
+                        element.attrs.insert(name, String::new());
+                    }
+                    Some(_) => text = Some((name, String::new())),
+                    // Data on the graph itself is not kept
+                    None => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if !seen_root {
+        return Err(ReadError::new(ReadErrorKind::Syntax(
+            "the input contains no <graphml> element".to_string(),
+        )));
+    }
+
+    for (element, source, target) in edges {
+        let Element {
+            line,
+            description,
+            attrs,
+            ..
+        } = element;
+        let error = |kind| ReadError::new(kind).at_line(line).in_element(&description);
+        let from = *ids
+            .get(&source)
+            .ok_or_else(|| error(ReadErrorKind::UnknownNode(source.clone())))?;
+        let to = *ids
+            .get(&target)
+            .ok_or_else(|| error(ReadErrorKind::UnknownNode(target.clone())))?;
+        let data = edge(&attrs).map_err(|message| error(ReadErrorKind::Payload(message)))?;
+        graph.add_edge(data, from, to);
+    }
+
+    Ok(graph)
+}
+
+/// Adds a finished node to the graph, or queues a finished edge until all nodes are known.
+fn finish_element<N, E>(
+    mut element: Element,
+    keys: &HashMap<String, Key>,
+    graph: &mut VecGraph<N, E>,
+    ids: &mut HashMap<String, crate::vec_graph::NodeIx>,
+    edges: &mut Vec<(Element, String, String)>,
+    node: &mut impl FnMut(&Attributes) -> Result<N, String>,
+) -> Result<(), ReadError> {
+    let domain = match element.kind {
+        ElementKind::Node(_) => "node",
+        ElementKind::Edge(..) => "edge",
+    };
+    for key in keys.values().filter(|key| key.applies_to(domain)) {
+        if let Some(default) = &key.default {
+            element
+                .attrs
+                .entry(key.name.clone())
+                .or_insert_with(|| default.clone());
+        }
+    }
+
+    match core::mem::replace(&mut element.kind, ElementKind::Node(String::new())) {
+        ElementKind::Node(id) => {
+            let error = |kind| {
+                ReadError::new(kind)
+                    .at_line(element.line)
+                    .in_element(&element.description)
+            };
+            if ids.contains_key(&id) {
+                return Err(error(ReadErrorKind::DuplicateNode(id)));
+            }
+            let data =
+                node(&element.attrs).map_err(|message| error(ReadErrorKind::Payload(message)))?;
+            ids.insert(id, graph.add_node(data));
+        }
+        ElementKind::Edge(source, target) => edges.push((element, source, target)),
+    }
+    Ok(())
+}
+
+/// Returns a start tag as it would be written, for error messages.
+fn describe(start: &BytesStart<'_>) -> String {
+    let mut description = format!("<{}", String::from_utf8_lossy(start.name().as_ref()));
+    for attr in start.attributes().flatten() {
+        description.push_str(&format!(
+            " {}=\"{}\"",
+            String::from_utf8_lossy(attr.key.as_ref()),
+            String::from_utf8_lossy(&attr.value)
+        ));
+    }
+    description.push('>');
+    description
+}
+
+fn attribute(
+    start: &BytesStart<'_>,
+    name: &str,
+    line: usize,
+    description: &str,
+) -> Result<Option<String>, ReadError> {
+    let error = |message: String| {
+        ReadError::new(ReadErrorKind::Syntax(message))
+            .at_line(line)
+            .in_element(description)
+    };
+    match start.try_get_attribute(name) {
+        Ok(Some(attr)) => attr
+            .unescape_value()
+            .map(|value| Some(value.into_owned()))
+            .map_err(|err| error(err.to_string())),
+        Ok(None) => Ok(None),
+        Err(err) => Err(error(err.to_string())),
+    }
+}
+
+fn missing(name: &'static str, line: usize, description: &str) -> ReadError {
+    ReadError::new(ReadErrorKind::Missing(name))
+        .at_line(line)
+        .in_element(description)
+}
@@ -0,0 +1,229 @@
+use super::{Attributes, ReadError, ReadErrorKind};
+use crate::prelude::*;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::io::{Read, Write};
+
+/// Writes a graph in the node-link JSON format used by d3 and networkx.
+///
+/// The output is an object with a `nodes` array and a `links` array. Nodes get the ids
+/// `0`, `1`, ... in the order of `node_indices`, and each link refers to them with
+/// `source` and `target`; links are written in the order of `edge_indices`, so
+/// self-loops and parallel edges are kept. The attributes returned by `node_attrs` and
+/// `edge_attrs` become string fields of the node and link objects. The field names
+/// `id`, `source` and `target` are reserved and override attributes of the same name.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::io::{write_json_node_link, Attributes};
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// graph.add_edge((), a, b);
+///
+/// let mut out = Vec::new();
+/// write_json_node_link(
+///     &graph,
+///     &mut out,
+///     |name| Attributes::from([("name".to_string(), name.to_string())]),
+///     |_| Attributes::new(),
+/// )
+/// .unwrap();
+///
+/// let json = String::from_utf8(out).unwrap();
+/// assert!(json.contains(r#""name": "B""#));
+/// ```
+pub fn write_json_node_link<G: Graph>(
+    graph: &G,
+    writer: impl Write,
+    mut node_attrs: impl FnMut(&G::Node) -> Attributes,
+    mut edge_attrs: impl FnMut(&G::Edge) -> Attributes,
+) -> std::io::Result<()> {
+    let mut ids = graph.init_node_map(|_, _| 0);
+    let nodes: Vec<_> = graph
+        .node_pairs()
+        .enumerate()
+        .map(|(i, (ix, node))| {
+            ids[ix] = i;
+            let mut object = to_object(node_attrs(node));
+            object.insert("id".to_string(), i.into());
+            Value::Object(object)
+        })
+        .collect();
+    let links: Vec<_> = graph
+        .edge_pairs()
+        .map(|(ix, edge)| {
+            let [from, to] = graph.endpoints(ix);
+            let mut object = to_object(edge_attrs(edge));
+            object.insert("source".to_string(), ids[from].into());
+            object.insert("target".to_string(), ids[to].into());
+            Value::Object(object)
+        })
+        .collect();
+
+    let mut document = Map::new();
+    document.insert("directed".to_string(), true.into());
+    document.insert("multigraph".to_string(), true.into());
+    document.insert("graph".to_string(), Value::Object(Map::new()));
+    document.insert("nodes".to_string(), nodes.into());
+    document.insert("links".to_string(), links.into());
+    serde_json::to_writer_pretty(writer, &Value::Object(document))?;
+    Ok(())
+}
+
+fn to_object(attrs: Attributes) -> Map<String, Value> {
+    attrs
+        .into_iter()
+        .map(|(name, value)| (name, Value::String(value)))
+        .collect()
+}
+
+/// Reads a graph in the node-link JSON format used by d3 and networkx.
+///
+/// The input must be an object with a `nodes` array of objects, each with an `id`, and
+/// optionally a `links` (or `edges`) array of objects with `source` and `target` ids.
+/// Ids may be strings or numbers. Nodes and links are added in array order, so the
+/// `i`-th node gets the `NodeIx` with `index() == i`. The remaining fields of each object
+/// are passed to `node` or `edge` as [`Attributes`]: strings as they are, other values
+/// as JSON text. Errors returned by these closures are reported as
+/// [`ReadErrorKind::Payload`].
+///
+/// Every link is read as directed from `source` to `target`, even if the document has
+/// `"directed": false`.
+///
+/// # Errors
+///
+/// Returns a [`ReadError`] if the input is not valid JSON (with the line of the
+/// problem), or, with the offending element such as `links[3]`, if the document does
+/// not have the structure above, if node ids repeat, if a link refers to an unknown
+/// node, or if a payload closure fails.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::io::read_json_node_link;
+/// use gotgraph::prelude::*;
+///
+/// let json = r#"{
+///     "nodes": [{"id": "a", "label": "A"}, {"id": "b", "label": "B"}],
+///     "links": [{"source": "a", "target": "b", "weight": 3}]
+/// }"#;
+///
+/// let graph = read_json_node_link(
+///     json.as_bytes(),
+///     |attrs| Ok(attrs["label"].clone()),
+///     |attrs| attrs["weight"].parse::<u32>().map_err(|e| e.to_string()),
+/// )
+/// .unwrap();
+/// assert_eq!(graph.len_nodes(), 2);
+/// assert_eq!(graph.edges().collect::<Vec<_>>(), vec![&3]);
+///
+/// let err = read_json_node_link(
+///     r#"{"nodes": [{"id": 0}], "links": [{"source": 0, "target": 7}]}"#.as_bytes(),
+///     |_| Ok(()),
+///     |_| Ok(()),
+/// )
+/// .unwrap_err();
+/// assert_eq!(err.to_string(), r#"links[0]: edge refers to unknown node "7""#);
+/// ```
+pub fn read_json_node_link<N, E>(
+    reader: impl Read,
+    mut node: impl FnMut(&Attributes) -> Result<N, String>,
+    mut edge: impl FnMut(&Attributes) -> Result<E, String>,
+) -> Result<VecGraph<N, E>, ReadError> {
+    let document: Value = serde_json::from_reader(reader).map_err(|err| {
+        if err.is_io() {
+            ReadError::from(std::io::Error::from(err))
+        } else {
+            let line = err.line();
+            ReadError::new(ReadErrorKind::Syntax(err.to_string())).at_line(line)
+        }
+    })?;
+    let syntax = |message: &str, element: &str| {
+        ReadError::new(ReadErrorKind::Syntax(message.to_string())).in_element(element)
+    };
+
+    let Value::Object(mut document) = document else {
+        return Err(ReadError::new(ReadErrorKind::Syntax(
+            "the document is not a JSON object".to_string(),
+        )));
+    };
+    let nodes = match document.remove("nodes") {
+        Some(Value::Array(nodes)) => nodes,
+        Some(_) => return Err(syntax("expected an array", "nodes")),
+        None => return Err(ReadError::new(ReadErrorKind::Missing("nodes"))),
+    };
+    let (links_name, links) = match document.remove("links") {
+        Some(links) => ("links", links),
+        None => ("edges", document.remove("edges").unwrap_or_default()),
+    };
+    let links = match links {
+        Value::Array(links) => links,
+        Value::Null => Vec::new(),
+        _ => return Err(syntax("expected an array", links_name)),
+    };
+
+    let mut graph = VecGraph::default();
+    let mut ids = HashMap::new();
+    for (i, value) in nodes.into_iter().enumerate() {
+        let element = format!("nodes[{}]", i);
+        let error = |kind| ReadError::new(kind).in_element(&element);
+        let Value::Object(mut object) = value else {
+            return Err(syntax("expected an object", &element));
+        };
+        let id = take_id(&mut object, "id").map_err(error)?;
+        if ids.contains_key(&id) {
+            return Err(error(ReadErrorKind::DuplicateNode(id)));
+        }
+        let data = node(&to_attributes(object))
+            .map_err(|message| error(ReadErrorKind::Payload(message)))?;
+        ids.insert(id, graph.add_node(data));
+    }
+
+    for (i, value) in links.into_iter().enumerate() {
+        let element = format!("{}[{}]", links_name, i);
+        let error = |kind| ReadError::new(kind).in_element(&element);
+        let Value::Object(mut object) = value else {
+            return Err(syntax("expected an object", &element));
+        };
+        let mut endpoint = |name| {
+            let id = take_id(&mut object, name).map_err(error)?;
+            ids.get(&id)
+                .copied()
+                .ok_or_else(|| error(ReadErrorKind::UnknownNode(id)))
+        };
+        let from = endpoint("source")?;
+        let to = endpoint("target")?;
+        let data = edge(&to_attributes(object))
+            .map_err(|message| error(ReadErrorKind::Payload(message)))?;
+        graph.add_edge(data, from, to);
+    }
+
+    Ok(graph)
+}
+
+/// Removes an id field from `object`, accepting strings and numbers.
+fn take_id(object: &mut Map<String, Value>, name: &'static str) -> Result<String, ReadErrorKind> {
+    match object.remove(name) {
+        Some(Value::String(id)) => Ok(id),
+        Some(Value::Number(id)) => Ok(id.to_string()),
+        Some(_) => Err(ReadErrorKind::Syntax(format!(
+            "`{}` must be a string or a number",
+            name
+        ))),
+        None => Err(ReadErrorKind::Missing(name)),
+    }
+}
+
+fn to_attributes(object: Map<String, Value>) -> Attributes {
+    object
+        .into_iter()
+        .map(|(name, value)| match value {
+            Value::String(value) => (name, value),
+            value => (name, value.to_string()),
+        })
+        .collect()
+}
@@ -0,0 +1,147 @@
+//! Reading and writing graphs in interchange formats.
+//!
+//! For the richer formats, payloads are converted to and from
+//! [`Attributes`](crate::io::Attributes), maps from attribute names to string values, by
+//! closures passed to the readers and writers. Each of them is behind its own feature:
+//!
+//! - `graphml`: [GraphML](http://graphml.graphdrawing.org/), with `write_graphml`,
+//!   `to_graphml` and `read_graphml`.
+//! - `json`: the "node-link" JSON format used by d3 and networkx, with
+//!   `write_json_node_link` and `read_json_node_link`.
+//!
+//! Plain `from,to,weight` edge lists are always available through
+//! [`from_edge_list_str`](crate::io::from_edge_list_str) and
+//! [`to_edge_list_str`](crate::io::to_edge_list_str).
+//!
+//! Readers return a [`VecGraph`](crate::vec_graph::VecGraph) whose node and edge indices
+//! follow the order of the input. They report invalid input as a
+//! [`ReadError`](crate::io::ReadError), or a [`ParseError`](crate::vec_graph::ParseError)
+//! for edge lists, instead of panicking.
+
+/// Comma-separated weighted edge lists.
+pub mod edge_list;
+/// GraphML reading and writing.
+#[cfg(feature = "graphml")]
+pub mod graphml;
+/// Node-link JSON reading and writing.
+#[cfg(feature = "json")]
+pub mod json;
+
+pub use edge_list::{from_edge_list_str, to_edge_list_str};
+#[cfg(feature = "graphml")]
+pub use graphml::{read_graphml, to_graphml, write_graphml};
+#[cfg(feature = "json")]
+pub use json::{read_json_node_link, write_json_node_link};
+
+/// String attributes of a node or edge, keyed by attribute name.
+pub type Attributes = std::collections::BTreeMap<String, String>;
+
+/// Error returned when reading a graph from invalid input.
+///
+/// Besides the [`ReadErrorKind`], the error records where the problem was found when
+/// the format allows it: the line number in the input and a description of the
+/// element, such as `<edge source="n0" target="n9">` or `links[3]`.
+#[derive(Debug)]
+pub struct ReadError {
+    kind: ReadErrorKind,
+    line: Option<usize>,
+    element: Option<String>,
+}
+
+/// The reason a graph could not be read.
+#[derive(Debug)]
+pub enum ReadErrorKind {
+    /// Reading the input failed.
+    Io(std::io::Error),
+    /// The input is not well-formed, or does not have the structure of the format.
+    Syntax(String),
+    /// A required attribute or field is missing.
+    Missing(&'static str),
+    /// Two nodes have the same id.
+    DuplicateNode(String),
+    /// An edge refers to a node id that is not declared in the input.
+    UnknownNode(String),
+    /// A GraphML `<data>` element refers to an undeclared `<key>`.
+    UnknownKey(String),
+    /// The payload conversion closure rejected the attributes.
+    Payload(String),
+}
+
+impl ReadError {
+    /// Creates an error without location information.
+    pub fn new(kind: ReadErrorKind) -> Self {
+        ReadError {
+            kind,
+            line: None,
+            element: None,
+        }
+    }
+
+    #[cfg_attr(not(any(feature = "graphml", feature = "json")), allow(dead_code))]
+    pub(crate) fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    #[cfg_attr(not(any(feature = "graphml", feature = "json")), allow(dead_code))]
+    pub(crate) fn in_element(mut self, element: impl Into<String>) -> Self {
+        self.element = Some(element.into());
+        self
+    }
+
+    /// Returns the reason of the error.
+    pub fn kind(&self) -> &ReadErrorKind {
+        &self.kind
+    }
+
+    /// Returns the 1-based line of the input where the error was found, if known.
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    /// Returns a description of the element where the error was found, if known.
+    pub fn element(&self) -> Option<&str> {
+        self.element.as_deref()
+    }
+}
+
+impl core::fmt::Display for ReadErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ReadErrorKind::Io(err) => write!(f, "{}", err),
+            ReadErrorKind::Syntax(message) => write!(f, "malformed input: {}", message),
+            ReadErrorKind::Missing(name) => write!(f, "missing required attribute `{}`", name),
+            ReadErrorKind::DuplicateNode(id) => write!(f, "duplicate node id {:?}", id),
+            ReadErrorKind::UnknownNode(id) => write!(f, "edge refers to unknown node {:?}", id),
+            ReadErrorKind::UnknownKey(key) => write!(f, "data refers to undeclared key {:?}", key),
+            ReadErrorKind::Payload(message) => write!(f, "invalid payload: {}", message),
+        }
+    }
+}
+
+impl core::fmt::Display for ReadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {}: ", line)?;
+        }
+        if let Some(element) = &self.element {
+            write!(f, "{}: ", element)?;
+        }
+        write!(f, "{}", self.kind)
+    }
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            ReadErrorKind::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ReadError {
+    fn from(err: std::io::Error) -> Self {
+        ReadError::new(ReadErrorKind::Io(err))
+    }
+}
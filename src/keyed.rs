@@ -0,0 +1,368 @@
+use crate::graph::{Graph, GraphMemory, GraphUpdate, IndexConversion, NotAContext};
+use crate::vec_graph::{EdgeIx, IndexType, NodeIx, VecGraph};
+use crate::Mapping;
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Error returned by [`KeyedGraph::add_node_keyed`] when a node with the same key
+/// already exists.
+///
+/// The graph is left unchanged and the rejected payload is handed back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateKey<N, Ix = u32> {
+    index: NodeIx<Ix>,
+    payload: N,
+}
+
+impl<N, Ix: Copy> DuplicateKey<N, Ix> {
+    /// Returns the index of the node that already has the key.
+    pub fn index(&self) -> NodeIx<Ix> {
+        self.index
+    }
+
+    /// Returns the payload that was not inserted.
+    pub fn into_payload(self) -> N {
+        self.payload
+    }
+}
+
+impl<N, Ix: IndexType> std::fmt::Display for DuplicateKey<N, Ix> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "a node with this key already exists at index {}",
+            self.index.index()
+        )
+    }
+}
+
+impl<N: std::fmt::Debug, Ix: IndexType> std::error::Error for DuplicateKey<N, Ix> {}
+
+/// Error returned by [`KeyedGraph::add_edge_by_keys`] when an endpoint key is unknown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingKey {
+    /// No node has the key of the source endpoint.
+    Source,
+    /// No node has the key of the target endpoint.
+    Target,
+}
+
+impl std::fmt::Display for MissingKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MissingKey::Source => write!(f, "no node has the key of the source endpoint"),
+            MissingKey::Target => write!(f, "no node has the key of the target endpoint"),
+        }
+    }
+}
+
+impl std::error::Error for MissingKey {}
+
+/// A `VecGraph` whose nodes are identified by unique keys.
+///
+/// The graph owns the map from keys to node indices and updates it when removing a node
+/// moves another one into the freed slot, so lookups by key stay valid across removals
+/// while node indices do not. Keys are stored twice, in the map and next to the nodes,
+/// hence the `Clone` bound on insertion.
+///
+/// `KeyedGraph` implements [`Graph`] by delegation, so algorithms and [`Graph::scope`]
+/// work on it directly. It does not implement the mutating traits, which could add or
+/// remove nodes behind the back of the key map; mutate it through its own methods.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::algo::tarjan;
+/// use gotgraph::keyed::KeyedGraph;
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: KeyedGraph<String, u32, ()> = KeyedGraph::default();
+/// graph.add_node_keyed("a".to_string(), 1).unwrap();
+/// graph.add_node_keyed("b".to_string(), 2).unwrap();
+/// graph.add_node_keyed("c".to_string(), 3).unwrap();
+/// graph.add_edge_by_keys((), "a", "b").unwrap();
+/// graph.add_edge_by_keys((), "b", "a").unwrap();
+///
+/// assert_eq!(tarjan(&graph).count(), 2);
+///
+/// assert_eq!(graph.remove_node_by_key("a"), Some(1));
+/// assert_eq!(graph.node_by_key("c"), Some(&3));
+/// assert_eq!(graph.len_edges(), 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyedGraph<K, N, E, Ix = u32> {
+    graph: VecGraph<N, E, Ix>,
+    indices: HashMap<K, NodeIx<Ix>>,
+    // Key of every node, at the position of its index
+    keys: Vec<K>,
+}
+
+unsafe impl<K, N, E, Ix> NotAContext for KeyedGraph<K, N, E, Ix> {}
+
+// Only for the default index type, as for `VecGraph`
+impl<K, N, E> Default for KeyedGraph<K, N, E> {
+    fn default() -> Self {
+        Self::with_index_type()
+    }
+}
+
+impl<K: Eq + Hash, N, E> KeyedGraph<K, N, E> {
+    /// Creates an empty graph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<K: Eq + Hash, N, E, Ix: IndexType> KeyedGraph<K, N, E, Ix> {
+    /// Adds a node identified by `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DuplicateKey`] holding the index of the existing node and `payload` if
+    /// a node with `key` already exists. The graph is not modified in that case.
+    pub fn add_node_keyed(&mut self, key: K, payload: N) -> Result<NodeIx<Ix>, DuplicateKey<N, Ix>>
+    where
+        K: Clone,
+    {
+        if let Some(&index) = self.indices.get(&key) {
+            return Err(DuplicateKey { index, payload });
+        }
+        let ix = self.graph.add_node(payload);
+        self.indices.insert(key.clone(), ix);
+        self.keys.push(key);
+        Ok(ix)
+    }
+
+    /// Returns the index of the node identified by `key`, if any.
+    pub fn index_of<Q>(&self, key: &Q) -> Option<NodeIx<Ix>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.indices.get(key).copied()
+    }
+
+    /// Returns the payload of the node identified by `key`, if any.
+    pub fn node_by_key<Q>(&self, key: &Q) -> Option<&N>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.index_of(key).map(|ix| self.graph.node(ix))
+    }
+
+    /// Returns the key of the node at `ix`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ix` is not a valid node index of the graph.
+    pub fn key_of(&self, ix: NodeIx<Ix>) -> &K {
+        &self.keys[ix.index()]
+    }
+
+    /// Adds an edge between the nodes identified by `from` and `to`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MissingKey`] if one of the keys does not belong to a node, reporting the
+    /// source first. The graph is not modified in that case.
+    pub fn add_edge_by_keys<Q>(
+        &mut self,
+        edge: E,
+        from: &Q,
+        to: &Q,
+    ) -> Result<EdgeIx<Ix>, MissingKey>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let from = self.index_of(from).ok_or(MissingKey::Source)?;
+        let to = self.index_of(to).ok_or(MissingKey::Target)?;
+        Ok(self.graph.add_edge(edge, from, to))
+    }
+
+    /// Removes the node identified by `key` together with its edges and returns its
+    /// payload, or `None` if no node has that key.
+    ///
+    /// As with [`VecGraph`], the last node is moved into the freed slot and edges may be
+    /// renumbered; the keys of all remaining nodes stay valid.
+    pub fn remove_node_by_key<Q>(&mut self, key: &Q) -> Option<N>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let ix = self.indices.remove(key)?;
+        let (payload, moved) = self.graph.remove_node_relocating(ix);
+        self.keys.swap_remove(ix.index());
+        if let Some(moved) = moved {
+            debug_assert_eq!(moved.index(), self.keys.len());
+            let moved_key = &self.keys[ix.index()];
+            *self
+                .indices
+                .get_mut::<K>(moved_key)
+                .expect("every node key is in the index map") = ix;
+        }
+        Some(payload)
+    }
+}
+
+impl<K, N, E, Ix: IndexType> KeyedGraph<K, N, E, Ix> {
+    /// Creates an empty graph storing its indices as `Ix`.
+    ///
+    /// See [`VecGraph::with_index_type`].
+    pub fn with_index_type() -> Self {
+        Self {
+            graph: VecGraph::with_index_type(),
+            indices: HashMap::new(),
+            keys: Vec::new(),
+        }
+    }
+
+    /// Returns the underlying graph, for its read-only `VecGraph` methods.
+    pub fn as_vec_graph(&self) -> &VecGraph<N, E, Ix> {
+        &self.graph
+    }
+
+    /// Returns the underlying graph, dropping the keys.
+    pub fn into_vec_graph(self) -> VecGraph<N, E, Ix> {
+        self.graph
+    }
+}
+
+impl<K, N, E, Ix: IndexType> Graph for KeyedGraph<K, N, E, Ix> {
+    type Node = N;
+    type Edge = E;
+    type NodeIx = NodeIx<Ix>;
+    type EdgeIx = EdgeIx<Ix>;
+
+    fn exists_node_index(&self, ix: Self::NodeIx) -> bool {
+        self.graph.exists_node_index(ix)
+    }
+
+    fn exists_edge_index(&self, ix: Self::EdgeIx) -> bool {
+        self.graph.exists_edge_index(ix)
+    }
+
+    unsafe fn node_unchecked(&self, ix: Self::NodeIx) -> &Self::Node {
+        self.graph.node_unchecked(ix)
+    }
+
+    unsafe fn edge_unchecked(&self, ix: Self::EdgeIx) -> &Self::Edge {
+        self.graph.edge_unchecked(ix)
+    }
+
+    fn node_indices(&self) -> impl Iterator<Item = Self::NodeIx> {
+        self.graph.node_indices()
+    }
+
+    fn edge_indices(&self) -> impl Iterator<Item = Self::EdgeIx> {
+        self.graph.edge_indices()
+    }
+
+    fn len_nodes(&self) -> usize {
+        self.graph.len_nodes()
+    }
+
+    fn len_edges(&self) -> usize {
+        self.graph.len_edges()
+    }
+
+    fn memory_footprint(&self) -> GraphMemory {
+        self.graph.memory_footprint()
+    }
+
+    fn index_generation(&self) -> Option<u64> {
+        self.graph.index_generation()
+    }
+
+    unsafe fn outgoing_edge_indices_unchecked(
+        &self,
+        node: Self::NodeIx,
+    ) -> impl Iterator<Item = Self::EdgeIx> {
+        self.graph.outgoing_edge_indices_unchecked(node)
+    }
+
+    unsafe fn incoming_edge_indices_unchecked(
+        &self,
+        node: Self::NodeIx,
+    ) -> impl Iterator<Item = Self::EdgeIx> {
+        self.graph.incoming_edge_indices_unchecked(node)
+    }
+
+    unsafe fn endpoints_unchecked(&self, edge: Self::EdgeIx) -> [Self::NodeIx; 2] {
+        self.graph.endpoints_unchecked(edge)
+    }
+
+    unsafe fn outgoing_edge_pairs_unchecked(
+        &self,
+        node: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge)> {
+        self.graph.outgoing_edge_pairs_unchecked(node)
+    }
+
+    unsafe fn incoming_edge_pairs_unchecked(
+        &self,
+        node: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge)> {
+        self.graph.incoming_edge_pairs_unchecked(node)
+    }
+
+    fn outgoing_targets(
+        &self,
+        node: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge, Self::NodeIx, &Self::Node)> {
+        self.graph.outgoing_targets(node)
+    }
+
+    fn incoming_sources(
+        &self,
+        node: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge, Self::NodeIx, &Self::Node)> {
+        self.graph.incoming_sources(node)
+    }
+
+    fn for_each_outgoing(&self, f: impl FnMut(Self::NodeIx, Self::EdgeIx, &Self::Edge)) {
+        self.graph.for_each_outgoing(f)
+    }
+
+    fn fold_edges<B>(
+        &self,
+        init: B,
+        f: impl FnMut(B, Self::NodeIx, Self::EdgeIx, &Self::Edge) -> B,
+    ) -> B {
+        self.graph.fold_edges(init, f)
+    }
+
+    fn init_node_map<V>(
+        &self,
+        f: impl FnMut(Self::NodeIx, &Self::Node) -> V,
+    ) -> impl Mapping<Self::NodeIx, V> {
+        self.graph.init_node_map(f)
+    }
+
+    fn init_edge_map<V>(
+        &self,
+        f: impl FnMut(Self::EdgeIx, &Self::Edge) -> V,
+    ) -> impl Mapping<Self::EdgeIx, V> {
+        self.graph.init_edge_map(f)
+    }
+}
+
+impl<K, N, E, Ix: IndexType> IndexConversion for KeyedGraph<K, N, E, Ix> {
+    fn node_to_usize(ix: NodeIx<Ix>) -> usize {
+        VecGraph::<N, E, Ix>::node_to_usize(ix)
+    }
+
+    fn node_from_usize(ix: usize) -> Option<NodeIx<Ix>> {
+        VecGraph::<N, E, Ix>::node_from_usize(ix)
+    }
+
+    fn edge_to_usize(ix: EdgeIx<Ix>) -> usize {
+        VecGraph::<N, E, Ix>::edge_to_usize(ix)
+    }
+
+    fn edge_from_usize(ix: usize) -> Option<EdgeIx<Ix>> {
+        VecGraph::<N, E, Ix>::edge_from_usize(ix)
+    }
+}
@@ -2,8 +2,23 @@
 
 /// Graph algorithms module containing strongly connected components and other graph algorithms.
 pub mod algo;
+/// Comparison of two graph snapshots by user-provided node keys.
+pub mod diff;
+/// Immutable graphs shared between threads.
+pub mod frozen;
 /// Core graph traits and context-based operations.
 pub mod graph;
+/// Reading and writing graphs in interchange formats.
+pub mod io;
+/// Graphs whose nodes are looked up by user-provided keys.
+pub mod keyed;
+/// Proptest strategies generating random graphs.
+#[cfg(feature = "proptest")]
+pub mod proptest;
+/// Batches of additions and removals applied to a graph at once.
+pub mod transaction;
+/// Undirected view over the vector-based graph implementation.
+pub mod undirected;
 /// Vector-based graph implementation.
 pub mod vec_graph;
 
@@ -21,7 +36,7 @@ pub mod vec_graph;
 /// // Now you have access to Graph, GraphUpdate, etc.
 /// ```
 pub mod prelude {
-    pub use crate::graph::{Graph, GraphRemove, GraphRemoveEdge, GraphUpdate};
+    pub use crate::graph::{Graph, GraphMut, GraphRemove, GraphRemoveEdge, GraphUpdate};
     pub use crate::vec_graph::VecGraph;
 }
 
@@ -85,6 +100,95 @@ pub trait Mapping<K, V>: std::ops::IndexMut<K, Output = V> + IntoIterator<Item =
     where
         V: 'a;
 
+    /// Returns an iterator over the keys of this mapping.
+    ///
+    /// Keys are visited in the same order as [`Mapping::iter`] visits the values.
+    fn keys(&self) -> impl Iterator<Item = K>;
+
+    /// Returns an iterator over the keys of this mapping together with their values.
+    ///
+    /// The order is the same as the one of [`Mapping::iter`] and [`Mapping::keys`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    /// use gotgraph::Mapping;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("a");
+    /// let b = graph.add_node("b");
+    ///
+    /// let names = graph.init_node_map(|_, name| name.to_uppercase());
+    /// let pairs: Vec<_> = names.pairs().map(|(ix, name)| (ix, name.as_str())).collect();
+    /// assert_eq!(pairs, vec![(a, "A"), (b, "B")]);
+    /// ```
+    fn pairs<'a>(&'a self) -> impl Iterator<Item = (K, &'a V)>
+    where
+        V: 'a;
+
+    /// Consumes the mapping and returns an iterator over its keys and values.
+    ///
+    /// The order is the same as the one of [`Mapping::pairs`].
+    fn into_pairs(self) -> impl Iterator<Item = (K, V)>;
+
+    /// Folds every value into an accumulator, in the order of [`Mapping::iter`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    /// use gotgraph::Mapping;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// graph.add_node("ab");
+    /// graph.add_node("cde");
+    ///
+    /// let lengths = graph.init_node_map(|_, name| name.len());
+    /// assert_eq!(lengths.fold_values(0, |total, len| total + len), 5);
+    /// ```
+    fn fold_values<B>(&self, init: B, f: impl FnMut(B, &V) -> B) -> B {
+        self.iter().fold(init, f)
+    }
+
+    /// Returns the key holding the largest value, together with that value.
+    ///
+    /// If several values are equally large, the last one in the order of
+    /// [`Mapping::pairs`] is returned, as with [`Iterator::max`]. Returns `None` if the
+    /// mapping is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    /// use gotgraph::Mapping;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let c = graph.add_node("C");
+    /// graph.add_edge((), a, b);
+    /// graph.add_edge((), c, b);
+    ///
+    /// let in_degree = graph.init_node_map(|ix, _| graph.incoming_edge_indices(ix).count());
+    /// assert_eq!(in_degree.max_by_key_value(), Some((b, &2)));
+    /// ```
+    fn max_by_key_value(&self) -> Option<(K, &V)>
+    where
+        V: Ord,
+    {
+        self.pairs().max_by(|(_, a), (_, b)| a.cmp(b))
+    }
+
+    /// Consumes the mapping and collects it into a [`HashMap`](std::collections::HashMap).
+    fn to_hash_map(self) -> std::collections::HashMap<K, V>
+    where
+        K: Eq + std::hash::Hash,
+        Self: Sized,
+    {
+        self.into_pairs().collect()
+    }
+
     /// Gets a reference to the value associated with the given key without bounds checking.
     ///
     /// # Safety
@@ -0,0 +1,242 @@
+//! [proptest](https://docs.rs/proptest) strategies generating random graphs.
+//!
+//! [`vec_graph`] builds a [`VecGraph`](crate::vec_graph::VecGraph) from strategies for
+//! the node and edge payloads, and [`VecGraph`](crate::vec_graph::VecGraph) implements
+//! [`Arbitrary`](::proptest::arbitrary::Arbitrary) when its payloads do. Edges connect
+//! uniformly chosen nodes, so self-loops and parallel edges occur.
+//!
+//! When a test fails, the graph is shrunk by first removing edges, then removing nodes
+//! that no remaining edge touches, and finally shrinking the payloads one by one. Every
+//! intermediate value is therefore a valid graph with the edges of the original.
+
+use crate::graph::GraphUpdate;
+use crate::vec_graph::{NodeIx, VecGraph};
+use ::proptest::arbitrary::{any_with, Arbitrary};
+use ::proptest::collection::SizeRange;
+use ::proptest::strategy::{NewTree, Strategy, ValueTree};
+use ::proptest::test_runner::TestRunner;
+
+/// Creates a strategy generating [`VecGraph`]s.
+///
+/// The node count is chosen from `len_nodes` and the edge count from `len_edges`, then
+/// every payload is drawn from `node` or `edge`. A graph without nodes never has edges,
+/// even if `len_edges` asks for some.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::prelude::*;
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///     fn edges_have_endpoints(graph in gotgraph::proptest::vec_graph(0..10u32, any::<u8>(), 1..8, 0..16)) {
+///         for edge in graph.edge_indices() {
+///             let [from, to] = graph.endpoints(edge);
+///             prop_assert!(graph.exists_node_index(from) && graph.exists_node_index(to));
+///         }
+///     }
+/// }
+/// # edges_have_endpoints();
+/// ```
+pub fn vec_graph<NS: Strategy, ES: Strategy>(
+    node: NS,
+    edge: ES,
+    len_nodes: impl Into<SizeRange>,
+    len_edges: impl Into<SizeRange>,
+) -> VecGraphStrategy<NS, ES> {
+    VecGraphStrategy {
+        node,
+        edge,
+        len_nodes: len_nodes.into(),
+        len_edges: len_edges.into(),
+    }
+}
+
+/// Strategy returned by [`vec_graph`].
+#[derive(Clone, Debug)]
+#[must_use = "strategies do nothing unless used"]
+pub struct VecGraphStrategy<NS, ES> {
+    node: NS,
+    edge: ES,
+    len_nodes: SizeRange,
+    len_edges: SizeRange,
+}
+
+impl<NS: Strategy, ES: Strategy> Strategy for VecGraphStrategy<NS, ES> {
+    type Tree = VecGraphValueTree<NS::Tree, ES::Tree>;
+    type Value = VecGraph<NS::Value, ES::Value>;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        let (start, end) = self.len_nodes.start_end_incl();
+        let len_nodes = (start..=end).new_tree(runner)?.current();
+        let nodes = (0..len_nodes)
+            .map(|_| self.node.new_tree(runner))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (start, end) = self.len_edges.start_end_incl();
+        let len_edges = if len_nodes == 0 {
+            0
+        } else {
+            (start..=end).new_tree(runner)?.current()
+        };
+        let mut edges = Vec::with_capacity(len_edges);
+        for _ in 0..len_edges {
+            let from = (0..len_nodes).new_tree(runner)?.current();
+            let to = (0..len_nodes).new_tree(runner)?.current();
+            edges.push((from, to, self.edge.new_tree(runner)?));
+        }
+
+        Ok(VecGraphValueTree {
+            included_nodes: vec![true; nodes.len()],
+            included_edges: vec![true; edges.len()],
+            min_nodes: self.len_nodes.start(),
+            min_edges: self.len_edges.start().min(edges.len()),
+            nodes,
+            edges,
+            shrink: Shrink::RemoveEdge(0),
+            prev_shrink: None,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Shrink {
+    RemoveEdge(usize),
+    RemoveNode(usize),
+    NodePayload(usize),
+    EdgePayload(usize),
+}
+
+/// Value tree of [`VecGraphStrategy`].
+#[derive(Clone, Debug)]
+pub struct VecGraphValueTree<NT, ET> {
+    nodes: Vec<NT>,
+    edges: Vec<(usize, usize, ET)>,
+    included_nodes: Vec<bool>,
+    included_edges: Vec<bool>,
+    min_nodes: usize,
+    min_edges: usize,
+    shrink: Shrink,
+    prev_shrink: Option<Shrink>,
+}
+
+impl<NT, ET> VecGraphValueTree<NT, ET> {
+    fn is_isolated(&self, node: usize) -> bool {
+        self.edges
+            .iter()
+            .zip(&self.included_edges)
+            .all(|(&(from, to, _), &included)| !included || (from != node && to != node))
+    }
+}
+
+impl<NT: ValueTree, ET: ValueTree> ValueTree for VecGraphValueTree<NT, ET> {
+    type Value = VecGraph<NT::Value, ET::Value>;
+
+    fn current(&self) -> Self::Value {
+        let mut graph = VecGraph::default();
+        let mut indices: Vec<Option<NodeIx>> = vec![None; self.nodes.len()];
+        for (i, node) in self.nodes.iter().enumerate() {
+            if self.included_nodes[i] {
+                indices[i] = Some(graph.add_node(node.current()));
+            }
+        }
+        for (i, (from, to, edge)) in self.edges.iter().enumerate() {
+            if self.included_edges[i] {
+                // Nodes are only removed once no included edge touches them
+                graph.add_edge(
+                    edge.current(),
+                    indices[*from].unwrap(),
+                    indices[*to].unwrap(),
+                );
+            }
+        }
+        graph
+    }
+
+    fn simplify(&mut self) -> bool {
+        loop {
+            match self.shrink {
+                Shrink::RemoveEdge(i) => {
+                    let len = self.included_edges.iter().filter(|&&b| b).count();
+                    if i >= self.edges.len() || len == self.min_edges {
+                        self.shrink = Shrink::RemoveNode(0);
+                    } else {
+                        self.included_edges[i] = false;
+                        self.prev_shrink = Some(self.shrink);
+                        self.shrink = Shrink::RemoveEdge(i + 1);
+                        return true;
+                    }
+                }
+                Shrink::RemoveNode(i) => {
+                    let len = self.included_nodes.iter().filter(|&&b| b).count();
+                    if i >= self.nodes.len() || len == self.min_nodes {
+                        self.shrink = Shrink::NodePayload(0);
+                    } else if !self.included_nodes[i] || !self.is_isolated(i) {
+                        self.shrink = Shrink::RemoveNode(i + 1);
+                    } else {
+                        self.included_nodes[i] = false;
+                        self.prev_shrink = Some(self.shrink);
+                        self.shrink = Shrink::RemoveNode(i + 1);
+                        return true;
+                    }
+                }
+                Shrink::NodePayload(i) => {
+                    if i >= self.nodes.len() {
+                        self.shrink = Shrink::EdgePayload(0);
+                    } else if !self.included_nodes[i] || !self.nodes[i].simplify() {
+                        self.shrink = Shrink::NodePayload(i + 1);
+                    } else {
+                        self.prev_shrink = Some(self.shrink);
+                        return true;
+                    }
+                }
+                Shrink::EdgePayload(i) => {
+                    if i >= self.edges.len() {
+                        return false;
+                    } else if !self.included_edges[i] || !self.edges[i].2.simplify() {
+                        self.shrink = Shrink::EdgePayload(i + 1);
+                    } else {
+                        self.prev_shrink = Some(self.shrink);
+                        return true;
+                    }
+                }
+            }
+        }
+    }
+
+    fn complicate(&mut self) -> bool {
+        let complicated = match self.prev_shrink {
+            None => return false,
+            Some(Shrink::RemoveEdge(i)) => {
+                self.included_edges[i] = true;
+                self.prev_shrink = None;
+                return true;
+            }
+            Some(Shrink::RemoveNode(i)) => {
+                self.included_nodes[i] = true;
+                self.prev_shrink = None;
+                return true;
+            }
+            Some(Shrink::NodePayload(i)) => self.nodes[i].complicate(),
+            Some(Shrink::EdgePayload(i)) => self.edges[i].2.complicate(),
+        };
+        if !complicated {
+            self.prev_shrink = None;
+        }
+        complicated
+    }
+}
+
+impl<N, E> Arbitrary for VecGraph<N, E>
+where
+    N: Arbitrary,
+    E: Arbitrary,
+{
+    type Parameters = (N::Parameters, E::Parameters);
+    type Strategy = VecGraphStrategy<N::Strategy, E::Strategy>;
+
+    /// Generates graphs with fewer than 16 nodes and fewer than 32 edges.
+    fn arbitrary_with((node, edge): Self::Parameters) -> Self::Strategy {
+        vec_graph(any_with::<N>(node), any_with::<E>(edge), 0..16, 0..32)
+    }
+}
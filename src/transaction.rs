@@ -0,0 +1,326 @@
+use crate::graph::{Graph, GraphUpdate};
+use crate::vec_graph::{EdgeIx, IndexType, NodeIx, VecGraph};
+
+/// A node queued in a [`GraphTransaction`] that does not have an index yet.
+///
+/// Resolve it with [`TransactionResult::node`] after committing.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct PendingNode(usize);
+
+/// An edge queued in a [`GraphTransaction`] that does not have an index yet.
+///
+/// Resolve it with [`TransactionResult::edge`] after committing.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct PendingEdge(usize);
+
+/// An endpoint of an edge queued in a [`GraphTransaction`].
+///
+/// Both [`PendingNode`] and [`NodeIx`] convert into it, so they can be passed directly
+/// to [`GraphTransaction::add_edge`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum NodeRef<Ix = u32> {
+    /// A node added by the same transaction.
+    Pending(PendingNode),
+    /// A node already in the graph, by its index before the transaction.
+    Existing(NodeIx<Ix>),
+}
+
+impl<Ix> From<PendingNode> for NodeRef<Ix> {
+    fn from(node: PendingNode) -> Self {
+        NodeRef::Pending(node)
+    }
+}
+
+impl<Ix> From<NodeIx<Ix>> for NodeRef<Ix> {
+    fn from(node: NodeIx<Ix>) -> Self {
+        NodeRef::Existing(node)
+    }
+}
+
+/// Error returned by [`GraphTransaction::commit`] when a queued edge is attached to a
+/// node queued for removal.
+///
+/// Nothing is applied to the graph in that case.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransactionConflict<Ix = u32> {
+    edge: PendingEdge,
+    node: NodeIx<Ix>,
+}
+
+impl<Ix: Copy> TransactionConflict<Ix> {
+    /// Returns the queued edge that could not be added.
+    pub fn edge(&self) -> PendingEdge {
+        self.edge
+    }
+
+    /// Returns the index of the removed node the edge is attached to.
+    pub fn node(&self) -> NodeIx<Ix> {
+        self.node
+    }
+}
+
+impl<Ix: IndexType> std::fmt::Display for TransactionConflict<Ix> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "queued edge {} is attached to node {}, which is queued for removal",
+            self.edge.0,
+            self.node.index()
+        )
+    }
+}
+
+impl<Ix: IndexType> std::error::Error for TransactionConflict<Ix> {}
+
+/// Outcome of a committed [`GraphTransaction`].
+///
+/// Maps the pending nodes and edges to their indices in the graph and the indices the
+/// remaining nodes had before the commit to the ones they have now. Also holds the data
+/// of everything that was removed.
+#[derive(Debug)]
+pub struct TransactionResult<N, E, Ix = u32> {
+    nodes: Vec<NodeIx<Ix>>,
+    edges: Vec<EdgeIx<Ix>>,
+    remap: Vec<Option<NodeIx<Ix>>>,
+    removed_nodes: Vec<N>,
+    removed_edges: Vec<E>,
+}
+
+impl<N, E, Ix: IndexType> TransactionResult<N, E, Ix> {
+    /// Returns the index a pending node was added at.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` was not queued by the committed transaction.
+    pub fn node(&self, node: PendingNode) -> NodeIx<Ix> {
+        self.nodes[node.0]
+    }
+
+    /// Returns the index a pending edge was added at.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `edge` was not queued by the committed transaction.
+    pub fn edge(&self, edge: PendingEdge) -> EdgeIx<Ix> {
+        self.edges[edge.0]
+    }
+
+    /// Returns the index a node that was in the graph before the commit has now, or
+    /// `None` if it was removed.
+    ///
+    /// Removal moves nodes into the freed slots, so indices held from before the commit
+    /// must be translated with this method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` was not a valid index before the commit.
+    pub fn remapped(&self, node: NodeIx<Ix>) -> Option<NodeIx<Ix>> {
+        self.remap[node.index()]
+    }
+
+    /// Returns the data of the removed nodes and edges, including the edges removed along
+    /// with their nodes.
+    pub fn into_removed(self) -> (Vec<N>, Vec<E>) {
+        (self.removed_nodes, self.removed_edges)
+    }
+}
+
+/// A batch of additions and removals applied to a [`VecGraph`] at once.
+///
+/// Obtained from [`VecGraph::transaction`]. Removing a node renumbers the remaining ones,
+/// so interleaving additions and removals is error-prone. A transaction instead queues
+/// them, referring to existing nodes by their current indices and to new nodes by
+/// [`PendingNode`] handles, and [`GraphTransaction::commit`] applies all removals in one
+/// batch before the additions. Dropping a transaction without committing it discards
+/// everything queued.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// let c = graph.add_node("C");
+/// graph.add_edge((), a, b);
+/// graph.add_edge((), b, c);
+///
+/// // Replace B with a new node D between A and C
+/// let mut txn = graph.transaction();
+/// txn.remove_node(b);
+/// let d = txn.add_node("D");
+/// txn.add_edge((), a, d);
+/// txn.add_edge((), d, c);
+/// let result = txn.commit().unwrap();
+///
+/// let (a, c, d) = (
+///     result.remapped(a).unwrap(),
+///     result.remapped(c).unwrap(),
+///     result.node(d),
+/// );
+/// assert_eq!(*graph.node(d), "D");
+/// assert!(graph.find_edge(a, d).is_some());
+/// assert!(graph.find_edge(d, c).is_some());
+/// assert_eq!(graph.len_edges(), 2);
+/// ```
+#[must_use = "a transaction does nothing unless committed"]
+#[derive(Debug)]
+pub struct GraphTransaction<'a, N, E, Ix: IndexType = u32> {
+    graph: &'a mut VecGraph<N, E, Ix>,
+    nodes: Vec<N>,
+    edges: Vec<(E, NodeRef<Ix>, NodeRef<Ix>)>,
+    removed_nodes: Vec<NodeIx<Ix>>,
+    removed_edges: Vec<EdgeIx<Ix>>,
+}
+
+impl<N, E, Ix: IndexType> VecGraph<N, E, Ix> {
+    /// Starts a [`GraphTransaction`] on this graph.
+    pub fn transaction(&mut self) -> GraphTransaction<'_, N, E, Ix> {
+        GraphTransaction {
+            graph: self,
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            removed_nodes: Vec::new(),
+            removed_edges: Vec::new(),
+        }
+    }
+}
+
+impl<N, E, Ix: IndexType> GraphTransaction<'_, N, E, Ix> {
+    /// Queues a node to be added.
+    pub fn add_node(&mut self, node: N) -> PendingNode {
+        self.nodes.push(node);
+        PendingNode(self.nodes.len() - 1)
+    }
+
+    /// Queues an edge to be added between pending or existing nodes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an existing endpoint does not exist in the graph, or a pending one was
+    /// not queued by this transaction.
+    pub fn add_edge(
+        &mut self,
+        edge: E,
+        from: impl Into<NodeRef<Ix>>,
+        to: impl Into<NodeRef<Ix>>,
+    ) -> PendingEdge {
+        let (from, to) = (from.into(), to.into());
+        self.check_node_ref(from);
+        self.check_node_ref(to);
+        self.edges.push((edge, from, to));
+        PendingEdge(self.edges.len() - 1)
+    }
+
+    /// Queues an existing node to be removed, along with all its edges.
+    ///
+    /// Queueing the same node twice removes it once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node index does not exist in the graph.
+    pub fn remove_node(&mut self, ix: NodeIx<Ix>) {
+        assert!(
+            self.graph.exists_node_index(ix),
+            "Node index {:?} does not exist",
+            ix
+        );
+        self.removed_nodes.push(ix);
+    }
+
+    /// Queues an existing edge to be removed.
+    ///
+    /// Queueing the same edge twice removes it once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the edge index does not exist in the graph.
+    pub fn remove_edge(&mut self, ix: EdgeIx<Ix>) {
+        assert!(
+            self.graph.exists_edge_index(ix),
+            "Edge index {:?} does not exist",
+            ix
+        );
+        self.removed_edges.push(ix);
+    }
+
+    /// Applies the queued removals, then the queued additions.
+    ///
+    /// The removals are applied in one batch, so the indices passed to
+    /// [`GraphTransaction::add_edge`] and the removal methods all refer to the graph as it
+    /// was before the commit. Nodes are added in the order they were queued, then edges.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TransactionConflict`] if a queued edge is attached to an existing node
+    /// queued for removal. The graph is left unchanged in that case.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graph cannot hold the added nodes or edges.
+    pub fn commit(self) -> Result<TransactionResult<N, E, Ix>, TransactionConflict<Ix>> {
+        let GraphTransaction {
+            graph,
+            nodes,
+            edges,
+            mut removed_nodes,
+            mut removed_edges,
+        } = self;
+
+        removed_nodes.sort_unstable();
+        removed_nodes.dedup();
+        removed_edges.sort_unstable();
+        removed_edges.dedup();
+        for (i, (_, from, to)) in edges.iter().enumerate() {
+            for endpoint in [from, to] {
+                if let NodeRef::Existing(node) = *endpoint {
+                    if removed_nodes.binary_search(&node).is_ok() {
+                        return Err(TransactionConflict {
+                            edge: PendingEdge(i),
+                            node,
+                        });
+                    }
+                }
+            }
+        }
+
+        // SAFETY: the indices were checked when queued and the graph has been borrowed by
+        // the transaction since
+        let (removed_nodes, removed_edges, remap) =
+            unsafe { graph.remove_nodes_edges_remapping(&removed_nodes, &removed_edges) };
+
+        let nodes: Vec<_> = nodes.into_iter().map(|node| graph.add_node(node)).collect();
+        let resolve = |endpoint| match endpoint {
+            NodeRef::Pending(PendingNode(i)) => nodes[i],
+            NodeRef::Existing(node) => remap[node.index()].expect("conflicts were checked"),
+        };
+        let edges = edges
+            .into_iter()
+            .map(|(edge, from, to)| graph.add_edge(edge, resolve(from), resolve(to)))
+            .collect();
+
+        Ok(TransactionResult {
+            nodes,
+            edges,
+            remap,
+            removed_nodes,
+            removed_edges,
+        })
+    }
+
+    fn check_node_ref(&self, node: NodeRef<Ix>) {
+        match node {
+            NodeRef::Pending(PendingNode(i)) => assert!(
+                i < self.nodes.len(),
+                "Pending node {} was not queued by this transaction",
+                i
+            ),
+            NodeRef::Existing(ix) => assert!(
+                self.graph.exists_node_index(ix),
+                "Node index {:?} does not exist",
+                ix
+            ),
+        }
+    }
+}
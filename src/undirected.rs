@@ -0,0 +1,699 @@
+use crate::graph::{
+    CapacityError, Graph, GraphMemory, GraphMut, GraphRemove, GraphRemoveEdge, GraphUpdate,
+    IndexConversion, NotAContext,
+};
+use crate::vec_graph::{self, IndexType, NodeIx, ParseError, VecGraph};
+use crate::Mapping;
+
+/// Edge index type for `UnVecGraph`.
+///
+/// Wraps the index of the underlying directed edge together with the direction in
+/// which the edge is looked at, so every edge has two indices, one per orientation.
+/// [`Graph::endpoints`] returns the endpoints in that orientation. Equality, ordering
+/// and hashing consider the orientation too, so equal indices always report the same
+/// endpoints.
+///
+/// The traversal methods orient an edge away from the node it is reached from, and an
+/// edge listed by `outgoing_edge_indices` of one endpoint is therefore equal to the one
+/// listed by `incoming_edge_indices` of the other. Indices from [`Graph::edge_indices`]
+/// and [`GraphUpdate::add_edge`] are oriented like the underlying directed edge. Both
+/// indices of an edge refer to the same data, share one entry in the maps created by
+/// [`Graph::init_edge_map`], and compare equal after [`EdgeIx::inner`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EdgeIx<Ix = u32> {
+    edge: vec_graph::EdgeIx<Ix>,
+    flipped: bool,
+}
+
+impl<Ix: IndexType> EdgeIx<Ix> {
+    /// Returns the index of the underlying directed edge in the wrapped `VecGraph`.
+    pub fn inner(self) -> vec_graph::EdgeIx<Ix> {
+        self.edge
+    }
+
+    /// Returns the index of the same edge in the opposite orientation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    /// use gotgraph::undirected::UnVecGraph;
+    ///
+    /// let mut graph: UnVecGraph<&str, ()> = UnVecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let ab = graph.add_edge((), a, b);
+    ///
+    /// let ba = graph.outgoing_edge_indices(b).next().unwrap();
+    /// assert_eq!(graph.endpoints(ba), [b, a]);
+    /// assert_eq!(ba.reversed(), ab);
+    /// ```
+    pub fn reversed(self) -> Self {
+        EdgeIx {
+            edge: self.edge,
+            flipped: !self.flipped,
+        }
+    }
+
+    fn new(edge: vec_graph::EdgeIx<Ix>) -> Self {
+        EdgeIx {
+            edge,
+            flipped: false,
+        }
+    }
+}
+
+/// An undirected view of a `VecGraph`.
+///
+/// `UnVecGraph` stores its edges in a regular `VecGraph`, but traverses them in both
+/// directions: `outgoing_edge_indices` and `incoming_edge_indices` of a node both yield
+/// every edge connected to it. Each edge yielded by the traversal methods is oriented
+/// relative to the node it was reached from, so `endpoints(e)[1]` is always the
+/// neighbour for an edge returned by `outgoing_edge_indices` (and `endpoints(e)[0]` for
+/// one returned by `incoming_edge_indices`). This lets the directed algorithms in
+/// [`crate::algo`] work unchanged; for example `tarjan` yields connected components.
+/// The orientation is part of the index, so the two endpoints of an edge see it under
+/// two different indices; see [`EdgeIx`].
+///
+/// Edges obtained from `edge_indices` or from `add_edge` report the direction they
+/// were inserted with.
+///
+/// # Self-loops
+///
+/// A self-loop is yielded exactly once by `outgoing_edge_indices` and once by
+/// `incoming_edge_indices` of its node, so it contributes one to each of those counts
+/// rather than two.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::prelude::*;
+/// use gotgraph::undirected::UnVecGraph;
+///
+/// let mut graph: UnVecGraph<&str, ()> = UnVecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// graph.add_edge((), a, b);
+///
+/// // The edge can be traversed from either endpoint
+/// assert_eq!(graph.outgoing_edge_indices(a).count(), 1);
+/// assert_eq!(graph.outgoing_edge_indices(b).count(), 1);
+///
+/// let e = graph.outgoing_edge_indices(b).next().unwrap();
+/// assert_eq!(graph.endpoints(e), [b, a]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct UnVecGraph<N, E, Ix = u32>(pub VecGraph<N, E, Ix>);
+
+// Only for the default index type, as for `VecGraph`
+impl<N, E> Default for UnVecGraph<N, E> {
+    fn default() -> Self {
+        UnVecGraph(VecGraph::default())
+    }
+}
+
+unsafe impl<N, E, Ix> NotAContext for UnVecGraph<N, E, Ix> {}
+
+impl<N, E, Ix> From<VecGraph<N, E, Ix>> for UnVecGraph<N, E, Ix> {
+    fn from(graph: VecGraph<N, E, Ix>) -> Self {
+        UnVecGraph(graph)
+    }
+}
+
+impl<N, E, Ix: IndexType> UnVecGraph<N, E, Ix> {
+    /// Consumes the wrapper and returns the underlying directed graph.
+    pub fn into_inner(self) -> VecGraph<N, E, Ix> {
+        self.0
+    }
+
+    // SAFETY: `node` must be a valid node index of `self.0`
+    unsafe fn adjacent(
+        &self,
+        node: NodeIx<Ix>,
+        incoming: bool,
+    ) -> impl Iterator<Item = EdgeIx<Ix>> + '_ {
+        // Every connecting edge is reached exactly once: edges stored as outgoing
+        // from `node` (which includes self-loops), then edges stored as incoming
+        // except self-loops.
+        let graph = &self.0;
+        graph
+            .outgoing_edge_indices_unchecked(node)
+            .map(move |edge| EdgeIx {
+                edge,
+                flipped: incoming,
+            })
+            .chain(
+                graph
+                    .incoming_edge_indices_unchecked(node)
+                    .filter(move |&edge| graph.endpoints_unchecked(edge)[0] != node)
+                    .map(move |edge| EdgeIx {
+                        edge,
+                        flipped: !incoming,
+                    }),
+            )
+    }
+
+    // SAFETY: `node` must be a valid node index of `self.0`
+    unsafe fn adjacent_pairs_mut(
+        &mut self,
+        node: NodeIx<Ix>,
+        incoming: bool,
+    ) -> impl Iterator<Item = (EdgeIx<Ix>, &mut E)> {
+        struct AdjacentEdgePairsMutIterUnchecked<'a, N, E, Ix> {
+            graph: &'a mut VecGraph<N, E, Ix>,
+            indices: std::vec::IntoIter<EdgeIx<Ix>>,
+        }
+
+        impl<'a, N, E, Ix: IndexType> Iterator for AdjacentEdgePairsMutIterUnchecked<'a, N, E, Ix> {
+            type Item = (EdgeIx<Ix>, &'a mut E);
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.indices.next().map(|ix| unsafe {
+                    let ptr = self.graph.edge_unchecked_mut(ix.edge) as *mut E;
+                    (ix, &mut *ptr)
+                })
+            }
+        }
+
+        let indices: Vec<_> = self.adjacent(node, incoming).collect();
+        AdjacentEdgePairsMutIterUnchecked {
+            graph: &mut self.0,
+            indices: indices.into_iter(),
+        }
+    }
+}
+
+/// Header that may precede a graph6 string.
+const GRAPH6_HEADER: &str = ">>graph6<<";
+
+impl UnVecGraph<(), ()> {
+    /// Parses an undirected graph from the graph6 format.
+    ///
+    /// graph6 encodes the node count followed by the upper triangle of the adjacency
+    /// matrix, six bits per printable character. An optional `>>graph6<<` header and
+    /// trailing whitespace are accepted. Node `i` of the encoding gets the index
+    /// `NodeIx::try_from(i)`, and every edge is stored from the lower to the higher node
+    /// index, in column order of the matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::Graph6`] with the byte offset of the problem if the input
+    /// contains characters outside the graph6 range, or is too short or too long for the
+    /// node count it declares.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    /// use gotgraph::undirected::UnVecGraph;
+    ///
+    /// // The Petersen graph
+    /// let graph = UnVecGraph::from_graph6("IheA@GUAo").unwrap();
+    /// assert_eq!(graph.len_nodes(), 10);
+    /// assert_eq!(graph.len_edges(), 15);
+    /// assert!(graph.node_indices().all(|n| graph.outgoing_edge_indices(n).count() == 3));
+    /// ```
+    pub fn from_graph6(s: &str) -> Result<Self, ParseError> {
+        let offset = if s.starts_with(GRAPH6_HEADER) {
+            GRAPH6_HEADER.len()
+        } else {
+            0
+        };
+        let bytes = s[offset..].trim_end().as_bytes();
+        let error = |position: usize, message| ParseError::Graph6 {
+            position: offset + position,
+            message,
+        };
+        if let Some(position) = bytes.iter().position(|b| !(63..=126).contains(b)) {
+            return Err(error(position, "character outside the graph6 range"));
+        }
+
+        // The node count takes one, four or eight bytes
+        let (len_bytes, len) = match bytes {
+            [] => return Err(error(0, "missing node count")),
+            [126, 126, rest @ ..] => (8, rest.get(..6)),
+            [126, rest @ ..] => (4, rest.get(..3)),
+            [first, ..] => (1, Some(core::slice::from_ref(first))),
+        };
+        let len = len.ok_or_else(|| error(bytes.len(), "truncated node count"))?;
+        let len = len.iter().fold(0u64, |n, &b| (n << 6) | u64::from(b - 63));
+        if len > u64::from(u32::MAX) {
+            return Err(error(0, "too many nodes"));
+        }
+        let len = len as usize;
+
+        let bits = len as u128 * (len as u128).saturating_sub(1) / 2;
+        let expected = bits.div_ceil(6);
+        let data = &bytes[len_bytes..];
+        if (data.len() as u128) < expected {
+            return Err(error(bytes.len(), "adjacency data is too short"));
+        }
+        if (data.len() as u128) > expected {
+            return Err(error(
+                len_bytes + expected as usize,
+                "adjacency data is too long",
+            ));
+        }
+
+        let mut graph = VecGraph::default();
+        let nodes = graph.add_nodes_range((0..len).map(|_| ()));
+        let node = |i: usize| NodeIx::try_from(nodes.start as usize + i).unwrap();
+        let mut k = 0;
+        for j in 1..len {
+            for i in 0..j {
+                if (data[k / 6] - 63) & (0b100000 >> (k % 6)) != 0 {
+                    graph.add_edge((), node(i), node(j));
+                }
+                k += 1;
+            }
+        }
+        Ok(UnVecGraph(graph))
+    }
+}
+
+impl<N, E, Ix: IndexType> UnVecGraph<N, E, Ix> {
+    /// Encodes the graph in the graph6 format, without header.
+    ///
+    /// Nodes are numbered by their index. graph6 only describes simple graphs, so
+    /// self-loops are left out and parallel edges are written once.
+    /// [`UnVecGraph::from_graph6`] reads the result back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    /// use gotgraph::undirected::UnVecGraph;
+    ///
+    /// let mut graph: UnVecGraph<(), ()> = UnVecGraph::default();
+    /// let a = graph.add_node(());
+    /// let b = graph.add_node(());
+    /// graph.add_edge((), b, a);
+    /// assert_eq!(graph.to_graph6(), "A_");
+    /// ```
+    pub fn to_graph6(&self) -> String {
+        let len = self.0.len_nodes();
+        let mut out = Vec::new();
+        if len <= 62 {
+            out.push(len as u8);
+        } else {
+            let groups = if len <= 258047 {
+                out.push(63);
+                3
+            } else {
+                out.extend([63, 63]);
+                6
+            };
+            out.extend((0..groups).rev().map(|g| (len >> (6 * g)) as u8 & 0b111111));
+        }
+
+        let mut bits = vec![0u8; (len * len.saturating_sub(1) / 2).div_ceil(6)];
+        for edge in self.0.edge_indices() {
+            let [from, to] = self.0.endpoints(edge);
+            let (i, j) = (from.index().min(to.index()), from.index().max(to.index()));
+            if i != j {
+                let k = j * (j - 1) / 2 + i;
+                bits[k / 6] |= 0b100000 >> (k % 6);
+            }
+        }
+        out.extend(bits);
+        out.into_iter().map(|b| char::from(b + 63)).collect()
+    }
+}
+
+impl<N, E, Ix: IndexType> Graph for UnVecGraph<N, E, Ix> {
+    type Node = N;
+    type Edge = E;
+    type NodeIx = NodeIx<Ix>;
+    type EdgeIx = EdgeIx<Ix>;
+
+    fn exists_node_index(&self, ix: Self::NodeIx) -> bool {
+        self.0.exists_node_index(ix)
+    }
+
+    fn exists_edge_index(&self, ix: Self::EdgeIx) -> bool {
+        self.0.exists_edge_index(ix.edge)
+    }
+
+    fn node_indices(&self) -> impl Iterator<Item = Self::NodeIx> {
+        self.0.node_indices()
+    }
+
+    fn edge_indices(&self) -> impl Iterator<Item = Self::EdgeIx> {
+        self.0.edge_indices().map(EdgeIx::new)
+    }
+
+    unsafe fn outgoing_edge_indices_unchecked(
+        &self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = Self::EdgeIx> {
+        self.adjacent(tag, false)
+    }
+
+    unsafe fn incoming_edge_indices_unchecked(
+        &self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = Self::EdgeIx> {
+        self.adjacent(tag, true)
+    }
+
+    unsafe fn connecting_edge_pairs_unchecked(
+        &self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge)> {
+        self.outgoing_edge_pairs_unchecked(tag)
+    }
+
+    unsafe fn outgoing_edge_pairs_unchecked(
+        &self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge)> {
+        self.adjacent(tag, false)
+            .map(move |ix| (ix, unsafe { self.0.edge_unchecked(ix.edge) }))
+    }
+
+    unsafe fn incoming_edge_pairs_unchecked(
+        &self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge)> {
+        self.adjacent(tag, true)
+            .map(move |ix| (ix, unsafe { self.0.edge_unchecked(ix.edge) }))
+    }
+
+    unsafe fn node_unchecked(&self, tag: Self::NodeIx) -> &Self::Node {
+        self.0.node_unchecked(tag)
+    }
+
+    unsafe fn edge_unchecked(&self, tag: Self::EdgeIx) -> &Self::Edge {
+        self.0.edge_unchecked(tag.edge)
+    }
+
+    unsafe fn endpoints_unchecked(&self, ix: Self::EdgeIx) -> [Self::NodeIx; 2] {
+        let [from, to] = self.0.endpoints_unchecked(ix.edge);
+        if ix.flipped {
+            [to, from]
+        } else {
+            [from, to]
+        }
+    }
+
+    fn len_nodes(&self) -> usize {
+        self.0.len_nodes()
+    }
+
+    fn len_edges(&self) -> usize {
+        self.0.len_edges()
+    }
+
+    fn memory_footprint(&self) -> GraphMemory {
+        self.0.memory_footprint()
+    }
+
+    fn index_generation(&self) -> Option<u64> {
+        self.0.index_generation()
+    }
+
+    fn init_edge_map<V>(
+        &self,
+        mut f: impl FnMut(Self::EdgeIx, &Self::Edge) -> V,
+    ) -> impl Mapping<Self::EdgeIx, V> {
+        // Keyed by the underlying edge, so both orientations find the same entry
+        #[derive(Debug)]
+        pub struct UnEdgeMap<M, Ix> {
+            _ix: PhantomData<Ix>,
+            inner: M,
+        }
+
+        impl<M: std::ops::Index<vec_graph::EdgeIx<Ix>>, Ix> std::ops::Index<EdgeIx<Ix>>
+            for UnEdgeMap<M, Ix>
+        {
+            type Output = M::Output;
+
+            fn index(&self, ix: EdgeIx<Ix>) -> &Self::Output {
+                &self.inner[ix.edge]
+            }
+        }
+
+        impl<M: std::ops::IndexMut<vec_graph::EdgeIx<Ix>>, Ix> std::ops::IndexMut<EdgeIx<Ix>>
+            for UnEdgeMap<M, Ix>
+        {
+            fn index_mut(&mut self, ix: EdgeIx<Ix>) -> &mut Self::Output {
+                &mut self.inner[ix.edge]
+            }
+        }
+
+        impl<M: IntoIterator, Ix> IntoIterator for UnEdgeMap<M, Ix> {
+            type Item = M::Item;
+            type IntoIter = M::IntoIter;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.inner.into_iter()
+            }
+        }
+
+        impl<V, M: Mapping<vec_graph::EdgeIx<Ix>, V>, Ix: IndexType> Mapping<EdgeIx<Ix>, V>
+            for UnEdgeMap<M, Ix>
+        {
+            fn map<VV>(self, f: impl FnMut(V) -> VV) -> impl Mapping<EdgeIx<Ix>, VV> {
+                UnEdgeMap {
+                    _ix: self._ix,
+                    inner: self.inner.map(f),
+                }
+            }
+
+            fn iter<'a>(&'a self) -> impl Iterator<Item = &'a V>
+            where
+                V: 'a,
+            {
+                self.inner.iter()
+            }
+
+            fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut V>
+            where
+                V: 'a,
+            {
+                self.inner.iter_mut()
+            }
+
+            fn keys(&self) -> impl Iterator<Item = EdgeIx<Ix>> {
+                self.inner.keys().map(EdgeIx::new)
+            }
+
+            fn pairs<'a>(&'a self) -> impl Iterator<Item = (EdgeIx<Ix>, &'a V)>
+            where
+                V: 'a,
+            {
+                self.inner
+                    .pairs()
+                    .map(|(ix, value)| (EdgeIx::new(ix), value))
+            }
+
+            fn into_pairs(self) -> impl Iterator<Item = (EdgeIx<Ix>, V)> {
+                self.inner
+                    .into_pairs()
+                    .map(|(ix, value)| (EdgeIx::new(ix), value))
+            }
+
+            unsafe fn get_unchecked(&self, ix: EdgeIx<Ix>) -> &V {
+                self.inner.get_unchecked(ix.edge)
+            }
+
+            unsafe fn get_unchecked_mut(&mut self, ix: EdgeIx<Ix>) -> &mut V {
+                self.inner.get_unchecked_mut(ix.edge)
+            }
+        }
+
+        use core::marker::PhantomData;
+        UnEdgeMap {
+            _ix: PhantomData,
+            inner: self
+                .0
+                .init_edge_map(move |ix, edge| f(EdgeIx::new(ix), edge)),
+        }
+    }
+}
+
+impl<N, E, Ix: IndexType> GraphMut for UnVecGraph<N, E, Ix> {
+    unsafe fn node_unchecked_mut(&mut self, tag: Self::NodeIx) -> &mut Self::Node {
+        self.0.node_unchecked_mut(tag)
+    }
+
+    unsafe fn edge_unchecked_mut(&mut self, tag: Self::EdgeIx) -> &mut Self::Edge {
+        self.0.edge_unchecked_mut(tag.edge)
+    }
+
+    fn get_node_disjoint_mut<const K: usize>(
+        &mut self,
+        ixs: [Self::NodeIx; K],
+    ) -> Option<[&mut Self::Node; K]> {
+        self.0.get_node_disjoint_mut(ixs)
+    }
+
+    fn get_edge_disjoint_mut<const K: usize>(
+        &mut self,
+        ixs: [Self::EdgeIx; K],
+    ) -> Option<[&mut Self::Edge; K]> {
+        self.0.get_edge_disjoint_mut(ixs.map(|ix| ix.edge))
+    }
+
+    unsafe fn outgoing_edge_pairs_unchecked_mut(
+        &mut self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
+    where
+        Self: Sized,
+    {
+        self.adjacent_pairs_mut(tag, false)
+    }
+
+    unsafe fn incoming_edge_pairs_unchecked_mut(
+        &mut self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
+    where
+        Self: Sized,
+    {
+        self.adjacent_pairs_mut(tag, true)
+    }
+
+    unsafe fn connecting_edge_pairs_unchecked_mut(
+        &mut self,
+        tag: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
+    where
+        Self: Sized,
+    {
+        self.adjacent_pairs_mut(tag, false)
+    }
+
+    unsafe fn reverse_edge_unchecked(
+        &mut self,
+        edge_ix: Self::EdgeIx,
+        new_from: Self::NodeIx,
+        new_to: Self::NodeIx,
+    ) where
+        Self: Sized,
+    {
+        // Keep `endpoints(edge_ix)` equal to `[new_from, new_to]` for a flipped index
+        if edge_ix.flipped {
+            self.0
+                .reverse_edge_unchecked(edge_ix.edge, new_to, new_from)
+        } else {
+            self.0
+                .reverse_edge_unchecked(edge_ix.edge, new_from, new_to)
+        }
+    }
+}
+
+impl<N, E, Ix: IndexType> GraphUpdate for UnVecGraph<N, E, Ix> {
+    fn add_node(&mut self, node: Self::Node) -> Self::NodeIx {
+        self.0.add_node(node)
+    }
+
+    fn add_edge(&mut self, edge: Self::Edge, from: Self::NodeIx, to: Self::NodeIx) -> Self::EdgeIx {
+        EdgeIx::new(self.0.add_edge(edge, from, to))
+    }
+
+    fn try_add_node(
+        &mut self,
+        node: Self::Node,
+    ) -> Result<Self::NodeIx, CapacityError<Self::Node>> {
+        self.0.try_add_node(node)
+    }
+
+    fn try_add_edge(
+        &mut self,
+        edge: Self::Edge,
+        from: Self::NodeIx,
+        to: Self::NodeIx,
+    ) -> Result<Self::EdgeIx, CapacityError<Self::Edge>> {
+        self.0.try_add_edge(edge, from, to).map(EdgeIx::new)
+    }
+
+    fn add_node_with(&mut self, f: impl FnOnce(Self::NodeIx) -> Self::Node) -> Self::NodeIx {
+        self.0.add_node_with(f)
+    }
+
+    fn add_edge_with(
+        &mut self,
+        from: Self::NodeIx,
+        to: Self::NodeIx,
+        f: impl FnOnce(Self::EdgeIx) -> Self::Edge,
+    ) -> Self::EdgeIx {
+        EdgeIx::new(self.0.add_edge_with(from, to, |ix| f(EdgeIx::new(ix))))
+    }
+
+    fn add_nodes<I: IntoIterator<Item = Self::Node>>(&mut self, nodes: I) -> Vec<Self::NodeIx> {
+        self.0.add_nodes(nodes)
+    }
+
+    unsafe fn add_edge_unchecked(
+        &mut self,
+        edge: Self::Edge,
+        from: Self::NodeIx,
+        to: Self::NodeIx,
+    ) -> Self::EdgeIx {
+        EdgeIx::new(self.0.add_edge_unchecked(edge, from, to))
+    }
+}
+
+impl<N, E, Ix: IndexType> GraphRemoveEdge for UnVecGraph<N, E, Ix> {
+    unsafe fn remove_edge_unchecked(&mut self, ix: Self::EdgeIx) -> Self::Edge {
+        self.0.remove_edge_unchecked(ix.edge)
+    }
+
+    fn remove_edges<I, C>(&mut self, edges: I) -> C
+    where
+        I: IntoIterator<Item = Self::EdgeIx>,
+        C: Default + Extend<Self::Edge>,
+    {
+        // Both orientations of an edge are removed once
+        self.0.remove_edges(edges.into_iter().map(|ix| ix.edge))
+    }
+}
+
+impl<N, E, Ix: IndexType> GraphRemove for UnVecGraph<N, E, Ix> {
+    unsafe fn remove_node_unchecked(&mut self, ix: Self::NodeIx) -> Self::Node {
+        self.0.remove_node_unchecked(ix)
+    }
+
+    unsafe fn remove_nodes_edges_unchecked<CN, CE>(
+        &mut self,
+        nodes: impl IntoIterator<Item = Self::NodeIx>,
+        edges: impl IntoIterator<Item = Self::EdgeIx>,
+    ) -> (CN, CE)
+    where
+        CN: Default + Extend<Self::Node>,
+        CE: Default + Extend<Self::Edge>,
+        Self: Sized,
+    {
+        self.0
+            .remove_nodes_edges_unchecked(nodes, edges.into_iter().map(|ix| ix.edge))
+    }
+
+    fn merge_nodes(
+        &mut self,
+        keep: Self::NodeIx,
+        merge: impl IntoIterator<Item = Self::NodeIx>,
+    ) -> (Self::NodeIx, Vec<Self::Node>) {
+        // Rewiring the directed edges also rewires their undirected views
+        self.0.merge_nodes(keep, merge)
+    }
+}
+
+impl<N, E, Ix: IndexType> IndexConversion for UnVecGraph<N, E, Ix> {
+    fn node_to_usize(ix: NodeIx<Ix>) -> usize {
+        ix.index()
+    }
+
+    fn node_from_usize(ix: usize) -> Option<NodeIx<Ix>> {
+        VecGraph::<N, E, Ix>::node_from_usize(ix)
+    }
+
+    fn edge_to_usize(ix: EdgeIx<Ix>) -> usize {
+        ix.edge.index()
+    }
+
+    fn edge_from_usize(ix: usize) -> Option<EdgeIx<Ix>> {
+        VecGraph::<N, E, Ix>::edge_from_usize(ix).map(EdgeIx::new)
+    }
+}
@@ -1,9 +1,61 @@
-use crate::graph::{Graph, GraphRemove, GraphRemoveEdge, GraphUpdate};
+use crate::graph::{
+    CapacityError, Graph, GraphMemory, GraphMut, GraphRemove, GraphRemoveEdge, GraphUpdate,
+    IndexConversion, NotAContext,
+};
 use crate::Mapping;
+/// Integer type used to store the node and edge indices of a [`VecGraph`].
+///
+/// Narrower types make indices and adjacency links smaller, at the cost of a lower
+/// capacity: the largest value of the type is reserved as the end-of-list sentinel, so a
+/// graph holds at most `Ix::max()` nodes and as many edges. `u32` is the default.
+///
+/// This trait is sealed; it is implemented for `u16`, `u32`, `u64` and `usize`.
+pub trait IndexType:
+    Copy + core::fmt::Debug + Eq + Ord + core::hash::Hash + Send + Sync + 'static + private::Sealed
+{
+    /// Converts a position to an index, truncating values that do not fit.
+    fn from_usize(ix: usize) -> Self;
+
+    /// Converts an index back to a position.
+    fn to_usize(self) -> usize;
+
+    /// Returns the largest value of the type, which is reserved as the sentinel.
+    fn max() -> Self;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_index_type {
+    ($($ty:ty),*) => {$(
+        impl private::Sealed for $ty {}
+
+        impl IndexType for $ty {
+            #[inline]
+            fn from_usize(ix: usize) -> Self {
+                ix as $ty
+            }
+
+            #[inline]
+            fn to_usize(self) -> usize {
+                self as usize
+            }
+
+            #[inline]
+            fn max() -> Self {
+                <$ty>::MAX
+            }
+        }
+    )*};
+}
+
+impl_index_type!(u16, u32, u64, usize);
+
 /// Node index type for `VecGraph`.
 ///
-/// This is a newtype wrapper around `u32` that provides type safety
-/// by preventing confusion between node and edge indices.
+/// This is a newtype wrapper around the graph's [`IndexType`], `u32` by default, that
+/// provides type safety by preventing confusion between node and edge indices.
 ///
 /// # Examples
 ///
@@ -18,49 +70,295 @@ use crate::Mapping;
 /// });
 /// ```
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
-pub struct NodeIx(u32);
+pub struct NodeIx<Ix = u32>(Ix);
 
 /// Edge index type for `VecGraph`.
 ///
-/// This is a newtype wrapper around `u32` that provides type safety
-/// by preventing confusion between node and edge indices.
+/// This is a newtype wrapper around the graph's [`IndexType`], `u32` by default, that
+/// provides type safety by preventing confusion between node and edge indices.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
-pub struct EdgeIx(u32);
+pub struct EdgeIx<Ix = u32>(Ix);
+
+impl<Ix: IndexType> NodeIx<Ix> {
+    /// Returns the position of this node in the graph's node storage.
+    ///
+    /// Converting back with `NodeIx::try_from` yields the same index.
+    pub fn index(self) -> usize {
+        self.0.to_usize()
+    }
 
-impl NodeIx {
+    fn new(ix: usize) -> Self {
+        NodeIx(Ix::from_usize(ix))
+    }
+
+    #[cfg(test)]
     fn end() -> Self {
-        NodeIx(u32::MAX)
+        NodeIx(<Ix as IndexType>::max())
     }
 
     fn is_end(self) -> bool {
-        self.0 as i32 as i64 as u64 == u64::MAX
+        self.0 == <Ix as IndexType>::max()
     }
 }
 
-impl EdgeIx {
+impl NodeIx {
+    /// Returns the raw `u32` this index wraps, for storing it outside the graph.
+    ///
+    /// [`NodeIx::from_index`] turns it back into the same index.
+    pub fn as_u32(self) -> u32 {
+        self.0
+    }
+
+    /// Creates an index from a raw `u32`, as returned by [`NodeIx::as_u32`].
+    ///
+    /// Nothing checks that a node with this index exists; use
+    /// [`Graph::exists_node_index`] before relying on it. Methods taking an index check it
+    /// against the graph, except for the unsafe unchecked variants.
+    pub fn from_index(index: u32) -> Self {
+        NodeIx(index)
+    }
+}
+
+impl<Ix: IndexType> EdgeIx<Ix> {
+    /// Returns the position of this edge in the graph's edge storage.
+    ///
+    /// Converting back with `EdgeIx::try_from` yields the same index.
+    pub fn index(self) -> usize {
+        self.0.to_usize()
+    }
+
+    fn new(ix: usize) -> Self {
+        EdgeIx(Ix::from_usize(ix))
+    }
+
     fn end() -> Self {
-        EdgeIx(u32::MAX)
+        EdgeIx(<Ix as IndexType>::max())
     }
 
     fn is_end(self) -> bool {
-        self.0 as i32 as i64 as u64 == u64::MAX
+        self.0 == <Ix as IndexType>::max()
     }
 }
 
+impl EdgeIx {
+    /// Returns the raw `u32` this index wraps, for storing it outside the graph.
+    ///
+    /// [`EdgeIx::from_index`] turns it back into the same index.
+    pub fn as_u32(self) -> u32 {
+        self.0
+    }
+
+    /// Creates an index from a raw `u32`, as returned by [`EdgeIx::as_u32`].
+    ///
+    /// Nothing checks that a edge with this index exists; use
+    /// [`Graph::exists_edge_index`] before relying on it. Methods taking an index check it
+    /// against the graph, except for the unsafe unchecked variants.
+    pub fn from_index(index: u32) -> Self {
+        EdgeIx(index)
+    }
+}
+
+// Number of positions below the sentinel of `Ix`, which bounds the nodes and the edges
+fn max_len<Ix: IndexType>() -> usize {
+    <Ix as IndexType>::max().to_usize()
+}
+
+// Accepts the positions below the sentinel of `Ix`
+fn index_from_usize<Ix: IndexType>(ix: usize) -> Result<Ix, IndexOutOfRange> {
+    if ix < max_len::<Ix>() {
+        Ok(Ix::from_usize(ix))
+    } else {
+        Err(IndexOutOfRange(()))
+    }
+}
+
+/// Converts an integer back to a node index.
+///
+/// Any value below `u32::MAX` is accepted, even if no node with that index exists in
+/// a particular graph; methods taking the index check it against the graph (or use
+/// [`Graph::exists_node_index`] to check it up front). `u32::MAX` is reserved as an
+/// internal sentinel and is rejected along with all larger values.
+///
+/// Indices of graphs with another [`IndexType`] are converted with
+/// [`IndexConversion::node_from_usize`], which applies the same rule to `Ix::max()`.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::prelude::*;
+/// use gotgraph::vec_graph::NodeIx;
+///
+/// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+/// let a = graph.add_node("A");
+///
+/// assert_eq!(NodeIx::try_from(a.index()), Ok(a));
+/// assert!(NodeIx::try_from(u32::MAX as usize).is_err());
+/// ```
+impl TryFrom<usize> for NodeIx {
+    type Error = IndexOutOfRange;
+
+    fn try_from(ix: usize) -> Result<Self, Self::Error> {
+        index_from_usize(ix).map(NodeIx)
+    }
+}
+
+/// Converts an integer back to an edge index.
+///
+/// As with [`NodeIx`], any value below `u32::MAX` is accepted without checking that the
+/// edge exists.
+impl TryFrom<usize> for EdgeIx {
+    type Error = IndexOutOfRange;
+
+    fn try_from(ix: usize) -> Result<Self, Self::Error> {
+        index_from_usize(ix).map(EdgeIx)
+    }
+}
+
+/// Error returned when converting an integer that is too large to a `VecGraph` index.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct IndexOutOfRange(());
+
+impl core::fmt::Display for IndexOutOfRange {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "value is out of range for a graph index")
+    }
+}
+
+impl std::error::Error for IndexOutOfRange {}
+
+/// Error returned when building a `VecGraph` from an invalid adjacency list or matrix.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AdjacencyError {
+    /// The adjacency list of `node` contains `target`, which is not a node index.
+    OutOfRange {
+        /// Row of the adjacency list containing the entry.
+        node: usize,
+        /// The offending entry.
+        target: usize,
+        /// Number of nodes, i.e. the number of rows.
+        len: usize,
+    },
+    /// A row of an adjacency matrix does not have one column per node.
+    NotSquare {
+        /// Index of the offending row.
+        row: usize,
+        /// Number of columns in that row.
+        columns: usize,
+        /// Number of nodes, i.e. the number of rows.
+        len: usize,
+    },
+}
+
+impl core::fmt::Display for AdjacencyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            AdjacencyError::OutOfRange { node, target, len } => write!(
+                f,
+                "adjacency list of node {} refers to node {}, but there are only {} nodes",
+                node, target, len
+            ),
+            AdjacencyError::NotSquare { row, columns, len } => write!(
+                f,
+                "row {} of the adjacency matrix has {} columns, expected {}",
+                row, columns, len
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AdjacencyError {}
+
+/// Error returned when parsing a graph from text.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ParseError {
+    /// A line of an edge list does not consist of exactly two tokens.
+    TokenCount {
+        /// 1-based number of the offending line.
+        line: usize,
+        /// Number of tokens found on the line.
+        tokens: usize,
+    },
+    /// A token of an edge list is not a valid node index.
+    InvalidIndex {
+        /// 1-based number of the offending line.
+        line: usize,
+        /// The offending token.
+        token: String,
+    },
+    /// A row of a comma-separated edge list does not consist of exactly three fields.
+    FieldCount {
+        /// 1-based number of the offending line.
+        line: usize,
+        /// Number of fields found on the line.
+        fields: usize,
+    },
+    /// A field of a comma-separated edge list is not a valid weight.
+    InvalidWeight {
+        /// 1-based number of the offending line.
+        line: usize,
+        /// The offending field.
+        token: String,
+    },
+    /// A graph6 string is malformed.
+    Graph6 {
+        /// Byte offset of the problem in the input.
+        position: usize,
+        /// Description of the problem.
+        message: &'static str,
+    },
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseError::TokenCount { line, tokens } => write!(
+                f,
+                "line {}: expected two node indices, found {} tokens",
+                line, tokens
+            ),
+            ParseError::InvalidIndex { line, token } => {
+                write!(f, "line {}: {:?} is not a valid node index", line, token)
+            }
+            ParseError::FieldCount { line, fields } => write!(
+                f,
+                "line {}: expected `from,to,weight`, found {} fields",
+                line, fields
+            ),
+            ParseError::InvalidWeight { line, token } => {
+                write!(f, "line {}: {:?} is not a valid weight", line, token)
+            }
+            ParseError::Graph6 { position, message } => {
+                write!(f, "invalid graph6 at byte {}: {}", position, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Report of the edges removed by [`VecGraph::simplify`].
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct SimplifyReport {
+    /// Number of parallel edges merged into another edge.
+    pub merged_edges: usize,
+    /// Number of self-loops removed.
+    pub dropped_self_loops: usize,
+}
+
 #[derive(Clone, Debug)]
-struct NodeRepr<N> {
+struct NodeRepr<N, Ix> {
     data: N,
     // next outgoing / incoming edge
-    next: [EdgeIx; 2],
+    next: [EdgeIx<Ix>; 2],
 }
 
 #[derive(Clone, Debug)]
-struct EdgeRepr<E> {
+struct EdgeRepr<E, Ix> {
     data: E,
     // next outgoing / incoming edge
-    next: [EdgeIx; 2],
+    next: [EdgeIx<Ix>; 2],
     // start and end node
-    node: [NodeIx; 2],
+    node: [NodeIx<Ix>; 2],
 }
 
 /// A vector-based graph implementation.
@@ -73,6 +371,9 @@ struct EdgeRepr<E> {
 ///
 /// - `N`: The type of data stored in nodes
 /// - `E`: The type of data stored in edges
+/// - `Ix`: The [`IndexType`] storing node and edge indices, `u32` by default. The
+///   graph holds at most `Ix::max()` nodes and as many edges; adding more panics, or returns
+///   a [`CapacityError`] from the `try_` methods
 ///
 /// # Memory Layout
 ///
@@ -88,6 +389,14 @@ struct EdgeRepr<E> {
 /// - **Edge Traversal**: O(degree)
 /// - **Memory Usage**: Efficient for dense graphs, some overhead for sparse graphs
 ///
+/// # Thread Safety
+///
+/// `VecGraph<N, E>` is `Send` and `Sync` whenever `N` and `E` are. A `&VecGraph` can
+/// therefore be shared between threads for read-only analysis, for example running
+/// algorithms or opening [`Graph::scope`]s concurrently. Mutation requires `&mut` and so
+/// excludes any concurrent reader. See [`FrozenGraph`](crate::frozen::FrozenGraph) to
+/// share an owned graph through an `Arc`.
+///
 /// # Examples
 ///
 /// ```rust
@@ -109,269 +418,1695 @@ struct EdgeRepr<E> {
 /// });
 /// ```
 #[derive(Clone, Debug)]
-pub struct VecGraph<N, E> {
-    nodes: Vec<NodeRepr<N>>,
-    edges: Vec<EdgeRepr<E>>,
+pub struct VecGraph<N, E, Ix = u32> {
+    nodes: Vec<NodeRepr<N, Ix>>,
+    edges: Vec<EdgeRepr<E, Ix>>,
+    // Bumped whenever existing indices are renumbered, see `Graph::index_generation`
+    generation: u64,
 }
 
+// Only for the default index type, so that `VecGraph::default()` needs no annotation
 impl<N, E> Default for VecGraph<N, E> {
     fn default() -> Self {
+        Self::with_index_type()
+    }
+}
+
+impl<N, E, Ix: IndexType> VecGraph<N, E, Ix> {
+    /// Creates an empty graph storing its indices as `Ix`.
+    ///
+    /// [`VecGraph::default`] only builds graphs with the default `u32` indices; use this
+    /// constructor for the other [`IndexType`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<u32, (), u16> = VecGraph::with_index_type();
+    /// let nodes = graph.add_nodes(0..300);
+    /// graph.add_edge((), nodes[0], nodes[299]);
+    /// assert_eq!(graph.len_nodes(), 300);
+    /// assert_eq!(nodes[299].index(), 299);
+    /// ```
+    pub fn with_index_type() -> Self {
         Self {
             nodes: Vec::new(),
             edges: Vec::new(),
+            generation: 0,
         }
     }
+
+    /// Swaps the slots of two nodes, so that each takes the index of the other.
+    ///
+    /// The topology is unchanged: the node data and every edge move along, and the edges
+    /// of the two nodes are updated to refer to their new indices. Edge indices do not
+    /// change. This can be used to lay out nodes in traversal order for better cache
+    /// locality. In contrast, [`GraphUpdate::swap_nodes`] only swaps the data and leaves
+    /// the edges at their indices.
+    ///
+    /// Runs in O(degree) of the two nodes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node index does not exist in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let ab = graph.add_edge((), a, b);
+    ///
+    /// graph.swap_node_slots(a, b);
+    /// assert_eq!(*graph.node(a), "B");
+    /// assert_eq!(graph.endpoints(ab), [b, a]);
+    /// assert_eq!(graph.endpoint_data(ab), (&"A", &"B"));
+    /// ```
+    pub fn swap_node_slots(&mut self, a: NodeIx<Ix>, b: NodeIx<Ix>) {
+        for ix in [a, b] {
+            assert!(
+                self.exists_node_index(ix),
+                "Node index {:?} does not exist",
+                ix
+            );
+        }
+        if a == b {
+            return;
+        }
+        self.generation += 1;
+        self.nodes.swap(a.index(), b.index());
+
+        // The adjacency lists moved with the nodes; point their edges at the new slots
+        for node in [a, b] {
+            for dir in 0..2 {
+                let mut current = self.nodes[node.index()].next[dir];
+                while !current.is_end() {
+                    let edge = &mut self.edges[current.index()];
+                    edge.node[dir] = node;
+                    current = edge.next[dir];
+                }
+            }
+        }
+        #[cfg(debug_assertions)]
+        self.debug_validate_links(&[a, b]);
+    }
+
+    /// Swaps the slots of two edges, so that each takes the index of the other.
+    ///
+    /// The topology is unchanged: the edge data and endpoints move along, and the
+    /// adjacency lists are updated to refer to the new indices without changing their
+    /// order. Node indices do not change.
+    ///
+    /// Runs in O(degree) of the endpoints of the two edges.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either edge index does not exist in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, &str> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let ab = graph.add_edge("a->b", a, b);
+    /// let ba = graph.add_edge("b->a", b, a);
+    ///
+    /// graph.swap_edge_slots(ab, ba);
+    /// assert_eq!(*graph.edge(ab), "b->a");
+    /// assert_eq!(graph.endpoints(ab), [b, a]);
+    /// assert_eq!(graph.outgoing_edge_indices(a).collect::<Vec<_>>(), vec![ba]);
+    /// ```
+    pub fn swap_edge_slots(&mut self, a: EdgeIx<Ix>, b: EdgeIx<Ix>) {
+        for ix in [a, b] {
+            assert!(
+                self.exists_edge_index(ix),
+                "Edge index {:?} does not exist",
+                ix
+            );
+        }
+        if a == b {
+            return;
+        }
+        self.generation += 1;
+        self.edges.swap(a.index(), b.index());
+        let relabel = |ix: EdgeIx<Ix>| {
+            if ix == a {
+                b
+            } else if ix == b {
+                a
+            } else {
+                ix
+            }
+        };
+
+        // Only the lists containing the two edges link to them. Each list is walked once,
+        // relabelling the links in it and following the relabelled ones.
+        let [a_ends, b_ends] = [a, b].map(|ix| self.edges[ix.index()].node);
+        let mut lists = vec![
+            (a_ends[0], 0),
+            (a_ends[1], 1),
+            (b_ends[0], 0),
+            (b_ends[1], 1),
+        ];
+        lists.sort_unstable();
+        lists.dedup();
+        for (node, dir) in lists {
+            let head = &mut self.nodes[node.index()].next[dir];
+            *head = relabel(*head);
+            let mut current = *head;
+            while !current.is_end() {
+                let edge = &mut self.edges[current.index()];
+                edge.next[dir] = relabel(edge.next[dir]);
+                current = edge.next[dir];
+            }
+        }
+        #[cfg(debug_assertions)]
+        self.debug_validate_links(&[a_ends[0], a_ends[1], b_ends[0], b_ends[1]]);
+    }
 }
 
-impl<N, E> crate::graph::Graph for VecGraph<N, E> {
-    type NodeIx = NodeIx;
-    type EdgeIx = EdgeIx;
-    type Node = N;
-    type Edge = E;
+unsafe impl<N, E, Ix> NotAContext for VecGraph<N, E, Ix> {}
+
+impl VecGraph<usize, ()> {
+    /// Builds a graph from adjacency lists.
+    ///
+    /// Node `i` stores `i` and has an edge to every node listed in `adj[i]`. See
+    /// [`VecGraph::from_adjacency_list_with`] for the details and for choosing other
+    /// payloads.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AdjacencyError::OutOfRange`] if an entry is not less than `adj.len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    /// use gotgraph::vec_graph::AdjacencyError;
+    ///
+    /// let graph = VecGraph::from_adjacency_list(&[vec![1], vec![0, 1]]).unwrap();
+    /// assert_eq!(graph.len_nodes(), 2);
+    /// assert_eq!(graph.len_edges(), 3);
+    ///
+    /// let err = VecGraph::from_adjacency_list(&[vec![1], vec![2]]).unwrap_err();
+    /// assert_eq!(err, AdjacencyError::OutOfRange { node: 1, target: 2, len: 2 });
+    /// ```
+    pub fn from_adjacency_list(adj: &[Vec<usize>]) -> Result<Self, AdjacencyError> {
+        Self::from_adjacency_list_with(adj, |i| i, |_, _| ())
+    }
 
-    fn exists_node_index(&self, NodeIx(ix): Self::NodeIx) -> bool {
-        (ix as usize) < self.nodes.len()
+    /// Builds a graph from a square boolean adjacency matrix.
+    ///
+    /// Node `i` stores `i` and has an edge to node `j` when `m[i][j]` is `true`. See
+    /// [`VecGraph::from_adjacency_matrix_with`] for the details and for choosing other
+    /// payloads.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AdjacencyError::NotSquare`] if a row does not have `m.len()` columns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let m = vec![vec![false, true], vec![true, true]];
+    /// let graph = VecGraph::from_adjacency_matrix(&m).unwrap();
+    /// assert_eq!(graph.to_adjacency_matrix().0, m);
+    /// ```
+    pub fn from_adjacency_matrix(m: &[Vec<bool>]) -> Result<Self, AdjacencyError> {
+        Self::from_adjacency_matrix_with(m, |i| i, |_, _| ())
     }
+}
 
-    fn exists_edge_index(&self, EdgeIx(ix): Self::EdgeIx) -> bool {
-        (ix as usize) < self.edges.len()
+impl VecGraph<(), ()> {
+    /// Parses a graph from a plain edge list.
+    ///
+    /// Every line holds the indices of the source and target node of one edge, separated
+    /// by whitespace, e.g. `"0 1\n1 2\n2 0"`. Blank lines and lines starting with `#`
+    /// are skipped, as is anything after a `#` on an edge line. Nodes are created
+    /// implicitly: the graph has one node for every index up to the largest one seen,
+    /// and `NodeIx::try_from(i)` is node `i`. Edges are added in line order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::TokenCount`] for a line without exactly two tokens and
+    /// [`ParseError::InvalidIndex`] for a token that is not an index, both with the
+    /// 1-based line number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    /// use gotgraph::vec_graph::ParseError;
+    ///
+    /// let graph = VecGraph::parse_edge_list("# triangle\n0 1\n1 2\n\n2 0\n").unwrap();
+    /// assert_eq!(graph.len_nodes(), 3);
+    /// assert_eq!(graph.len_edges(), 3);
+    ///
+    /// let err = VecGraph::parse_edge_list("0 1\n1\n").unwrap_err();
+    /// assert_eq!(err, ParseError::TokenCount { line: 2, tokens: 1 });
+    /// ```
+    pub fn parse_edge_list(s: &str) -> Result<Self, ParseError> {
+        let mut edges = Vec::new();
+        for (i, line) in s.lines().enumerate() {
+            let line_number = i + 1;
+            let content = line.split('#').next().unwrap_or_default();
+            let tokens: Vec<_> = content.split_whitespace().collect();
+            if tokens.is_empty() {
+                continue;
+            }
+            if tokens.len() != 2 {
+                return Err(ParseError::TokenCount {
+                    line: line_number,
+                    tokens: tokens.len(),
+                });
+            }
+            let parse = |token: &str| {
+                token
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|&ix| !NodeIx(ix).is_end())
+                    .ok_or_else(|| ParseError::InvalidIndex {
+                        line: line_number,
+                        token: token.to_string(),
+                    })
+            };
+            edges.push([parse(tokens[0])?, parse(tokens[1])?]);
+        }
+
+        let len = edges.iter().flatten().map(|&ix| ix as usize + 1).max();
+        let mut graph = VecGraph::default();
+        graph.add_nodes_range((0..len.unwrap_or(0)).map(|_| ()));
+        graph.reserve_edges_for(edges.len());
+        for [from, to] in edges {
+            graph.add_edge((), NodeIx(from), NodeIx(to));
+        }
+        Ok(graph)
     }
+}
 
-    unsafe fn node_unchecked(&self, NodeIx(ix): Self::NodeIx) -> &Self::Node {
-        debug_assert!((ix as usize) < self.nodes.len());
-        &self.nodes.get_unchecked(ix as usize).data
+impl<N, E, Ix: IndexType> VecGraph<N, E, Ix> {
+    /// Renumbers the node indices so that they are dense, returning the old to new
+    /// index remapping.
+    ///
+    /// `VecGraph` removes nodes with `swap_remove`, so its indices are always dense
+    /// already and the returned mapping is the identity. It is still provided so that
+    /// code keeping external tables of node indices can update them the same way for
+    /// every graph type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// graph.remove_node(a);
+    ///
+    /// let b = graph.node_indices().next().unwrap();
+    /// let remap = graph.compact();
+    /// assert_eq!(*graph.node(remap[b]), "B");
+    /// ```
+    pub fn compact(&mut self) -> impl Mapping<NodeIx<Ix>, NodeIx<Ix>> {
+        VecNodeMap::<'static, _, Ix> {
+            _graph: core::marker::PhantomData,
+            _ix: core::marker::PhantomData,
+            data: (0..self.nodes.len()).map(NodeIx::new).collect(),
+        }
     }
 
-    unsafe fn edge_unchecked(&self, EdgeIx(ix): Self::EdgeIx) -> &Self::Edge {
-        debug_assert!((ix as usize) < self.edges.len());
-        &self.edges.get_unchecked(ix as usize).data
+    /// Reserves storage so that the graph can hold `expected_total_edges` edges in total
+    /// without reallocating.
+    ///
+    /// Edges are stored in a single vector, so building a dense graph edge by edge
+    /// repeatedly grows it. When the final number of edges is known or can be estimated
+    /// (for example from the expected degree of each node), reserving up front avoids
+    /// those reallocations. Reserving less than the current number of edges does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// // A 50x50 grid where every node connects to its right and bottom neighbours
+    /// const GRID_SIZE: usize = 50;
+    /// let mut graph: VecGraph<usize, ()> = VecGraph::default();
+    /// graph.reserve_edges_for(2 * GRID_SIZE * (GRID_SIZE - 1));
+    /// let capacity = graph.edge_capacity();
+    ///
+    /// let nodes: Vec<_> = (0..GRID_SIZE * GRID_SIZE).map(|i| graph.add_node(i)).collect();
+    /// for row in 0..GRID_SIZE {
+    ///     for col in 0..GRID_SIZE {
+    ///         let node = nodes[row * GRID_SIZE + col];
+    ///         if col + 1 < GRID_SIZE {
+    ///             graph.add_edge((), node, nodes[row * GRID_SIZE + col + 1]);
+    ///         }
+    ///         if row + 1 < GRID_SIZE {
+    ///             graph.add_edge((), node, nodes[(row + 1) * GRID_SIZE + col]);
+    ///         }
+    ///     }
+    /// }
+    /// assert_eq!(graph.edge_capacity(), capacity);
+    /// ```
+    pub fn reserve_edges_for(&mut self, expected_total_edges: usize) {
+        self.edges
+            .reserve(expected_total_edges.saturating_sub(self.edges.len()));
     }
 
-    fn node_indices(&self) -> impl Iterator<Item = Self::NodeIx> {
-        (0..self.nodes.len()).map(|i| NodeIx(i as u32))
+    /// Returns the number of edges the graph can hold without reallocating.
+    pub fn edge_capacity(&self) -> usize {
+        self.edges.capacity()
     }
 
-    fn edge_indices(&self) -> impl Iterator<Item = Self::EdgeIx> {
-        (0..self.edges.len()).map(|i| EdgeIx(i as u32))
+    /// Returns the number of nodes and edges the graph can hold without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<u32, ()> = VecGraph::default();
+    /// assert_eq!(graph.capacity(), (0, 0));
+    ///
+    /// graph.add_nodes(0..100);
+    /// let (nodes, edges) = graph.capacity();
+    /// assert!(nodes >= 100);
+    /// assert_eq!(edges, graph.edge_capacity());
+    /// ```
+    pub fn capacity(&self) -> (usize, usize) {
+        (self.nodes.capacity(), self.edges.capacity())
     }
 
-    unsafe fn outgoing_edge_indices_unchecked(
-        &self,
-        node: Self::NodeIx,
-    ) -> impl Iterator<Item = Self::EdgeIx> {
-        impl_get_edges::<false, N, E>(self, node)
+    /// Shrinks the node and edge storage as much as possible.
+    ///
+    /// Removing nodes and edges never releases memory, so a graph that was once large
+    /// keeps its allocation after most of it has been removed. Use
+    /// [`Graph::memory_footprint`] to find such graphs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<u32, ()> = VecGraph::default();
+    /// let nodes: Vec<_> = (0..1000).map(|i| graph.add_node(i)).collect();
+    /// graph.add_edge((), nodes[0], nodes[1]);
+    /// graph.clear();
+    /// assert!(graph.memory_footprint().nodes_capacity >= 1000);
+    ///
+    /// graph.shrink_to_fit();
+    /// assert_eq!(graph.memory_footprint().nodes_bytes, 0);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.nodes.shrink_to_fit();
+        self.edges.shrink_to_fit();
     }
 
-    unsafe fn incoming_edge_indices_unchecked(
-        &self,
-        node: Self::NodeIx,
-    ) -> impl Iterator<Item = Self::EdgeIx> {
-        impl_get_edges::<true, N, E>(self, node)
+    /// Converts the graph to adjacency lists.
+    ///
+    /// `list[i]` holds the row of the target of every edge leaving the node in row `i`,
+    /// in edge index order. Parallel edges appear as repeated entries and a self-loop as
+    /// the row's own number. The returned mapping gives the row of each node, as in
+    /// [`VecGraph::to_adjacency_matrix`], and for a graph built with
+    /// [`VecGraph::from_adjacency_list`] the lists are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let adj = vec![vec![1, 1], vec![0, 1], vec![]];
+    /// let graph = VecGraph::from_adjacency_list(&adj).unwrap();
+    /// let (list, _) = graph.to_adjacency_list();
+    /// assert_eq!(list, adj);
+    /// ```
+    pub fn to_adjacency_list(&self) -> (Vec<Vec<usize>>, impl Mapping<NodeIx<Ix>, usize>) {
+        let mut list = vec![Vec::new(); self.nodes.len()];
+        for edge in &self.edges {
+            let [from, to] = edge.node.map(NodeIx::index);
+            list[from].push(to);
+        }
+        (list, self.node_rows())
     }
 
-    unsafe fn endpoints_unchecked(&self, EdgeIx(edge): Self::EdgeIx) -> [Self::NodeIx; 2] {
-        debug_assert!((edge as usize) < self.edges.len());
-        let edge_repr = self.edges.get_unchecked(edge as usize);
-        edge_repr.node
+    /// Formats the edges as a plain edge list, one `from to` line per edge.
+    ///
+    /// Nodes are written as their raw indices and edges in index order; payloads are
+    /// not written. [`VecGraph::parse_edge_list`] reads the result back into a graph with
+    /// the same edges, except that nodes after the last one with an edge are lost.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, u32> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// graph.add_edge(7, a, b);
+    /// graph.add_edge(8, b, b);
+    ///
+    /// assert_eq!(graph.to_edge_list_string(), "0 1\n1 1\n");
+    /// ```
+    pub fn to_edge_list_string(&self) -> String {
+        let mut out = String::new();
+        for edge in &self.edges {
+            let [from, to] = edge.node.map(NodeIx::index);
+            out.push_str(&format!("{} {}\n", from, to));
+        }
+        out
     }
 
-    unsafe fn outgoing_edge_pairs_unchecked(
-        &self,
-        node: Self::NodeIx,
-    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge)> {
-        impl_get_edges::<false, N, E>(self, node)
-            .map(move |edge_ix| (edge_ix, unsafe { self.edge_unchecked(edge_ix) }))
+    /// Converts the graph to an N×N boolean adjacency matrix.
+    ///
+    /// `matrix[i][j]` is `true` when there is at least one edge from the node in row `i`
+    /// to the node in row `j`, so parallel edges collapse into a single entry and
+    /// self-loops set the diagonal. The returned mapping gives the row of each node.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// graph.add_edge((), a, b);
+    /// graph.add_edge((), b, b);
+    ///
+    /// let (matrix, rows) = graph.to_adjacency_matrix();
+    /// assert!(matrix[rows[a]][rows[b]]);
+    /// assert!(!matrix[rows[b]][rows[a]]);
+    /// assert!(matrix[rows[b]][rows[b]]);
+    /// ```
+    pub fn to_adjacency_matrix(&self) -> (Vec<Vec<bool>>, impl Mapping<NodeIx<Ix>, usize>) {
+        let n = self.nodes.len();
+        let mut matrix = vec![vec![false; n]; n];
+        for edge in &self.edges {
+            let [from, to] = edge.node.map(NodeIx::index);
+            matrix[from][to] = true;
+        }
+        (matrix, self.node_rows())
     }
 
-    unsafe fn incoming_edge_pairs_unchecked(
+    /// Converts the graph to an N×N matrix of edge weights computed by `f`.
+    ///
+    /// `matrix[i][j]` is `Some(f(edge))` when there is an edge from the node in row `i` to
+    /// the node in row `j` and `None` otherwise. When there are parallel edges, the weight
+    /// of the one with the highest edge index is kept. The returned mapping gives the row
+    /// of each node, as in [`VecGraph::to_adjacency_matrix`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, f64> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// graph.add_edge(2.5, a, b);
+    ///
+    /// let (matrix, rows) = graph.to_weighted_matrix(|&w| w);
+    /// assert_eq!(matrix[rows[a]][rows[b]], Some(2.5));
+    /// assert_eq!(matrix[rows[b]][rows[a]], None);
+    /// ```
+    pub fn to_weighted_matrix<W>(
         &self,
-        node: Self::NodeIx,
-    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge)> {
-        impl_get_edges::<true, N, E>(self, node)
-            .map(move |edge_ix| (edge_ix, unsafe { self.edge_unchecked(edge_ix) }))
+        f: impl Fn(&E) -> W,
+    ) -> (Vec<Vec<Option<W>>>, impl Mapping<NodeIx<Ix>, usize>) {
+        let n = self.nodes.len();
+        let mut matrix: Vec<Vec<Option<W>>> =
+            (0..n).map(|_| (0..n).map(|_| None).collect()).collect();
+        for edge in &self.edges {
+            let [from, to] = edge.node.map(NodeIx::index);
+            matrix[from][to] = Some(f(&edge.data));
+        }
+        (matrix, self.node_rows())
     }
 
-    unsafe fn node_unchecked_mut(&mut self, NodeIx(ix): Self::NodeIx) -> &mut Self::Node {
-        debug_assert!((ix as usize) < self.nodes.len());
-        &mut self.nodes.get_unchecked_mut(ix as usize).data
+    /// Maps each node to its position in `nodes`, which is the matrix row used by the
+    /// adjacency matrix converters.
+    fn node_rows(&self) -> VecNodeMap<'static, usize, Ix> {
+        VecNodeMap {
+            _graph: core::marker::PhantomData,
+            _ix: core::marker::PhantomData,
+            data: (0..self.nodes.len()).collect(),
+        }
     }
 
-    unsafe fn edge_unchecked_mut(&mut self, EdgeIx(ix): Self::EdgeIx) -> &mut Self::Edge {
-        debug_assert!((ix as usize) < self.edges.len());
-        &mut self.edges.get_unchecked_mut(ix as usize).data
-    }
+    /// Splits the graph in two according to a node predicate.
+    ///
+    /// Nodes for which `f` returns `true` are moved into the first graph and the others
+    /// into the second, keeping their relative order. Edges between two nodes on the same
+    /// side are moved along with them. Edges that cross the partition cannot be kept in
+    /// either graph, so their payloads are returned in the third element, in edge index
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<i32, &str> = VecGraph::default();
+    /// let a = graph.add_node(1);
+    /// let b = graph.add_node(2);
+    /// let c = graph.add_node(-1);
+    /// graph.add_edge("a-b", a, b);
+    /// graph.add_edge("b-c", b, c);
+    ///
+    /// let (positive, negative, crossing) = graph.partition_nodes(|&n| n > 0);
+    /// assert_eq!(positive.len_nodes(), 2);
+    /// assert_eq!(positive.edges().collect::<Vec<_>>(), vec![&"a-b"]);
+    /// assert_eq!(negative.len_nodes(), 1);
+    /// assert_eq!(crossing, vec!["b-c"]);
+    /// ```
+    pub fn partition_nodes<F: FnMut(&N) -> bool>(self, mut f: F) -> (Self, Self, Vec<E>) {
+        let mut sides = [Self::with_index_type(), Self::with_index_type()];
+        // Side (0 for `true`) and new index of each node
+        let placement: Vec<(usize, NodeIx<Ix>)> = self
+            .nodes
+            .into_iter()
+            .map(|node| {
+                let side = if f(&node.data) { 0 } else { 1 };
+                (side, sides[side].add_node(node.data))
+            })
+            .collect();
 
-    unsafe fn outgoing_edge_pairs_unchecked_mut(
-        &mut self,
-        node: Self::NodeIx,
-    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
-    where
-        Self: Sized,
-    {
-        struct OutgoingEdgePairsMutIterUnchecked<'a, N, E> {
-            graph: &'a mut VecGraph<N, E>,
-            indices: std::vec::IntoIter<EdgeIx>,
+        let mut crossing = Vec::new();
+        for edge in self.edges {
+            let [from, to] = edge.node.map(NodeIx::index);
+            let (from_side, from) = placement[from];
+            let (to_side, to) = placement[to];
+            if from_side == to_side {
+                sides[from_side].add_edge(edge.data, from, to);
+            } else {
+                crossing.push(edge.data);
+            }
         }
 
-        impl<'a, N, E> Iterator for OutgoingEdgePairsMutIterUnchecked<'a, N, E> {
-            type Item = (EdgeIx, &'a mut E);
+        let [matching, rest] = sides;
+        (matching, rest, crossing)
+    }
 
-            fn next(&mut self) -> Option<Self::Item> {
-                self.indices.next().map(|ix| unsafe {
-                    let ptr = self.graph.edge_unchecked_mut(ix) as *mut E;
-                    (ix, &mut *ptr)
+    /// Consumes the graph, keeping only the nodes for which `f` returns `Some`.
+    ///
+    /// `f` is called once for every node, in index order, with its index and data. The
+    /// kept nodes get the returned data and are renumbered from zero, keeping their
+    /// relative order. Edges incident to a dropped node are dropped too; the remaining
+    /// edges keep their order and point to the renumbered endpoints.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<i32, &str> = VecGraph::default();
+    /// let a = graph.add_node(1);
+    /// let b = graph.add_node(-2);
+    /// let c = graph.add_node(3);
+    /// graph.add_edge("a-b", a, b);
+    /// graph.add_edge("c-a", c, a);
+    ///
+    /// let positive = graph.filter_map_nodes(|_, n| (n > 0).then(|| n.to_string()));
+    /// assert_eq!(positive.nodes().collect::<Vec<_>>(), vec!["1", "3"]);
+    /// assert_eq!(positive.edges().collect::<Vec<_>>(), vec![&"c-a"]);
+    /// ```
+    pub fn filter_map_nodes<N2>(
+        self,
+        mut f: impl FnMut(NodeIx<Ix>, N) -> Option<N2>,
+    ) -> VecGraph<N2, E, Ix> {
+        let mut graph = VecGraph::with_index_type();
+        let placement: Vec<Option<NodeIx<Ix>>> = self
+            .nodes
+            .into_iter()
+            .enumerate()
+            .map(|(i, node)| f(NodeIx::new(i), node.data).map(|data| graph.add_node(data)))
+            .collect();
+
+        for edge in self.edges {
+            let [from, to] = edge.node.map(NodeIx::index);
+            if let (Some(from), Some(to)) = (placement[from], placement[to]) {
+                graph.add_edge(edge.data, from, to);
+            }
+        }
+        graph
+    }
+
+    /// Consumes the graph, keeping all nodes but only the edges for which `f` returns
+    /// `true`.
+    ///
+    /// `f` is called once for every edge, in index order, with its index and data. Nodes
+    /// keep their indices. The kept edges are renumbered from zero, keeping their relative
+    /// order, and the adjacency lists are rebuilt from them. Use
+    /// [`filter_map_nodes`](VecGraph::filter_map_nodes) to filter nodes instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, u32> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// graph.add_edge(1, a, b);
+    /// graph.add_edge(5, b, a);
+    /// graph.add_edge(2, a, a);
+    ///
+    /// let light = graph.filter_edges(|_, &w| w < 3);
+    /// assert_eq!(light.len_nodes(), 2);
+    /// assert_eq!(light.edges().collect::<Vec<_>>(), vec![&1, &2]);
+    /// assert_eq!(light.incoming_edge_indices(a).count(), 1);
+    /// ```
+    pub fn filter_edges<F: FnMut(EdgeIx<Ix>, &E) -> bool>(self, mut f: F) -> VecGraph<N, E, Ix> {
+        let mut graph = VecGraph {
+            nodes: self
+                .nodes
+                .into_iter()
+                .map(|node| NodeRepr {
+                    data: node.data,
+                    next: [EdgeIx::end(), EdgeIx::end()],
                 })
+                .collect(),
+            edges: Vec::new(),
+            // Node indices are kept but the edges are renumbered
+            generation: self.generation + 1,
+        };
+        for (i, edge) in self.edges.into_iter().enumerate() {
+            if f(EdgeIx::new(i), &edge.data) {
+                let [from, to] = edge.node;
+                // SAFETY: the endpoints existed in the original graph, whose nodes are kept
+                unsafe { graph.add_edge_unchecked(edge.data, from, to) };
             }
         }
+        graph
+    }
 
-        let indices: Vec<_> = unsafe { impl_get_edges::<false, N, E>(self, node) }.collect();
-        OutgoingEdgePairsMutIterUnchecked {
-            graph: self,
-            indices: indices.into_iter(),
+    /// Removes every node outside the `k`-core, along with its edges.
+    ///
+    /// See [`algo::k_core`](fn@crate::algo::k_core) for how degrees are counted. The
+    /// remaining nodes and edges may be renumbered, as with [`GraphRemove::remove_node`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let c = graph.add_node("C");
+    /// let d = graph.add_node("D");
+    /// graph.add_edge((), a, b);
+    /// graph.add_edge((), b, c);
+    /// graph.add_edge((), c, a);
+    /// graph.add_edge((), c, d);
+    ///
+    /// graph.retain_k_core(2);
+    /// assert_eq!(graph.len_nodes(), 3);
+    /// assert_eq!(graph.len_edges(), 3);
+    /// assert!(!graph.nodes().any(|&n| n == "D"));
+    /// ```
+    pub fn retain_k_core(&mut self, k: usize) {
+        let core = crate::algo::k_core(self, k);
+        let mut keep = vec![false; self.nodes.len()];
+        for ix in core {
+            keep[ix.index()] = true;
         }
+        let peeled: Vec<_> = self.node_indices().filter(|ix| !keep[ix.index()]).collect();
+        // SAFETY: the indices are distinct and exist in the graph
+        let _: (Vec<N>, Vec<E>) = unsafe { self.remove_nodes_edges_unchecked(peeled, []) };
     }
 
-    unsafe fn incoming_edge_pairs_unchecked_mut(
-        &mut self,
-        node: Self::NodeIx,
-    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
-    where
-        Self: Sized,
-    {
-        struct IncomingEdgePairsMutIterUnchecked<'a, N, E> {
-            graph: &'a mut VecGraph<N, E>,
-            indices: std::vec::IntoIter<EdgeIx>,
+    /// Removes every self-loop from the graph and returns their data.
+    ///
+    /// The data is returned in the order of the edge indices the self-loops had. Other
+    /// edges may be renumbered, as with [`GraphRemoveEdge::remove_edge`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, &str> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// graph.add_edge("a-a", a, a);
+    /// graph.add_edge("a-b", a, b);
+    ///
+    /// assert_eq!(graph.remove_self_loops(), vec!["a-a"]);
+    /// assert_eq!(graph.self_loop_edges().count(), 0);
+    /// assert_eq!(graph.len_edges(), 1);
+    /// ```
+    pub fn remove_self_loops(&mut self) -> Vec<E> {
+        let loops: Vec<_> = self.self_loop_edges().collect();
+        self.remove_edges(loops)
+    }
+
+    /// Returns `true` if some ordered pair of nodes is connected by more than one edge.
+    ///
+    /// Edges in opposite directions are not parallel; repeated self-loops on the same node
+    /// are. See [`VecGraph::parallel_edge_groups`] to find the edges.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// graph.add_edge((), a, b);
+    /// graph.add_edge((), b, a);
+    /// assert!(!graph.has_parallel_edges());
+    ///
+    /// graph.add_edge((), a, b);
+    /// assert!(graph.has_parallel_edges());
+    /// ```
+    pub fn has_parallel_edges(&self) -> bool {
+        let mut seen = std::collections::HashSet::new();
+        self.edges.iter().any(|edge| !seen.insert(edge.node))
+    }
+
+    /// Returns the groups of parallel edges, one for every ordered pair of nodes connected
+    /// by more than one edge.
+    ///
+    /// Each group holds the source and target node and the edges between them in index
+    /// order. Groups are yielded in the order of their first edge. Repeated self-loops on
+    /// a node `n` are grouped under `(n, n)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let ab1 = graph.add_edge((), a, b);
+    /// graph.add_edge((), b, a);
+    /// let ab2 = graph.add_edge((), a, b);
+    ///
+    /// let groups: Vec<_> = graph.parallel_edge_groups().collect();
+    /// assert_eq!(groups, vec![(a, b, vec![ab1, ab2])]);
+    /// ```
+    pub fn parallel_edge_groups(
+        &self,
+    ) -> impl Iterator<Item = (NodeIx<Ix>, NodeIx<Ix>, Vec<EdgeIx<Ix>>)> {
+        let mut groups = Vec::new();
+        let mut positions = std::collections::HashMap::new();
+        for (i, edge) in self.edges.iter().enumerate() {
+            let [from, to] = edge.node;
+            let position = *positions.entry(edge.node).or_insert_with(|| {
+                groups.push((from, to, Vec::new()));
+                groups.len() - 1
+            });
+            groups[position].2.push(EdgeIx::new(i));
         }
+        groups.into_iter().filter(|(_, _, edges)| edges.len() > 1)
+    }
 
-        impl<'a, N, E> Iterator for IncomingEdgePairsMutIterUnchecked<'a, N, E> {
-            type Item = (EdgeIx, &'a mut E);
+    /// Merges parallel edges, keeping one edge for every ordered pair of nodes.
+    ///
+    /// For each `(from, to)` pair the edge with the lowest index is kept, and the data of
+    /// every other edge between the same pair is folded into it with `combine`, in edge
+    /// index order. Edges in opposite directions are not parallel; repeated self-loops on
+    /// the same node are. Other edges may be renumbered, as with
+    /// [`GraphRemoveEdge::remove_edge`].
+    ///
+    /// # Returns
+    ///
+    /// The number of edges removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, u32> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// graph.add_edge(1, a, b);
+    /// graph.add_edge(2, a, b);
+    /// graph.add_edge(4, b, a);
+    ///
+    /// assert_eq!(graph.dedup_parallel_edges(|total, w| *total += w), 1);
+    /// let mut weights: Vec<_> = graph.edges().copied().collect();
+    /// weights.sort();
+    /// assert_eq!(weights, vec![3, 4]);
+    /// ```
+    pub fn dedup_parallel_edges(&mut self, mut combine: impl FnMut(&mut E, E)) -> usize {
+        let mut seen = std::collections::HashSet::new();
+        let (duplicates, pairs): (Vec<_>, Vec<_>) = self
+            .edges
+            .iter()
+            .enumerate()
+            .filter(|(_, edge)| !seen.insert(edge.node))
+            .map(|(i, edge)| (EdgeIx::new(i), edge.node))
+            .unzip();
+        let removed: Vec<_> = self.remove_edges(duplicates);
 
-            fn next(&mut self) -> Option<Self::Item> {
-                self.indices.next().map(|ix| unsafe {
-                    let ptr = self.graph.edge_unchecked_mut(ix) as *mut E;
-                    (ix, &mut *ptr)
-                })
+        // Removal may have moved the kept edges, so look them up again
+        let kept: std::collections::HashMap<_, _> = self
+            .edges
+            .iter()
+            .enumerate()
+            .map(|(i, edge)| (edge.node, i))
+            .collect();
+        let count = removed.len();
+        for (pair, data) in pairs.into_iter().zip(removed) {
+            combine(&mut self.edges[kept[&pair]].data, data);
+        }
+        count
+    }
+
+    /// Turns the graph into a simple graph, merging parallel edges and optionally
+    /// removing self-loops.
+    ///
+    /// Every group of edges between the same ordered pair of nodes is replaced by one
+    /// edge whose data is the data of the group folded with `merge`, in edge index order.
+    /// If `drop_self_loops` is `true`, self-loops are removed; otherwise repeated
+    /// self-loops on a node are merged like other parallel edges.
+    ///
+    /// All affected edges are removed in one batch, as with
+    /// [`GraphRemoveEdge::remove_edges`], and the merged edges are then added back in the
+    /// order of the first edge of their group, so they get the highest edge indices.
+    /// Other edges may be renumbered; node indices do not change.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, u32> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// graph.add_edge(1, a, b);
+    /// graph.add_edge(2, a, b);
+    /// graph.add_edge(4, b, a);
+    /// graph.add_edge(8, b, b);
+    ///
+    /// let report = graph.simplify(|x, y| x + y, true);
+    /// assert_eq!(report.merged_edges, 1);
+    /// assert_eq!(report.dropped_self_loops, 1);
+    /// assert_eq!(graph.len_edges(), 2);
+    /// assert_eq!(*graph.edge(graph.find_edge(a, b).unwrap()), 3);
+    /// ```
+    pub fn simplify(
+        &mut self,
+        mut merge: impl FnMut(E, E) -> E,
+        drop_self_loops: bool,
+    ) -> SimplifyReport {
+        let mut groups = Vec::new();
+        let mut positions = std::collections::HashMap::new();
+        let mut self_loops = Vec::new();
+        for (i, edge) in self.edges.iter().enumerate() {
+            let ix = EdgeIx::new(i);
+            if drop_self_loops && edge.node[0] == edge.node[1] {
+                self_loops.push(ix);
+                continue;
             }
+            let position = *positions.entry(edge.node).or_insert_with(|| {
+                groups.push((edge.node, Vec::new()));
+                groups.len() - 1
+            });
+            groups[position].1.push(ix);
         }
+        groups.retain(|(_, edges)| edges.len() > 1);
 
-        let indices: Vec<_> = unsafe { impl_get_edges::<true, N, E>(self, node) }.collect();
-        IncomingEdgePairsMutIterUnchecked {
-            graph: self,
-            indices: indices.into_iter(),
+        let report = SimplifyReport {
+            merged_edges: groups.iter().map(|(_, edges)| edges.len() - 1).sum(),
+            dropped_self_loops: self_loops.len(),
+        };
+        let batch: Vec<_> = groups
+            .iter()
+            .flat_map(|(_, edges)| edges.iter().copied())
+            .chain(self_loops)
+            .collect();
+        // The data comes back in batch order: the groups one after another, then the
+        // self-loops, which are dropped
+        let mut removed = self.remove_edges::<_, Vec<_>>(batch).into_iter();
+        for ([from, to], edges) in groups {
+            let first = removed.next().expect("every group was removed");
+            let data = removed
+                .by_ref()
+                .take(edges.len() - 1)
+                .fold(first, &mut merge);
+            self.add_edge(data, from, to);
         }
+        report
     }
 
-    unsafe fn connecting_edge_pairs_unchecked_mut(
+    /// Reorders the outgoing and incoming edge lists of every node with a comparator.
+    ///
+    /// New edges are prepended to the lists of their endpoints, so by default
+    /// `outgoing_edge_indices` and `incoming_edge_indices` yield edges in reverse
+    /// insertion order. After this call they yield them in the order given by `cmp`,
+    /// which receives the index and data of the two edges to compare. The sort is
+    /// stable. Indices and payloads do not change; only the iteration order does.
+    ///
+    /// The order holds until the graph is modified: edges added or rewired later are
+    /// prepended again, and removals may renumber edges.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, u32> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// let c = graph.add_node("C");
+    /// graph.add_edge(5, a, b);
+    /// graph.add_edge(3, a, c);
+    /// graph.add_edge(1, a, b);
+    ///
+    /// graph.sort_adjacency_by(|_, x, _, y| y.cmp(x));
+    /// let weights: Vec<_> = graph
+    ///     .outgoing_edge_indices(a)
+    ///     .map(|e| *graph.edge(e))
+    ///     .collect();
+    /// assert_eq!(weights, vec![5, 3, 1]);
+    /// ```
+    pub fn sort_adjacency_by(
         &mut self,
-        node: Self::NodeIx,
-    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
-    where
-        Self: Sized,
-    {
-        struct ConnectingEdgePairsMutIterUnchecked<'a, N, E> {
-            graph: &'a mut VecGraph<N, E>,
-            indices: std::vec::IntoIter<EdgeIx>,
+        mut cmp: impl FnMut(EdgeIx<Ix>, &E, EdgeIx<Ix>, &E) -> core::cmp::Ordering,
+    ) {
+        let mut chain = Vec::new();
+        for node in 0..self.nodes.len() {
+            for dir in 0..2 {
+                chain.clear();
+                let mut current = self.nodes[node].next[dir];
+                while !current.is_end() {
+                    chain.push(current);
+                    current = self.edges[current.index()].next[dir];
+                }
+                if chain.len() < 2 {
+                    continue;
+                }
+                chain.sort_by(|&a, &b| {
+                    cmp(
+                        a,
+                        &self.edges[a.index()].data,
+                        b,
+                        &self.edges[b.index()].data,
+                    )
+                });
+
+                // Relink back to front so that every edge points at its successor
+                let mut next = EdgeIx::end();
+                for &ix in chain.iter().rev() {
+                    self.edges[ix.index()].next[dir] = next;
+                    next = ix;
+                }
+                self.nodes[node].next[dir] = next;
+            }
         }
 
-        impl<'a, N, E> Iterator for ConnectingEdgePairsMutIterUnchecked<'a, N, E> {
-            type Item = (EdgeIx, &'a mut E);
+        #[cfg(debug_assertions)]
+        {
+            let nodes: Vec<_> = (0..self.nodes.len()).map(NodeIx::new).collect();
+            self.debug_validate_links(&nodes);
+        }
+    }
 
-            fn next(&mut self) -> Option<Self::Item> {
-                self.indices.next().map(|ix| unsafe {
-                    let ptr = self.graph.edge_unchecked_mut(ix) as *mut E;
-                    (ix, &mut *ptr)
-                })
+    /// Reorders the edge lists of every node by a key extracted from the edge data.
+    ///
+    /// Equivalent to [`sort_adjacency_by`](Self::sort_adjacency_by) comparing
+    /// `key(data)` in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let mut graph: VecGraph<&str, f64> = VecGraph::default();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// graph.add_edge(2.5, a, b);
+    /// graph.add_edge(0.5, a, b);
+    /// graph.add_edge(1.5, b, a);
+    ///
+    /// graph.sort_adjacency_by_edge_key(|w| (w * 10.0) as i64);
+    /// let weights: Vec<_> = graph
+    ///     .incoming_edge_indices(b)
+    ///     .map(|e| *graph.edge(e))
+    ///     .collect();
+    /// assert_eq!(weights, vec![0.5, 2.5]);
+    /// ```
+    pub fn sort_adjacency_by_edge_key<K: Ord>(&mut self, mut key: impl FnMut(&E) -> K) {
+        self.sort_adjacency_by(|_, a, _, b| key(a).cmp(&key(b)))
+    }
+}
+
+// Only for the default index type: `add_nodes_range` returns raw `u32` indices, and the
+// constructors would otherwise need the index type spelled out at every call
+impl<N, E> VecGraph<N, E> {
+    /// Adds a batch of nodes and returns the range of their raw indices.
+    ///
+    /// Nodes are appended, so their indices are consecutive: the `i`-th node of the
+    /// batch gets the index `start + i`, which can be turned into a `NodeIx` with
+    /// `NodeIx::try_from`. Storage is reserved up front from the iterator's size hint.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no more nodes can be added to the graph. Nodes added before that point
+    /// are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    /// use gotgraph::vec_graph::NodeIx;
+    ///
+    /// let mut graph: VecGraph<u32, ()> = VecGraph::default();
+    /// graph.add_node(0);
+    /// let range = graph.add_nodes_range(10..20);
+    /// assert_eq!(range, 1..11);
+    ///
+    /// let fifth = NodeIx::try_from(range.start as usize + 4).unwrap();
+    /// assert_eq!(*graph.node(fifth), 14);
+    /// ```
+    pub fn add_nodes_range(&mut self, nodes: impl IntoIterator<Item = N>) -> core::ops::Range<u32> {
+        let nodes = nodes.into_iter();
+        self.nodes.reserve(nodes.size_hint().0);
+        let start = self.nodes.len() as u32;
+        for node in nodes {
+            self.add_node(node);
+        }
+        start..self.nodes.len() as u32
+    }
+
+    /// Builds a graph from adjacency lists, creating payloads with the given factories.
+    ///
+    /// Node `i` is created for `adj[i]` and gets the payload `node(i)`; for every entry
+    /// `j` of `adj[i]` an edge from node `i` to node `j` is added with the payload
+    /// `edge(i, j)`. Node indices are assigned in order, so `NodeIx::try_from(i)` is the
+    /// node of row `i`. Repeated entries create parallel edges and `i` in `adj[i]`
+    /// creates a self-loop. Edges are added row by row, in the order of the entries.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AdjacencyError::OutOfRange`] if an entry is not less than `adj.len()`.
+    /// The factories are not called in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    ///
+    /// let graph = VecGraph::from_adjacency_list_with(
+    ///     &[vec![1, 2], vec![2], vec![]],
+    ///     |i| format!("n{}", i),
+    ///     |from, to| from * 10 + to,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(graph.len_edges(), 3);
+    /// assert_eq!(graph.edges().copied().collect::<Vec<_>>(), vec![1, 2, 12]);
+    /// ```
+    pub fn from_adjacency_list_with(
+        adj: &[Vec<usize>],
+        mut node: impl FnMut(usize) -> N,
+        mut edge: impl FnMut(usize, usize) -> E,
+    ) -> Result<Self, AdjacencyError> {
+        let len = adj.len();
+        for (i, targets) in adj.iter().enumerate() {
+            if let Some(&target) = targets.iter().find(|&&j| j >= len) {
+                return Err(AdjacencyError::OutOfRange {
+                    node: i,
+                    target,
+                    len,
+                });
             }
         }
 
-        let outgoing_indices: Vec<_> =
-            unsafe { impl_get_edges::<false, N, E>(self, node) }.collect();
-        let incoming_indices: Vec<_> =
-            unsafe { impl_get_edges::<true, N, E>(self, node) }.collect();
-        let indices: Vec<_> = outgoing_indices
-            .into_iter()
-            .chain(incoming_indices)
+        let mut graph = VecGraph::default();
+        graph.reserve_edges_for(adj.iter().map(Vec::len).sum());
+        let nodes: Vec<_> = (0..len).map(|i| graph.add_node(node(i))).collect();
+        for (i, targets) in adj.iter().enumerate() {
+            for &j in targets {
+                graph.add_edge(edge(i, j), nodes[i], nodes[j]);
+            }
+        }
+        Ok(graph)
+    }
+
+    /// Builds a graph from a square boolean adjacency matrix, creating payloads with the
+    /// given factories.
+    ///
+    /// Node `i` is created for row `i` with the payload `node(i)`, and a single edge from
+    /// node `i` to node `j` with the payload `edge(i, j)` is added for every `true` cell
+    /// `m[i][j]`; a `true` diagonal cell creates a self-loop. Edges are added row by row.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AdjacencyError::NotSquare`] if a row does not have `m.len()` columns.
+    /// The factories are not called in that case.
+    pub fn from_adjacency_matrix_with(
+        m: &[Vec<bool>],
+        node: impl FnMut(usize) -> N,
+        edge: impl FnMut(usize, usize) -> E,
+    ) -> Result<Self, AdjacencyError> {
+        let len = m.len();
+        if let Some((row, cells)) = m.iter().enumerate().find(|(_, r)| r.len() != len) {
+            return Err(AdjacencyError::NotSquare {
+                row,
+                columns: cells.len(),
+                len,
+            });
+        }
+        let adj: Vec<Vec<usize>> = m
+            .iter()
+            .map(|row| (0..len).filter(|&j| row[j]).collect())
             .collect();
-        ConnectingEdgePairsMutIterUnchecked {
-            graph: self,
-            indices: indices.into_iter(),
+        Self::from_adjacency_list_with(&adj, node, edge)
+    }
+}
+
+/// Formats the graph as a human-readable adjacency dump.
+///
+/// Each node is printed on its own line as `index: payload`, followed by one indented
+/// line per outgoing edge in the form `-> target_index (edge_payload)`. Nodes are listed
+/// in index order and the edges of a node in edge index order, so the output only
+/// depends on the graph's contents.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, i32> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// graph.add_edge(10, a, b);
+///
+/// assert_eq!(graph.to_string(), "0: A\n  -> 1 (10)\n1: B\n");
+/// ```
+impl<N: core::fmt::Display, E: core::fmt::Display, Ix: IndexType> core::fmt::Display
+    for VecGraph<N, E, Ix>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, node) in self.nodes.iter().enumerate() {
+            writeln!(f, "{}: {}", i, node.data)?;
+            let mut outgoing: Vec<_> =
+                unsafe { impl_get_edges::<false, N, E, Ix>(self, NodeIx::new(i)) }.collect();
+            outgoing.sort_unstable();
+            for edge in outgoing {
+                let edge = &self.edges[edge.index()];
+                writeln!(f, "  -> {} ({})", edge.node[1].index(), edge.data)?;
+            }
         }
+        Ok(())
     }
+}
 
-    fn init_node_map<V>(
-        &self,
-        mut f: impl FnMut(Self::NodeIx, &Self::Node) -> V,
-    ) -> impl Mapping<Self::NodeIx, V> {
-        #[derive(Debug)]
-        #[allow(dead_code)]
-        pub struct VecNodeMap<'graph, V> {
-            _graph: crate::Invariant<'graph>,
-            data: Vec<V>,
+/// Compares two graphs index by index.
+///
+/// Graphs are equal when they have the same number of nodes and edges, the same data at
+/// every node index, and the same data and endpoints at every edge index. The order of
+/// the adjacency lists, which depends on how the graph was built, is ignored. Graphs
+/// that only differ by a renumbering of their indices compare unequal; use
+/// [`is_structurally_equal`](crate::algo::is_structurally_equal) to compare them.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, i32> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// let ab = graph.add_edge(1, a, b);
+///
+/// let mut other = graph.clone();
+/// assert_eq!(graph, other);
+///
+/// other.reverse_edge(ab);
+/// assert_ne!(graph, other);
+/// ```
+impl<N: PartialEq, E: PartialEq, Ix: IndexType> PartialEq for VecGraph<N, E, Ix> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len_nodes() == other.len_nodes()
+            && self.len_edges() == other.len_edges()
+            && self.nodes().eq(other.nodes())
+            && self.edge_pairs().zip(other.edge_pairs()).all(
+                |((ix, edge), (other_ix, other_edge))| {
+                    edge == other_edge && self.endpoints(ix) == other.endpoints(other_ix)
+                },
+            )
+    }
+}
+
+impl<N: Eq, E: Eq, Ix: IndexType> Eq for VecGraph<N, E, Ix> {}
+
+/// Adds a batch of edges between existing nodes.
+///
+/// Each item is a `(from, to, data)` triple passed on to
+/// [`add_edge`](GraphUpdate::add_edge), in iteration order. Use
+/// [`append`](GraphUpdate::append) to merge a whole graph instead.
+///
+/// # Panics
+///
+/// Panics if an endpoint does not exist in the graph. Edges added before the offending
+/// item are kept.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::prelude::*;
+///
+/// let mut graph: VecGraph<&str, u32> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// let c = graph.add_node("C");
+///
+/// graph.extend([(a, b, 1), (b, c, 2), (c, a, 3)]);
+/// assert_eq!(graph.len_edges(), 3);
+/// ```
+impl<N, E, Ix: IndexType> Extend<(NodeIx<Ix>, NodeIx<Ix>, E)> for VecGraph<N, E, Ix> {
+    fn extend<I: IntoIterator<Item = (NodeIx<Ix>, NodeIx<Ix>, E)>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.edges.reserve(iter.size_hint().0);
+        for (from, to, edge) in iter {
+            self.add_edge(edge, from, to);
         }
+    }
+}
 
-        impl<'graph, V> std::ops::Index<NodeIx> for VecNodeMap<'graph, V> {
-            type Output = V;
+/// Parallel iteration, available with the `rayon` feature.
+///
+/// The iterators borrow the graph immutably, so node payloads must be `Sync` to be
+/// shared between threads (and edge payloads for the edge iterators). Indices are
+/// always `Send + Sync`.
+#[cfg(feature = "rayon")]
+impl<N, E, Ix: IndexType> VecGraph<N, E, Ix> {
+    /// Returns a parallel iterator over all node indices in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut graph: VecGraph<u64, ()> = VecGraph::default();
+    /// for i in 0..100 {
+    ///     graph.add_node(i);
+    /// }
+    ///
+    /// let sum: u64 = graph.par_node_indices().map(|ix| *graph.node(ix)).sum();
+    /// assert_eq!(sum, 4950);
+    /// ```
+    pub fn par_node_indices(&self) -> impl rayon::iter::IndexedParallelIterator<Item = NodeIx<Ix>> {
+        use rayon::prelude::*;
+        (0..self.nodes.len()).into_par_iter().map(NodeIx::new)
+    }
 
-            fn index(&self, NodeIx(ix): NodeIx) -> &Self::Output {
-                &self.data[ix as usize]
-            }
+    /// Returns a parallel iterator over all edge indices in the graph.
+    pub fn par_edge_indices(&self) -> impl rayon::iter::IndexedParallelIterator<Item = EdgeIx<Ix>> {
+        use rayon::prelude::*;
+        (0..self.edges.len()).into_par_iter().map(EdgeIx::new)
+    }
+
+    /// Returns a parallel iterator over all node indices and payloads in the graph.
+    pub fn par_node_pairs(
+        &self,
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = (NodeIx<Ix>, &N)>
+    where
+        N: Sync,
+    {
+        use rayon::prelude::*;
+        self.nodes
+            .par_iter()
+            .enumerate()
+            .map(|(i, node)| (NodeIx::new(i), &node.data))
+    }
+
+    /// Returns a parallel iterator over all edge indices and payloads in the graph.
+    pub fn par_edge_pairs(
+        &self,
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = (EdgeIx<Ix>, &E)>
+    where
+        E: Sync,
+    {
+        use rayon::prelude::*;
+        self.edges
+            .par_iter()
+            .enumerate()
+            .map(|(i, edge)| (EdgeIx::new(i), &edge.data))
+    }
+
+    /// Returns a parallel iterator over all node payloads in the graph.
+    pub fn par_nodes(&self) -> impl rayon::iter::IndexedParallelIterator<Item = &N>
+    where
+        N: Sync,
+    {
+        use rayon::prelude::*;
+        self.nodes.par_iter().map(|node| &node.data)
+    }
+
+    /// Returns a parallel iterator over all edge payloads in the graph.
+    pub fn par_edges(&self) -> impl rayon::iter::IndexedParallelIterator<Item = &E>
+    where
+        E: Sync,
+    {
+        use rayon::prelude::*;
+        self.edges.par_iter().map(|edge| &edge.data)
+    }
+}
+
+/// Converts a `VecGraph` to a petgraph `DiGraph` by cloning its payloads.
+///
+/// Indices correspond one to one: the node with index `ix` becomes
+/// `NodeIndex::new(ix.index())` in the resulting graph, and likewise for edges.
+///
+/// # Examples
+///
+/// ```rust
+/// use gotgraph::prelude::*;
+/// use petgraph::graph::{DiGraph, NodeIndex};
+///
+/// let mut graph: VecGraph<&str, u32> = VecGraph::default();
+/// let a = graph.add_node("A");
+/// let b = graph.add_node("B");
+/// graph.add_edge(7, a, b);
+///
+/// let converted = DiGraph::from(&graph);
+/// assert_eq!(converted[NodeIndex::new(b.index())], "B");
+/// assert!(converted.contains_edge(NodeIndex::new(a.index()), NodeIndex::new(b.index())));
+/// ```
+#[cfg(feature = "petgraph")]
+impl<N: Clone, E: Clone> From<&VecGraph<N, E>> for petgraph::graph::DiGraph<N, E> {
+    fn from(graph: &VecGraph<N, E>) -> Self {
+        use petgraph::graph::NodeIndex;
+
+        let mut converted =
+            petgraph::graph::DiGraph::with_capacity(graph.nodes.len(), graph.edges.len());
+        for node in &graph.nodes {
+            converted.add_node(node.data.clone());
         }
+        for edge in &graph.edges {
+            let [NodeIx(from), NodeIx(to)] = edge.node;
+            converted.add_edge(
+                NodeIndex::new(from as usize),
+                NodeIndex::new(to as usize),
+                edge.data.clone(),
+            );
+        }
+        converted
+    }
+}
 
-        impl<'graph, V> std::ops::IndexMut<NodeIx> for VecNodeMap<'graph, V> {
-            fn index_mut(&mut self, NodeIx(ix): NodeIx) -> &mut Self::Output {
-                &mut self.data[ix as usize]
-            }
+#[cfg(feature = "petgraph")]
+impl<N: Clone, E: Clone> VecGraph<N, E> {
+    /// Builds a `VecGraph` from a petgraph `DiGraph` by cloning its payloads.
+    ///
+    /// Indices correspond one to one: the node at `NodeIndex` `i` gets the `NodeIx` with
+    /// `index() == i`, and likewise for edges.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use gotgraph::prelude::*;
+    /// use gotgraph::vec_graph::NodeIx;
+    /// use petgraph::graph::DiGraph;
+    ///
+    /// let mut graph: DiGraph<&str, u32> = DiGraph::new();
+    /// let a = graph.add_node("A");
+    /// let b = graph.add_node("B");
+    /// graph.add_edge(a, b, 7);
+    ///
+    /// let converted = VecGraph::from_petgraph(&graph);
+    /// let b = NodeIx::try_from(b.index()).unwrap();
+    /// assert_eq!(*converted.node(b), "B");
+    /// assert_eq!(converted.len_edges(), 1);
+    /// ```
+    pub fn from_petgraph(graph: &petgraph::graph::DiGraph<N, E>) -> Self {
+        let mut converted = VecGraph::default();
+        converted.nodes.reserve(graph.node_count());
+        converted.edges.reserve(graph.edge_count());
+        for node in graph.raw_nodes() {
+            converted.add_node(node.weight.clone());
+        }
+        for edge in graph.raw_edges() {
+            converted.add_edge(
+                edge.weight.clone(),
+                NodeIx(edge.source().index() as u32),
+                NodeIx(edge.target().index() as u32),
+            );
         }
+        converted
+    }
+}
 
-        impl<'graph, V> IntoIterator for VecNodeMap<'graph, V> {
-            type Item = V;
-            type IntoIter = std::vec::IntoIter<V>;
+/// Dense node mapping used by `VecGraph`, indexed by the position of the node.
+#[derive(Debug)]
+#[allow(dead_code)]
+struct VecNodeMap<'graph, V, Ix = u32> {
+    _graph: crate::Invariant<'graph>,
+    _ix: core::marker::PhantomData<Ix>,
+    data: Vec<V>,
+}
 
-            fn into_iter(self) -> Self::IntoIter {
-                self.data.into_iter()
-            }
+impl<'graph, V, Ix: IndexType> std::ops::Index<NodeIx<Ix>> for VecNodeMap<'graph, V, Ix> {
+    type Output = V;
+
+    fn index(&self, NodeIx(ix): NodeIx<Ix>) -> &Self::Output {
+        &self.data[ix.to_usize()]
+    }
+}
+
+impl<'graph, V, Ix: IndexType> std::ops::IndexMut<NodeIx<Ix>> for VecNodeMap<'graph, V, Ix> {
+    fn index_mut(&mut self, NodeIx(ix): NodeIx<Ix>) -> &mut Self::Output {
+        &mut self.data[ix.to_usize()]
+    }
+}
+
+impl<'graph, V, Ix> IntoIterator for VecNodeMap<'graph, V, Ix> {
+    type Item = V;
+    type IntoIter = std::vec::IntoIter<V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'graph, V, Ix: IndexType> Mapping<NodeIx<Ix>, V> for VecNodeMap<'graph, V, Ix> {
+    fn map<VV>(self, f: impl FnMut(V) -> VV) -> impl Mapping<NodeIx<Ix>, VV> {
+        VecNodeMap {
+            _graph: self._graph,
+            _ix: self._ix,
+            data: self.data.into_iter().map(f).collect(),
         }
+    }
 
-        impl<'graph, V> Mapping<NodeIx, V> for VecNodeMap<'graph, V> {
-            fn map<VV>(self, f: impl FnMut(V) -> VV) -> impl Mapping<NodeIx, VV> {
-                VecNodeMap {
-                    _graph: self._graph,
-                    data: self.data.into_iter().map(f).collect(),
-                }
-            }
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a V>
+    where
+        V: 'a,
+    {
+        self.data.iter()
+    }
 
-            fn iter<'a>(&'a self) -> impl Iterator<Item = &'a V>
-            where
-                V: 'a,
-            {
-                self.data.iter()
-            }
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut V>
+    where
+        V: 'a,
+    {
+        self.data.iter_mut()
+    }
 
-            fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut V>
-            where
-                V: 'a,
-            {
-                self.data.iter_mut()
-            }
+    fn keys(&self) -> impl Iterator<Item = NodeIx<Ix>> {
+        (0..self.data.len()).map(NodeIx::new)
+    }
 
-            unsafe fn get_unchecked(&self, NodeIx(ix): NodeIx) -> &V {
-                self.data.get_unchecked(ix as usize)
-            }
+    fn pairs<'a>(&'a self) -> impl Iterator<Item = (NodeIx<Ix>, &'a V)>
+    where
+        V: 'a,
+    {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(i, value)| (NodeIx::new(i), value))
+    }
 
-            unsafe fn get_unchecked_mut(&mut self, NodeIx(ix): NodeIx) -> &mut V {
-                self.data.get_unchecked_mut(ix as usize)
-            }
+    fn into_pairs(self) -> impl Iterator<Item = (NodeIx<Ix>, V)> {
+        self.data
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| (NodeIx::new(i), value))
+    }
+
+    unsafe fn get_unchecked(&self, NodeIx(ix): NodeIx<Ix>) -> &V {
+        self.data.get_unchecked(ix.to_usize())
+    }
+
+    unsafe fn get_unchecked_mut(&mut self, NodeIx(ix): NodeIx<Ix>) -> &mut V {
+        self.data.get_unchecked_mut(ix.to_usize())
+    }
+}
+
+impl<N, E, Ix: IndexType> crate::graph::Graph for VecGraph<N, E, Ix> {
+    type NodeIx = NodeIx<Ix>;
+    type EdgeIx = EdgeIx<Ix>;
+    type Node = N;
+    type Edge = E;
+
+    fn exists_node_index(&self, NodeIx(ix): Self::NodeIx) -> bool {
+        ix.to_usize() < self.nodes.len()
+    }
+
+    fn exists_edge_index(&self, EdgeIx(ix): Self::EdgeIx) -> bool {
+        ix.to_usize() < self.edges.len()
+    }
+
+    unsafe fn node_unchecked(&self, NodeIx(ix): Self::NodeIx) -> &Self::Node {
+        debug_assert!(ix.to_usize() < self.nodes.len());
+        &self.nodes.get_unchecked(ix.to_usize()).data
+    }
+
+    unsafe fn edge_unchecked(&self, EdgeIx(ix): Self::EdgeIx) -> &Self::Edge {
+        debug_assert!(ix.to_usize() < self.edges.len());
+        &self.edges.get_unchecked(ix.to_usize()).data
+    }
+
+    fn node_indices(&self) -> impl Iterator<Item = Self::NodeIx> {
+        (0..self.nodes.len()).map(NodeIx::new)
+    }
+
+    fn edge_indices(&self) -> impl Iterator<Item = Self::EdgeIx> {
+        (0..self.edges.len()).map(EdgeIx::new)
+    }
+
+    fn len_nodes(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn len_edges(&self) -> usize {
+        self.edges.len()
+    }
+
+    fn memory_footprint(&self) -> GraphMemory {
+        GraphMemory {
+            nodes_bytes: self.nodes.capacity() * core::mem::size_of::<NodeRepr<N, Ix>>(),
+            edges_bytes: self.edges.capacity() * core::mem::size_of::<EdgeRepr<E, Ix>>(),
+            nodes_capacity: self.nodes.capacity(),
+            edges_capacity: self.edges.capacity(),
+            len_nodes: self.nodes.len(),
+            len_edges: self.edges.len(),
         }
+    }
+
+    fn index_generation(&self) -> Option<u64> {
+        Some(self.generation)
+    }
+
+    unsafe fn outgoing_edge_indices_unchecked(
+        &self,
+        node: Self::NodeIx,
+    ) -> impl Iterator<Item = Self::EdgeIx> {
+        impl_get_edges::<false, N, E, Ix>(self, node)
+    }
+
+    unsafe fn incoming_edge_indices_unchecked(
+        &self,
+        node: Self::NodeIx,
+    ) -> impl Iterator<Item = Self::EdgeIx> {
+        impl_get_edges::<true, N, E, Ix>(self, node)
+    }
+
+    unsafe fn endpoints_unchecked(&self, EdgeIx(edge): Self::EdgeIx) -> [Self::NodeIx; 2] {
+        debug_assert!(edge.to_usize() < self.edges.len());
+        let edge_repr = self.edges.get_unchecked(edge.to_usize());
+        edge_repr.node
+    }
+
+    unsafe fn outgoing_edge_pairs_unchecked(
+        &self,
+        node: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge)> {
+        impl_get_edges::<false, N, E, Ix>(self, node)
+            .map(move |edge_ix| (edge_ix, unsafe { self.edge_unchecked(edge_ix) }))
+    }
+
+    unsafe fn incoming_edge_pairs_unchecked(
+        &self,
+        node: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge)> {
+        impl_get_edges::<true, N, E, Ix>(self, node)
+            .map(move |edge_ix| (edge_ix, unsafe { self.edge_unchecked(edge_ix) }))
+    }
+
+    fn outgoing_targets(
+        &self,
+        node: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge, Self::NodeIx, &Self::Node)> {
+        assert!(
+            self.exists_node_index(node),
+            "Node index {:?} does not exist",
+            node
+        );
+        // The edge repr holds both its data and its target, so one lookup per edge does
+        unsafe { impl_get_edges::<false, N, E, Ix>(self, node) }.map(move |ix| {
+            let edge = unsafe { self.edges.get_unchecked(ix.0.to_usize()) };
+            let to = edge.node[1];
+            let target = unsafe { self.nodes.get_unchecked(to.0.to_usize()) };
+            (ix, &edge.data, to, &target.data)
+        })
+    }
+
+    fn incoming_sources(
+        &self,
+        node: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &Self::Edge, Self::NodeIx, &Self::Node)> {
+        assert!(
+            self.exists_node_index(node),
+            "Node index {:?} does not exist",
+            node
+        );
+        unsafe { impl_get_edges::<true, N, E, Ix>(self, node) }.map(move |ix| {
+            let edge = unsafe { self.edges.get_unchecked(ix.0.to_usize()) };
+            let from = edge.node[0];
+            let source = unsafe { self.nodes.get_unchecked(from.0.to_usize()) };
+            (ix, &edge.data, from, &source.data)
+        })
+    }
+
+    fn for_each_outgoing(&self, mut f: impl FnMut(Self::NodeIx, Self::EdgeIx, &Self::Edge)) {
+        for (ix, edge) in self.edges.iter().enumerate() {
+            f(edge.node[0], EdgeIx::new(ix), &edge.data);
+        }
+    }
 
+    fn fold_edges<B>(
+        &self,
+        init: B,
+        mut f: impl FnMut(B, Self::NodeIx, Self::EdgeIx, &Self::Edge) -> B,
+    ) -> B {
+        self.edges.iter().enumerate().fold(init, |acc, (ix, edge)| {
+            f(acc, edge.node[0], EdgeIx::new(ix), &edge.data)
+        })
+    }
+
+    fn init_node_map<V>(
+        &self,
+        mut f: impl FnMut(Self::NodeIx, &Self::Node) -> V,
+    ) -> impl Mapping<Self::NodeIx, V> {
         use core::marker::PhantomData;
         let data = self
             .nodes
             .iter()
             .enumerate()
-            .map(|(i, node)| f(NodeIx(i as u32), &node.data))
+            .map(|(i, node)| f(NodeIx::new(i), &node.data))
             .collect();
         VecNodeMap {
             _graph: PhantomData,
+            _ix: PhantomData,
             data,
         }
     }
@@ -382,26 +2117,27 @@ impl<N, E> crate::graph::Graph for VecGraph<N, E> {
     ) -> impl Mapping<Self::EdgeIx, V> {
         #[derive(Debug)]
         #[allow(dead_code)]
-        pub struct VecEdgeMap<'graph, V> {
+        pub struct VecEdgeMap<'graph, V, Ix> {
             _graph: crate::Invariant<'graph>,
+            _ix: PhantomData<Ix>,
             data: Vec<V>,
         }
 
-        impl<'graph, V> std::ops::Index<EdgeIx> for VecEdgeMap<'graph, V> {
+        impl<'graph, V, Ix: IndexType> std::ops::Index<EdgeIx<Ix>> for VecEdgeMap<'graph, V, Ix> {
             type Output = V;
 
-            fn index(&self, EdgeIx(ix): EdgeIx) -> &Self::Output {
-                &self.data[ix as usize]
+            fn index(&self, EdgeIx(ix): EdgeIx<Ix>) -> &Self::Output {
+                &self.data[ix.to_usize()]
             }
         }
 
-        impl<'graph, V> std::ops::IndexMut<EdgeIx> for VecEdgeMap<'graph, V> {
-            fn index_mut(&mut self, EdgeIx(ix): EdgeIx) -> &mut Self::Output {
-                &mut self.data[ix as usize]
+        impl<'graph, V, Ix: IndexType> std::ops::IndexMut<EdgeIx<Ix>> for VecEdgeMap<'graph, V, Ix> {
+            fn index_mut(&mut self, EdgeIx(ix): EdgeIx<Ix>) -> &mut Self::Output {
+                &mut self.data[ix.to_usize()]
             }
         }
 
-        impl<'graph, V> IntoIterator for VecEdgeMap<'graph, V> {
+        impl<'graph, V, Ix> IntoIterator for VecEdgeMap<'graph, V, Ix> {
             type Item = V;
             type IntoIter = std::vec::IntoIter<V>;
 
@@ -410,10 +2146,11 @@ impl<N, E> crate::graph::Graph for VecGraph<N, E> {
             }
         }
 
-        impl<'graph, V> Mapping<EdgeIx, V> for VecEdgeMap<'graph, V> {
-            fn map<VV>(self, f: impl FnMut(V) -> VV) -> impl Mapping<EdgeIx, VV> {
+        impl<'graph, V, Ix: IndexType> Mapping<EdgeIx<Ix>, V> for VecEdgeMap<'graph, V, Ix> {
+            fn map<VV>(self, f: impl FnMut(V) -> VV) -> impl Mapping<EdgeIx<Ix>, VV> {
                 VecEdgeMap {
                     _graph: self._graph,
+                    _ix: self._ix,
                     data: self.data.into_iter().map(f).collect(),
                 }
             }
@@ -432,12 +2169,33 @@ impl<N, E> crate::graph::Graph for VecGraph<N, E> {
                 self.data.iter_mut()
             }
 
-            unsafe fn get_unchecked(&self, EdgeIx(ix): EdgeIx) -> &V {
-                self.data.get_unchecked(ix as usize)
+            fn keys(&self) -> impl Iterator<Item = EdgeIx<Ix>> {
+                (0..self.data.len()).map(EdgeIx::new)
+            }
+
+            fn pairs<'a>(&'a self) -> impl Iterator<Item = (EdgeIx<Ix>, &'a V)>
+            where
+                V: 'a,
+            {
+                self.data
+                    .iter()
+                    .enumerate()
+                    .map(|(i, value)| (EdgeIx::new(i), value))
+            }
+
+            fn into_pairs(self) -> impl Iterator<Item = (EdgeIx<Ix>, V)> {
+                self.data
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, value)| (EdgeIx::new(i), value))
+            }
+
+            unsafe fn get_unchecked(&self, EdgeIx(ix): EdgeIx<Ix>) -> &V {
+                self.data.get_unchecked(ix.to_usize())
             }
 
-            unsafe fn get_unchecked_mut(&mut self, EdgeIx(ix): EdgeIx) -> &mut V {
-                self.data.get_unchecked_mut(ix as usize)
+            unsafe fn get_unchecked_mut(&mut self, EdgeIx(ix): EdgeIx<Ix>) -> &mut V {
+                self.data.get_unchecked_mut(ix.to_usize())
             }
         }
 
@@ -446,38 +2204,223 @@ impl<N, E> crate::graph::Graph for VecGraph<N, E> {
             .edges
             .iter()
             .enumerate()
-            .map(|(i, edge)| f(EdgeIx(i as u32), &edge.data))
+            .map(|(i, edge)| f(EdgeIx::new(i), &edge.data))
             .collect();
         VecEdgeMap {
             _graph: PhantomData,
+            _ix: PhantomData,
             data,
         }
     }
+}
+
+impl<N, E, Ix: IndexType> GraphMut for VecGraph<N, E, Ix> {
+    unsafe fn node_unchecked_mut(&mut self, NodeIx(ix): Self::NodeIx) -> &mut Self::Node {
+        debug_assert!(ix.to_usize() < self.nodes.len());
+        &mut self.nodes.get_unchecked_mut(ix.to_usize()).data
+    }
+
+    unsafe fn edge_unchecked_mut(&mut self, EdgeIx(ix): Self::EdgeIx) -> &mut Self::Edge {
+        debug_assert!(ix.to_usize() < self.edges.len());
+        &mut self.edges.get_unchecked_mut(ix.to_usize()).data
+    }
+
+    fn get_node_disjoint_mut<const K: usize>(
+        &mut self,
+        ixs: [Self::NodeIx; K],
+    ) -> Option<[&mut Self::Node; K]> {
+        get_disjoint_mut(&mut self.nodes, ixs.map(NodeIx::index))
+            .map(|reprs| reprs.map(|repr| &mut repr.data))
+    }
+
+    fn get_edge_disjoint_mut<const K: usize>(
+        &mut self,
+        ixs: [Self::EdgeIx; K],
+    ) -> Option<[&mut Self::Edge; K]> {
+        get_disjoint_mut(&mut self.edges, ixs.map(EdgeIx::index))
+            .map(|reprs| reprs.map(|repr| &mut repr.data))
+    }
+
+    unsafe fn outgoing_edge_pairs_unchecked_mut(
+        &mut self,
+        node: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
+    where
+        Self: Sized,
+    {
+        struct OutgoingEdgePairsMutIterUnchecked<'a, N, E, Ix> {
+            graph: &'a mut VecGraph<N, E, Ix>,
+            indices: std::vec::IntoIter<EdgeIx<Ix>>,
+        }
+
+        impl<'a, N, E, Ix: IndexType> Iterator for OutgoingEdgePairsMutIterUnchecked<'a, N, E, Ix> {
+            type Item = (EdgeIx<Ix>, &'a mut E);
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.indices.next().map(|ix| unsafe {
+                    let ptr = self.graph.edge_unchecked_mut(ix) as *mut E;
+                    (ix, &mut *ptr)
+                })
+            }
+        }
+
+        let indices: Vec<_> = unsafe { impl_get_edges::<false, N, E, Ix>(self, node) }.collect();
+        OutgoingEdgePairsMutIterUnchecked {
+            graph: self,
+            indices: indices.into_iter(),
+        }
+    }
+
+    unsafe fn incoming_edge_pairs_unchecked_mut(
+        &mut self,
+        node: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
+    where
+        Self: Sized,
+    {
+        struct IncomingEdgePairsMutIterUnchecked<'a, N, E, Ix> {
+            graph: &'a mut VecGraph<N, E, Ix>,
+            indices: std::vec::IntoIter<EdgeIx<Ix>>,
+        }
+
+        impl<'a, N, E, Ix: IndexType> Iterator for IncomingEdgePairsMutIterUnchecked<'a, N, E, Ix> {
+            type Item = (EdgeIx<Ix>, &'a mut E);
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.indices.next().map(|ix| unsafe {
+                    let ptr = self.graph.edge_unchecked_mut(ix) as *mut E;
+                    (ix, &mut *ptr)
+                })
+            }
+        }
+
+        let indices: Vec<_> = unsafe { impl_get_edges::<true, N, E, Ix>(self, node) }.collect();
+        IncomingEdgePairsMutIterUnchecked {
+            graph: self,
+            indices: indices.into_iter(),
+        }
+    }
+
+    unsafe fn connecting_edge_pairs_unchecked_mut(
+        &mut self,
+        node: Self::NodeIx,
+    ) -> impl Iterator<Item = (Self::EdgeIx, &mut Self::Edge)>
+    where
+        Self: Sized,
+    {
+        struct ConnectingEdgePairsMutIterUnchecked<'a, N, E, Ix> {
+            graph: &'a mut VecGraph<N, E, Ix>,
+            indices: std::vec::IntoIter<EdgeIx<Ix>>,
+        }
+
+        impl<'a, N, E, Ix: IndexType> Iterator for ConnectingEdgePairsMutIterUnchecked<'a, N, E, Ix> {
+            type Item = (EdgeIx<Ix>, &'a mut E);
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.indices.next().map(|ix| unsafe {
+                    let ptr = self.graph.edge_unchecked_mut(ix) as *mut E;
+                    (ix, &mut *ptr)
+                })
+            }
+        }
+
+        let outgoing_indices: Vec<_> =
+            unsafe { impl_get_edges::<false, N, E, Ix>(self, node) }.collect();
+        let incoming_indices: Vec<_> =
+            unsafe { impl_get_edges::<true, N, E, Ix>(self, node) }.collect();
+        let indices: Vec<_> = outgoing_indices
+            .into_iter()
+            .chain(incoming_indices)
+            .collect();
+        ConnectingEdgePairsMutIterUnchecked {
+            graph: self,
+            indices: indices.into_iter(),
+        }
+    }
 
     unsafe fn reverse_edge_unchecked(&mut self, EdgeIx(edge_ix): Self::EdgeIx, new_from: Self::NodeIx, new_to: Self::NodeIx)
     where
         Self: Sized,
     {
-        debug_assert!((edge_ix as usize) < self.edges.len());
-        self.edges.get_unchecked_mut(edge_ix as usize).node = [new_from, new_to];
+        let ix = EdgeIx(edge_ix);
+        debug_assert!(edge_ix.to_usize() < self.edges.len());
+        let edge_repr = self.edges.get_unchecked(edge_ix.to_usize());
+        let [old_from, old_to] = edge_repr.node;
+        let [next_out, next_in] = edge_repr.next;
+
+        // Unlink from the lists of the old endpoints, then prepend to the new ones
+        self.replace_link(old_from, 0, ix, next_out);
+        self.replace_link(old_to, 1, ix, next_in);
+        debug_assert!(new_from.0.to_usize() < self.nodes.len());
+        debug_assert!(new_to.0.to_usize() < self.nodes.len());
+        let head_out = core::mem::replace(
+            &mut self.nodes.get_unchecked_mut(new_from.0.to_usize()).next[0],
+            ix,
+        );
+        let head_in = core::mem::replace(
+            &mut self.nodes.get_unchecked_mut(new_to.0.to_usize()).next[1],
+            ix,
+        );
+        let edge_repr = self.edges.get_unchecked_mut(edge_ix.to_usize());
+        edge_repr.node = [new_from, new_to];
+        edge_repr.next = [head_out, head_in];
+
+        #[cfg(debug_assertions)]
+        self.debug_validate_links(&[old_from, old_to, new_from, new_to]);
     }
 }
 
-impl<N, E> GraphUpdate for VecGraph<N, E> {
+// `Ix::max()` is reserved for the end-of-list sentinel, so indices go up to `Ix::max() - 1`
+impl<N, E, Ix: IndexType> IndexConversion for VecGraph<N, E, Ix> {
+    fn node_to_usize(ix: NodeIx<Ix>) -> usize {
+        ix.index()
+    }
+
+    fn node_from_usize(ix: usize) -> Option<NodeIx<Ix>> {
+        index_from_usize(ix).ok().map(NodeIx)
+    }
+
+    fn edge_to_usize(ix: EdgeIx<Ix>) -> usize {
+        ix.index()
+    }
+
+    fn edge_from_usize(ix: usize) -> Option<EdgeIx<Ix>> {
+        index_from_usize(ix).ok().map(EdgeIx)
+    }
+}
+
+// Hands `payload` back as an error if a container of length `len` is already full
+fn check_capacity<T>(len: usize, limit: usize, payload: T) -> Result<T, CapacityError<T>> {
+    if len >= limit {
+        Err(CapacityError::new(payload))
+    } else {
+        Ok(payload)
+    }
+}
+
+impl<N, E, Ix: IndexType> GraphUpdate for VecGraph<N, E, Ix> {
     fn add_node(&mut self, node: Self::Node) -> Self::NodeIx {
-        if self.nodes.len() == u32::MAX as usize {
-            panic!(
+        match self.try_add_node(node) {
+            Ok(ix) => ix,
+            Err(_) => panic!(
                 "Cannot add more nodes: maximum capacity ({}) reached",
-                u32::MAX
-            );
+                max_len::<Ix>()
+            ),
         }
-        let ix = NodeIx(self.nodes.len() as u32);
+    }
+
+    fn try_add_node(
+        &mut self,
+        node: Self::Node,
+    ) -> Result<Self::NodeIx, CapacityError<Self::Node>> {
+        let node = check_capacity(self.nodes.len(), max_len::<Ix>(), node)?;
+        let ix = NodeIx::new(self.nodes.len());
         debug_assert!(!ix.is_end());
         self.nodes.push(NodeRepr {
             data: node,
             next: [EdgeIx::end(), EdgeIx::end()],
         });
-        ix
+        Ok(ix)
     }
 
     fn add_edge(&mut self, edge: Self::Edge, from: Self::NodeIx, to: Self::NodeIx) -> Self::EdgeIx {
@@ -494,41 +2437,109 @@ impl<N, E> GraphUpdate for VecGraph<N, E> {
         unsafe { self.add_edge_unchecked(edge, from, to) }
     }
 
-    unsafe fn add_edge_unchecked(
+    fn try_add_edge(
         &mut self,
         edge: Self::Edge,
-        n_from: Self::NodeIx,
-        n_to: Self::NodeIx,
+        from: Self::NodeIx,
+        to: Self::NodeIx,
+    ) -> Result<Self::EdgeIx, CapacityError<Self::Edge>> {
+        assert!(
+            self.exists_node_index(from),
+            "Node index {:?} does not exist",
+            from
+        );
+        assert!(
+            self.exists_node_index(to),
+            "Node index {:?} does not exist",
+            to
+        );
+        let edge = check_capacity(self.edges.len(), max_len::<Ix>(), edge)?;
+        Ok(unsafe { self.add_edge_unchecked(edge, from, to) })
+    }
+
+    fn add_node_with(&mut self, f: impl FnOnce(Self::NodeIx) -> Self::Node) -> Self::NodeIx {
+        if check_capacity(self.nodes.len(), max_len::<Ix>(), ()).is_err() {
+            panic!(
+                "Cannot add more nodes: maximum capacity ({}) reached",
+                max_len::<Ix>()
+            );
+        }
+        let ix = NodeIx::new(self.nodes.len());
+        self.nodes.push(NodeRepr {
+            data: f(ix),
+            next: [EdgeIx::end(), EdgeIx::end()],
+        });
+        ix
+    }
+
+    fn add_nodes<I: IntoIterator<Item = Self::Node>>(&mut self, nodes: I) -> Vec<Self::NodeIx> {
+        let nodes = nodes.into_iter();
+        self.nodes.reserve(nodes.size_hint().0);
+        nodes.map(|node| self.add_node(node)).collect()
+    }
+
+    fn add_edge_with(
+        &mut self,
+        from: Self::NodeIx,
+        to: Self::NodeIx,
+        f: impl FnOnce(Self::EdgeIx) -> Self::Edge,
     ) -> Self::EdgeIx {
-        if self.edges.len() == u32::MAX as usize {
+        assert!(
+            self.exists_node_index(from),
+            "Node index {:?} does not exist",
+            from
+        );
+        assert!(
+            self.exists_node_index(to),
+            "Node index {:?} does not exist",
+            to
+        );
+        if check_capacity(self.edges.len(), max_len::<Ix>(), ()).is_err() {
             panic!(
                 "Cannot add more edges: maximum capacity ({}) reached",
-                u32::MAX
+                max_len::<Ix>()
             );
         }
-        let ix = EdgeIx(self.edges.len() as u32);
+        let edge = f(EdgeIx::new(self.edges.len()));
+        unsafe { self.add_edge_unchecked(edge, from, to) }
+    }
+
+    unsafe fn add_edge_unchecked(
+        &mut self,
+        edge: Self::Edge,
+        n_from: Self::NodeIx,
+        n_to: Self::NodeIx,
+    ) -> Self::EdgeIx {
+        let edge = match check_capacity(self.edges.len(), max_len::<Ix>(), edge) {
+            Ok(edge) => edge,
+            Err(_) => panic!(
+                "Cannot add more edges: maximum capacity ({}) reached",
+                max_len::<Ix>()
+            ),
+        };
+        let ix = EdgeIx::new(self.edges.len());
         debug_assert!(!ix.is_end());
-        let next = match (n_from.0 as usize).cmp(&(n_to.0 as usize)) {
+        let next = match n_from.0.to_usize().cmp(&n_to.0.to_usize()) {
             core::cmp::Ordering::Equal => {
-                debug_assert!((n_from.0 as usize) < self.nodes.len());
-                let n = self.nodes.get_unchecked_mut(n_from.0 as usize);
+                debug_assert!(n_from.0.to_usize() < self.nodes.len());
+                let n = self.nodes.get_unchecked_mut(n_from.0.to_usize());
                 core::mem::replace(&mut n.next, [ix, ix])
             }
             o => {
                 let (v_from, v_to) = if o == core::cmp::Ordering::Greater {
-                    debug_assert!((n_from.0 as usize) < self.nodes.len());
-                    debug_assert!((n_to.0 as usize) < (n_from.0 as usize));
-                    let (ns1, ns2) = self.nodes.split_at_mut_unchecked(n_from.0 as usize);
+                    debug_assert!(n_from.0.to_usize() < self.nodes.len());
+                    debug_assert!(n_to.0.to_usize() < n_from.0.to_usize());
+                    let (ns1, ns2) = self.nodes.split_at_mut_unchecked(n_from.0.to_usize());
                     (
                         ns2.get_unchecked_mut(0),
-                        ns1.get_unchecked_mut(n_to.0 as usize),
+                        ns1.get_unchecked_mut(n_to.0.to_usize()),
                     )
                 } else {
-                    debug_assert!((n_to.0 as usize) < self.nodes.len());
-                    debug_assert!((n_from.0 as usize) < (n_to.0 as usize));
-                    let (ns1, ns2) = self.nodes.split_at_mut_unchecked(n_to.0 as usize);
+                    debug_assert!(n_to.0.to_usize() < self.nodes.len());
+                    debug_assert!(n_from.0.to_usize() < n_to.0.to_usize());
+                    let (ns1, ns2) = self.nodes.split_at_mut_unchecked(n_to.0.to_usize());
                     (
-                        ns1.get_unchecked_mut(n_from.0 as usize),
+                        ns1.get_unchecked_mut(n_from.0.to_usize()),
                         ns2.get_unchecked_mut(0),
                     )
                 };
@@ -547,78 +2558,150 @@ impl<N, E> GraphUpdate for VecGraph<N, E> {
     }
 }
 
-impl<N, E> GraphRemoveEdge for VecGraph<N, E> {
+impl<N, E, Ix: IndexType> VecGraph<N, E, Ix> {
+    /// Replaces the link to `old` in the outgoing (`dir == 0`) or incoming (`dir == 1`)
+    /// edge list of `node` with `new`.
+    ///
+    /// Only the chain of `node` is walked, so this is O(degree). The chain is not
+    /// followed past `old`, which therefore does not need to be a valid position.
+    ///
+    /// # Safety
+    ///
+    /// `node` must exist and `old` must be in its `dir` edge list.
+    unsafe fn replace_link(
+        &mut self,
+        NodeIx(node): NodeIx<Ix>,
+        dir: usize,
+        old: EdgeIx<Ix>,
+        new: EdgeIx<Ix>,
+    ) {
+        debug_assert!(node.to_usize() < self.nodes.len());
+        let head = unsafe { &mut self.nodes.get_unchecked_mut(node.to_usize()).next[dir] };
+        if *head == old {
+            *head = new;
+            return;
+        }
+        let mut current = *head;
+        loop {
+            debug_assert!(
+                !current.is_end(),
+                "edge is not in the adjacency list of its endpoint"
+            );
+            debug_assert!(current.0.to_usize() < self.edges.len());
+            let current_edge = unsafe { self.edges.get_unchecked_mut(current.0.to_usize()) };
+            if current_edge.next[dir] == old {
+                current_edge.next[dir] = new;
+                return;
+            }
+            current = current_edge.next[dir];
+        }
+    }
+
+    /// Removes `node` with [`GraphRemove::remove_node`] and reports which node took its
+    /// place.
+    ///
+    /// Removal moves the last node into the freed slot; the returned index is the one that
+    /// node had before, or `None` if `node` was the last one and nothing moved.
+    pub(crate) fn remove_node_relocating(&mut self, node: NodeIx<Ix>) -> (N, Option<NodeIx<Ix>>) {
+        let data = self.remove_node(node);
+        let last = NodeIx::new(self.nodes.len());
+        (data, (node != last).then_some(last))
+    }
+
+    /// Removes `nodes` and `edges` with [`GraphRemove::remove_nodes_edges_unchecked`] and
+    /// reports where the remaining nodes ended up.
+    ///
+    /// The returned vector is indexed by the old node indices and holds the new index of
+    /// every remaining node, or `None` for the removed ones.
+    ///
+    /// # Safety
+    ///
+    /// All indices must exist in the graph.
+    pub(crate) unsafe fn remove_nodes_edges_remapping(
+        &mut self,
+        nodes: &[NodeIx<Ix>],
+        edges: &[EdgeIx<Ix>],
+    ) -> (Vec<N>, Vec<E>, Vec<Option<NodeIx<Ix>>>) {
+        // Batched removal compacts the nodes with `swap_remove`, whose result only depends
+        // on which nodes are removed, so running it on the flags alone predicts the moves
+        let mut del_ord: Vec<_> = (0..self.nodes.len()).map(|i| (false, i)).collect();
+        for ix in nodes {
+            del_ord[ix.index()].0 = true;
+        }
+        let removed: Vec<_> = del_ord.iter().map(|&(deleted, _)| deleted).collect();
+        swap_remove(&mut del_ord, |_, _| {});
+        let remap = removed
+            .into_iter()
+            .zip(del_ord)
+            .map(|(deleted, (_, new))| (!deleted).then(|| NodeIx::new(new)))
+            .collect();
+
+        let (removed_nodes, removed_edges) =
+            self.remove_nodes_edges_unchecked(nodes.iter().copied(), edges.iter().copied());
+        (removed_nodes, removed_edges, remap)
+    }
+
+    /// Checks that the outgoing and incoming edge lists of `nodes` only contain existing
+    /// edges attached to the right endpoint.
+    #[cfg(debug_assertions)]
+    fn debug_validate_links(&self, nodes: &[NodeIx<Ix>]) {
+        for &node in nodes {
+            for dir in 0..2 {
+                let mut current = self.nodes[node.0.to_usize()].next[dir];
+                while !current.is_end() {
+                    let edge = self.edges.get(current.0.to_usize());
+                    debug_assert!(
+                        edge.is_some(),
+                        "dangling edge {:?} in adjacency list",
+                        current
+                    );
+                    let edge = edge.unwrap();
+                    debug_assert_eq!(
+                        edge.node[dir], node,
+                        "edge {:?} in wrong adjacency list",
+                        current
+                    );
+                    current = edge.next[dir];
+                }
+            }
+        }
+    }
+}
+
+impl<N, E, Ix: IndexType> GraphRemoveEdge for VecGraph<N, E, Ix> {
     unsafe fn remove_edge_unchecked(&mut self, EdgeIx(ix): Self::EdgeIx) -> Self::Edge {
-        let ix = ix as usize;
+        let ix = ix.to_usize();
         debug_assert!(ix < self.edges.len());
         let edge_repr = unsafe { self.edges.get_unchecked(ix) };
         let [from_node, to_node] = edge_repr.node;
         let [next_out, next_in] = edge_repr.next;
 
-        // Remove from outgoing edge list of from_node
-        debug_assert!((from_node.0 as usize) < self.nodes.len());
-        if unsafe { self.nodes.get_unchecked(from_node.0 as usize).next[0] } == EdgeIx(ix as u32) {
-            unsafe { self.nodes.get_unchecked_mut(from_node.0 as usize).next[0] = next_out };
-        } else {
-            let mut current = unsafe { self.nodes.get_unchecked(from_node.0 as usize).next[0] };
-            while !current.is_end() {
-                debug_assert!((current.0 as usize) < self.edges.len());
-                let current_edge = unsafe { self.edges.get_unchecked_mut(current.0 as usize) };
-                if current_edge.next[0] == EdgeIx(ix as u32) {
-                    current_edge.next[0] = next_out;
-                    break;
-                }
-                current = current_edge.next[0];
-            }
-        }
-
-        // Remove from incoming edge list of to_node
-        debug_assert!((to_node.0 as usize) < self.nodes.len());
-        if unsafe { self.nodes.get_unchecked(to_node.0 as usize).next[1] } == EdgeIx(ix as u32) {
-            unsafe { self.nodes.get_unchecked_mut(to_node.0 as usize).next[1] = next_in };
-        } else {
-            let mut current = unsafe { self.nodes.get_unchecked(to_node.0 as usize).next[1] };
-            while !current.is_end() {
-                debug_assert!((current.0 as usize) < self.edges.len());
-                let current_edge = unsafe { self.edges.get_unchecked_mut(current.0 as usize) };
-                if current_edge.next[1] == EdgeIx(ix as u32) {
-                    current_edge.next[1] = next_in;
-                    break;
-                }
-                current = current_edge.next[1];
-            }
+        // Unlink from the outgoing list of from_node and the incoming list of to_node
+        unsafe {
+            self.replace_link(from_node, 0, EdgeIx::new(ix), next_out);
+            self.replace_link(to_node, 1, EdgeIx::new(ix), next_in);
         }
 
+        self.generation += 1;
         let edge_data = self.edges.swap_remove(ix).data;
 
-        // Update edge indices after swap_remove
+        // The last edge was moved into `ix`; only the lists of its endpoints refer to it
         if ix < self.edges.len() {
-            let moved_edge_ix = EdgeIx(self.edges.len() as u32);
-
-            // Update in node adjacency lists
-            for node in &mut self.nodes {
-                for next_edge in &mut node.next {
-                    if *next_edge == moved_edge_ix {
-                        *next_edge = EdgeIx(ix as u32);
-                    }
-                }
-            }
-
-            // Update in edge next pointers
-            for edge in &mut self.edges {
-                for next_edge in &mut edge.next {
-                    if *next_edge == moved_edge_ix {
-                        *next_edge = EdgeIx(ix as u32);
-                    }
-                }
+            let moved_edge_ix = EdgeIx::new(self.edges.len());
+            let [moved_from, moved_to] = unsafe { self.edges.get_unchecked(ix).node };
+            unsafe {
+                self.replace_link(moved_from, 0, moved_edge_ix, EdgeIx::new(ix));
+                self.replace_link(moved_to, 1, moved_edge_ix, EdgeIx::new(ix));
             }
+            #[cfg(debug_assertions)]
+            self.debug_validate_links(&[from_node, to_node, moved_from, moved_to]);
         }
 
         edge_data
     }
 }
 
-impl<N, E> GraphRemove for VecGraph<N, E> {
+impl<N, E, Ix: IndexType> GraphRemove for VecGraph<N, E, Ix> {
     unsafe fn remove_nodes_edges_unchecked<CN, CE>(
         &mut self,
         del_nodes: impl IntoIterator<Item = Self::NodeIx>,
@@ -630,15 +2713,17 @@ impl<N, E> GraphRemove for VecGraph<N, E> {
         Self: Sized,
     {
         use core::mem::MaybeUninit;
+        self.generation += 1;
         let (mut cn, mut ce): (CN, CE) = Default::default();
         let mut del_ord_edge = (0..self.edges.len())
             .map(|i| (false, i))
             .collect::<Vec<_>>();
-        let edges = core::mem::transmute::<&mut Vec<EdgeRepr<E>>, &mut Vec<MaybeUninit<EdgeRepr<E>>>>(
-            &mut self.edges,
-        );
+        let edges = core::mem::transmute::<
+            &mut Vec<EdgeRepr<E, Ix>>,
+            &mut Vec<MaybeUninit<EdgeRepr<E, Ix>>>,
+        >(&mut self.edges);
         for EdgeIx(del_edge) in del_edges {
-            let del_edge = del_edge as usize;
+            let del_edge = del_edge.to_usize();
             debug_assert!(del_edge < del_ord_edge.len());
             let flag = unsafe { del_ord_edge.get_unchecked_mut(del_edge) };
             if !flag.0 {
@@ -652,24 +2737,25 @@ impl<N, E> GraphRemove for VecGraph<N, E> {
         let mut del_ord_node = (0..self.nodes.len())
             .map(|i| (false, i))
             .collect::<Vec<_>>();
-        let nodes = core::mem::transmute::<&mut Vec<NodeRepr<N>>, &mut Vec<MaybeUninit<NodeRepr<N>>>>(
-            &mut self.nodes,
-        );
+        let nodes = core::mem::transmute::<
+            &mut Vec<NodeRepr<N, Ix>>,
+            &mut Vec<MaybeUninit<NodeRepr<N, Ix>>>,
+        >(&mut self.nodes);
         for NodeIx(del_node) in del_nodes {
-            let del_node = del_node as usize;
+            let del_node = del_node.to_usize();
             debug_assert!(del_node < del_ord_node.len());
             let flag = unsafe { del_ord_node.get_unchecked_mut(del_node) };
             debug_assert!(del_node < nodes.len());
-            let node = unsafe { nodes.get_unchecked(del_node).assume_init_read() };
             if !flag.0 {
+                let node = unsafe { nodes.get_unchecked(del_node).assume_init_read() };
                 cn.extend(core::iter::once(node.data));
                 flag.0 = true;
             }
             for EdgeIx(edge) in
-                unsafe { impl_get_edges::<false, N, E>(self, NodeIx(del_node as u32)) }
-                    .chain(unsafe { impl_get_edges::<true, N, E>(self, NodeIx(del_node as u32)) })
+                unsafe { impl_get_edges::<false, N, E, Ix>(self, NodeIx::new(del_node)) }
+                    .chain(unsafe { impl_get_edges::<true, N, E, Ix>(self, NodeIx::new(del_node)) })
             {
-                let edge = edge as usize;
+                let edge = edge.to_usize();
                 debug_assert!(edge < del_ord_edge.len());
                 let flag = unsafe { del_ord_edge.get_unchecked_mut(edge) };
                 if !flag.0 {
@@ -681,24 +2767,40 @@ impl<N, E> GraphRemove for VecGraph<N, E> {
                 }
             }
         }
+        // Unlink the deleted edges from the lists of the remaining nodes, which would
+        // otherwise point to their old slots after compaction
+        for (edge, &(deleted, _)) in del_ord_edge.iter().enumerate() {
+            if !deleted {
+                continue;
+            }
+            debug_assert!(edge < self.edges.len());
+            let edge_repr = unsafe { self.edges.get_unchecked(edge) };
+            let (node, next) = (edge_repr.node, edge_repr.next);
+            for dir in 0..2 {
+                if !del_ord_node[node[dir].index()].0 {
+                    unsafe { self.replace_link(node[dir], dir, EdgeIx::new(edge), next[dir]) };
+                }
+            }
+        }
+
         let alive_edges = swap_remove(&mut del_ord_edge, |i, j| self.edges.swap(i, j));
         debug_assert!(alive_edges <= self.edges.len());
         unsafe { self.edges.set_len(alive_edges) };
         for edge in &mut self.edges {
             for edge_ix in &mut edge.next {
                 if !(*edge_ix).is_end() {
-                    debug_assert!((edge_ix.0 as usize) < del_ord_edge.len());
+                    debug_assert!(edge_ix.0.to_usize() < del_ord_edge.len());
                     *edge_ix =
-                        EdgeIx(unsafe { del_ord_edge.get_unchecked(edge_ix.0 as usize).1 as u32 });
+                        EdgeIx::new(unsafe { del_ord_edge.get_unchecked(edge_ix.0.to_usize()).1 });
                 }
             }
         }
         for node in &mut self.nodes {
             for edge_ix in &mut node.next {
                 if !(*edge_ix).is_end() {
-                    debug_assert!((edge_ix.0 as usize) < del_ord_edge.len());
+                    debug_assert!(edge_ix.0.to_usize() < del_ord_edge.len());
                     *edge_ix =
-                        EdgeIx(unsafe { del_ord_edge.get_unchecked(edge_ix.0 as usize).1 as u32 });
+                        EdgeIx::new(unsafe { del_ord_edge.get_unchecked(edge_ix.0.to_usize()).1 });
                 }
             }
         }
@@ -706,9 +2808,9 @@ impl<N, E> GraphRemove for VecGraph<N, E> {
         let alive_nodes = swap_remove(&mut del_ord_node, |i, j| self.nodes.swap(i, j));
         unsafe { self.nodes.set_len(alive_nodes) };
         for edge in &mut self.edges {
-            edge.node.iter_mut().for_each(|NodeIx(ix)| {
-                debug_assert!((*ix as usize) < del_ord_node.len());
-                *ix = unsafe { del_ord_node.get_unchecked(*ix as usize).1 as u32 };
+            edge.node.iter_mut().for_each(|ix| {
+                debug_assert!(ix.index() < del_ord_node.len());
+                *ix = NodeIx::new(unsafe { del_ord_node.get_unchecked(ix.index()).1 });
             });
         }
 
@@ -716,30 +2818,31 @@ impl<N, E> GraphRemove for VecGraph<N, E> {
     }
 
     unsafe fn remove_node_unchecked(&mut self, node_ix: Self::NodeIx) -> Self::Node {
-        // Collect all outgoing edges first
-        let outgoing_edges: Vec<_> = self.outgoing_edge_indices_unchecked(node_ix).collect();
-        for edge_ix in outgoing_edges {
-            self.remove_edge_unchecked(edge_ix);
-        }
-
-        // Collect all incoming edges
-        let incoming_edges: Vec<_> = self.incoming_edge_indices_unchecked(node_ix).collect();
-        for edge_ix in incoming_edges {
-            self.remove_edge_unchecked(edge_ix);
+        // Removing an edge moves the last edge into its slot, so indices collected up
+        // front may be stale; always remove the current head of each list instead
+        for dir in 0..2 {
+            loop {
+                let head = self.nodes.get_unchecked(node_ix.0.to_usize()).next[dir];
+                if head.is_end() {
+                    break;
+                }
+                self.remove_edge_unchecked(head);
+            }
         }
 
         // Remove the node
         let NodeIx(ix) = node_ix;
-        let ix = ix as usize;
+        let ix = ix.to_usize();
+        self.generation += 1;
         let node_data = self.nodes.swap_remove(ix).data;
 
         // Update node indices in edges after swap_remove
         if ix < self.nodes.len() {
-            let moved_node_ix = NodeIx(self.nodes.len() as u32);
+            let moved_node_ix = NodeIx::new(self.nodes.len());
             for edge in &mut self.edges {
                 for node_ref in &mut edge.node {
                     if *node_ref == moved_node_ix {
-                        *node_ref = NodeIx(ix as u32);
+                        *node_ref = NodeIx::new(ix);
                     }
                 }
             }
@@ -747,15 +2850,46 @@ impl<N, E> GraphRemove for VecGraph<N, E> {
 
         node_data
     }
+
+    fn merge_nodes(
+        &mut self,
+        keep: Self::NodeIx,
+        merge: impl IntoIterator<Item = Self::NodeIx>,
+    ) -> (Self::NodeIx, Vec<Self::Node>) {
+        let merge = crate::graph::remove::rewire_merged_edges(self, keep, merge);
+        // Removing the nodes in one batch is cheaper than one at a time
+        // SAFETY: the nodes exist, are distinct and no longer have edges
+        let (nodes, _, remap) = unsafe { self.remove_nodes_edges_remapping(&merge, &[]) };
+        (remap[keep.index()].expect("`keep` is not merged"), nodes)
+    }
+}
+
+/// Returns mutable references to the elements at `ixs`, or `None` if an index is out of
+/// bounds or appears more than once.
+///
+/// Splits the slice at the indices in ascending order, so no unsafe code is needed.
+fn get_disjoint_mut<T, const K: usize>(slice: &mut [T], ixs: [usize; K]) -> Option<[&mut T; K]> {
+    let mut order: [usize; K] = core::array::from_fn(|i| i);
+    order.sort_unstable_by_key(|&i| ixs[i]);
+    let mut found: [Option<&mut T>; K] = [(); K].map(|_| None);
+    let (mut rest, mut offset) = (slice, 0);
+    for i in order {
+        let (_, tail) = rest.split_at_mut(ixs[i].checked_sub(offset)?.min(rest.len()));
+        let (element, tail) = tail.split_first_mut()?;
+        found[i] = Some(element);
+        rest = tail;
+        offset = ixs[i] + 1;
+    }
+    Some(found.map(|element| element.expect("every index was split off")))
 }
 
 fn swap_remove(del_ord: &mut [(bool, usize)], mut cb: impl FnMut(usize, usize)) -> usize {
     const TO_REMOVE: bool = true;
-    let mut i = 0;
-    let mut j = del_ord.len() - 1;
-    if del_ord.len() == 0 {
+    if del_ord.is_empty() {
         return 0;
     }
+    let mut i = 0;
+    let mut j = del_ord.len() - 1;
 
     // SAFETY: in this loop, `0 <= i <= j < len` holds everywhere, so we have no need to check the
     // boundary.
@@ -814,16 +2948,18 @@ fn swap_remove(del_ord: &mut [(bool, usize)], mut cb: impl FnMut(usize, usize))
 }
 
 // SAFETY: the internal index of `node` is valid in `graph`
-unsafe fn impl_get_edges<const IS_INCOMING: bool, N, E>(
-    graph: &VecGraph<N, E>,
-    NodeIx(node): NodeIx,
-) -> impl Iterator<Item = EdgeIx> + use<'_, IS_INCOMING, N, E> {
-    struct Iter<'a, const IS_INCOMING: bool, N, E>(&'a VecGraph<N, E>, EdgeIx);
-    impl<'a, const IS_INCOMING: bool, N, E> Iterator for Iter<'a, IS_INCOMING, N, E> {
-        type Item = EdgeIx;
+unsafe fn impl_get_edges<const IS_INCOMING: bool, N, E, Ix: IndexType>(
+    graph: &VecGraph<N, E, Ix>,
+    NodeIx(node): NodeIx<Ix>,
+) -> impl Iterator<Item = EdgeIx<Ix>> + use<'_, IS_INCOMING, N, E, Ix> {
+    struct Iter<'a, const IS_INCOMING: bool, N, E, Ix>(&'a VecGraph<N, E, Ix>, EdgeIx<Ix>);
+    impl<'a, const IS_INCOMING: bool, N, E, Ix: IndexType> Iterator
+        for Iter<'a, IS_INCOMING, N, E, Ix>
+    {
+        type Item = EdgeIx<Ix>;
 
         fn next(&mut self) -> Option<Self::Item> {
-            if let Some(next_edge_repr) = self.0.edges.get(self.1 .0 as usize) {
+            if let Some(next_edge_repr) = self.0.edges.get(self.1 .0.to_usize()) {
                 let next = next_edge_repr.next[IS_INCOMING as usize];
                 let next_ix = core::mem::replace(&mut self.1, next);
                 Some(next_ix)
@@ -832,7 +2968,52 @@ unsafe fn impl_get_edges<const IS_INCOMING: bool, N, E>(
             }
         }
     }
-    debug_assert!((node as usize) < graph.nodes.len());
-    let node_repr = graph.nodes.get_unchecked(node as usize);
-    Iter::<'_, IS_INCOMING, N, E>(graph, node_repr.next[IS_INCOMING as usize])
+    debug_assert!(node.to_usize() < graph.nodes.len());
+    let node_repr = graph.nodes.get_unchecked(node.to_usize());
+    Iter::<'_, IS_INCOMING, N, E, Ix>(graph, node_repr.next[IS_INCOMING as usize])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_capacity, max_len, EdgeIx, NodeIx};
+
+    #[test]
+    fn node_ix_is_end() {
+        assert!(NodeIx::<u32>::end().is_end());
+        assert!(!NodeIx(0u32).is_end());
+        assert!(!NodeIx(1u32).is_end());
+        assert!(!NodeIx(u32::MAX - 1).is_end());
+    }
+
+    #[test]
+    fn edge_ix_is_end() {
+        assert!(EdgeIx::<u32>::end().is_end());
+        assert!(!EdgeIx(0u32).is_end());
+        assert!(!EdgeIx(1u32).is_end());
+        assert!(!EdgeIx(u32::MAX - 1).is_end());
+    }
+
+    #[test]
+    fn narrow_ix_is_end() {
+        assert!(NodeIx::<u16>::end().is_end());
+        assert!(!NodeIx(u16::MAX - 1).is_end());
+        assert!(EdgeIx::<u64>::end().is_end());
+        assert!(!EdgeIx(u64::from(u32::MAX)).is_end());
+    }
+
+    #[test]
+    fn check_capacity_returns_payload() {
+        assert_eq!(check_capacity(2, 3, "fits"), Ok("fits"));
+        let err = check_capacity(3, 3, String::from("full")).unwrap_err();
+        assert_eq!(err.into_inner(), "full");
+        assert!(check_capacity(4, 3, ()).is_err());
+    }
+
+    #[test]
+    fn check_capacity_at_u16_bound() {
+        let limit = max_len::<u16>();
+        assert_eq!(limit, 65535);
+        assert!(check_capacity(limit - 1, limit, ()).is_ok());
+        assert!(check_capacity(limit, limit, ()).is_err());
+    }
 }
@@ -0,0 +1,208 @@
+use gotgraph::prelude::*;
+use gotgraph::Mapping;
+
+fn sample() -> (
+    VecGraph<&'static str, u32>,
+    Vec<gotgraph::vec_graph::NodeIx>,
+) {
+    let mut graph = VecGraph::default();
+    let nodes: Vec<_> = ["A", "B", "C", "D"]
+        .iter()
+        .map(|&n| graph.add_node(n))
+        .collect();
+    let [a, b, c, d] = [nodes[0], nodes[1], nodes[2], nodes[3]];
+    graph.add_edge(1, a, b);
+    graph.add_edge(2, a, b); // parallel
+    graph.add_edge(3, b, c);
+    graph.add_edge(4, c, c); // self-loop
+    graph.add_edge(5, c, a);
+    graph.add_edge(6, d, b);
+    (graph, nodes)
+}
+
+#[test]
+fn test_to_adjacency_matrix_cells() {
+    let (graph, nodes) = sample();
+    let [a, b, c, d] = [nodes[0], nodes[1], nodes[2], nodes[3]];
+    let (matrix, rows) = graph.to_adjacency_matrix();
+
+    assert_eq!(matrix.len(), 4);
+    assert!(matrix.iter().all(|row| row.len() == 4));
+
+    assert!(matrix[rows[a]][rows[b]]);
+    assert!(!matrix[rows[b]][rows[a]]);
+    assert!(matrix[rows[b]][rows[c]]);
+    assert!(matrix[rows[c]][rows[c]]);
+    assert!(matrix[rows[c]][rows[a]]);
+    assert!(matrix[rows[d]][rows[b]]);
+    assert!(!matrix[rows[a]][rows[a]]);
+    assert!(!matrix[rows[a]][rows[d]]);
+
+    // Parallel edges collapse into a single entry
+    let total: usize = matrix.iter().flatten().filter(|&&cell| cell).count();
+    assert_eq!(total, 5);
+}
+
+#[test]
+fn test_adjacency_matrix_mapping_round_trips() {
+    let (graph, _) = sample();
+    let (matrix, rows) = graph.to_adjacency_matrix();
+
+    // Every node gets a distinct row, and the row identifies the node again
+    let mut by_row = vec![None; matrix.len()];
+    for ix in graph.node_indices() {
+        assert!(by_row[rows[ix]].replace(ix).is_none());
+    }
+    let by_row: Vec<_> = by_row.into_iter().map(Option::unwrap).collect();
+    for ix in graph.node_indices() {
+        assert_eq!(by_row[rows[ix]], ix);
+    }
+
+    // Each true cell corresponds to an edge between the nodes of that row and column
+    for (i, row) in matrix.iter().enumerate() {
+        for (j, &cell) in row.iter().enumerate() {
+            let exists = graph
+                .edge_indices()
+                .any(|e| graph.endpoints(e) == [by_row[i], by_row[j]]);
+            assert_eq!(cell, exists);
+        }
+    }
+    assert_eq!(rows.iter().count(), graph.len_nodes());
+}
+
+#[test]
+fn test_to_weighted_matrix() {
+    let (graph, nodes) = sample();
+    let [a, b, c, d] = [nodes[0], nodes[1], nodes[2], nodes[3]];
+    let (matrix, rows) = graph.to_weighted_matrix(|&w| w * 10);
+
+    assert_eq!(matrix[rows[a]][rows[b]], Some(20));
+    assert_eq!(matrix[rows[b]][rows[c]], Some(30));
+    assert_eq!(matrix[rows[c]][rows[c]], Some(40));
+    assert_eq!(matrix[rows[c]][rows[a]], Some(50));
+    assert_eq!(matrix[rows[d]][rows[b]], Some(60));
+    assert_eq!(matrix[rows[b]][rows[a]], None);
+    assert_eq!(matrix[rows[d]][rows[d]], None);
+}
+
+#[test]
+fn test_adjacency_matrix_empty_graph() {
+    let graph: VecGraph<(), ()> = VecGraph::default();
+    let (matrix, _) = graph.to_adjacency_matrix();
+    assert!(matrix.is_empty());
+    let (weighted, _) = graph.to_weighted_matrix(|_| 1);
+    assert!(weighted.is_empty());
+}
+
+fn list_fixtures() -> Vec<Vec<Vec<usize>>> {
+    vec![
+        vec![],
+        vec![vec![]],
+        vec![vec![0]],
+        vec![vec![1, 2], vec![2], vec![0]],
+        // parallel edges and self-loops
+        vec![vec![1, 1, 0], vec![1], vec![], vec![3, 0, 3]],
+        (0..6).map(|i| ((i + 1)..6).collect()).collect(),
+    ]
+}
+
+#[test]
+fn test_adjacency_list_round_trip() {
+    for adj in list_fixtures() {
+        let graph = VecGraph::from_adjacency_list(&adj).unwrap();
+        assert_eq!(graph.len_nodes(), adj.len());
+        assert_eq!(graph.len_edges(), adj.iter().map(Vec::len).sum::<usize>());
+        for node in graph.node_indices() {
+            assert_eq!(*graph.node(node), node.index());
+        }
+
+        let (list, rows) = graph.to_adjacency_list();
+        assert_eq!(list, adj);
+        for node in graph.node_indices() {
+            assert_eq!(rows[node], node.index());
+        }
+    }
+}
+
+#[test]
+fn test_adjacency_matrix_round_trip() {
+    for adj in list_fixtures() {
+        let n = adj.len();
+        let mut m = vec![vec![false; n]; n];
+        for (i, targets) in adj.iter().enumerate() {
+            for &j in targets {
+                m[i][j] = true;
+            }
+        }
+
+        let graph = VecGraph::from_adjacency_matrix(&m).unwrap();
+        assert_eq!(graph.to_adjacency_matrix().0, m);
+        // Parallel edges collapse into one entry of the matrix
+        assert_eq!(
+            graph.len_edges(),
+            m.iter().flatten().filter(|&&cell| cell).count()
+        );
+
+        // A graph with parallel edges maps to the same matrix
+        let from_list = VecGraph::from_adjacency_list(&adj).unwrap();
+        assert_eq!(from_list.to_adjacency_matrix().0, m);
+    }
+}
+
+#[test]
+fn test_from_adjacency_with_payloads() {
+    let adj = vec![vec![1, 2], vec![2], vec![0]];
+    let graph =
+        VecGraph::from_adjacency_list_with(&adj, |i| format!("n{}", i), |i, j| (i, j)).unwrap();
+    for edge in graph.edge_indices() {
+        let [from, to] = graph.endpoints(edge);
+        assert_eq!(*graph.edge(edge), (from.index(), to.index()));
+        assert_eq!(*graph.node(from), format!("n{}", from.index()));
+    }
+
+    let m = vec![vec![true, false], vec![true, true]];
+    let graph = VecGraph::from_adjacency_matrix_with(&m, |i| i * 10, |i, j| i + j).unwrap();
+    assert_eq!(graph.nodes().copied().collect::<Vec<_>>(), vec![0, 10]);
+    assert_eq!(graph.edges().copied().collect::<Vec<_>>(), vec![0, 1, 2]);
+}
+
+#[test]
+fn test_from_adjacency_errors() {
+    use gotgraph::vec_graph::AdjacencyError;
+
+    let err = VecGraph::from_adjacency_list(&[vec![0], vec![0, 5]]).unwrap_err();
+    assert_eq!(
+        err,
+        AdjacencyError::OutOfRange {
+            node: 1,
+            target: 5,
+            len: 2
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "adjacency list of node 1 refers to node 5, but there are only 2 nodes"
+    );
+
+    // Factories are not called for invalid input
+    let result = VecGraph::<(), ()>::from_adjacency_list_with(
+        &[vec![1]],
+        |_| panic!("node factory called"),
+        |_, _| panic!("edge factory called"),
+    );
+    assert!(result.is_err());
+
+    let err = VecGraph::from_adjacency_matrix(&[vec![true, false], vec![true]]).unwrap_err();
+    assert_eq!(
+        err,
+        AdjacencyError::NotSquare {
+            row: 1,
+            columns: 1,
+            len: 2
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "row 1 of the adjacency matrix has 1 columns, expected 2"
+    );
+}
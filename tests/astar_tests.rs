@@ -0,0 +1,276 @@
+use gotgraph::algo::{astar, astar_until, bellman_ford};
+use gotgraph::prelude::*;
+use gotgraph::vec_graph::NodeIx;
+
+const SIZE: usize = 8;
+
+/// Grid graph with edges in both directions between neighbours. Nodes store their
+/// coordinates; a few cells are walls without any edges.
+fn create_grid() -> (VecGraph<(usize, usize), u32>, Vec<NodeIx>) {
+    let mut graph = VecGraph::default();
+    let nodes: Vec<_> = (0..SIZE * SIZE)
+        .map(|i| graph.add_node((i / SIZE, i % SIZE)))
+        .collect();
+    let wall = |r: usize, c: usize| c == 3 && r < SIZE - 1 || c == 5 && r > 0;
+    for r in 0..SIZE {
+        for c in 0..SIZE {
+            if wall(r, c) {
+                continue;
+            }
+            let here = nodes[r * SIZE + c];
+            let mut link = |r2: usize, c2: usize| {
+                if !wall(r2, c2) {
+                    // Vary costs a little so that there is a single best path length
+                    let cost = 1 + ((r + c + r2 + c2) % 3) as u32;
+                    graph.add_edge(cost, here, nodes[r2 * SIZE + c2]);
+                }
+            };
+            if r > 0 {
+                link(r - 1, c);
+            }
+            if r + 1 < SIZE {
+                link(r + 1, c);
+            }
+            if c > 0 {
+                link(r, c - 1);
+            }
+            if c + 1 < SIZE {
+                link(r, c + 1);
+            }
+        }
+    }
+    (graph, nodes)
+}
+
+fn manhattan(a: (usize, usize), b: (usize, usize)) -> u32 {
+    (a.0.abs_diff(b.0) + a.1.abs_diff(b.1)) as u32
+}
+
+#[test]
+fn test_astar_grid_matches_dijkstra() {
+    let (graph, nodes) = create_grid();
+    let start = nodes[0];
+    let reference = bellman_ford(&graph, start, |&w| w as i64).unwrap();
+
+    for &goal in &nodes {
+        let target = *graph.node(goal);
+        let result = astar(
+            &graph,
+            start,
+            goal,
+            |_, &w| w,
+            |_, &pos| manhattan(pos, target),
+        );
+        match reference[goal] {
+            None => assert!(result.is_none()),
+            Some(expected) => {
+                let (cost, path) = result.unwrap();
+                assert_eq!(cost as i64, expected);
+
+                // The path is connected, goes from start to goal and costs what was reported
+                let mut at = start;
+                let mut total = 0;
+                for &edge in &path {
+                    let [from, to] = graph.endpoints(edge);
+                    assert_eq!(from, at);
+                    total += *graph.edge(edge);
+                    at = to;
+                }
+                assert_eq!(at, goal);
+                assert_eq!(total, cost);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_astar_zero_heuristic_is_dijkstra() {
+    let (graph, nodes) = create_grid();
+    let start = nodes[SIZE * SIZE - 1];
+    let reference = bellman_ford(&graph, start, |&w| w as i64).unwrap();
+    for &goal in &nodes {
+        let result = astar(&graph, start, goal, |_, &w| w, |_, _| 0);
+        assert_eq!(result.map(|(cost, _)| cost as i64), reference[goal]);
+    }
+}
+
+#[test]
+fn test_astar_unreachable_goal() {
+    let mut graph: VecGraph<(), u32> = VecGraph::default();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    graph.add_edge(1, a, b);
+    graph.add_edge(1, c, a);
+
+    assert!(astar(&graph, a, c, |_, &w| w, |_, _| 0).is_none());
+
+    // Walls cut off part of the grid's corner
+    let (graph, nodes) = create_grid();
+    let wall = nodes[3];
+    assert!(astar(&graph, nodes[0], wall, |_, &w| w, |_, _| 0).is_none());
+}
+
+#[test]
+fn test_astar_start_is_goal() {
+    let (graph, nodes) = create_grid();
+    let (cost, path) = astar(&graph, nodes[9], nodes[9], |_, &w| w, |_, _| 0).unwrap();
+    assert_eq!(cost, 0);
+    assert!(path.is_empty());
+}
+
+#[test]
+fn test_astar_is_deterministic_with_ties() {
+    // Two paths of equal cost from a to d
+    let build = || {
+        let mut graph: VecGraph<&str, u32> = VecGraph::default();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(1, a, b);
+        graph.add_edge(1, a, c);
+        graph.add_edge(1, b, d);
+        graph.add_edge(1, c, d);
+        (graph, a, d)
+    };
+
+    let (graph, a, d) = build();
+    let (cost, first) = astar(&graph, a, d, |_, &w| w, |_, _| 0).unwrap();
+    assert_eq!(cost, 2);
+    assert_eq!(first.len(), 2);
+    for _ in 0..10 {
+        let (graph, a, d) = build();
+        let (_, path) = astar(&graph, a, d, |_, &w| w, |_, _| 0).unwrap();
+        assert_eq!(path, first);
+    }
+}
+
+#[test]
+fn test_astar_inside_scope() {
+    let (graph, nodes) = create_grid();
+    let expected = astar(&graph, nodes[0], nodes[SIZE - 1], |_, &w| w, |_, _| 0)
+        .unwrap()
+        .0;
+
+    graph.scope(|ctx| {
+        let start = ctx
+            .node_indices()
+            .find(|&ix| *ctx.node(ix) == (0, 0))
+            .unwrap();
+        let goal = ctx
+            .node_indices()
+            .find(|&ix| *ctx.node(ix) == (0, SIZE - 1))
+            .unwrap();
+        let target = *ctx.node(goal);
+        let (cost, path) = astar(
+            ctx,
+            start,
+            goal,
+            |_, &w| w,
+            |_, &pos| manhattan(pos, target),
+        )
+        .unwrap();
+        assert_eq!(cost, expected);
+        assert_eq!(ctx.endpoints(*path.last().unwrap())[1], goal);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Goal node does not exist")]
+fn test_astar_invalid_goal_panics() {
+    let (graph, _) = create_grid();
+    let mut other: VecGraph<(), ()> = VecGraph::default();
+    for _ in 0..=SIZE * SIZE {
+        other.add_node(());
+    }
+    let missing = other.node_indices().last().unwrap();
+    astar(
+        &graph,
+        graph.node_indices().next().unwrap(),
+        missing,
+        |_, &w| w,
+        |_, _| 0,
+    );
+}
+
+/// Checks that consecutive nodes of `path` are connected and returns the cheapest cost
+/// of walking it
+fn node_path_cost(graph: &VecGraph<(usize, usize), u32>, path: &[NodeIx]) -> u32 {
+    path.windows(2)
+        .map(|w| {
+            graph
+                .outgoing_edge_pairs(w[0])
+                .filter(|&(e, _)| graph.endpoints(e)[1] == w[1])
+                .map(|(_, &cost)| cost)
+                .min()
+                .expect("consecutive path nodes should be connected")
+        })
+        .sum()
+}
+
+#[test]
+fn test_astar_until_zero_heuristic_matches_dijkstra() {
+    let (graph, nodes) = create_grid();
+    let start = nodes[0];
+    let reference = bellman_ford(&graph, start, |&w| w as i64).unwrap();
+
+    for &goal in &nodes {
+        let result = astar_until(&graph, start, |n| n == goal, |&w| w, |_| 0);
+        match reference[goal] {
+            None => assert!(result.is_none()),
+            Some(expected) => {
+                let (cost, path) = result.unwrap();
+                assert_eq!(cost as i64, expected);
+                assert_eq!(path.first(), Some(&start));
+                assert_eq!(path.last(), Some(&goal));
+                assert_eq!(node_path_cost(&graph, &path), cost);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_astar_until_admissible_heuristic_is_optimal() {
+    let (graph, nodes) = create_grid();
+    let start = nodes[0];
+    let reference = bellman_ford(&graph, start, |&w| w as i64).unwrap();
+
+    // Any cell in the last column is a goal; the heuristic is the column distance
+    let is_goal = |n: NodeIx| graph.node(n).1 == SIZE - 1;
+    let expected = nodes
+        .iter()
+        .filter(|&&n| is_goal(n))
+        .filter_map(|&n| reference[n])
+        .min()
+        .unwrap();
+
+    let (cost, path) = astar_until(
+        &graph,
+        start,
+        is_goal,
+        |&w| w,
+        |n| (SIZE - 1 - graph.node(n).1) as u32,
+    )
+    .unwrap();
+    assert_eq!(cost as i64, expected);
+    assert!(is_goal(*path.last().unwrap()));
+    assert_eq!(node_path_cost(&graph, &path), cost);
+
+    // Same result as searching with a zero heuristic
+    let (zero_cost, _) = astar_until(&graph, start, is_goal, |&w| w, |_| 0).unwrap();
+    assert_eq!(zero_cost, cost);
+}
+
+#[test]
+fn test_astar_until_start_is_goal_and_no_goal() {
+    let (graph, nodes) = create_grid();
+    assert_eq!(
+        astar_until(&graph, nodes[0], |_| true, |&w| w, |_| 0),
+        Some((0, vec![nodes[0]]))
+    );
+    assert_eq!(
+        astar_until(&graph, nodes[0], |_| false, |&w| w, |_| 0),
+        None
+    );
+}
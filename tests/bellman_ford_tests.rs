@@ -0,0 +1,55 @@
+use gotgraph::algo::{bellman_ford, NegativeCycle};
+use gotgraph::prelude::*;
+
+#[test]
+fn test_negative_edge_without_cycle() {
+    let mut graph: VecGraph<&str, i64> = VecGraph::default();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    let d = graph.add_node("D");
+    let unreachable = graph.add_node("E");
+
+    graph.add_edge(5, a, b);
+    graph.add_edge(2, a, c);
+    graph.add_edge(-4, b, c);
+    graph.add_edge(1, c, d);
+    graph.add_edge(1, unreachable, a);
+
+    let dist = bellman_ford(&graph, a, |&w| w).unwrap();
+    assert_eq!(dist[a], Some(0));
+    assert_eq!(dist[b], Some(5));
+    assert_eq!(dist[c], Some(1));
+    assert_eq!(dist[d], Some(2));
+    assert_eq!(dist[unreachable], None);
+}
+
+#[test]
+fn test_negative_cycle_is_reported() {
+    let mut graph: VecGraph<(), i64> = VecGraph::default();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+
+    graph.add_edge(1, a, b);
+    graph.add_edge(-3, b, c);
+    graph.add_edge(1, c, b);
+
+    assert_eq!(bellman_ford(&graph, a, |&w| w).err(), Some(NegativeCycle));
+}
+
+#[test]
+fn test_unreachable_negative_cycle_is_ignored() {
+    let mut graph: VecGraph<(), i64> = VecGraph::default();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+
+    graph.add_edge(-1, b, c);
+    graph.add_edge(-1, c, b);
+
+    let dist = bellman_ford(&graph, a, |&w| w).unwrap();
+    assert_eq!(dist[a], Some(0));
+    assert_eq!(dist[b], None);
+    assert_eq!(dist[c], None);
+}
@@ -0,0 +1,89 @@
+use gotgraph::algo::betweenness_centrality;
+use gotgraph::prelude::*;
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-9,
+        "expected {}, got {}",
+        expected,
+        actual
+    );
+}
+
+/// Path with edges in both directions: 0 - 1 - 2 - 3 - 4
+fn path_graph(len: usize) -> VecGraph<usize, ()> {
+    let mut graph = VecGraph::default();
+    let nodes = graph.add_nodes(0..len);
+    for pair in nodes.windows(2) {
+        graph.add_edge((), pair[0], pair[1]);
+        graph.add_edge((), pair[1], pair[0]);
+    }
+    graph
+}
+
+#[test]
+fn test_path_graph_middle_is_most_central() {
+    let graph = path_graph(5);
+    let nodes: Vec<_> = graph.node_indices().collect();
+    let centrality = betweenness_centrality(&graph, false);
+
+    // Node i lies on the paths between the i nodes before it and the 4 - i after it,
+    // in both directions
+    for (i, &node) in nodes.iter().enumerate() {
+        assert_close(centrality[node], (2 * i * (4 - i)) as f64);
+    }
+    let best = nodes
+        .iter()
+        .max_by(|&&a, &&b| centrality[a].total_cmp(&centrality[b]))
+        .unwrap();
+    assert_eq!(*graph.node(*best), 2);
+
+    let normalized = betweenness_centrality(&graph, true);
+    assert_close(normalized[nodes[2]], 8.0 / 12.0);
+    assert_close(normalized[nodes[0]], 0.0);
+}
+
+#[test]
+fn test_star_graph_center_dominates() {
+    let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    let center = graph.add_node("center");
+    let leaves = graph.add_nodes(["a", "b", "c", "d", "e"]);
+    for &leaf in &leaves {
+        graph.add_edge((), center, leaf);
+        graph.add_edge((), leaf, center);
+    }
+
+    let centrality = betweenness_centrality(&graph, false);
+    // Every ordered pair of distinct leaves goes through the center
+    assert_close(centrality[center], 20.0);
+    for &leaf in &leaves {
+        assert_close(centrality[leaf], 0.0);
+    }
+    let normalized = betweenness_centrality(&graph, true);
+    assert_close(normalized[center], 1.0);
+}
+
+#[test]
+fn test_split_shortest_paths() {
+    // Two shortest paths from s to t, one of them doubled by a parallel edge
+    let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    let [s, a, b, t]: [_; 4] = graph.add_nodes(["s", "a", "b", "t"]).try_into().unwrap();
+    graph.add_edge((), s, a);
+    graph.add_edge((), s, a);
+    graph.add_edge((), s, b);
+    graph.add_edge((), a, t);
+    graph.add_edge((), b, t);
+    graph.add_edge((), t, t);
+
+    let centrality = betweenness_centrality(&graph, false);
+    assert_close(centrality[a], 2.0 / 3.0);
+    assert_close(centrality[b], 1.0 / 3.0);
+    assert_close(centrality[s], 0.0);
+    assert_close(centrality[t], 0.0);
+
+    let empty: VecGraph<(), ()> = VecGraph::default();
+    assert_eq!(
+        gotgraph::Mapping::iter(&betweenness_centrality(&empty, true)).count(),
+        0
+    );
+}
@@ -0,0 +1,161 @@
+use gotgraph::algo::{articulation_points, bridges};
+use gotgraph::prelude::*;
+use gotgraph::undirected::UnVecGraph;
+use gotgraph::vec_graph::{EdgeIx, NodeIx};
+
+fn path_graph(n: usize) -> (VecGraph<usize, ()>, Vec<NodeIx>, Vec<EdgeIx>) {
+    let mut graph = VecGraph::default();
+    let nodes: Vec<_> = (0..n).map(|i| graph.add_node(i)).collect();
+    let edges = nodes
+        .windows(2)
+        .enumerate()
+        .map(|(i, pair)| {
+            // Alternate directions, which must not matter
+            if i % 2 == 0 {
+                graph.add_edge((), pair[0], pair[1])
+            } else {
+                graph.add_edge((), pair[1], pair[0])
+            }
+        })
+        .collect();
+    (graph, nodes, edges)
+}
+
+#[test]
+fn test_path_graph() {
+    let (graph, nodes, edges) = path_graph(6);
+    assert_eq!(articulation_points(&graph), nodes[1..5].to_vec());
+    assert_eq!(bridges(&graph), edges);
+}
+
+#[test]
+fn test_cycle_graph() {
+    let (mut graph, nodes, _) = path_graph(6);
+    graph.add_edge((), nodes[5], nodes[0]);
+    assert!(articulation_points(&graph).is_empty());
+    assert!(bridges(&graph).is_empty());
+}
+
+#[test]
+fn test_trivial_graphs() {
+    let mut graph: VecGraph<(), ()> = VecGraph::default();
+    assert!(articulation_points(&graph).is_empty());
+    assert!(bridges(&graph).is_empty());
+
+    let a = graph.add_node(());
+    graph.add_edge((), a, a);
+    assert!(articulation_points(&graph).is_empty());
+    assert!(bridges(&graph).is_empty());
+
+    let b = graph.add_node(());
+    let ab = graph.add_edge((), a, b);
+    assert!(articulation_points(&graph).is_empty());
+    assert_eq!(bridges(&graph), vec![ab]);
+
+    // A parallel edge in the other direction removes the bridge
+    graph.add_edge((), b, a);
+    assert!(bridges(&graph).is_empty());
+}
+
+#[test]
+fn test_components_and_star() {
+    let mut graph: VecGraph<(), ()> = VecGraph::default();
+    let center = graph.add_node(());
+    let leaves: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+    for &leaf in &leaves {
+        graph.add_edge((), center, leaf);
+    }
+    // A separate triangle
+    let t: Vec<_> = (0..3).map(|_| graph.add_node(())).collect();
+    graph.add_edge((), t[0], t[1]);
+    graph.add_edge((), t[1], t[2]);
+    graph.add_edge((), t[2], t[0]);
+
+    assert_eq!(articulation_points(&graph), vec![center]);
+    assert_eq!(bridges(&graph).len(), 4);
+}
+
+#[test]
+fn test_matches_brute_force() {
+    let mut seed: u64 = 11;
+    let mut next = || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) as usize
+    };
+
+    for _ in 0..30 {
+        let mut graph: VecGraph<(), ()> = VecGraph::default();
+        let nodes: Vec<_> = (0..12).map(|_| graph.add_node(())).collect();
+        for _ in 0..14 {
+            graph.add_edge((), nodes[next() % 12], nodes[next() % 12]);
+        }
+
+        let components =
+            |graph: &VecGraph<(), ()>| gotgraph::algo::weakly_connected_components(graph).count();
+        let base = components(&graph);
+
+        let expected_points: Vec<_> = graph
+            .node_indices()
+            .filter(|&ix| {
+                let mut copy = graph.clone();
+                copy.remove_node(ix);
+                // The removed node was a component of its own only if it was isolated
+                components(&copy) > base - usize::from(graph.degree(ix) == 0)
+            })
+            .collect();
+        assert_eq!(articulation_points(&graph), expected_points);
+
+        let expected_bridges: Vec<_> = graph
+            .edge_indices()
+            .filter(|&ix| {
+                let mut copy = graph.clone();
+                copy.remove_edge(ix);
+                components(&copy) > base
+            })
+            .collect();
+        assert_eq!(bridges(&graph), expected_bridges);
+    }
+}
+
+#[test]
+fn test_undirected_graph() {
+    let mut graph: UnVecGraph<(), ()> = UnVecGraph::default();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    graph.add_edge((), a, b);
+    graph.add_edge((), b, c);
+    assert_eq!(articulation_points(&graph), vec![b]);
+    assert_eq!(bridges(&graph).len(), 2);
+
+    graph.add_edge((), c, a);
+    assert!(articulation_points(&graph).is_empty());
+    assert!(bridges(&graph).is_empty());
+}
+
+#[test]
+fn test_undirected_edges_stored_against_the_search() {
+    let mut graph: UnVecGraph<(), ()> = UnVecGraph::default();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    let d = graph.add_node(());
+    let ba = graph.add_edge((), b, a);
+    let cb = graph.add_edge((), c, b);
+    graph.add_edge((), c, d);
+    graph.add_edge((), d, c);
+
+    // The search from `a` crosses both bridges against their stored direction, and the
+    // parallel edges between `c` and `d` are not bridges
+    let found = bridges(&graph);
+    assert_eq!(found, vec![ba.reversed(), cb.reversed()]);
+    assert_eq!(graph.endpoints(found[0]), [a, b]);
+    assert_eq!(articulation_points(&graph), vec![b, c]);
+
+    graph.scope(|ctx| {
+        assert_eq!(bridges(&ctx).len(), 2);
+        assert_eq!(articulation_points(&ctx).len(), 2);
+    });
+}
@@ -0,0 +1,140 @@
+use gotgraph::algo::{bipartite, odd_cycle};
+use gotgraph::prelude::*;
+
+fn cycle_graph(len: usize) -> VecGraph<usize, ()> {
+    let mut graph = VecGraph::default();
+    let nodes: Vec<_> = (0..len).map(|i| graph.add_node(i)).collect();
+    for i in 0..len {
+        graph.add_edge((), nodes[i], nodes[(i + 1) % len]);
+    }
+    graph
+}
+
+fn connected<G: Graph>(graph: &G, a: G::NodeIx, b: G::NodeIx) -> bool {
+    graph.edge_indices().any(|e| {
+        let [from, to] = graph.endpoints(e);
+        (from, to) == (a, b) || (from, to) == (b, a)
+    })
+}
+
+/// Checks that `cycle` is a simple odd cycle of `graph`
+fn assert_odd_cycle<G: Graph>(graph: &G, cycle: &[G::NodeIx]) {
+    assert_eq!(cycle.len() % 2, 1);
+    let mut distinct = cycle.to_vec();
+    distinct.sort();
+    distinct.dedup();
+    assert_eq!(distinct.len(), cycle.len());
+    for i in 0..cycle.len() {
+        assert!(connected(graph, cycle[i], cycle[(i + 1) % cycle.len()]));
+    }
+}
+
+/// Checks that every edge connects nodes on different sides
+fn assert_valid_coloring(graph: &VecGraph<usize, ()>) {
+    let sides = bipartite(graph).expect("graph should be bipartite");
+    for e in graph.edge_indices() {
+        let [from, to] = graph.endpoints(e);
+        assert_ne!(sides[from], sides[to]);
+    }
+    assert!(odd_cycle(graph).is_none());
+}
+
+#[test]
+fn test_even_cycles() {
+    for len in [2, 4, 6, 10] {
+        assert_valid_coloring(&cycle_graph(len));
+    }
+}
+
+#[test]
+fn test_odd_cycles() {
+    for len in [3, 5, 9] {
+        let graph = cycle_graph(len);
+        assert!(bipartite(&graph).is_none());
+        let cycle = odd_cycle(&graph).unwrap();
+        assert_eq!(cycle.len(), len);
+        assert_odd_cycle(&graph, &cycle);
+    }
+}
+
+#[test]
+fn test_odd_cycle_inside_larger_graph() {
+    // Even cycle 0-1-2-3 with a pendant path to the triangle 5-6-7
+    let mut graph: VecGraph<usize, ()> = VecGraph::default();
+    let n: Vec<_> = (0..8).map(|i| graph.add_node(i)).collect();
+    for &(a, b) in &[
+        (0, 1),
+        (1, 2),
+        (2, 3),
+        (3, 0),
+        (2, 4),
+        (4, 5),
+        (5, 6),
+        (7, 6),
+        (5, 7),
+    ] {
+        graph.add_edge((), n[a], n[b]);
+    }
+
+    assert!(bipartite(&graph).is_none());
+    let cycle = odd_cycle(&graph).unwrap();
+    assert_odd_cycle(&graph, &cycle);
+    let mut nodes: Vec<_> = cycle.iter().map(|&ix| *graph.node(ix)).collect();
+    nodes.sort();
+    assert_eq!(nodes, vec![5, 6, 7]);
+}
+
+#[test]
+fn test_tree() {
+    let mut graph: VecGraph<usize, ()> = VecGraph::default();
+    let nodes: Vec<_> = (0..15).map(|i| graph.add_node(i)).collect();
+    for i in 1..nodes.len() {
+        // Alternate edge directions, which must not matter
+        if i % 2 == 0 {
+            graph.add_edge((), nodes[(i - 1) / 2], nodes[i]);
+        } else {
+            graph.add_edge((), nodes[i], nodes[(i - 1) / 2]);
+        }
+    }
+    assert_valid_coloring(&graph);
+}
+
+#[test]
+fn test_disconnected_with_isolated_nodes() {
+    let mut graph = cycle_graph(4);
+    let a = graph.add_node(100);
+    let b = graph.add_node(101);
+    graph.add_node(102);
+    graph.add_edge((), a, b);
+    graph.add_edge((), b, a);
+    assert_valid_coloring(&graph);
+}
+
+#[test]
+fn test_self_loop() {
+    let mut graph = cycle_graph(4);
+    let loop_node = graph.node_indices().nth(2).unwrap();
+    graph.add_edge((), loop_node, loop_node);
+
+    assert!(bipartite(&graph).is_none());
+    assert_eq!(odd_cycle(&graph), Some(vec![loop_node]));
+}
+
+#[test]
+fn test_in_scope() {
+    let graph = cycle_graph(6);
+    graph.scope(|ctx| {
+        let sides = bipartite(ctx).unwrap();
+        for e in ctx.edge_indices() {
+            let [from, to] = ctx.endpoints(e);
+            assert_ne!(sides[from], sides[to]);
+        }
+    });
+}
+
+#[test]
+fn test_empty_graph() {
+    let graph: VecGraph<usize, ()> = VecGraph::default();
+    assert!(bipartite(&graph).is_some());
+    assert!(odd_cycle(&graph).is_none());
+}
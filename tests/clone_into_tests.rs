@@ -0,0 +1,116 @@
+use gotgraph::algo::is_structurally_equal;
+use gotgraph::graph::clone_into;
+use gotgraph::prelude::*;
+use gotgraph::undirected::UnVecGraph;
+use gotgraph::Mapping;
+
+fn create_graph() -> VecGraph<u16, u16> {
+    let mut graph = VecGraph::default();
+    let n: Vec<_> = (0..5).map(|i| graph.add_node(i * 10)).collect();
+    graph.add_edge(1, n[0], n[1]);
+    graph.add_edge(2, n[0], n[1]); // parallel
+    graph.add_edge(3, n[1], n[0]);
+    graph.add_edge(4, n[2], n[2]); // self-loop
+    graph.add_edge(5, n[3], n[2]);
+    graph.add_edge(6, n[2], n[3]);
+    // n[4] is isolated
+    graph.remove_node(n[1]);
+    // Removal moved the last node, so look it up again
+    let last = graph
+        .node_indices()
+        .find(|&ix| *graph.node(ix) == 40)
+        .unwrap();
+    graph.add_edge(7, n[0], last);
+    graph
+}
+
+#[test]
+fn test_clone_into_same_type() {
+    let graph = create_graph();
+    let (copy, remap): (VecGraph<u16, u16>, _) = clone_into(&graph);
+
+    assert_eq!(copy.len_nodes(), graph.len_nodes());
+    assert_eq!(copy.len_edges(), graph.len_edges());
+    for (ix, node) in graph.node_pairs() {
+        assert_eq!(copy.node(remap[ix]), node);
+    }
+
+    // Every edge exists between the mapped endpoints, with multiplicity
+    let mut copied: Vec<_> = copy
+        .edge_pairs()
+        .map(|(e, &w)| (copy.endpoints(e), w))
+        .collect();
+    let mut expected: Vec<_> = graph
+        .edge_pairs()
+        .map(|(e, &w)| {
+            let [from, to] = graph.endpoints(e);
+            ([remap[from], remap[to]], w)
+        })
+        .collect();
+    copied.sort();
+    expected.sort();
+    assert_eq!(copied, expected);
+
+    assert!(is_structurally_equal(
+        &graph,
+        &copy,
+        |a, b| a == b,
+        |a, b| a == b
+    ));
+
+    // The mapping is a bijection onto the new graph's nodes
+    let mut targets: Vec<_> = remap.iter().copied().collect();
+    targets.sort();
+    targets.dedup();
+    assert_eq!(targets.len(), copy.len_nodes());
+}
+
+#[test]
+fn test_clone_into_converts_payloads() {
+    let graph = create_graph();
+    let (copy, remap): (VecGraph<u64, i32>, _) = clone_into(&graph);
+    for (ix, &node) in graph.node_pairs() {
+        assert_eq!(*copy.node(remap[ix]), node as u64);
+    }
+    assert!(is_structurally_equal(
+        &graph,
+        &copy,
+        |&a, &b| a as u64 == b,
+        |&a, &b| a as i32 == b
+    ));
+}
+
+#[test]
+fn test_clone_into_other_backend() {
+    let graph = create_graph();
+    let (undirected, remap): (UnVecGraph<u16, u16>, _) = clone_into(&graph);
+    assert_eq!(undirected.len_edges(), graph.len_edges());
+    for ix in graph.node_indices() {
+        assert_eq!(undirected.node(remap[ix]), graph.node(ix));
+    }
+    let mut copied: Vec<_> = undirected
+        .edge_pairs()
+        .map(|(e, &w)| (undirected.endpoints(e), w))
+        .collect();
+    let mut expected: Vec<_> = graph
+        .edge_pairs()
+        .map(|(e, &w)| {
+            let [from, to] = graph.endpoints(e);
+            ([remap[from], remap[to]], w)
+        })
+        .collect();
+    copied.sort();
+    expected.sort();
+    assert_eq!(copied, expected);
+}
+
+#[test]
+fn test_clone_into_from_scope() {
+    let graph = create_graph();
+    graph.scope(|ctx| {
+        let (copy, remap): (VecGraph<u16, u16>, _) = clone_into(ctx);
+        for ix in ctx.node_indices() {
+            assert_eq!(copy.node(remap[ix]), ctx.node(ix));
+        }
+    });
+}
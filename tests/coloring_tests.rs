@@ -0,0 +1,72 @@
+use gotgraph::algo::greedy_coloring;
+use gotgraph::prelude::*;
+use gotgraph::Mapping;
+
+/// Checks that no edge other than a self-loop connects nodes of the same color
+fn assert_proper<G: Graph>(graph: &G, colors: &impl Mapping<G::NodeIx, usize>, len: usize) {
+    for node in graph.node_indices() {
+        assert!(colors[node] < len);
+    }
+    for edge in graph.edge_indices() {
+        let [from, to] = graph.endpoints(edge);
+        if from != to {
+            assert_ne!(colors[from], colors[to]);
+        }
+    }
+}
+
+#[test]
+fn test_bipartite_graph_uses_two_colors() {
+    let mut graph: VecGraph<usize, ()> = VecGraph::default();
+    let left: Vec<_> = (0..3).map(|i| graph.add_node(i)).collect();
+    let right: Vec<_> = (3..7).map(|i| graph.add_node(i)).collect();
+    for &l in &left {
+        for &r in &right {
+            graph.add_edge((), l, r);
+        }
+    }
+
+    let (colors, len) = greedy_coloring(&graph);
+    assert_eq!(len, 2);
+    assert_proper(&graph, &colors, len);
+    assert!(left.iter().all(|&l| colors[l] == colors[left[0]]));
+}
+
+#[test]
+fn test_triangle_uses_three_colors() {
+    let mut graph: VecGraph<(), ()> = VecGraph::default();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    graph.add_edge((), a, b);
+    graph.add_edge((), b, c);
+    graph.add_edge((), c, a);
+
+    let (colors, len) = greedy_coloring(&graph);
+    assert_eq!(len, 3);
+    assert_proper(&graph, &colors, len);
+}
+
+#[test]
+fn test_self_loops_and_parallel_edges() {
+    let mut graph: VecGraph<(), ()> = VecGraph::default();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let isolated = graph.add_node(());
+    graph.add_edge((), a, a);
+    graph.add_edge((), a, b);
+    graph.add_edge((), b, a);
+
+    let (colors, len) = greedy_coloring(&graph);
+    assert_eq!(len, 2);
+    assert_proper(&graph, &colors, len);
+    assert_eq!(colors[a], 0);
+    assert_eq!(colors[isolated], 0);
+}
+
+#[test]
+fn test_empty_graph() {
+    let graph: VecGraph<(), ()> = VecGraph::default();
+    let (_, len) = greedy_coloring(&graph);
+    assert_eq!(len, 0);
+}
@@ -0,0 +1,15 @@
+use gotgraph::prelude::*;
+
+fn main() {
+    let mut graph: VecGraph<i32, &str> = VecGraph::default();
+    graph.add_node(1);
+
+    graph.scope(|outer_ctx| {
+        // ERROR: scopes cannot be opened on a context
+        outer_ctx.scope(|inner_ctx| {
+            for node in inner_ctx.node_indices() {
+                println!("{}", inner_ctx.node(node));
+            }
+        });
+    });
+}
@@ -0,0 +1,19 @@
+use gotgraph::prelude::*;
+
+fn main() {
+    let mut graph: VecGraph<i32, &str> = VecGraph::default();
+
+    graph.scope_mut(|mut outer_ctx| {
+        let node1 = outer_ctx.add_node(1);
+        let node2 = outer_ctx.add_node(2);
+        let edge1 = outer_ctx.add_edge("edge1", node1, node2);
+
+        // ERROR: an inner scope could remove nodes the outer tags still refer to
+        outer_ctx.scope_mut(|mut inner_ctx| {
+            let _node3 = inner_ctx.add_node(3);
+        });
+
+        assert_eq!(*outer_ctx.node(node1), 1);
+        assert_eq!(*outer_ctx.edge(edge1), "edge1");
+    });
+}
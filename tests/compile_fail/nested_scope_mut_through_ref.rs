@@ -0,0 +1,12 @@
+use gotgraph::prelude::*;
+
+fn main() {
+    let mut graph: VecGraph<i32, &str> = VecGraph::default();
+
+    graph.scope_mut(|mut ctx| {
+        // ERROR: a reference to a context cannot open a scope either
+        (&mut ctx).scope_mut(|mut inner_ctx| {
+            inner_ctx.add_node(1);
+        });
+    });
+}
@@ -9,7 +9,7 @@ fn main() {
         let node2 = outer_ctx.add_node(2);
         let edge1 = outer_ctx.add_edge("edge1", node1, node2);
         
-        // This is the bug! We can call scope_mut on contexts, which creates nested scoping
+        // ERROR: scope_mut cannot be called on a context, so no nested scope can be created
         outer_ctx.scope_mut(|mut inner_ctx| {
             // In the inner scope, we clear all nodes and edges
             // This should invalidate the outer scope's references but doesn't prevent compilation
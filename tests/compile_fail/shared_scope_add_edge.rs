@@ -0,0 +1,14 @@
+use gotgraph::prelude::*;
+
+fn main() {
+    let mut graph: VecGraph<i32, &str> = VecGraph::default();
+    graph.add_node(1);
+    graph.add_node(2);
+
+    graph.scope(|ctx| {
+        let mut ctx = ctx;
+        let tags: Vec<_> = ctx.node_indices().collect();
+        // ERROR: a context created by `scope` cannot add edges
+        ctx.add_edge("edge", tags[0], tags[1]);
+    });
+}
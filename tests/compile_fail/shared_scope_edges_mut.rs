@@ -0,0 +1,17 @@
+use gotgraph::prelude::*;
+
+fn main() {
+    let mut graph: VecGraph<i32, i32> = VecGraph::default();
+    let a = graph.add_node(1);
+    let b = graph.add_node(2);
+    graph.add_edge(10, a, b);
+
+    graph.scope(|ctx| {
+        let tag = ctx.node_indices().next().unwrap();
+        // ERROR: mutable edge iteration is not available through a read-only context
+        let mut ctx = ctx;
+        for edge in ctx.outgoing_edges_mut(tag) {
+            *edge += 1;
+        }
+    });
+}
@@ -0,0 +1,12 @@
+use gotgraph::prelude::*;
+
+fn main() {
+    let mut graph: VecGraph<i32, &str> = VecGraph::default();
+    graph.add_node(1);
+
+    graph.scope(|ctx| {
+        let tag = ctx.node_indices().next().unwrap();
+        // ERROR: a context created by `scope` only has read-only access
+        *ctx.node_mut(tag) += 1;
+    });
+}
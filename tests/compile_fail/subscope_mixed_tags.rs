@@ -0,0 +1,16 @@
+use gotgraph::prelude::*;
+
+fn main() {
+    let mut graph: VecGraph<i32, &str> = VecGraph::default();
+
+    graph.scope_mut(|mut ctx| {
+        let outer = ctx.add_node(1);
+
+        ctx.subscope(|sub| {
+            let inner = sub.node_indices().next().unwrap();
+            // ERROR: outer tags cannot be used with the subscope
+            println!("{}", sub.node(outer));
+            println!("{}", sub.node(inner));
+        });
+    });
+}
@@ -0,0 +1,13 @@
+use gotgraph::prelude::*;
+
+fn main() {
+    let mut graph: VecGraph<i32, &str> = VecGraph::default();
+
+    graph.scope_mut(|mut ctx| {
+        ctx.add_node(1);
+
+        // ERROR: tags of a subscope cannot be returned from it
+        let escaped = ctx.subscope(|sub| sub.node_indices().next().unwrap());
+        println!("{}", ctx.node(escaped));
+    });
+}
@@ -677,3 +677,353 @@ fn test_double_reverse() {
         assert_eq!(current, original, "Double reverse did not restore original state for edge {:?}", edge_ix);
     }
 }
+
+#[test]
+fn test_source_and_sink_nodes() {
+    let graph = create_test_graph();
+
+    let sources: Vec<_> = graph.source_nodes().map(|ix| *graph.node(ix)).collect();
+    let sinks: Vec<_> = graph.sink_nodes().map(|ix| *graph.node(ix)).collect();
+    assert_eq!(sources, vec![0]);
+    assert_eq!(sinks, vec![3]);
+}
+
+#[test]
+fn test_source_and_sink_nodes_with_self_loops() {
+    let mut graph = create_test_graph();
+    let isolated = graph.add_node(4);
+    let looped = graph.add_node(5);
+    graph.add_edge("5->5", looped, looped);
+
+    let mut sources: Vec<_> = graph.source_nodes().map(|ix| *graph.node(ix)).collect();
+    let mut sinks: Vec<_> = graph.sink_nodes().map(|ix| *graph.node(ix)).collect();
+    sources.sort();
+    sinks.sort();
+
+    // An isolated node is both a source and a sink, a node with only a self-loop is neither
+    assert_eq!(sources, vec![0, 4]);
+    assert_eq!(sinks, vec![3, 4]);
+    assert!(graph.source_nodes().any(|ix| ix == isolated));
+    assert!(!graph.source_nodes().any(|ix| ix == looped));
+    assert!(!graph.sink_nodes().any(|ix| ix == looped));
+
+    // A self-loop on the sink removes it from the sinks
+    let sink = graph
+        .node_indices()
+        .find(|&ix| *graph.node(ix) == 3)
+        .unwrap();
+    graph.add_edge("3->3", sink, sink);
+    let mut sinks: Vec<_> = graph.sink_nodes().map(|ix| *graph.node(ix)).collect();
+    sinks.sort();
+    assert_eq!(sinks, vec![4]);
+}
+
+#[test]
+fn test_source_and_sink_nodes_empty_graph() {
+    let graph: VecGraph<i32, ()> = VecGraph::default();
+    assert_eq!(graph.source_nodes().count(), 0);
+    assert_eq!(graph.sink_nodes().count(), 0);
+}
+
+#[test]
+fn test_common_successors_and_predecessors() {
+    let mut graph = create_test_graph();
+    let n: Vec<_> = graph.node_indices().collect();
+
+    // 0 -> {1, 2} and 1 -> {2, 3} share 2; 2 <- {0, 1} and 3 <- {1, 2} share 1
+    assert_eq!(
+        graph.common_successors(n[0], n[1]).collect::<Vec<_>>(),
+        vec![n[2]]
+    );
+    assert_eq!(
+        graph.common_predecessors(n[2], n[3]).collect::<Vec<_>>(),
+        vec![n[1]]
+    );
+    assert_eq!(graph.common_successors(n[2], n[3]).count(), 0);
+    assert_eq!(graph.common_predecessors(n[0], n[1]).count(), 0);
+
+    // Parallel edges do not yield a node twice
+    graph.add_edge("1->2", n[1], n[2]);
+    graph.add_edge("0->3", n[0], n[3]);
+    let mut common: Vec<_> = graph.common_successors(n[0], n[1]).collect();
+    common.sort();
+    assert_eq!(common, vec![n[2], n[3]]);
+
+    let mut own: Vec<_> = graph.common_successors(n[1], n[1]).collect();
+    own.sort();
+    assert_eq!(own, vec![n[2], n[3]]);
+}
+
+#[test]
+fn test_outgoing_targets_and_incoming_sources() {
+    let mut graph = create_test_graph();
+    let n: Vec<_> = graph.node_indices().collect();
+    let loop_edge = graph.add_edge("2->2", n[2], n[2]);
+    graph.add_edge("0->2 again", n[0], n[2]);
+
+    // Same items as the manual lookups, in the same order
+    for node in graph.node_indices() {
+        let manual: Vec<_> = graph
+            .outgoing_edge_indices(node)
+            .map(|e| {
+                let [_, to] = graph.endpoints(e);
+                (e, graph.edge(e), to, graph.node(to))
+            })
+            .collect();
+        assert_eq!(graph.outgoing_targets(node).collect::<Vec<_>>(), manual);
+
+        let manual: Vec<_> = graph
+            .incoming_edge_indices(node)
+            .map(|e| {
+                let [from, _] = graph.endpoints(e);
+                (e, graph.edge(e), from, graph.node(from))
+            })
+            .collect();
+        assert_eq!(graph.incoming_sources(node).collect::<Vec<_>>(), manual);
+    }
+
+    // Parallel edges yield their target once per edge
+    let mut targets: Vec<_> = graph
+        .outgoing_targets(n[0])
+        .map(|(_, &edge, _, &node)| (edge, node))
+        .collect();
+    targets.sort();
+    assert_eq!(targets, vec![("0->1", 1), ("0->2", 2), ("0->2 again", 2)]);
+
+    // A self-loop is its own target and source
+    let (_, _, to, _) = graph
+        .outgoing_targets(n[2])
+        .find(|&(e, ..)| e == loop_edge)
+        .unwrap();
+    let (_, _, from, _) = graph
+        .incoming_sources(n[2])
+        .find(|&(e, ..)| e == loop_edge)
+        .unwrap();
+    assert_eq!((to, from), (n[2], n[2]));
+
+    graph.scope(|ctx| {
+        for tag in ctx.node_indices() {
+            for (edge, _, target, &value) in ctx.outgoing_targets(tag) {
+                assert_eq!(ctx.endpoints(edge), [tag, target]);
+                assert_eq!(*ctx.node(target), value);
+            }
+            for (edge, _, source, _) in ctx.incoming_sources(tag) {
+                assert_eq!(ctx.endpoints(edge), [source, tag]);
+            }
+        }
+    });
+}
+
+#[test]
+fn test_scoped_targets_and_sources_match_direct() {
+    let mut graph = create_test_graph();
+    let n: Vec<_> = graph.node_indices().collect();
+    graph.add_edge("0->0", n[0], n[0]);
+
+    let direct: Vec<Vec<_>> = n
+        .iter()
+        .map(|&node| {
+            let targets = graph.outgoing_targets(node).map(|(_, e, _, t)| (*e, *t));
+            let sources = graph.incoming_sources(node).map(|(_, e, _, s)| (*e, *s));
+            targets.chain(sources).collect()
+        })
+        .collect();
+
+    graph.scope(|ctx| {
+        let scoped: Vec<Vec<_>> = ctx
+            .node_indices()
+            .map(|tag| {
+                let targets = ctx.outgoing_targets(tag).map(|(_, e, _, t)| (*e, *t));
+                let sources = ctx.incoming_sources(tag).map(|(_, e, _, s)| (*e, *s));
+                targets.chain(sources).collect()
+            })
+            .collect();
+        assert_eq!(scoped, direct);
+    });
+}
+
+#[test]
+fn test_endpoint_data() {
+    let mut graph = create_test_graph();
+
+    for edge_ix in graph.edge_indices() {
+        let [from, to] = graph.endpoints(edge_ix);
+        let (source, target) = graph.endpoint_data(edge_ix);
+        assert_eq!((source, target), (graph.node(from), graph.node(to)));
+        assert_eq!(*graph.edge(edge_ix), format!("{}->{}", source, target));
+    }
+
+    // Both references point to the same node for a self-loop
+    let node = graph.node_indices().next().unwrap();
+    let self_loop = graph.add_edge("self", node, node);
+    let (source, target) = graph.endpoint_data(self_loop);
+    assert!(std::ptr::eq(source, target));
+    assert!(std::ptr::eq(source, graph.node(node)));
+
+    graph.scope(|ctx| {
+        for edge_ix in ctx.edge_indices() {
+            let [from, to] = ctx.endpoints(edge_ix);
+            assert_eq!(ctx.endpoint_data(edge_ix), (ctx.node(from), ctx.node(to)));
+        }
+    });
+}
+
+#[test]
+fn test_try_node_and_try_edge() {
+    use gotgraph::vec_graph::{EdgeIx, NodeIx};
+
+    let graph = create_test_graph();
+
+    for node_ix in graph.node_indices() {
+        assert_eq!(graph.try_node(node_ix), Ok(graph.node(node_ix)));
+    }
+    for edge_ix in graph.edge_indices() {
+        assert_eq!(graph.try_edge(edge_ix), Ok(graph.edge(edge_ix)));
+    }
+
+    let bad_node = NodeIx::try_from(graph.len_nodes()).unwrap();
+    let err = graph.try_node(bad_node).unwrap_err();
+    assert_eq!(err.index(), bad_node);
+    assert_eq!(
+        err.to_string(),
+        format!("index {:?} does not exist in the graph", bad_node)
+    );
+
+    let bad_edge = EdgeIx::try_from(1000).unwrap();
+    assert_eq!(graph.try_edge(bad_edge).unwrap_err().index(), bad_edge);
+
+    // Through a reference, and inside a scope where every tag is valid
+    assert!(Graph::try_node(&&graph, bad_node).is_err());
+    graph.scope(|ctx| {
+        for node_ix in ctx.node_indices() {
+            assert_eq!(ctx.try_node(node_ix), Ok(ctx.node(node_ix)));
+        }
+    });
+}
+
+#[test]
+fn test_descendants_and_ancestors() {
+    let mut graph = create_test_graph();
+    let nodes: Vec<_> = graph.node_indices().collect();
+    let payloads = |graph: &VecGraph<i32, &str>, it: &mut dyn Iterator<Item = _>| {
+        let mut values: Vec<_> = it.map(|ix| *graph.node(ix)).collect();
+        values.sort();
+        values
+    };
+
+    // Node 0 is the only source and node 3 the only sink
+    assert_eq!(
+        payloads(&graph, &mut graph.descendants(nodes[0])),
+        vec![1, 2, 3]
+    );
+    assert_eq!(payloads(&graph, &mut graph.descendants(nodes[2])), vec![3]);
+    assert_eq!(graph.descendants(nodes[3]).count(), 0);
+    assert_eq!(
+        payloads(&graph, &mut graph.ancestors(nodes[3])),
+        vec![0, 1, 2]
+    );
+    assert_eq!(payloads(&graph, &mut graph.ancestors(nodes[1])), vec![0]);
+    assert_eq!(graph.ancestors(nodes[0]).count(), 0);
+
+    // Closing a cycle includes the start node, still without repeats
+    graph.add_edge("3->1", nodes[3], nodes[1]);
+    assert_eq!(
+        payloads(&graph, &mut graph.descendants(nodes[1])),
+        vec![1, 2, 3]
+    );
+    assert_eq!(
+        payloads(&graph, &mut graph.ancestors(nodes[2])),
+        vec![0, 1, 2, 3]
+    );
+
+    graph.scope(|ctx| {
+        let start = ctx.node_indices().next().unwrap();
+        assert_eq!(ctx.descendants(start).count(), 3);
+    });
+}
+
+#[test]
+fn test_node_and_edge_indices_where() {
+    let mut graph = create_test_graph();
+
+    let even: Vec<_> = graph
+        .node_indices_where(|&n| n % 2 == 0)
+        .map(|ix| *graph.node(ix))
+        .collect();
+    assert_eq!(even, vec![0, 2]);
+    assert_eq!(graph.node_indices_where(|&n| n > 10).count(), 0);
+
+    let from_one: Vec<_> = graph
+        .edge_indices_where(|e| e.starts_with('1'))
+        .map(|ix| *graph.edge(ix))
+        .collect();
+    assert_eq!(from_one, vec!["1->2", "1->3"]);
+
+    // The selected indices can be used to mutate the graph afterwards
+    let odd: Vec<_> = graph.node_indices_where(|&n| n % 2 == 1).collect();
+    for ix in odd {
+        *graph.node_mut(ix) *= 10;
+    }
+    assert_eq!(
+        graph.nodes().copied().collect::<Vec<_>>(),
+        vec![0, 10, 2, 30]
+    );
+
+    graph.scope(|ctx| {
+        let large: Vec<_> = ctx.node_indices_where(|&n| n >= 10).collect();
+        assert_eq!(large.len(), 2);
+        assert!(large.iter().all(|&ix| *ctx.node(ix) >= 10));
+        assert_eq!(ctx.edge_indices_where(|e| e.ends_with('3')).count(), 2);
+    });
+}
+
+#[test]
+fn test_node_indices_sorted_by_key() {
+    let mut graph: VecGraph<(&str, u32), ()> = VecGraph::default();
+    let c = graph.add_node(("c", 2));
+    let a = graph.add_node(("a", 1));
+    let d = graph.add_node(("d", 1));
+    let b = graph.add_node(("b", 2));
+
+    assert_eq!(graph.node_indices_sorted_by_key(|n| n.0), vec![a, b, c, d]);
+    // Equal keys keep the order of node_indices
+    assert_eq!(graph.node_indices_sorted_by_key(|n| n.1), vec![a, d, c, b]);
+    assert_eq!(
+        graph.node_indices_sorted_by_key(|n| core::cmp::Reverse(n.0)),
+        vec![d, c, b, a]
+    );
+
+    graph.scope(|ctx| {
+        let names: Vec<_> = ctx
+            .node_indices_sorted_by_key(|n| n.0)
+            .into_iter()
+            .map(|ix| ctx.node(ix).0)
+            .collect();
+        assert_eq!(names, vec!["a", "b", "c", "d"]);
+    });
+
+    let empty: VecGraph<u32, ()> = VecGraph::default();
+    assert!(empty.node_indices_sorted_by_key(|&n| n).is_empty());
+}
+
+#[test]
+fn test_edge_triples_match_endpoints() {
+    let graph = create_test_graph();
+
+    let triples: Vec<_> = graph.edge_triples().collect();
+    assert_eq!(triples.len(), graph.len_edges());
+    for (ix, endpoints, edge) in triples {
+        assert_eq!(endpoints, graph.endpoints(ix));
+        assert_eq!(edge, graph.edge(ix));
+        let [from, to] = endpoints;
+        assert_eq!(*edge, format!("{}->{}", graph.node(from), graph.node(to)));
+    }
+
+    graph.scope(|ctx| {
+        assert_eq!(ctx.edge_triples().count(), ctx.len_edges());
+        for (ix, endpoints, edge) in ctx.edge_triples() {
+            assert_eq!(endpoints, ctx.endpoints(ix));
+            assert_eq!(edge, ctx.edge(ix));
+        }
+    });
+}
@@ -0,0 +1,258 @@
+use gotgraph::algo::tarjan;
+use gotgraph::prelude::*;
+use gotgraph::Mapping;
+
+/// Create a graph with two SCCs: 0 <-> 1 and 2 <-> 3, connected by 1 -> 2
+fn create_two_scc_graph() -> VecGraph<i32, &'static str> {
+    let mut graph = VecGraph::default();
+
+    graph.scope_mut(|mut ctx| {
+        let n0 = ctx.add_node(0);
+        let n1 = ctx.add_node(1);
+        let n2 = ctx.add_node(2);
+        let n3 = ctx.add_node(3);
+
+        ctx.add_edge("0->1", n0, n1);
+        ctx.add_edge("1->0", n1, n0);
+        ctx.add_edge("2->3", n2, n3);
+        ctx.add_edge("3->2", n3, n2);
+        ctx.add_edge("1->2", n1, n2);
+    });
+
+    graph
+}
+
+#[test]
+fn test_scc_ids_carried_into_mutation_scope() {
+    let mut graph = create_two_scc_graph();
+
+    let scc_ids = graph.scope(|ctx| {
+        let mut ids = ctx.init_node_map(|_, _| usize::MAX);
+        for (id, scc) in tarjan(ctx).enumerate() {
+            for &tag in scc.iter() {
+                ids[tag] = id;
+            }
+        }
+        ids.into_raw(ctx)
+    });
+
+    graph.scope_mut(|mut ctx| {
+        let scc_ids = ctx.adopt_node_map(scc_ids);
+        let tags: Vec<_> = ctx.node_indices().collect();
+        for tag in tags {
+            *ctx.node_mut(tag) = scc_ids[tag] as i32;
+        }
+    });
+
+    let ids: Vec<_> = graph.nodes().copied().collect();
+    assert_eq!(ids[0], ids[1]);
+    assert_eq!(ids[2], ids[3]);
+    assert_ne!(ids[0], ids[2]);
+}
+
+#[test]
+fn test_raw_map_is_indexable_by_raw_indices() {
+    let graph = create_two_scc_graph();
+
+    let doubled = graph.scope(|ctx| ctx.init_node_map(|_, &value| value * 2).into_raw(ctx));
+
+    for (ix, &value) in graph.node_pairs() {
+        assert_eq!(doubled[ix], value * 2);
+    }
+    let mut values: Vec<_> = doubled.map(|v| v + 1).into_iter().collect();
+    values.sort();
+    assert_eq!(values, vec![1, 3, 5, 7]);
+}
+
+#[test]
+fn test_edge_map_survives_node_payload_mutation() {
+    let mut graph = create_two_scc_graph();
+
+    let lengths = graph.scope(|ctx| ctx.init_edge_map(|_, edge| edge.len()).into_raw(ctx));
+
+    graph.scope_mut(|mut ctx| {
+        let lengths = ctx.adopt_edge_map(lengths);
+        let tags: Vec<_> = ctx.edge_indices().collect();
+        for tag in tags {
+            assert_eq!(lengths[tag], ctx.edge(tag).len());
+            ctx.add_node(lengths[tag] as i32);
+        }
+    });
+
+    assert_eq!(graph.len_nodes(), 9);
+}
+
+#[test]
+#[should_panic(expected = "Node map was created for 4 nodes, but the graph now has 3 nodes")]
+fn test_adopt_node_map_panics_after_removal() {
+    let mut graph = create_two_scc_graph();
+
+    let raw = graph.scope(|ctx| ctx.init_node_map(|_, &value| value).into_raw(ctx));
+
+    let first = graph.node_indices().next().unwrap();
+    graph.remove_node(first);
+
+    graph.scope(|ctx| {
+        ctx.adopt_node_map(raw);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Edge map was created for 5 edges, but the graph now has 4 edges")]
+fn test_adopt_edge_map_panics_after_removal() {
+    let mut graph = create_two_scc_graph();
+
+    let raw = graph.scope(|ctx| ctx.init_edge_map(|_, _| ()).into_raw(ctx));
+
+    let first = graph.edge_indices().next().unwrap();
+    graph.remove_edge(first);
+
+    graph.scope(|ctx| {
+        ctx.adopt_edge_map(raw);
+    });
+}
+
+#[test]
+fn test_snapshot_indices_used_after_scope() {
+    let mut graph = create_two_scc_graph();
+
+    let (nodes, edges) = graph.scope(|ctx| {
+        let (nodes, edges) = ctx.snapshot();
+        assert_eq!(nodes, ctx.snapshot_nodes());
+        assert_eq!(edges, ctx.snapshot_edges());
+        let tags: Vec<_> = ctx.node_indices().map(|tag| tag.inner()).collect();
+        assert_eq!(nodes, tags);
+        (nodes, edges)
+    });
+
+    assert_eq!(nodes.len(), 4);
+    assert_eq!(edges.len(), 5);
+    let values: Vec<_> = nodes.iter().map(|&ix| *graph.node(ix)).collect();
+    assert_eq!(values, vec![0, 1, 2, 3]);
+    for &edge in &edges {
+        let (from, to) = graph.endpoint_data(edge);
+        assert_eq!(*graph.edge(edge), format!("{}->{}", from, to));
+    }
+
+    // Snapshots taken in a mutable scope see the nodes added in it
+    let added = graph.scope_mut(|mut ctx| {
+        let n4 = ctx.add_node(4);
+        let n0 = ctx.node_indices().next().unwrap();
+        ctx.add_edge("4->0", n4, n0);
+        ctx.snapshot()
+    });
+    assert_eq!(added.0.len(), 5);
+    assert_eq!(*graph.node(added.0[4]), 4);
+    assert_eq!(*graph.edge(added.1[5]), "4->0");
+}
+
+#[test]
+fn test_frozen_indices_validity_after_mutation() {
+    let mut graph = create_two_scc_graph();
+    let snapshot = graph.scope(|ctx| ctx.freeze());
+    let nodes: Vec<_> = snapshot.nodes().collect();
+    let edges: Vec<_> = snapshot.edges().collect();
+    assert_eq!((nodes.len(), edges.len()), (4, 5));
+    assert!(nodes.iter().all(|&ix| snapshot.is_node_valid(&graph, ix)));
+    assert!(edges.iter().all(|&ix| snapshot.is_edge_valid(&graph, ix)));
+    assert_eq!(snapshot.edge(&graph, edges[4]), Some(&"1->2"));
+
+    // Additions leave the existing indices alone
+    let added = graph.add_node(7);
+    let added_edge = graph.add_edge("7->0", added, nodes[0]);
+    assert!(nodes.iter().all(|&ix| snapshot.is_node_valid(&graph, ix)));
+    assert_eq!(snapshot.valid_edges(&graph).count(), 5);
+    assert!(!snapshot.is_node_valid(&graph, added));
+    assert!(!snapshot.is_edge_valid(&graph, added_edge));
+
+    // Removing the last node frees its slot for the next addition
+    graph.remove_node(added);
+    assert!(!snapshot.is_current(&graph));
+    assert_eq!(snapshot.valid_nodes(&graph).count(), 0);
+    assert_eq!(snapshot.valid_edges(&graph).count(), 0);
+    assert_eq!(snapshot.endpoints(edges[0]), Some([nodes[0], nodes[1]]));
+}
+
+#[test]
+fn test_frozen_indices_after_removing_inner_node() {
+    let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    let bc = graph.add_edge((), b, c);
+    let snapshot = graph.scope(|ctx| ctx.freeze());
+
+    // `C` moves into the slot of `A`, which still exists
+    graph.remove_node(a);
+    assert!(graph.exists_node_index(a));
+    assert_eq!(*graph.node(a), "C");
+    assert!(!snapshot.is_node_valid(&graph, a));
+    assert_eq!(snapshot.node(&graph, a), None);
+    assert_eq!(snapshot.node(&graph, b), None);
+    assert!(!snapshot.is_node_valid(&graph, c));
+    assert_eq!(snapshot.valid_nodes(&graph).count(), 0);
+    assert_eq!(snapshot.edge(&graph, bc), None);
+
+    // A fresh snapshot sees the renumbered graph
+    let snapshot = graph.scope(|ctx| ctx.freeze());
+    assert_eq!(snapshot.node(&graph, a), Some(&"C"));
+}
+
+#[test]
+fn test_btreemap_iterates_in_sorted_order() {
+    let mut graph: VecGraph<i32, &str> = VecGraph::default();
+    let n: Vec<_> = (0..6).map(|i| graph.add_node(i * 10)).collect();
+    graph.add_edge("a", n[0], n[1]);
+    graph.add_edge("b", n[4], n[2]);
+    graph.add_edge("c", n[5], n[3]);
+    graph.remove_node(n[1]);
+
+    graph.scope(|ctx| {
+        let mut visited = Vec::new();
+        let values = ctx.init_node_btreemap(|tag, &value| {
+            visited.push(tag);
+            value + 1
+        });
+        assert_eq!(visited.len(), ctx.len_nodes());
+
+        let keys: Vec<_> = values.keys().collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(keys, sorted);
+        assert_eq!(
+            values.iter().copied().collect::<Vec<_>>(),
+            keys.iter()
+                .map(|&tag| *ctx.node(tag) + 1)
+                .collect::<Vec<_>>()
+        );
+
+        let edges = ctx.init_edge_btreemap(|_, &name| name);
+        let keys: Vec<_> = edges.pairs().map(|(tag, _)| tag).collect();
+        assert!(keys.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(keys.len(), ctx.len_edges());
+    });
+}
+
+#[test]
+fn test_btreemap_indexed_by_tags() {
+    let mut graph = create_two_scc_graph();
+
+    graph.scope_mut(|mut ctx| {
+        let tags: Vec<_> = ctx.node_indices().collect();
+        let mut out_degree = ctx.init_node_btreemap(|_, _| 0);
+        for edge in ctx.edge_indices() {
+            let [from, _] = ctx.endpoints(edge);
+            out_degree[from] += 1;
+        }
+        assert_eq!(
+            tags.iter().map(|&tag| out_degree[tag]).collect::<Vec<_>>(),
+            vec![1, 2, 1, 1]
+        );
+
+        for &tag in &tags {
+            *ctx.node_mut(tag) = out_degree[tag];
+        }
+    });
+
+    assert_eq!(graph.nodes().copied().collect::<Vec<_>>(), vec![1, 2, 1, 1]);
+}
@@ -0,0 +1,379 @@
+use gotgraph::prelude::*;
+use gotgraph::undirected::UnVecGraph;
+use gotgraph::vec_graph::{EdgeIx, NodeIx};
+
+/// Returns the payloads of the other endpoints of a node's edges, sorted
+fn neighbour_payloads(
+    graph: &VecGraph<&'static str, i32>,
+    node: &str,
+) -> (Vec<&'static str>, Vec<&'static str>) {
+    let ix = graph.node_pairs().find(|(_, &n)| n == node).unwrap().0;
+    let mut outgoing: Vec<_> = graph
+        .outgoing_edge_indices(ix)
+        .map(|e| *graph.node(graph.endpoints(e)[1]))
+        .collect();
+    let mut incoming: Vec<_> = graph
+        .incoming_edge_indices(ix)
+        .map(|e| *graph.node(graph.endpoints(e)[0]))
+        .collect();
+    outgoing.sort();
+    incoming.sort();
+    (outgoing, incoming)
+}
+
+#[test]
+fn test_contract_edge_merges_external_edges() {
+    let mut graph: VecGraph<&str, i32> = VecGraph::default();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    let d = graph.add_node("D");
+    let e = graph.add_node("E");
+
+    let ab = graph.add_edge(0, a, b);
+    graph.add_edge(1, c, a);
+    graph.add_edge(2, b, d);
+    graph.add_edge(3, e, b);
+    graph.add_edge(4, a, e);
+
+    let (a, removed) = graph.contract_edge(ab);
+    assert_eq!(removed, "B");
+    assert_eq!(*graph.node(a), "A");
+    assert_eq!(graph.len_nodes(), 4);
+    assert_eq!(graph.len_edges(), 4);
+
+    let (outgoing, incoming) = neighbour_payloads(&graph, "A");
+    assert_eq!(outgoing, vec!["D", "E"]);
+    assert_eq!(incoming, vec!["C", "E"]);
+
+    let mut payloads: Vec<_> = graph.edges().copied().collect();
+    payloads.sort();
+    assert_eq!(payloads, vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn test_contract_edge_preserves_self_loops() {
+    let mut graph: VecGraph<&str, i32> = VecGraph::default();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+
+    let ab = graph.add_edge(0, a, b);
+    graph.add_edge(1, a, b); // parallel edge becomes a self-loop
+    graph.add_edge(2, b, a); // reverse edge becomes a self-loop
+    graph.add_edge(3, b, b); // self-loop on the removed node stays a self-loop
+    graph.add_edge(4, a, a);
+
+    let (a, removed) = graph.contract_edge(ab);
+    assert_eq!(removed, "B");
+    assert_eq!(graph.len_nodes(), 1);
+    assert_eq!(graph.len_edges(), 4);
+
+    for e in graph.edge_indices() {
+        assert_eq!(graph.endpoints(e), [a, a]);
+    }
+    assert_eq!(graph.out_degree(a), 4);
+    assert_eq!(graph.in_degree(a), 4);
+}
+
+#[test]
+fn test_contract_edge_degree_is_union() {
+    let mut graph: VecGraph<&str, i32> = VecGraph::default();
+    let nodes: Vec<_> = ["A", "B", "C", "D", "E", "F"]
+        .iter()
+        .map(|&n| graph.add_node(n))
+        .collect();
+    let [a, b] = [nodes[0], nodes[1]];
+    let ab = graph.add_edge(0, a, b);
+    for (i, &other) in nodes[2..].iter().enumerate() {
+        graph.add_edge(10 + i as i32, a, other);
+        graph.add_edge(20 + i as i32, other, b);
+    }
+    let external = graph.degree(a) + graph.degree(b) - 2;
+
+    let (a, _) = graph.contract_edge(ab);
+    assert_eq!(graph.degree(a), external);
+}
+
+#[test]
+fn test_contract_edge_undirected() {
+    let mut graph: UnVecGraph<&str, ()> = UnVecGraph::default();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    let ab = graph.add_edge((), a, b);
+    graph.add_edge((), c, b);
+
+    let (a, removed) = graph.contract_edge(ab);
+    assert_eq!(removed, "B");
+    assert_eq!(*graph.node(a), "A");
+    let c = graph.node_pairs().find(|(_, &n)| n == "C").unwrap().0;
+    let e = graph.outgoing_edge_indices(a).next().unwrap();
+    assert_eq!(graph.endpoints(e), [a, c]);
+}
+
+#[test]
+#[should_panic(expected = "Cannot contract self-loop")]
+fn test_contract_self_loop_panics() {
+    let mut graph: VecGraph<&str, i32> = VecGraph::default();
+    let a = graph.add_node("A");
+    let aa = graph.add_edge(0, a, a);
+    graph.contract_edge(aa);
+}
+
+#[test]
+fn test_merge_nodes_combines_external_degree() {
+    let mut graph: VecGraph<&str, i32> = VecGraph::default();
+    let k = graph.add_node("K");
+    let m1 = graph.add_node("M1");
+    let m2 = graph.add_node("M2");
+    let m3 = graph.add_node("M3");
+    let x = graph.add_node("X");
+    let y = graph.add_node("Y");
+
+    // External edges
+    graph.add_edge(0, k, x);
+    graph.add_edge(1, m1, x);
+    graph.add_edge(2, y, m2);
+    graph.add_edge(3, m3, y);
+    graph.add_edge(4, x, m3);
+    graph.add_edge(5, x, y);
+    // Edges inside the merged group
+    graph.add_edge(6, k, m1);
+    graph.add_edge(7, m2, m3);
+    graph.add_edge(8, m3, m3);
+
+    let group = [k, m1, m2, m3];
+    let external = graph
+        .edge_triples()
+        .filter(|(_, [from, to], _)| group.contains(from) != group.contains(to))
+        .count();
+    assert_eq!(external, 5);
+
+    let (k, removed) = graph.merge_nodes(k, [m2, m1, m3]);
+    assert_eq!(removed, vec!["M2", "M1", "M3"]);
+    assert_eq!(graph.len_nodes(), 3);
+    assert_eq!(graph.len_edges(), 9);
+    assert_eq!(*graph.node(k), "K");
+
+    let loops = graph.self_loop_edges().count();
+    assert_eq!(loops, 3);
+    let degree = graph.outgoing_edge_indices(k).count() + graph.incoming_edge_indices(k).count();
+    assert_eq!(degree, external + 2 * loops);
+
+    let (outgoing, incoming) = neighbour_payloads(&graph, "K");
+    assert_eq!(outgoing, vec!["K", "K", "K", "X", "X", "Y"]);
+    assert_eq!(incoming, vec!["K", "K", "K", "X", "Y"]);
+
+    let mut payloads: Vec<_> = graph.edges().copied().collect();
+    payloads.sort();
+    assert_eq!(payloads, (0..9).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_merge_nodes_reports_renumbered_keep() {
+    let mut graph: VecGraph<&str, i32> = VecGraph::default();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    let d = graph.add_node("D");
+    graph.add_edge(0, a, d);
+    graph.add_edge(1, d, b);
+
+    // Removing `A` moves the last node into its slot
+    let (d, removed) = graph.merge_nodes(d, [a]);
+    assert_eq!(removed, vec!["A"]);
+    assert_eq!(*graph.node(d), "D");
+    assert_eq!(*graph.node(c), "C");
+    assert_eq!(graph.out_degree(d), 2);
+    assert_eq!(graph.in_degree(d), 1);
+    assert!(graph.find_edge(d, b).is_some());
+
+    let dc = graph.add_edge(2, d, c);
+    let (d, removed) = graph.contract_edge(dc);
+    assert_eq!(removed, "C");
+    assert_eq!(*graph.node(d), "D");
+    assert_eq!(graph.len_nodes(), 2);
+}
+
+#[test]
+#[should_panic(expected = "into itself")]
+fn test_merge_node_into_itself_panics() {
+    let mut graph: VecGraph<(), ()> = VecGraph::default();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    graph.merge_nodes(a, [b, a]);
+}
+
+/// Forwards to a `VecGraph` but keeps the provided `merge_nodes`
+struct Plain(VecGraph<&'static str, i32>);
+
+impl Graph for Plain {
+    type Node = &'static str;
+    type Edge = i32;
+    type NodeIx = NodeIx;
+    type EdgeIx = EdgeIx;
+
+    fn exists_node_index(&self, ix: NodeIx) -> bool {
+        self.0.exists_node_index(ix)
+    }
+
+    fn exists_edge_index(&self, ix: EdgeIx) -> bool {
+        self.0.exists_edge_index(ix)
+    }
+
+    fn node_indices(&self) -> impl Iterator<Item = NodeIx> {
+        self.0.node_indices()
+    }
+
+    fn edge_indices(&self) -> impl Iterator<Item = EdgeIx> {
+        self.0.edge_indices()
+    }
+
+    unsafe fn outgoing_edge_pairs_unchecked(
+        &self,
+        tag: NodeIx,
+    ) -> impl Iterator<Item = (EdgeIx, &i32)> {
+        self.0.outgoing_edge_pairs_unchecked(tag)
+    }
+
+    unsafe fn incoming_edge_pairs_unchecked(
+        &self,
+        tag: NodeIx,
+    ) -> impl Iterator<Item = (EdgeIx, &i32)> {
+        self.0.incoming_edge_pairs_unchecked(tag)
+    }
+
+    unsafe fn node_unchecked(&self, tag: NodeIx) -> &&'static str {
+        self.0.node_unchecked(tag)
+    }
+
+    unsafe fn edge_unchecked(&self, tag: EdgeIx) -> &i32 {
+        self.0.edge_unchecked(tag)
+    }
+
+    unsafe fn endpoints_unchecked(&self, ix: EdgeIx) -> [NodeIx; 2] {
+        self.0.endpoints_unchecked(ix)
+    }
+}
+
+impl GraphMut for Plain {
+    unsafe fn node_unchecked_mut(&mut self, tag: NodeIx) -> &mut &'static str {
+        self.0.node_unchecked_mut(tag)
+    }
+
+    unsafe fn edge_unchecked_mut(&mut self, tag: EdgeIx) -> &mut i32 {
+        self.0.edge_unchecked_mut(tag)
+    }
+
+    fn get_node_disjoint_mut<const K: usize>(
+        &mut self,
+        ixs: [NodeIx; K],
+    ) -> Option<[&mut &'static str; K]> {
+        self.0.get_node_disjoint_mut(ixs)
+    }
+
+    fn get_edge_disjoint_mut<const K: usize>(&mut self, ixs: [EdgeIx; K]) -> Option<[&mut i32; K]> {
+        self.0.get_edge_disjoint_mut(ixs)
+    }
+
+    unsafe fn outgoing_edge_pairs_unchecked_mut(
+        &mut self,
+        tag: NodeIx,
+    ) -> impl Iterator<Item = (EdgeIx, &mut i32)> {
+        self.0.outgoing_edge_pairs_unchecked_mut(tag)
+    }
+
+    unsafe fn incoming_edge_pairs_unchecked_mut(
+        &mut self,
+        tag: NodeIx,
+    ) -> impl Iterator<Item = (EdgeIx, &mut i32)> {
+        self.0.incoming_edge_pairs_unchecked_mut(tag)
+    }
+
+    unsafe fn connecting_edge_pairs_unchecked_mut(
+        &mut self,
+        tag: NodeIx,
+    ) -> impl Iterator<Item = (EdgeIx, &mut i32)> {
+        self.0.connecting_edge_pairs_unchecked_mut(tag)
+    }
+
+    unsafe fn reverse_edge_unchecked(&mut self, edge_ix: EdgeIx, new_from: NodeIx, new_to: NodeIx) {
+        self.0.reverse_edge_unchecked(edge_ix, new_from, new_to)
+    }
+}
+
+impl GraphUpdate for Plain {
+    fn add_node(&mut self, node: &'static str) -> NodeIx {
+        self.0.add_node(node)
+    }
+
+    fn add_node_with(&mut self, f: impl FnOnce(NodeIx) -> &'static str) -> NodeIx {
+        self.0.add_node_with(f)
+    }
+
+    fn add_edge(&mut self, edge: i32, from: NodeIx, to: NodeIx) -> EdgeIx {
+        self.0.add_edge(edge, from, to)
+    }
+
+    fn add_edge_with(&mut self, from: NodeIx, to: NodeIx, f: impl FnOnce(EdgeIx) -> i32) -> EdgeIx {
+        self.0.add_edge_with(from, to, f)
+    }
+}
+
+impl GraphRemoveEdge for Plain {
+    unsafe fn remove_edge_unchecked(&mut self, ix: EdgeIx) -> i32 {
+        self.0.remove_edge_unchecked(ix)
+    }
+}
+
+impl GraphRemove for Plain {
+    unsafe fn remove_node_unchecked(&mut self, ix: NodeIx) -> &'static str {
+        self.0.remove_node_unchecked(ix)
+    }
+}
+
+#[test]
+fn test_provided_merge_nodes_matches_vec_graph() {
+    let mut graph: VecGraph<&str, i32> = VecGraph::default();
+    let [a, b, c, d, e, f] = ["A", "B", "C", "D", "E", "F"].map(|n| graph.add_node(n));
+    graph.add_edge(0, a, f);
+    graph.add_edge(1, f, b);
+    graph.add_edge(2, e, c);
+    graph.add_edge(3, c, d);
+    graph.add_edge(4, b, e);
+    graph.add_edge(5, e, e);
+    graph.add_edge(6, d, a);
+
+    // Removing `A` moves `F`, which is merged later, into its slot, and removing `B`
+    // then moves `E`, the node kept
+    let mut plain = Plain(graph.clone());
+    let (e_plain, removed_plain) = plain.merge_nodes(e, [a, f, b]);
+    let (e, removed) = graph.merge_nodes(e, [a, f, b]);
+    assert_eq!(removed_plain, vec!["A", "F", "B"]);
+    assert_eq!(removed_plain, removed);
+    assert_eq!(*plain.node(e_plain), "E");
+    assert_eq!(*graph.node(e), "E");
+
+    let names = |g: &VecGraph<&'static str, i32>| {
+        let mut triples: Vec<_> = g
+            .edge_triples()
+            .map(|(_, [from, to], &w)| (*g.node(from), *g.node(to), w))
+            .collect();
+        triples.sort();
+        triples
+    };
+    assert_eq!(names(&plain.0), names(&graph));
+    assert_eq!(
+        names(&graph),
+        vec![
+            ("C", "D", 3),
+            ("D", "E", 6),
+            ("E", "C", 2),
+            ("E", "E", 0),
+            ("E", "E", 1),
+            ("E", "E", 4),
+            ("E", "E", 5),
+        ]
+    );
+    assert_eq!(plain.len_nodes(), 3);
+}
@@ -0,0 +1,103 @@
+use gotgraph::algo::{find_cycle, has_cycle};
+use gotgraph::prelude::*;
+
+/// Asserts that `cycle` is a non-empty sequence of edges chaining head-to-tail
+fn assert_chains<G: Graph>(graph: &G, cycle: &[G::EdgeIx]) {
+    assert!(!cycle.is_empty());
+    for (i, &edge) in cycle.iter().enumerate() {
+        let next = cycle[(i + 1) % cycle.len()];
+        assert_eq!(graph.endpoints(edge)[1], graph.endpoints(next)[0]);
+    }
+}
+
+#[test]
+fn test_dag_has_no_cycle() {
+    let mut graph: VecGraph<(), ()> = VecGraph::default();
+    let nodes: Vec<_> = (0..6).map(|_| graph.add_node(())).collect();
+    for i in 0..6 {
+        for j in i + 1..6 {
+            graph.add_edge((), nodes[i], nodes[j]);
+        }
+    }
+    // Parallel edges do not create a cycle
+    graph.add_edge((), nodes[0], nodes[1]);
+
+    assert_eq!(find_cycle(&graph), None);
+    assert!(!has_cycle(&graph));
+}
+
+#[test]
+fn test_empty_graph() {
+    let graph: VecGraph<(), ()> = VecGraph::default();
+    assert!(!has_cycle(&graph));
+}
+
+#[test]
+fn test_self_loop() {
+    let mut graph: VecGraph<(), ()> = VecGraph::default();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    graph.add_edge((), a, b);
+    let lp = graph.add_edge((), b, b);
+
+    assert_eq!(find_cycle(&graph), Some(vec![lp]));
+    assert!(has_cycle(&graph));
+}
+
+#[test]
+fn test_long_cycle_chains() {
+    let mut graph: VecGraph<usize, ()> = VecGraph::default();
+    let nodes: Vec<_> = (0..50).map(|i| graph.add_node(i)).collect();
+    for w in nodes.windows(2) {
+        graph.add_edge((), w[0], w[1]);
+    }
+    graph.add_edge((), nodes[49], nodes[0]);
+    // Some chords that do not shorten the search into a different answer type
+    graph.add_edge((), nodes[3], nodes[40]);
+    graph.add_edge((), nodes[10], nodes[20]);
+
+    let cycle = find_cycle(&graph).unwrap();
+    assert_chains(&graph, &cycle);
+}
+
+#[test]
+fn test_cycle_deep_in_second_tree() {
+    let mut graph: VecGraph<usize, ()> = VecGraph::default();
+    let nodes: Vec<_> = (0..20).map(|i| graph.add_node(i)).collect();
+    // First DFS tree: an acyclic chain 0 -> 1 -> ... -> 9
+    for i in 0..9 {
+        graph.add_edge((), nodes[i], nodes[i + 1]);
+    }
+    // Second tree rooted at 10, with a cycle 15 -> 16 -> 17 -> 15 at the bottom
+    for i in 10..17 {
+        graph.add_edge((), nodes[i], nodes[i + 1]);
+    }
+    let back = graph.add_edge((), nodes[17], nodes[15]);
+    // The second tree also points into the first one, which is already finished
+    graph.add_edge((), nodes[12], nodes[5]);
+
+    let cycle = find_cycle(&graph).unwrap();
+    assert_chains(&graph, &cycle);
+    assert_eq!(cycle.len(), 3);
+    assert!(cycle.contains(&back));
+}
+
+#[test]
+fn test_in_scope() {
+    let mut graph: VecGraph<(), ()> = VecGraph::default();
+    graph.scope_mut(|mut ctx| {
+        let a = ctx.add_node(());
+        let b = ctx.add_node(());
+        let ab = ctx.add_edge((), a, b);
+        assert!(!has_cycle(&ctx));
+
+        let ba = ctx.add_edge((), b, a);
+        let cycle = find_cycle(&ctx).unwrap();
+        assert_chains(&ctx, &cycle);
+        assert_eq!(cycle.len(), 2);
+        assert!(cycle.contains(&ab) && cycle.contains(&ba));
+    });
+
+    graph.scope(|ctx| assert!(has_cycle(ctx)));
+    assert!(has_cycle(&&graph));
+}
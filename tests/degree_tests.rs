@@ -0,0 +1,91 @@
+use gotgraph::prelude::*;
+use gotgraph::undirected::UnVecGraph;
+
+/// Build a pseudo-random graph with parallel edges and self-loops
+fn create_random_graph(nodes: usize, edges: usize, mut seed: u64) -> VecGraph<usize, usize> {
+    let mut graph = VecGraph::default();
+    let ixs: Vec<_> = (0..nodes).map(|i| graph.add_node(i)).collect();
+    let mut next = || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) as usize
+    };
+    for i in 0..edges {
+        let from = ixs[next() % nodes];
+        // Every fifth edge is a self-loop
+        let to = if i % 5 == 0 {
+            from
+        } else {
+            ixs[next() % nodes]
+        };
+        graph.add_edge(i, from, to);
+    }
+    graph
+}
+
+#[test]
+fn test_degree_map_matches_per_node_methods() {
+    let graph = create_random_graph(50, 400, 7);
+    let degrees = graph.degree_map();
+
+    let mut total_in = 0;
+    let mut total_out = 0;
+    for node in graph.node_indices() {
+        let (in_degree, out_degree) = degrees[node];
+        assert_eq!(in_degree, graph.in_degree(node));
+        assert_eq!(out_degree, graph.out_degree(node));
+        assert_eq!(in_degree + out_degree, graph.degree(node));
+        total_in += in_degree;
+        total_out += out_degree;
+    }
+    assert_eq!(total_in, graph.len_edges());
+    assert_eq!(total_out, graph.len_edges());
+}
+
+#[test]
+fn test_self_loop_counts_once_each_way() {
+    let mut graph: VecGraph<(), ()> = VecGraph::default();
+    let a = graph.add_node(());
+    graph.add_edge((), a, a);
+
+    assert_eq!(graph.in_degree(a), 1);
+    assert_eq!(graph.out_degree(a), 1);
+    assert_eq!(graph.degree(a), 2);
+    assert_eq!(graph.degree_map()[a], (1, 1));
+}
+
+#[test]
+fn test_degrees_in_scope() {
+    let mut graph = create_random_graph(20, 100, 42);
+    graph.scope_mut(|ctx| {
+        let degrees = ctx.degree_map();
+        for node in ctx.node_indices() {
+            assert_eq!(degrees[node], (ctx.in_degree(node), ctx.out_degree(node)));
+        }
+    });
+}
+
+#[test]
+fn test_degrees_of_undirected_graph() {
+    let mut graph: UnVecGraph<(), ()> = UnVecGraph::default();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    graph.add_edge((), a, b);
+    graph.add_edge((), c, a);
+
+    // Every connecting edge is both incoming and outgoing in the undirected view
+    assert_eq!(graph.out_degree(a), 2);
+    assert_eq!(graph.in_degree(a), 2);
+    assert_eq!(graph.out_degree(b), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_degree_of_invalid_node_panics() {
+    let mut graph: VecGraph<(), ()> = VecGraph::default();
+    let a = graph.add_node(());
+    graph.remove_node(a);
+    graph.degree(a);
+}
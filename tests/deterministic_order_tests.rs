@@ -0,0 +1,199 @@
+use gotgraph::algo::tarjan;
+use gotgraph::prelude::*;
+use gotgraph::Mapping;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hasher};
+
+/// Hasher state with a fixed seed, so that different seeds give different but
+/// reproducible iteration orders.
+#[derive(Clone, Copy)]
+struct Seeded(u64);
+
+struct SeededHasher(u64);
+
+impl BuildHasher for Seeded {
+    type Hasher = SeededHasher;
+
+    fn build_hasher(&self) -> SeededHasher {
+        SeededHasher(self.0.wrapping_mul(0x9e37_79b9_7f4a_7c15) ^ 0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for SeededHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ byte as u64).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0 ^ (self.0 >> 29)
+    }
+}
+
+struct NodeData {
+    name: &'static str,
+    outgoing: Vec<u32>,
+    incoming: Vec<u32>,
+}
+
+/// A graph whose storage iterates in hash order, but whose index iterators follow the
+/// ordering contract by sorting.
+struct HashGraph {
+    nodes: HashMap<u32, NodeData, Seeded>,
+    edges: HashMap<u32, [u32; 2], Seeded>,
+}
+
+impl HashGraph {
+    fn new(seed: u64, names: &[&'static str], edges: &[(u32, u32)]) -> Self {
+        let mut graph = HashGraph {
+            nodes: HashMap::with_hasher(Seeded(seed)),
+            edges: HashMap::with_hasher(Seeded(seed)),
+        };
+        for (i, &name) in names.iter().enumerate() {
+            let data = NodeData {
+                name,
+                outgoing: Vec::new(),
+                incoming: Vec::new(),
+            };
+            graph.nodes.insert(i as u32 * 7, data);
+        }
+        for (i, &(from, to)) in edges.iter().enumerate() {
+            let (from, to) = (from * 7, to * 7);
+            let ix = i as u32 * 3;
+            graph.edges.insert(ix, [from, to]);
+            graph.nodes.get_mut(&from).unwrap().outgoing.push(ix);
+            graph.nodes.get_mut(&to).unwrap().incoming.push(ix);
+        }
+        graph
+    }
+}
+
+impl Graph for HashGraph {
+    type Node = &'static str;
+    type Edge = [u32; 2];
+    type NodeIx = u32;
+    type EdgeIx = u32;
+
+    fn exists_node_index(&self, ix: u32) -> bool {
+        self.nodes.contains_key(&ix)
+    }
+
+    fn exists_edge_index(&self, ix: u32) -> bool {
+        self.edges.contains_key(&ix)
+    }
+
+    fn node_indices(&self) -> impl Iterator<Item = u32> {
+        let mut indices: Vec<_> = self.nodes.keys().copied().collect();
+        indices.sort_unstable();
+        indices.into_iter()
+    }
+
+    fn edge_indices(&self) -> impl Iterator<Item = u32> {
+        let mut indices: Vec<_> = self.edges.keys().copied().collect();
+        indices.sort_unstable();
+        indices.into_iter()
+    }
+
+    unsafe fn outgoing_edge_pairs_unchecked(
+        &self,
+        tag: u32,
+    ) -> impl Iterator<Item = (u32, &[u32; 2])> {
+        self.nodes[&tag]
+            .outgoing
+            .iter()
+            .map(move |ix| (*ix, &self.edges[ix]))
+    }
+
+    unsafe fn incoming_edge_pairs_unchecked(
+        &self,
+        tag: u32,
+    ) -> impl Iterator<Item = (u32, &[u32; 2])> {
+        self.nodes[&tag]
+            .incoming
+            .iter()
+            .map(move |ix| (*ix, &self.edges[ix]))
+    }
+
+    unsafe fn node_unchecked(&self, tag: u32) -> &&'static str {
+        &self.nodes[&tag].name
+    }
+
+    unsafe fn edge_unchecked(&self, tag: u32) -> &[u32; 2] {
+        &self.edges[&tag]
+    }
+
+    unsafe fn endpoints_unchecked(&self, ix: u32) -> [u32; 2] {
+        self.edges[&ix]
+    }
+}
+
+const NAMES: [&str; 8] = ["a", "b", "c", "d", "e", "f", "g", "h"];
+const EDGES: [(u32, u32); 10] = [
+    (0, 1),
+    (1, 2),
+    (2, 0),
+    (2, 3),
+    (3, 4),
+    (4, 3),
+    (5, 6),
+    (6, 5),
+    (6, 4),
+    (7, 7),
+];
+
+fn components(graph: &HashGraph) -> Vec<Vec<&'static str>> {
+    tarjan(graph)
+        .map(|scc| scc.iter().map(|&ix| *graph.node(ix)).collect())
+        .collect()
+}
+
+#[test]
+fn test_hash_storage_order_varies_with_seed() {
+    let orders: Vec<Vec<_>> = (0..8)
+        .map(|seed| {
+            let graph = HashGraph::new(seed, &NAMES, &EDGES);
+            graph.nodes.keys().copied().collect()
+        })
+        .collect();
+    assert!(orders.iter().any(|order| order != &orders[0]));
+}
+
+#[test]
+fn test_tarjan_order_is_stable_across_seeds() {
+    let expected = components(&HashGraph::new(0, &NAMES, &EDGES));
+    assert_eq!(
+        expected,
+        vec![
+            vec!["e", "d"],
+            vec!["c", "b", "a"],
+            vec!["g", "f"],
+            vec!["h"],
+        ]
+    );
+    for seed in 1..32 {
+        let graph = HashGraph::new(seed, &NAMES, &EDGES);
+        assert_eq!(components(&graph), expected, "seed {}", seed);
+    }
+}
+
+#[test]
+fn test_default_maps_follow_index_order() {
+    for seed in 0..32 {
+        let graph = HashGraph::new(seed, &NAMES, &EDGES);
+
+        let nodes = graph.init_node_map(|ix, name| (ix, *name));
+        let expected: Vec<_> = graph.node_pairs().map(|(ix, name)| (ix, *name)).collect();
+        assert_eq!(nodes.iter().copied().collect::<Vec<_>>(), expected);
+        assert_eq!(nodes[14], (14, "c"));
+        let names: Vec<_> = nodes.map(|(_, name)| name).into_iter().collect();
+        assert_eq!(names, NAMES);
+
+        let mut edges = graph.init_edge_map(|ix, _| ix);
+        for value in edges.iter_mut() {
+            *value += 1;
+        }
+        let expected: Vec<_> = graph.edge_indices().map(|ix| ix + 1).collect();
+        assert_eq!(edges.into_iter().collect::<Vec<_>>(), expected);
+    }
+}
@@ -0,0 +1,144 @@
+use gotgraph::algo::{depth_first_search, has_cycle, CycleDetector, DfsVisitor, FinishOrder};
+use gotgraph::prelude::*;
+use gotgraph::vec_graph::{EdgeIx, NodeIx};
+
+#[derive(Debug, PartialEq, Eq)]
+enum Event {
+    Discover(&'static str),
+    Finish(&'static str),
+    Tree(&'static str),
+    Back(&'static str),
+    ForwardOrCross(&'static str),
+}
+
+/// Records every event, naming nodes and edges by their payloads
+struct Recorder<'a> {
+    graph: &'a VecGraph<&'static str, &'static str>,
+    events: Vec<Event>,
+}
+
+impl<'a> DfsVisitor<VecGraph<&'static str, &'static str>> for Recorder<'a> {
+    fn discover_node(&mut self, node: NodeIx) {
+        self.events.push(Event::Discover(self.graph.node(node)));
+    }
+
+    fn finish_node(&mut self, node: NodeIx) {
+        self.events.push(Event::Finish(self.graph.node(node)));
+    }
+
+    fn tree_edge(&mut self, edge: EdgeIx, _from: NodeIx, _to: NodeIx) {
+        self.events.push(Event::Tree(self.graph.edge(edge)));
+    }
+
+    fn back_edge(&mut self, edge: EdgeIx, _from: NodeIx, _to: NodeIx) {
+        self.events.push(Event::Back(self.graph.edge(edge)));
+    }
+
+    fn forward_or_cross_edge(&mut self, edge: EdgeIx, _from: NodeIx, _to: NodeIx) {
+        self.events
+            .push(Event::ForwardOrCross(self.graph.edge(edge)));
+    }
+}
+
+/// a -> b -> c -> a (back), a -> c (forward), d -> c (cross), e -> e (self-loop), and f
+/// on its own. Edges are added in reverse so that outgoing edges are explored in the
+/// order they are listed here.
+fn create_fixture() -> VecGraph<&'static str, &'static str> {
+    let mut graph = VecGraph::default();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let c = graph.add_node("c");
+    let d = graph.add_node("d");
+    let e = graph.add_node("e");
+    graph.add_node("f");
+    graph.add_edge("e->e", e, e);
+    graph.add_edge("d->c", d, c);
+    graph.add_edge("c->a", c, a);
+    graph.add_edge("b->c", b, c);
+    graph.add_edge("a->c", a, c);
+    graph.add_edge("a->b", a, b);
+    graph
+}
+
+#[test]
+fn test_edge_classification() {
+    use Event::*;
+    let graph = create_fixture();
+    let mut recorder = Recorder {
+        graph: &graph,
+        events: Vec::new(),
+    };
+    depth_first_search(&graph, graph.node_indices(), &mut recorder);
+
+    assert_eq!(
+        recorder.events,
+        vec![
+            Discover("a"),
+            Tree("a->b"),
+            Discover("b"),
+            Tree("b->c"),
+            Discover("c"),
+            Back("c->a"),
+            Finish("c"),
+            Finish("b"),
+            ForwardOrCross("a->c"),
+            Finish("a"),
+            Discover("d"),
+            ForwardOrCross("d->c"),
+            Finish("d"),
+            Discover("e"),
+            Back("e->e"),
+            Finish("e"),
+            Discover("f"),
+            Finish("f"),
+        ]
+    );
+}
+
+#[test]
+fn test_multiple_starts_cover_components() {
+    let graph = create_fixture();
+    let [a, _, c, d, e, f] = [0, 1, 2, 3, 4, 5].map(|i| graph.node_indices().nth(i).unwrap());
+
+    // Starting from c reaches a and b; d, e and f are separate
+    let mut finish = FinishOrder::new();
+    depth_first_search(&graph, [c], &mut finish);
+    assert_eq!(finish.order().len(), 3);
+
+    // Repeated and already reached starts are skipped
+    let mut finish = FinishOrder::new();
+    depth_first_search(&graph, [c, a, f, d, f], &mut finish);
+    let mut names: Vec<_> = finish.order().iter().map(|&n| *graph.node(n)).collect();
+    assert_eq!(names.split_off(3), vec!["f", "d"]);
+    names.sort();
+    assert_eq!(names, vec!["a", "b", "c"]);
+
+    let mut detector = CycleDetector::new();
+    depth_first_search(&graph, [f], &mut detector);
+    assert!(!detector.has_cycle());
+    depth_first_search(&graph, [e], &mut detector);
+    assert_eq!(detector.back_edge().map(|e| *graph.edge(e)), Some("e->e"));
+}
+
+#[test]
+fn test_finish_order_topological_order() {
+    let mut graph: VecGraph<usize, ()> = VecGraph::default();
+    let nodes: Vec<_> = (0..7).map(|i| graph.add_node(i)).collect();
+    for (from, to) in [(0, 3), (1, 3), (3, 4), (2, 4), (4, 5), (6, 0), (6, 2)] {
+        graph.add_edge((), nodes[from], nodes[to]);
+    }
+
+    let mut finish = FinishOrder::new();
+    depth_first_search(&graph, graph.node_indices(), &mut finish);
+    let order = finish.into_topological_order();
+    assert_eq!(order.len(), 7);
+    let position = |n| order.iter().position(|&m| m == n).unwrap();
+    for edge in graph.edge_indices() {
+        let [from, to] = graph.endpoints(edge);
+        assert!(position(from) < position(to));
+    }
+
+    assert!(!has_cycle(&graph));
+    graph.add_edge((), nodes[5], nodes[6]);
+    assert!(has_cycle(&graph));
+}
@@ -0,0 +1,134 @@
+use gotgraph::diff::{diff, EdgeKey};
+use gotgraph::prelude::*;
+
+fn build(nodes: &[(&str, i32)], edges: &[(f64, &str, &str)]) -> VecGraph<String, f64> {
+    let mut graph = VecGraph::default();
+    let ixs: Vec<_> = nodes
+        .iter()
+        .map(|&(name, value)| (name, graph.add_node(format!("{name}={value}"))))
+        .collect();
+    let find = |name: &str| ixs.iter().find(|(n, _)| *n == name).unwrap().1;
+    for &(weight, from, to) in edges {
+        graph.add_edge(weight, find(from), find(to));
+    }
+    graph
+}
+
+fn name(node: &str) -> String {
+    node.split('=').next().unwrap().to_string()
+}
+
+fn edge_key(source: &str, target: &str, occurrence: usize) -> EdgeKey<String> {
+    EdgeKey {
+        source: source.to_string(),
+        target: target.to_string(),
+        occurrence,
+    }
+}
+
+#[test]
+fn test_diff_identical_graphs() {
+    let a = build(&[("a", 1), ("b", 2)], &[(1.0, "a", "b")]);
+    let b = build(&[("b", 2), ("a", 1)], &[(1.0, "a", "b")]);
+    let changes = diff(&a, &b, |n| name(n), |x, y| x != y, |x, y| x != y);
+    assert!(changes.is_empty());
+}
+
+#[test]
+fn test_diff_payload_only_changes() {
+    let a = build(&[("a", 1), ("b", 2)], &[(1.0, "a", "b"), (2.0, "b", "a")]);
+    let b = build(&[("a", 1), ("b", 3)], &[(1.5, "a", "b"), (2.0, "b", "a")]);
+    let changes = diff(&a, &b, |n| name(n), |x, y| x != y, |x, y| x != y);
+
+    assert!(changes.added_nodes.is_empty());
+    assert!(changes.removed_nodes.is_empty());
+    assert!(changes.added_edges.is_empty());
+    assert!(changes.removed_edges.is_empty());
+
+    assert_eq!(changes.modified_nodes.len(), 1);
+    let (key, old, new) = &changes.modified_nodes[0];
+    assert_eq!(
+        (key.as_str(), old.as_str(), new.as_str()),
+        ("b", "b=2", "b=3")
+    );
+
+    assert_eq!(
+        changes.modified_edges,
+        vec![(edge_key("a", "b", 0), &1.0, &1.5)]
+    );
+}
+
+#[test]
+fn test_diff_changed_predicate_is_respected() {
+    let a = build(&[("a", 1)], &[(1.0, "a", "a")]);
+    let b = build(&[("a", 2)], &[(1.001, "a", "a")]);
+    let changes = diff(
+        &a,
+        &b,
+        |n| name(n),
+        |_, _| false,
+        |x, y| (x - y).abs() > 0.01,
+    );
+    assert!(changes.is_empty());
+}
+
+#[test]
+fn test_diff_topology_changes() {
+    let a = build(
+        &[("a", 0), ("b", 0), ("c", 0)],
+        &[(1.0, "a", "b"), (2.0, "b", "c")],
+    );
+    let b = build(
+        &[("a", 0), ("b", 0), ("d", 0)],
+        &[(1.0, "a", "b"), (3.0, "b", "d"), (4.0, "d", "a")],
+    );
+    let changes = diff(&a, &b, |n| name(n), |x, y| x != y, |x, y| x != y);
+
+    let names =
+        |nodes: &[(String, &String)]| nodes.iter().map(|(k, _)| k.clone()).collect::<Vec<_>>();
+    assert_eq!(names(&changes.added_nodes), vec!["d"]);
+    assert_eq!(names(&changes.removed_nodes), vec!["c"]);
+    assert!(changes.modified_nodes.is_empty());
+
+    assert_eq!(changes.removed_edges, vec![(edge_key("b", "c", 0), &2.0)]);
+    assert_eq!(
+        changes.added_edges,
+        vec![(edge_key("b", "d", 0), &3.0), (edge_key("d", "a", 0), &4.0)]
+    );
+    assert!(changes.modified_edges.is_empty());
+}
+
+#[test]
+fn test_diff_edge_direction_matters() {
+    let a = build(&[("a", 0), ("b", 0)], &[(1.0, "a", "b")]);
+    let b = build(&[("a", 0), ("b", 0)], &[(1.0, "b", "a")]);
+    let changes = diff(&a, &b, |n| name(n), |x, y| x != y, |x, y| x != y);
+    assert_eq!(changes.removed_edges, vec![(edge_key("a", "b", 0), &1.0)]);
+    assert_eq!(changes.added_edges, vec![(edge_key("b", "a", 0), &1.0)]);
+}
+
+#[test]
+fn test_diff_parallel_edge_count_changes() {
+    let a = build(&[("a", 0), ("b", 0)], &[(1.0, "a", "b"), (2.0, "a", "b")]);
+    let b = build(
+        &[("a", 0), ("b", 0)],
+        &[(1.0, "a", "b"), (2.0, "a", "b"), (5.0, "a", "b")],
+    );
+
+    let grown = diff(&a, &b, |n| name(n), |x, y| x != y, |x, y| x != y);
+    assert_eq!(grown.added_edges, vec![(edge_key("a", "b", 2), &5.0)]);
+    assert!(grown.removed_edges.is_empty());
+    assert!(grown.modified_edges.is_empty());
+
+    let shrunk = diff(&b, &a, |n| name(n), |x, y| x != y, |x, y| x != y);
+    assert_eq!(shrunk.removed_edges, vec![(edge_key("a", "b", 2), &5.0)]);
+    assert!(shrunk.added_edges.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "same key")]
+fn test_diff_duplicate_keys_panic() {
+    let a = build(&[("a", 1), ("a", 2)], &[]);
+    let b = build(&[("a", 1)], &[]);
+    diff(&a, &b, |n| name(n), |x, y| x != y, |x, y| x != y);
+}
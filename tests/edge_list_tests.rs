@@ -0,0 +1,141 @@
+use gotgraph::prelude::*;
+use gotgraph::undirected::UnVecGraph;
+use gotgraph::vec_graph::ParseError;
+
+fn edge_list(graph: &VecGraph<(), ()>) -> Vec<(usize, usize)> {
+    graph
+        .edge_indices()
+        .map(|e| {
+            let [from, to] = graph.endpoints(e);
+            (from.index(), to.index())
+        })
+        .collect()
+}
+
+#[test]
+fn test_parse_with_comments_and_blank_lines() {
+    let input = "# a small graph\n\n0 1\n  1\t2  \n\n# trailing comment\n2 0 # back edge\n4 4\n";
+    let graph = VecGraph::parse_edge_list(input).unwrap();
+
+    // Node 3 is created implicitly because index 4 is used
+    assert_eq!(graph.len_nodes(), 5);
+    assert_eq!(edge_list(&graph), vec![(0, 1), (1, 2), (2, 0), (4, 4)]);
+}
+
+#[test]
+fn test_parse_empty_input() {
+    let graph = VecGraph::parse_edge_list("# nothing here\n\n").unwrap();
+    assert_eq!(graph.len_nodes(), 0);
+    assert_eq!(graph.len_edges(), 0);
+}
+
+#[test]
+fn test_parse_rejects_single_token() {
+    let err = VecGraph::parse_edge_list("0 1\n# comment\n2\n").unwrap_err();
+    assert_eq!(err, ParseError::TokenCount { line: 3, tokens: 1 });
+
+    let err = VecGraph::parse_edge_list("0 1 2\n").unwrap_err();
+    assert_eq!(err, ParseError::TokenCount { line: 1, tokens: 3 });
+}
+
+#[test]
+fn test_parse_rejects_invalid_index() {
+    let err = VecGraph::parse_edge_list("0 1\n1 x\n").unwrap_err();
+    assert_eq!(
+        err,
+        ParseError::InvalidIndex {
+            line: 2,
+            token: "x".to_string()
+        }
+    );
+    assert!(VecGraph::parse_edge_list("-1 0").is_err());
+}
+
+#[test]
+fn test_edge_list_round_trip() {
+    let mut graph: VecGraph<(), ()> = VecGraph::default();
+    let n: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+    graph.add_edge((), n[3], n[0]);
+    graph.add_edge((), n[1], n[2]);
+    graph.add_edge((), n[1], n[2]);
+    graph.add_edge((), n[2], n[2]);
+
+    let text = graph.to_edge_list_string();
+    assert_eq!(text, "3 0\n1 2\n1 2\n2 2\n");
+
+    let parsed = VecGraph::parse_edge_list(&text).unwrap();
+    assert_eq!(parsed.len_nodes(), graph.len_nodes());
+    assert_eq!(edge_list(&parsed), edge_list(&graph));
+    assert_eq!(parsed.to_edge_list_string(), text);
+}
+
+#[test]
+fn test_graph6_single_edge() {
+    let graph = UnVecGraph::from_graph6("A_").unwrap();
+    assert_eq!(graph.len_nodes(), 2);
+    assert_eq!(graph.len_edges(), 1);
+    assert_eq!(graph.to_graph6(), "A_");
+
+    let empty = UnVecGraph::from_graph6(">>graph6<<?\n").unwrap();
+    assert_eq!(empty.len_nodes(), 0);
+    assert_eq!(empty.to_graph6(), "?");
+}
+
+#[test]
+fn test_graph6_petersen_round_trip() {
+    let graph = UnVecGraph::from_graph6("IheA@GUAo").unwrap();
+    assert_eq!(graph.len_nodes(), 10);
+    assert_eq!(graph.len_edges(), 15);
+    for node in graph.node_indices() {
+        assert_eq!(graph.outgoing_edge_indices(node).count(), 3);
+    }
+    assert_eq!(graph.to_graph6(), "IheA@GUAo");
+}
+
+#[test]
+fn test_graph6_large_node_count() {
+    let mut graph: UnVecGraph<(), ()> = UnVecGraph::default();
+    let nodes: Vec<_> = (0..100).map(|_| graph.add_node(())).collect();
+    for pair in nodes.windows(2) {
+        graph.add_edge((), pair[0], pair[1]);
+    }
+
+    let text = graph.to_graph6();
+    assert!(text.starts_with("~?@c"));
+    let parsed = UnVecGraph::from_graph6(&text).unwrap();
+    assert_eq!(parsed.len_nodes(), 100);
+    assert_eq!(parsed.len_edges(), 99);
+    assert_eq!(parsed.to_graph6(), text);
+}
+
+#[test]
+fn test_graph6_skips_loops_and_parallel_edges() {
+    let mut graph: UnVecGraph<(), ()> = UnVecGraph::default();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    graph.add_edge((), a, a);
+    graph.add_edge((), a, b);
+    graph.add_edge((), b, a);
+    assert_eq!(graph.to_graph6(), "A_");
+}
+
+#[test]
+fn test_graph6_malformed() {
+    assert!(matches!(
+        UnVecGraph::from_graph6(""),
+        Err(ParseError::Graph6 { position: 0, .. })
+    ));
+    // Petersen graph with its last byte missing
+    assert!(matches!(
+        UnVecGraph::from_graph6("IheA@GUA"),
+        Err(ParseError::Graph6 { position: 8, .. })
+    ));
+    assert!(matches!(
+        UnVecGraph::from_graph6("A_?"),
+        Err(ParseError::Graph6 { position: 2, .. })
+    ));
+    assert!(matches!(
+        UnVecGraph::from_graph6("A a"),
+        Err(ParseError::Graph6 { position: 1, .. })
+    ));
+}
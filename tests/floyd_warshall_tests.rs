@@ -0,0 +1,117 @@
+use gotgraph::algo::{astar, floyd_warshall};
+use gotgraph::prelude::*;
+use gotgraph::Mapping;
+
+fn weighted_fixture() -> VecGraph<(), u32> {
+    let mut rng_state = 11u64;
+    // Simple LCG for deterministic pseudo-random numbers
+    let mut next_random = || {
+        rng_state = rng_state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (rng_state >> 33) as usize
+    };
+
+    let mut graph = VecGraph::default();
+    let nodes: Vec<_> = (0..15).map(|_| graph.add_node(())).collect();
+    for _ in 0..40 {
+        let from = nodes[next_random() % nodes.len()];
+        let to = nodes[next_random() % nodes.len()];
+        graph.add_edge((next_random() % 20) as u32, from, to);
+    }
+    graph
+}
+
+#[test]
+fn test_matches_repeated_dijkstra() {
+    let graph = weighted_fixture();
+    let dist = floyd_warshall(&graph, |_, &w| w);
+    assert!(!dist.has_negative_cycle());
+
+    for from in graph.node_indices() {
+        for to in graph.node_indices() {
+            let expected = astar(&graph, from, to, |_, &w| w, |_, _| 0).map(|(cost, _)| cost);
+            assert_eq!(dist[(from, to)], expected, "{:?} -> {:?}", from, to);
+        }
+    }
+}
+
+#[test]
+fn test_negative_edges_and_parallel_edges() {
+    let mut graph: VecGraph<&str, i32> = VecGraph::default();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let c = graph.add_node("c");
+    let d = graph.add_node("d");
+    graph.add_edge(5, a, b);
+    graph.add_edge(2, a, b);
+    graph.add_edge(-3, b, c);
+    graph.add_edge(4, a, c);
+    graph.add_edge(1, c, a);
+
+    let dist = floyd_warshall(&graph, |_, &w| w);
+    assert!(!dist.has_negative_cycle());
+    assert_eq!(dist[(a, b)], Some(2));
+    assert_eq!(dist[(a, c)], Some(-1));
+    assert_eq!(dist[(b, a)], Some(-2));
+    assert_eq!(dist[(c, b)], Some(3));
+    assert_eq!(dist[(a, a)], Some(0));
+    assert_eq!(dist[(a, d)], None);
+    assert_eq!(dist[(d, d)], Some(0));
+
+    let maps = dist.into_mapping_of_maps();
+    assert_eq!(maps.keys().collect::<Vec<_>>(), vec![a, b, c, d]);
+    assert_eq!(maps[b][&a], Some(-2));
+    assert_eq!(maps[d][&a], None);
+    assert_eq!(maps[d].len(), 4);
+}
+
+#[test]
+fn test_negative_cycle_sets_flag() {
+    let mut graph: VecGraph<(), i64> = VecGraph::default();
+    let nodes = graph.add_nodes([(), (), (), ()]);
+    graph.add_edge(1, nodes[0], nodes[1]);
+    graph.add_edge(2, nodes[1], nodes[2]);
+    graph.add_edge(-4, nodes[2], nodes[1]);
+    graph.add_edge(1, nodes[2], nodes[3]);
+    assert!(floyd_warshall(&graph, |_, &w| w).has_negative_cycle());
+
+    // A negative self-loop is a negative cycle on its own
+    let mut graph: VecGraph<(), i64> = VecGraph::default();
+    let a = graph.add_node(());
+    graph.add_edge(-1, a, a);
+    assert!(floyd_warshall(&graph, |_, &w| w).has_negative_cycle());
+}
+
+#[test]
+fn test_empty_graph() {
+    let graph: VecGraph<(), u32> = VecGraph::default();
+    let dist = floyd_warshall(&graph, |_, &w| w);
+    assert!(!dist.has_negative_cycle());
+    assert_eq!(dist.into_mapping_of_maps().keys().count(), 0);
+}
+
+#[test]
+fn test_inside_scope() {
+    let graph = weighted_fixture();
+    let expected = floyd_warshall(&graph, |_, &w| w).into_mapping_of_maps();
+
+    graph.scope(|ctx| {
+        let dist = floyd_warshall(ctx, |_, &w| w);
+        for (from, from_ix) in ctx.node_indices().zip(graph.node_indices()) {
+            for (to, to_ix) in ctx.node_indices().zip(graph.node_indices()) {
+                assert_eq!(dist[(from, to)], expected[from_ix][&to_ix]);
+            }
+        }
+    });
+}
+
+#[test]
+#[should_panic(expected = "Node not found")]
+fn test_node_added_later_panics() {
+    let mut graph: VecGraph<(), u32> = VecGraph::default();
+    let a = graph.add_node(());
+    let dist = floyd_warshall(&graph, |_, &w| w);
+    let b = graph.add_node(());
+    let _ = dist[(a, b)];
+}
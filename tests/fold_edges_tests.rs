@@ -0,0 +1,116 @@
+use gotgraph::prelude::*;
+
+/// Build a pseudo-random graph with parallel edges and self-loops
+fn create_random_graph(nodes: usize, edges: usize, mut seed: u64) -> VecGraph<usize, usize> {
+    let mut graph = VecGraph::default();
+    let ixs: Vec<_> = (0..nodes).map(|i| graph.add_node(i)).collect();
+    let mut next = || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) as usize
+    };
+    for i in 0..edges {
+        let from = ixs[next() % nodes];
+        let to = ixs[next() % nodes];
+        graph.add_edge(i, from, to);
+    }
+    // Removals reorder the edge storage and the adjacency lists
+    for _ in 0..nodes / 10 {
+        let ix = graph
+            .node_indices()
+            .nth(next() % graph.len_nodes())
+            .unwrap();
+        graph.remove_node(ix);
+    }
+    graph
+}
+
+/// Sum of `node * edge` over every edge, computed with nested adjacency loops
+fn nested_total(graph: &VecGraph<usize, usize>) -> usize {
+    let mut total = 0;
+    for node in graph.node_indices() {
+        for edge in graph.outgoing_edge_indices(node) {
+            total += graph.node(node) * graph.edge(edge);
+        }
+    }
+    total
+}
+
+#[test]
+fn test_fold_edges_matches_nested_loops() {
+    for seed in 0..20 {
+        let graph = create_random_graph(40, 200, seed);
+        let folded = graph.fold_edges(0, |acc, from, _, &edge| acc + graph.node(from) * edge);
+        assert_eq!(folded, nested_total(&graph), "seed {}", seed);
+
+        let count = graph.fold_edges(0, |acc, _, _, _| acc + 1);
+        assert_eq!(count, graph.len_edges());
+    }
+}
+
+#[test]
+fn test_for_each_outgoing_visits_every_edge_once() {
+    for seed in 0..20 {
+        let graph = create_random_graph(40, 200, seed);
+
+        let mut visited = Vec::new();
+        graph.for_each_outgoing(|from, ix, &edge| {
+            assert_eq!(graph.endpoints(ix)[0], from);
+            assert_eq!(*graph.edge(ix), edge);
+            visited.push((from, ix));
+        });
+        visited.sort();
+
+        let mut expected: Vec<_> = graph
+            .node_indices()
+            .flat_map(|node| graph.outgoing_edge_indices(node).map(move |ix| (node, ix)))
+            .collect();
+        expected.sort();
+        assert_eq!(visited, expected, "seed {}", seed);
+    }
+}
+
+#[test]
+fn test_fold_edges_in_scope() {
+    let graph = create_random_graph(30, 120, 3);
+    let expected = nested_total(&graph);
+
+    graph.scope(|ctx| {
+        let folded = ctx.fold_edges(0, |acc, from, ix, &edge| {
+            assert_eq!(ctx.endpoints(ix)[0], from);
+            acc + ctx.node(from) * edge
+        });
+        assert_eq!(folded, expected);
+
+        let mut total = 0;
+        ctx.for_each_outgoing(|from, _, &edge| total += ctx.node(from) * edge);
+        assert_eq!(total, expected);
+    });
+}
+
+#[test]
+fn test_scoped_traversal_matches_direct_order() {
+    let graph = create_random_graph(30, 120, 5);
+
+    // Node and edge payloads are unique, so they identify the visit order
+    let mut direct = Vec::new();
+    graph.for_each_outgoing(|from, _, &edge| direct.push((*graph.node(from), edge)));
+    let direct_fold = graph.fold_edges(Vec::new(), |mut acc, from, _, &edge| {
+        acc.push((*graph.node(from), edge));
+        acc
+    });
+    assert_eq!(direct_fold, direct);
+
+    graph.scope(|ctx| {
+        let mut scoped = Vec::new();
+        ctx.for_each_outgoing(|from, _, &edge| scoped.push((*ctx.node(from), edge)));
+        assert_eq!(scoped, direct);
+
+        let scoped_fold = ctx.fold_edges(Vec::new(), |mut acc, from, _, &edge| {
+            acc.push((*ctx.node(from), edge));
+            acc
+        });
+        assert_eq!(scoped_fold, direct);
+    });
+}
@@ -0,0 +1,104 @@
+use gotgraph::algo::tarjan;
+use gotgraph::frozen::FrozenGraph;
+use gotgraph::prelude::*;
+use std::sync::Arc;
+use std::thread;
+
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<FrozenGraph<i32, i32>>();
+    assert_send_sync::<Arc<FrozenGraph<String, Vec<u8>>>>();
+};
+
+fn create_graph() -> VecGraph<i32, i32> {
+    let mut graph = VecGraph::default();
+    let n: Vec<_> = (0..200).map(|i| graph.add_node(i)).collect();
+    for i in 0..200 {
+        // Cycles of length 10 chained together, plus a few back edges
+        let next = if i % 10 == 9 { i - 9 } else { i + 1 };
+        graph.add_edge(i as i32, n[i], n[next]);
+        if i % 10 == 0 && i + 10 < 200 {
+            graph.add_edge(-(i as i32), n[i], n[i + 10]);
+        }
+        if i % 50 == 0 && i >= 50 {
+            graph.add_edge(1000, n[i], n[i - 40]);
+        }
+    }
+    graph
+}
+
+fn analyze<G: Graph<Node = i32, Edge = i32>>(graph: &G) -> (Vec<Vec<i32>>, Vec<i32>, i64) {
+    let mut sccs: Vec<Vec<i32>> = tarjan(graph)
+        .map(|scc| {
+            let mut nodes: Vec<_> = scc.iter().map(|&ix| *graph.node(ix)).collect();
+            nodes.sort();
+            nodes
+        })
+        .collect();
+    sccs.sort();
+
+    let start = graph.node_indices().next().unwrap();
+    let mut reachable: Vec<_> = graph.descendants(start).map(|ix| *graph.node(ix)).collect();
+    reachable.sort();
+
+    let weight = graph
+        .node_indices()
+        .flat_map(|n| graph.outgoing_edge_pairs(n))
+        .map(|(_, &w)| w as i64)
+        .sum();
+    (sccs, reachable, weight)
+}
+
+#[test]
+fn test_concurrent_readers_agree() {
+    let graph = create_graph();
+    let expected = analyze(&graph);
+
+    let frozen = graph.freeze();
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let frozen = Arc::clone(&frozen);
+            thread::spawn(move || analyze(&*frozen))
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), expected);
+    }
+
+    // Borrowed handles work as well
+    thread::scope(|s| {
+        let results: Vec<_> = (0..4).map(|_| s.spawn(|| analyze(&*frozen))).collect();
+        for result in results {
+            assert_eq!(result.join().unwrap(), expected);
+        }
+    });
+}
+
+#[test]
+fn test_scope_on_frozen_graph() {
+    let frozen = create_graph().freeze();
+    let doubled: Vec<i32> = frozen.scope(|ctx| {
+        let map = ctx.init_node_map(|_, &v| v * 2);
+        ctx.node_indices().map(|tag| map[tag]).collect()
+    });
+    assert_eq!(doubled, (0..200).map(|i| i * 2).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_thaw() {
+    let graph = create_graph();
+    let len_edges = graph.len_edges();
+    let frozen = graph.freeze();
+    let first = frozen.node_indices().next().unwrap();
+
+    let other = Arc::clone(&frozen);
+    let frozen = FrozenGraph::thaw(frozen).unwrap_err();
+    drop(other);
+
+    let mut graph = FrozenGraph::thaw(frozen).unwrap();
+    assert_eq!(graph.len_edges(), len_edges);
+    assert_eq!(*graph.node(first), 0);
+    let new = graph.add_node(-1);
+    graph.add_edge(0, new, first);
+    assert_eq!(graph.len_edges(), len_edges + 1);
+}
@@ -0,0 +1,214 @@
+#![cfg(feature = "graphml")]
+
+use gotgraph::io::{read_graphml, to_graphml, write_graphml, Attributes, ReadErrorKind};
+use gotgraph::prelude::*;
+
+#[derive(Clone, PartialEq, Debug)]
+struct City {
+    name: String,
+    population: u32,
+}
+
+fn create_graph() -> VecGraph<City, f64> {
+    let mut graph = VecGraph::default();
+    let cities: Vec<_> = [("Tokyo", 14000), ("Osaka <&> Kobe", 2700), ("Kyoto", 1400)]
+        .into_iter()
+        .map(|(name, population)| {
+            graph.add_node(City {
+                name: name.to_string(),
+                population,
+            })
+        })
+        .collect();
+    graph.add_edge(1.5, cities[0], cities[1]);
+    // Parallel edges and a self-loop
+    graph.add_edge(2.5, cities[0], cities[1]);
+    graph.add_edge(0.25, cities[2], cities[2]);
+    graph.add_edge(3.0, cities[1], cities[0]);
+    graph
+}
+
+fn city_attrs(city: &City) -> Attributes {
+    Attributes::from([
+        ("name".to_string(), city.name.clone()),
+        ("population".to_string(), city.population.to_string()),
+    ])
+}
+
+fn parse_city(attrs: &Attributes) -> Result<City, String> {
+    Ok(City {
+        name: attrs.get("name").ok_or("missing name")?.clone(),
+        population: attrs
+            .get("population")
+            .ok_or("missing population")?
+            .parse()
+            .map_err(|e| format!("bad population: {}", e))?,
+    })
+}
+
+fn weight_attrs(weight: &f64) -> Attributes {
+    Attributes::from([("weight".to_string(), weight.to_string())])
+}
+
+fn parse_weight(attrs: &Attributes) -> Result<f64, String> {
+    attrs["weight"].parse().map_err(|e| format!("{}", e))
+}
+
+fn read(xml: &str) -> Result<VecGraph<City, f64>, gotgraph::io::ReadError> {
+    read_graphml(xml.as_bytes(), parse_city, parse_weight)
+}
+
+#[test]
+fn test_graphml_round_trip() {
+    let graph = create_graph();
+    let mut out = Vec::new();
+    write_graphml(&graph, &mut out, city_attrs, weight_attrs).unwrap();
+    let xml = String::from_utf8(out).unwrap();
+    assert!(xml.contains("Osaka &lt;&amp;&gt; Kobe"));
+
+    let read_back = read(&xml).unwrap();
+    assert_eq!(
+        read_back.nodes().collect::<Vec<_>>(),
+        graph.nodes().collect::<Vec<_>>()
+    );
+    let edges = |graph: &VecGraph<City, f64>| {
+        graph
+            .edge_indices()
+            .map(|e| {
+                let [from, to] = graph.endpoints(e);
+                (from.index(), to.index(), *graph.edge(e))
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(edges(&read_back), edges(&graph));
+    assert_eq!(read_back.self_loop_edges().count(), 1);
+}
+
+#[test]
+fn test_graphml_defaults_and_forward_references() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="d0" for="node" attr.name="name"/>
+  <key id="d1" for="node" attr.name="population"><default>0</default></key>
+  <key id="d2" for="edge" attr.name="weight"><default>1.0</default></key>
+  <graph id="G" edgedefault="undirected">
+    <edge id="e0" source="b" target="a"/>
+    <node id="a"><data key="d0">A</data></node>
+    <node id="b"><data key="d0">B</data><data key="d1">42</data></node>
+    <edge source="a" target="b"><data key="d2"><![CDATA[2.0]]></data></edge>
+  </graph>
+</graphml>"#;
+    let graph = read(xml).unwrap();
+    let populations: Vec<_> = graph.nodes().map(|city| city.population).collect();
+    assert_eq!(populations, vec![0, 42]);
+    let edges: Vec<_> = graph
+        .edge_indices()
+        .map(|e| (graph.endpoints(e), *graph.edge(e)))
+        .collect();
+    let [a, b]: [_; 2] = graph.node_indices().collect::<Vec<_>>().try_into().unwrap();
+    assert_eq!(edges, vec![([b, a], 1.0), ([a, b], 2.0)]);
+}
+
+#[test]
+fn test_graphml_malformed_input() {
+    let unknown_node = r#"<graphml>
+  <key id="w" for="edge" attr.name="weight"/>
+  <graph>
+    <node id="a"><data key="w">1</data></node>
+    <edge source="a" target="z"><data key="w">1</data></edge>
+  </graph>
+</graphml>"#;
+    let err = read_graphml(unknown_node.as_bytes(), |_| Ok(()), parse_weight).unwrap_err();
+    assert!(matches!(err.kind(), ReadErrorKind::UnknownNode(id) if id == "z"));
+    assert_eq!(err.line(), Some(5));
+    assert_eq!(err.element(), Some(r#"<edge source="a" target="z">"#));
+    assert_eq!(
+        err.to_string(),
+        r#"line 5: <edge source="a" target="z">: edge refers to unknown node "z""#
+    );
+
+    let unclosed = "<graphml>\n<graph>\n<node id=\"a\">\n</graph>\n</graphml>";
+    let err = read(unclosed).unwrap_err();
+    assert!(matches!(err.kind(), ReadErrorKind::Syntax(_)));
+    assert_eq!(err.line(), Some(4));
+
+    let duplicate = r#"<graphml><graph><node id="a"/><node id="a"/></graph></graphml>"#;
+    let err = read_graphml(duplicate.as_bytes(), |_| Ok(()), |_| Ok(())).unwrap_err();
+    assert!(matches!(err.kind(), ReadErrorKind::DuplicateNode(id) if id == "a"));
+
+    let missing = "<graphml><graph>\n<edge target=\"a\"/></graph></graphml>";
+    let err = read_graphml(missing.as_bytes(), |_| Ok(()), |_| Ok(())).unwrap_err();
+    assert!(matches!(err.kind(), ReadErrorKind::Missing("source")));
+    assert_eq!(err.line(), Some(2));
+
+    let undeclared =
+        r#"<graphml><graph><node id="a"><data key="x">1</data></node></graph></graphml>"#;
+    let err = read_graphml(undeclared.as_bytes(), |_| Ok(()), |_| Ok(())).unwrap_err();
+    assert!(matches!(err.kind(), ReadErrorKind::UnknownKey(key) if key == "x"));
+
+    let bad_payload = r#"<graphml>
+<key id="n" attr.name="name"/><key id="p" attr.name="population"/>
+<graph><node id="a"><data key="n">A</data><data key="p">many</data></node></graph>
+</graphml>"#;
+    let err = read(bad_payload).unwrap_err();
+    assert!(matches!(err.kind(), ReadErrorKind::Payload(_)));
+    assert!(err
+        .to_string()
+        .starts_with(r#"line 3: <node id="a">: invalid payload: bad population"#));
+
+    let not_graphml = r#"<graph><node id="a"/></graph>"#;
+    let err = read_graphml(not_graphml.as_bytes(), |_| Ok(()), |_| Ok(())).unwrap_err();
+    assert!(matches!(err.kind(), ReadErrorKind::Syntax(_)));
+}
+
+/// Checks that start and end tags are balanced and every `&` starts an entity.
+fn assert_well_formed(xml: &str) {
+    let mut stack = Vec::new();
+    let mut rest = xml;
+    while let Some(start) = rest.find('<') {
+        let end = start + rest[start..].find('>').expect("unterminated tag");
+        let tag = &rest[start + 1..end];
+        if tag.starts_with('?') {
+            assert!(tag.ends_with('?'));
+        } else if let Some(name) = tag.strip_prefix('/') {
+            assert_eq!(stack.pop(), Some(name), "mismatched end tag");
+        } else if !tag.ends_with('/') {
+            stack.push(tag.split_whitespace().next().unwrap());
+        }
+        rest = &rest[end + 1..];
+    }
+    assert!(stack.is_empty(), "unclosed tags: {:?}", stack);
+    for (i, _) in xml.match_indices('&') {
+        let entity = &xml[i..xml[i..].find(';').map_or(xml.len(), |j| i + j + 1)];
+        assert!(
+            ["&amp;", "&lt;", "&gt;", "&quot;", "&apos;"].contains(&entity),
+            "unescaped `&` in {:?}",
+            entity
+        );
+    }
+}
+
+#[test]
+fn test_to_graphml() {
+    let graph = create_graph();
+    let xml = to_graphml(&graph, city_attrs, weight_attrs);
+
+    assert_well_formed(&xml);
+    assert!(xml.contains(r#"<graph edgedefault="directed">"#));
+    assert_eq!(xml.matches("<node id=").count(), graph.len_nodes());
+    assert_eq!(xml.matches("<edge source=").count(), graph.len_edges());
+    assert!(xml.contains(r#"<node id="n2">"#));
+    assert!(xml.contains(r#"<edge source="n2" target="n2">"#));
+    assert!(!xml.contains("<&>"));
+
+    // Without attributes every element is empty
+    let bare = to_graphml(&graph, |_| Attributes::new(), |_| Attributes::new());
+    assert_well_formed(&bare);
+    assert!(bare.contains(r#"<node id="n0"/>"#));
+    assert!(bare.contains(r#"<edge source="n0" target="n1"/>"#));
+    assert!(!bare.contains("<key"));
+
+    let read_back = read(&xml).unwrap();
+    assert_eq!(read_back.len_nodes(), graph.len_nodes());
+    assert_eq!(read_back.len_edges(), graph.len_edges());
+}
@@ -0,0 +1,135 @@
+use gotgraph::algo::{has_cycle, IncrementalCycles};
+use gotgraph::prelude::*;
+use gotgraph::vec_graph::NodeIx;
+
+fn assert_order_consistent<N, E>(graph: &VecGraph<N, E>, cycles: &IncrementalCycles<NodeIx>) {
+    let order: Vec<_> = cycles.order().collect();
+    assert_eq!(order.len(), graph.len_nodes());
+    let mut position = graph.init_node_map(|_, _| usize::MAX);
+    for (i, &ix) in order.iter().enumerate() {
+        position[ix] = i;
+    }
+    for edge in graph.edge_indices() {
+        let [from, to] = graph.endpoints(edge);
+        assert!(
+            position[from] < position[to],
+            "edge {:?} -> {:?} goes backward in the order",
+            from,
+            to
+        );
+    }
+}
+
+#[test]
+fn test_random_dag_insertion_is_accepted() {
+    let mut rng_state = 42u64;
+    // Simple LCG for deterministic pseudo-random numbers
+    let mut next_random = || {
+        rng_state = rng_state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (rng_state >> 33) as usize
+    };
+
+    for _ in 0..20 {
+        let node_count = 5 + next_random() % 30;
+        let mut graph: VecGraph<usize, ()> = VecGraph::default();
+        let mut cycles = IncrementalCycles::new(node_count);
+        let nodes: Vec<_> = (0..node_count)
+            .map(|i| cycles.add_node_to(&mut graph, i))
+            .collect();
+
+        // Edges always go from a larger to a smaller label, so the graph stays acyclic
+        // while being inserted against the initial order
+        for _ in 0..node_count * 3 {
+            let (a, b) = (next_random() % node_count, next_random() % node_count);
+            if a == b {
+                continue;
+            }
+            let (from, to) = (a.max(b), a.min(b));
+            assert!(cycles
+                .add_edge_to(&mut graph, (), nodes[from], nodes[to])
+                .is_ok());
+            assert_order_consistent(&graph, &cycles);
+        }
+        assert!(!has_cycle(&graph));
+    }
+}
+
+#[test]
+fn test_closing_edge_is_rejected() {
+    let mut graph: VecGraph<&str, &str> = VecGraph::default();
+    let mut cycles = IncrementalCycles::new(4);
+    let a = cycles.add_node_to(&mut graph, "a");
+    let b = cycles.add_node_to(&mut graph, "b");
+    let c = cycles.add_node_to(&mut graph, "c");
+    let d = cycles.add_node_to(&mut graph, "d");
+
+    cycles.add_edge_to(&mut graph, "d->c", d, c).unwrap();
+    cycles.add_edge_to(&mut graph, "c->b", c, b).unwrap();
+    cycles.add_edge_to(&mut graph, "b->a", b, a).unwrap();
+    let before: Vec<_> = cycles.order().collect();
+
+    let err = cycles.add_edge_to(&mut graph, "a->d", a, d).unwrap_err();
+    assert_eq!(err.into_inner(), "a->d");
+    assert!(cycles.try_add_edge(b, d).is_err());
+    assert!(cycles.try_add_edge(c, c).is_err());
+    assert_eq!(graph.len_edges(), 3);
+    assert_eq!(cycles.order().collect::<Vec<_>>(), before);
+
+    // Shortcuts that do not close a cycle are still fine after a rejection
+    cycles.add_edge_to(&mut graph, "d->a", d, a).unwrap();
+    cycles.add_edge_to(&mut graph, "d->a", d, a).unwrap();
+    assert_order_consistent(&graph, &cycles);
+    assert_eq!(cycles.order().collect::<Vec<_>>(), vec![d, c, b, a]);
+}
+
+#[test]
+fn test_random_insertions_match_full_cycle_check() {
+    let mut rng_state = 7u64;
+    let mut next_random = || {
+        rng_state = rng_state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (rng_state >> 33) as usize
+    };
+
+    for _ in 0..20 {
+        let node_count = 3 + next_random() % 15;
+        let mut graph: VecGraph<(), ()> = VecGraph::default();
+        let mut cycles = IncrementalCycles::new(node_count);
+        let nodes: Vec<_> = (0..node_count)
+            .map(|_| cycles.add_node_to(&mut graph, ()))
+            .collect();
+
+        for _ in 0..node_count * 2 {
+            let (from, to) = (
+                nodes[next_random() % node_count],
+                nodes[next_random() % node_count],
+            );
+            let mut candidate = graph.clone();
+            candidate.add_edge((), from, to);
+            let result = cycles.add_edge_to(&mut graph, (), from, to);
+            assert_eq!(result.is_err(), has_cycle(&candidate));
+            assert_order_consistent(&graph, &cycles);
+        }
+    }
+}
+
+#[test]
+fn test_from_graph() {
+    let mut graph: VecGraph<(), ()> = VecGraph::default();
+    let n: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+    graph.add_edge((), n[3], n[1]);
+    graph.add_edge((), n[1], n[0]);
+    graph.add_edge((), n[2], n[0]);
+
+    let mut cycles = IncrementalCycles::from_graph(&graph).unwrap();
+    assert_eq!(cycles.len_nodes(), 4);
+    assert!(cycles.contains_node(n[2]));
+    assert_order_consistent(&graph, &cycles);
+    assert!(cycles.add_edge_to(&mut graph, (), n[0], n[3]).is_err());
+
+    graph.add_edge((), n[0], n[3]);
+    assert!(IncrementalCycles::from_graph(&graph).is_err());
+}
@@ -0,0 +1,135 @@
+use gotgraph::graph::IndexConversion;
+use gotgraph::prelude::*;
+use gotgraph::undirected::UnVecGraph;
+use gotgraph::vec_graph::{EdgeIx, NodeIx};
+
+type G = VecGraph<usize, usize>;
+
+fn sample() -> G {
+    let mut graph = G::default();
+    let nodes: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+    for i in 0..4 {
+        graph.add_edge(i, nodes[i], nodes[i + 1]);
+    }
+    graph
+}
+
+#[test]
+fn test_index_round_trips_through_usize() {
+    let graph = sample();
+    for ix in graph.node_indices() {
+        assert_eq!(NodeIx::try_from(ix.index()), Ok(ix));
+        assert_eq!(
+            *graph.node(NodeIx::try_from(ix.index()).unwrap()),
+            *graph.node(ix)
+        );
+    }
+    for ix in graph.edge_indices() {
+        assert_eq!(EdgeIx::try_from(ix.index()), Ok(ix));
+    }
+
+    // Indices are dense positions in the storage
+    let mut positions: Vec<_> = graph.node_indices().map(NodeIx::index).collect();
+    positions.sort();
+    assert_eq!(positions, (0..graph.len_nodes()).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_index_conversion_rejects_sentinel() {
+    let sentinel = u32::MAX as usize;
+    assert!(NodeIx::try_from(sentinel).is_err());
+    assert!(EdgeIx::try_from(sentinel).is_err());
+    assert!(NodeIx::try_from(sentinel - 1).is_ok());
+    assert!(EdgeIx::try_from(sentinel - 1).is_ok());
+
+    assert_eq!(G::node_from_usize(sentinel), None);
+    assert_eq!(G::edge_from_usize(sentinel), None);
+    assert_eq!(
+        NodeIx::try_from(sentinel).unwrap_err().to_string(),
+        "value is out of range for a graph index"
+    );
+}
+
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn test_index_conversion_rejects_values_above_u32() {
+    assert!(NodeIx::try_from(u32::MAX as usize + 1).is_err());
+    assert!(EdgeIx::try_from(usize::MAX).is_err());
+}
+
+#[test]
+fn test_converted_index_out_of_graph_is_bounds_checked() {
+    let graph = sample();
+    let missing = NodeIx::try_from(100).unwrap();
+    assert!(!graph.exists_node_index(missing));
+    let missing = EdgeIx::try_from(100).unwrap();
+    assert!(!graph.exists_edge_index(missing));
+}
+
+#[test]
+#[should_panic]
+fn test_converted_index_out_of_graph_panics_on_access() {
+    let graph = sample();
+    graph.node(NodeIx::try_from(100).unwrap());
+}
+
+/// Stores node indices compactly as `u32` through the generic conversion
+fn pack_nodes<G: IndexConversion>(graph: &G) -> Vec<u32> {
+    graph
+        .node_indices()
+        .map(|ix| G::node_to_usize(ix) as u32)
+        .collect()
+}
+
+#[test]
+fn test_graph_level_conversion() {
+    let graph = sample();
+    for raw in pack_nodes(&graph) {
+        let ix = G::node_from_usize(raw as usize).unwrap();
+        assert!(graph.exists_node_index(ix));
+        assert_eq!(*graph.node(ix), raw as usize);
+    }
+    for ix in graph.edge_indices() {
+        assert_eq!(G::edge_from_usize(G::edge_to_usize(ix)), Some(ix));
+    }
+    assert_eq!(pack_nodes(&&graph), pack_nodes(&graph));
+}
+
+#[test]
+fn test_undirected_conversion() {
+    let mut graph: UnVecGraph<(), ()> = UnVecGraph::default();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let e = graph.add_edge((), a, b);
+
+    let back = UnVecGraph::<(), ()>::edge_from_usize(UnVecGraph::<(), ()>::edge_to_usize(e));
+    assert_eq!(back, Some(e));
+    // The same edge reached from the other endpoint converts to the same integer
+    let flipped = graph.outgoing_edge_indices(b).next().unwrap();
+    assert_eq!(
+        UnVecGraph::<(), ()>::edge_to_usize(flipped),
+        UnVecGraph::<(), ()>::edge_to_usize(e)
+    );
+}
+
+#[test]
+fn test_index_round_trips_through_u32() {
+    let graph = sample();
+    let raw_nodes: Vec<u32> = graph.node_indices().map(NodeIx::as_u32).collect();
+    let raw_edges: Vec<u32> = graph.edge_indices().map(EdgeIx::as_u32).collect();
+    assert_eq!(raw_nodes, vec![0, 1, 2, 3, 4]);
+
+    for (raw, ix) in raw_nodes.into_iter().zip(graph.node_indices()) {
+        assert_eq!(NodeIx::from_index(raw), ix);
+        assert_eq!(*graph.node(NodeIx::from_index(raw)), raw as usize);
+    }
+    for (raw, ix) in raw_edges.into_iter().zip(graph.edge_indices()) {
+        assert_eq!(EdgeIx::from_index(raw), ix);
+    }
+
+    // Fabricated indices are not checked until they are used with a graph
+    assert!(!graph.exists_node_index(NodeIx::from_index(5)));
+    assert!(!graph.exists_node_index(NodeIx::from_index(u32::MAX)));
+    assert!(!graph.exists_edge_index(EdgeIx::from_index(4)));
+    assert!(graph.try_node(NodeIx::from_index(5)).is_err());
+}
@@ -0,0 +1,173 @@
+use gotgraph::algo::tarjan;
+use gotgraph::frozen::FrozenGraph;
+use gotgraph::graph::IndexConversion;
+use gotgraph::keyed::KeyedGraph;
+use gotgraph::prelude::*;
+use gotgraph::undirected::UnVecGraph;
+use gotgraph::Mapping;
+
+/// Build a graph of `len` nodes linked in cycles of length 3, plus one edge between
+/// consecutive cycles
+fn create_cycles<Ix: gotgraph::vec_graph::IndexType>(len: usize) -> VecGraph<usize, (), Ix> {
+    let mut graph = VecGraph::with_index_type();
+    let nodes = graph.add_nodes(0..len);
+    for i in 0..len {
+        let next = if i % 3 == 2 { i - 2 } else { i + 1 };
+        graph.add_edge((), nodes[i], nodes[next]);
+        if i % 3 == 0 && i + 3 < len {
+            graph.add_edge((), nodes[i], nodes[i + 3]);
+        }
+    }
+    graph
+}
+
+#[test]
+fn test_u16_graph_with_more_than_255_nodes() {
+    let graph = create_cycles::<u16>(300);
+    assert_eq!(graph.len_nodes(), 300);
+    assert_eq!(graph.len_edges(), 399);
+
+    let last = graph.node_indices().last().unwrap();
+    assert_eq!(last.index(), 299);
+    assert_eq!(*graph.node(last), 299);
+    assert_eq!(graph.outgoing_edge_indices(last).count(), 1);
+
+    let sccs: Vec<_> = tarjan(&graph).collect();
+    assert_eq!(sccs.len(), 100);
+    assert!(sccs.iter().all(|scc| scc.len() == 3));
+}
+
+#[test]
+fn test_u64_graph_matches_u32_graph() {
+    let wide = create_cycles::<u64>(300);
+    let default = create_cycles::<u32>(300);
+    assert_eq!(wide.len_edges(), default.len_edges());
+    for (a, b) in wide.edge_indices().zip(default.edge_indices()) {
+        let [from, to] = wide.endpoints(a);
+        let [expected_from, expected_to] = default.endpoints(b);
+        assert_eq!(
+            (from.index(), to.index()),
+            (expected_from.index(), expected_to.index())
+        );
+    }
+    assert_eq!(tarjan(&wide).count(), tarjan(&default).count());
+}
+
+#[test]
+fn test_u16_graph_removal_and_maps() {
+    let mut graph = create_cycles::<u16>(300);
+    let first = graph.node_indices().next().unwrap();
+    graph.remove_node(first);
+    assert_eq!(graph.len_nodes(), 299);
+
+    // The last node moved into the freed slot
+    assert_eq!(*graph.node(first), 299);
+    let degrees = graph.init_node_map(|ix, _| graph.outgoing_edge_indices(ix).count());
+    let total: usize = degrees.iter().sum();
+    assert_eq!(total, graph.len_edges());
+
+    let payloads = graph.init_edge_map(|ix, _| graph.endpoints(ix)[0]);
+    for ix in graph.edge_indices() {
+        assert_eq!(payloads[ix], graph.endpoints(ix)[0]);
+    }
+}
+
+#[test]
+fn test_u16_index_conversion_rejects_sentinel() {
+    type Graph16 = VecGraph<(), (), u16>;
+    let ix = Graph16::node_from_usize(65534).unwrap();
+    assert_eq!(Graph16::node_to_usize(ix), 65534);
+    assert!(Graph16::node_from_usize(65535).is_none());
+    assert!(Graph16::edge_from_usize(65535).is_none());
+    assert!(Graph16::edge_from_usize(usize::MAX).is_none());
+}
+
+#[test]
+fn test_u16_node_capacity() {
+    let mut graph: VecGraph<u32, (), u16> = VecGraph::with_index_type();
+    for i in 0..u32::from(u16::MAX) {
+        graph.try_add_node(i).unwrap();
+    }
+    assert_eq!(graph.len_nodes(), 65535);
+    assert_eq!(graph.node_indices().last().unwrap().index(), 65534);
+
+    let err = graph.try_add_node(65535).unwrap_err();
+    assert_eq!(err.into_inner(), 65535);
+    assert_eq!(graph.len_nodes(), 65535);
+}
+
+#[test]
+#[should_panic(expected = "maximum capacity (65535) reached")]
+fn test_u16_add_node_panics_when_full() {
+    let mut graph: VecGraph<(), (), u16> = VecGraph::with_index_type();
+    graph.add_nodes((0..u16::MAX).map(|_| ()));
+    graph.add_node(());
+}
+
+#[test]
+fn test_u16_graph_methods_and_wrappers() {
+    let mut graph = create_cycles::<u16>(300);
+    let first = graph.node_indices().next().unwrap();
+    graph.add_edge((), first, first);
+    graph.add_edge((), first, first);
+    assert_eq!(graph.dedup_parallel_edges(|_, _| ()), 1);
+    assert_eq!(graph.remove_self_loops(), vec![()]);
+    graph.retain_k_core(2);
+    assert_eq!(graph.len_nodes(), 300);
+    graph.shrink_to_fit();
+
+    let frozen = graph.freeze();
+    assert_eq!(tarjan(&*frozen).count(), 100);
+    let graph = FrozenGraph::thaw(frozen).unwrap();
+
+    let undirected = UnVecGraph::from(graph);
+    let last = undirected.node_indices().last().unwrap();
+    assert_eq!(undirected.outgoing_edge_indices(last).count(), 2);
+    assert_eq!(undirected.into_inner().len_edges(), 399);
+
+    let mut keyed: KeyedGraph<&str, (), (), u16> = KeyedGraph::with_index_type();
+    let a = keyed.add_node_keyed("a", ()).unwrap();
+    keyed.add_node_keyed("b", ()).unwrap();
+    keyed.add_edge_by_keys((), "a", "b").unwrap();
+    assert_eq!(keyed.add_node_keyed("a", ()).unwrap_err().index(), a);
+}
+
+#[test]
+fn test_u16_filter_edges() {
+    let graph = create_cycles::<u16>(300);
+    let nodes: Vec<_> = graph.node_indices().collect();
+    let filtered = graph.filter_edges(|ix, _| ix.index() % 2 == 0);
+    assert_eq!(filtered.len_nodes(), 300);
+    assert_eq!(filtered.len_edges(), 200);
+    assert_eq!(*filtered.node(nodes[299]), 299);
+    assert_eq!(filtered.edge_indices().last().unwrap().index(), 199);
+}
+
+#[test]
+fn test_u16_parallel_edges() {
+    let mut graph = create_cycles::<u16>(300);
+    assert!(!graph.has_parallel_edges());
+    let nodes: Vec<_> = graph.node_indices().collect();
+    let extra = graph.add_edge((), nodes[298], nodes[299]);
+    assert!(graph.has_parallel_edges());
+
+    let groups: Vec<_> = graph.parallel_edge_groups().collect();
+    assert_eq!(groups.len(), 1);
+    let (from, to, edges) = &groups[0];
+    assert_eq!((from.index(), to.index()), (298, 299));
+    assert_eq!(edges.last(), Some(&extra));
+}
+
+#[test]
+fn test_u16_simplify() {
+    let mut graph = create_cycles::<u16>(300);
+    let nodes: Vec<_> = graph.node_indices().collect();
+    graph.add_edge((), nodes[298], nodes[299]);
+    graph.add_edge((), nodes[299], nodes[299]);
+
+    let report = graph.simplify(|a, _| a, true);
+    assert_eq!(report.merged_edges, 1);
+    assert_eq!(report.dropped_self_loops, 1);
+    assert_eq!(graph.len_edges(), 399);
+    assert!(!graph.has_parallel_edges());
+}
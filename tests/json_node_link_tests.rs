@@ -0,0 +1,125 @@
+#![cfg(feature = "json")]
+
+use gotgraph::io::{read_json_node_link, write_json_node_link, Attributes, ReadErrorKind};
+use gotgraph::prelude::*;
+
+fn create_graph() -> VecGraph<String, u32> {
+    let mut graph = VecGraph::default();
+    let nodes: Vec<_> = ["a", "b \"quoted\"", "c"]
+        .into_iter()
+        .map(|name| graph.add_node(name.to_string()))
+        .collect();
+    graph.add_edge(1, nodes[0], nodes[1]);
+    // Parallel edges and a self-loop
+    graph.add_edge(2, nodes[0], nodes[1]);
+    graph.add_edge(3, nodes[1], nodes[1]);
+    graph.add_edge(4, nodes[2], nodes[0]);
+    graph
+}
+
+fn name_attrs(name: &str) -> Attributes {
+    Attributes::from([("name".to_string(), name.to_string())])
+}
+
+fn weight_attrs(weight: &u32) -> Attributes {
+    Attributes::from([("weight".to_string(), weight.to_string())])
+}
+
+fn parse_name(attrs: &Attributes) -> Result<String, String> {
+    attrs
+        .get("name")
+        .cloned()
+        .ok_or_else(|| "missing name".to_string())
+}
+
+fn parse_weight(attrs: &Attributes) -> Result<u32, String> {
+    let weight = attrs.get("weight").ok_or("missing weight")?;
+    weight
+        .parse()
+        .map_err(|e| format!("bad weight {:?}: {}", weight, e))
+}
+
+#[test]
+fn test_json_round_trip() {
+    let graph = create_graph();
+    let mut out = Vec::new();
+    write_json_node_link(&graph, &mut out, |name| name_attrs(name), weight_attrs).unwrap();
+
+    let read_back = read_json_node_link(out.as_slice(), parse_name, parse_weight).unwrap();
+    assert_eq!(
+        read_back.nodes().collect::<Vec<_>>(),
+        graph.nodes().collect::<Vec<_>>()
+    );
+    let edges = |graph: &VecGraph<String, u32>| {
+        graph
+            .edge_indices()
+            .map(|e| {
+                let [from, to] = graph.endpoints(e);
+                (from.index(), to.index(), *graph.edge(e))
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(edges(&read_back), edges(&graph));
+}
+
+#[test]
+fn test_json_networkx_style_input() {
+    // networkx writes numeric attributes as numbers and may call the links "edges"
+    let json = r#"{
+        "directed": true,
+        "multigraph": false,
+        "graph": {},
+        "nodes": [{"id": 10, "name": "x"}, {"id": "y", "name": "y"}],
+        "edges": [{"source": 10, "target": "y", "weight": 7, "extra": [1, 2]}]
+    }"#;
+    let mut extras = Vec::new();
+    let graph = read_json_node_link(json.as_bytes(), parse_name, |attrs| {
+        extras.push(attrs["extra"].clone());
+        parse_weight(attrs)
+    })
+    .unwrap();
+    assert_eq!(graph.nodes().collect::<Vec<_>>(), vec!["x", "y"]);
+    assert_eq!(graph.edges().collect::<Vec<_>>(), vec![&7]);
+    assert_eq!(extras, vec!["[1,2]"]);
+}
+
+#[test]
+fn test_json_malformed_input() {
+    let invalid = "{\n  \"nodes\": [\n    {\"id\": 0,}\n  ]\n}";
+    let err = read_json_node_link(invalid.as_bytes(), parse_name, parse_weight).unwrap_err();
+    assert!(matches!(err.kind(), ReadErrorKind::Syntax(_)));
+    assert_eq!(err.line(), Some(3));
+    assert!(err.to_string().starts_with("line 3: malformed input"));
+
+    let unknown = r#"{"nodes": [{"id": "a", "name": "a"}],
+        "links": [{"source": "a", "target": "a", "weight": 1},
+                  {"source": "a", "target": "b", "weight": 2}]}"#;
+    let err = read_json_node_link(unknown.as_bytes(), parse_name, parse_weight).unwrap_err();
+    assert!(matches!(err.kind(), ReadErrorKind::UnknownNode(id) if id == "b"));
+    assert_eq!(err.element(), Some("links[1]"));
+    assert_eq!(
+        err.to_string(),
+        r#"links[1]: edge refers to unknown node "b""#
+    );
+
+    let missing = r#"{"nodes": [{"name": "a"}]}"#;
+    let err = read_json_node_link(missing.as_bytes(), parse_name, parse_weight).unwrap_err();
+    assert!(matches!(err.kind(), ReadErrorKind::Missing("id")));
+    assert_eq!(err.element(), Some("nodes[0]"));
+
+    let duplicate = r#"{"nodes": [{"id": 1, "name": "a"}, {"id": 1, "name": "b"}]}"#;
+    let err = read_json_node_link(duplicate.as_bytes(), parse_name, parse_weight).unwrap_err();
+    assert!(matches!(err.kind(), ReadErrorKind::DuplicateNode(_)));
+
+    let bad_payload = r#"{"nodes": [{"id": 0, "name": "a"}],
+        "links": [{"source": 0, "target": 0, "weight": "heavy"}]}"#;
+    let err = read_json_node_link(bad_payload.as_bytes(), parse_name, parse_weight).unwrap_err();
+    assert!(matches!(err.kind(), ReadErrorKind::Payload(_)));
+    assert!(err
+        .to_string()
+        .starts_with("links[0]: invalid payload: bad weight \"heavy\""));
+
+    for not_a_graph in [r#"[1, 2]"#, r#"{"links": []}"#, r#"{"nodes": {}}"#] {
+        assert!(read_json_node_link(not_a_graph.as_bytes(), parse_name, parse_weight).is_err());
+    }
+}
@@ -0,0 +1,96 @@
+use gotgraph::algo::{core_numbers, k_core};
+use gotgraph::prelude::*;
+use gotgraph::vec_graph::NodeIx;
+use gotgraph::Mapping;
+
+/// A clique on `size` nodes, each pair joined by one edge, plus a pendant node attached
+/// to every clique node
+fn clique_with_pendants(size: usize) -> (VecGraph<bool, ()>, Vec<NodeIx>, Vec<NodeIx>) {
+    let mut graph = VecGraph::default();
+    let clique: Vec<_> = (0..size).map(|_| graph.add_node(true)).collect();
+    let pendants: Vec<_> = (0..size).map(|_| graph.add_node(false)).collect();
+    for i in 0..size {
+        for j in i + 1..size {
+            graph.add_edge((), clique[i], clique[j]);
+        }
+        graph.add_edge((), clique[i], pendants[i]);
+    }
+    (graph, clique, pendants)
+}
+
+#[test]
+fn test_clique_survives() {
+    let (graph, clique, pendants) = clique_with_pendants(5);
+
+    assert_eq!(k_core(&graph, 2), clique);
+    assert_eq!(k_core(&graph, 4), clique);
+    assert!(k_core(&graph, 5).is_empty());
+    assert_eq!(k_core(&graph, 1).len(), 10);
+
+    let cores = core_numbers(&graph);
+    assert!(clique.iter().all(|&ix| cores[ix] == 4));
+    assert!(pendants.iter().all(|&ix| cores[ix] == 1));
+}
+
+#[test]
+fn test_pendant_chain_is_peeled_iteratively() {
+    // A path hanging off the clique only loses its inner nodes once the outer ones go
+    let (mut graph, clique, _) = clique_with_pendants(4);
+    let mut previous = clique[0];
+    for _ in 0..3 {
+        let next = graph.add_node(false);
+        graph.add_edge((), previous, next);
+        previous = next;
+    }
+
+    assert_eq!(k_core(&graph, 2), clique);
+    assert_eq!(k_core(&graph, 1).len(), graph.len_nodes());
+}
+
+#[test]
+fn test_retain_k_core() {
+    let (mut graph, _, _) = clique_with_pendants(5);
+    graph.retain_k_core(4);
+    assert_eq!(graph.len_nodes(), 5);
+    assert_eq!(graph.len_edges(), 10);
+    assert!(graph.nodes().all(|&in_clique| in_clique));
+
+    graph.retain_k_core(5);
+    assert!(graph.is_empty());
+}
+
+#[test]
+fn test_directed_cycle_has_coreness_two() {
+    let mut graph: VecGraph<(), ()> = VecGraph::default();
+    let nodes: Vec<_> = (0..6).map(|_| graph.add_node(())).collect();
+    for i in 0..6 {
+        graph.add_edge((), nodes[i], nodes[(i + 1) % 6]);
+    }
+
+    let cores = core_numbers(&graph);
+    assert!(nodes.iter().all(|&ix| cores[ix] == 2));
+    assert_eq!(k_core(&graph, 2), nodes);
+    assert!(k_core(&graph, 3).is_empty());
+}
+
+#[test]
+fn test_self_loop_counts_twice() {
+    let mut graph: VecGraph<(), ()> = VecGraph::default();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    graph.add_edge((), a, a);
+    graph.add_edge((), a, b);
+
+    assert_eq!(k_core(&graph, 2), vec![a]);
+    let cores = core_numbers(&graph);
+    assert_eq!((cores[a], cores[b]), (2, 1));
+}
+
+#[test]
+fn test_empty_graph() {
+    let mut graph: VecGraph<(), ()> = VecGraph::default();
+    assert!(k_core(&graph, 0).is_empty());
+    assert_eq!(core_numbers(&graph).iter().count(), 0);
+    graph.retain_k_core(1);
+    assert!(graph.is_empty());
+}
@@ -0,0 +1,104 @@
+use gotgraph::algo::tarjan;
+use gotgraph::keyed::{KeyedGraph, MissingKey};
+use gotgraph::prelude::*;
+
+fn create_graph(n: usize) -> KeyedGraph<String, usize, (usize, usize)> {
+    let mut graph = KeyedGraph::new();
+    for i in 0..n {
+        graph.add_node_keyed(format!("n{}", i), i).unwrap();
+    }
+    for i in 0..n {
+        let j = (i + 1) % n;
+        graph
+            .add_edge_by_keys((i, j), &format!("n{}", i), &format!("n{}", j))
+            .unwrap();
+    }
+    graph
+}
+
+#[test]
+fn test_remove_middle_node_keeps_keys() {
+    let mut graph = create_graph(10);
+
+    assert_eq!(graph.remove_node_by_key("n4"), Some(4));
+    assert_eq!(graph.remove_node_by_key("n4"), None);
+    assert_eq!(graph.len_nodes(), 9);
+    assert_eq!(graph.len_edges(), 8);
+
+    for i in (0..10).filter(|&i| i != 4) {
+        let key = format!("n{}", i);
+        let ix = graph.index_of(key.as_str()).unwrap();
+        assert_eq!(graph.node_by_key(key.as_str()), Some(&i));
+        assert_eq!(*graph.node(ix), i);
+        assert_eq!(graph.key_of(ix), &key);
+    }
+    assert_eq!(graph.index_of("n4"), None);
+    assert_eq!(graph.node_by_key("n4"), None);
+
+    // Edges still connect the nodes they were added between
+    for (_, [from, to], &(a, b)) in graph.edge_triples() {
+        assert_eq!((*graph.node(from), *graph.node(to)), (a, b));
+    }
+
+    // Removing the last node does not move anything
+    assert_eq!(graph.remove_node_by_key("n9"), Some(9));
+    assert_eq!(graph.node_by_key("n8"), Some(&8));
+    assert_eq!(graph.node_by_key("n0"), Some(&0));
+}
+
+#[test]
+fn test_duplicate_key_is_rejected() {
+    let mut graph: KeyedGraph<&str, i32, ()> = KeyedGraph::new();
+    let a = graph.add_node_keyed("a", 1).unwrap();
+
+    let err = graph.add_node_keyed("a", 2).unwrap_err();
+    assert_eq!(err.index(), a);
+    assert_eq!(err.into_payload(), 2);
+    assert_eq!(graph.len_nodes(), 1);
+    assert_eq!(graph.node_by_key("a"), Some(&1));
+
+    // The key can be reused once its node is gone
+    graph.remove_node_by_key("a");
+    assert!(graph.add_node_keyed("a", 3).is_ok());
+    assert_eq!(graph.node_by_key("a"), Some(&3));
+}
+
+#[test]
+fn test_add_edges_by_keys() {
+    let mut graph: KeyedGraph<&str, (), &str> = KeyedGraph::new();
+    let a = graph.add_node_keyed("a", ()).unwrap();
+    let b = graph.add_node_keyed("b", ()).unwrap();
+
+    let ab = graph.add_edge_by_keys("a-b", "a", "b").unwrap();
+    assert_eq!(graph.endpoints(ab), [a, b]);
+    assert_eq!(*graph.edge(ab), "a-b");
+
+    assert_eq!(
+        graph.add_edge_by_keys("x-b", "x", "b"),
+        Err(MissingKey::Source)
+    );
+    assert_eq!(
+        graph.add_edge_by_keys("a-x", "a", "x"),
+        Err(MissingKey::Target)
+    );
+    assert_eq!(
+        graph.add_edge_by_keys("x-y", "x", "y"),
+        Err(MissingKey::Source)
+    );
+    assert_eq!(graph.len_edges(), 1);
+}
+
+#[test]
+fn test_algorithms_and_scope() {
+    let mut graph = create_graph(5);
+    assert_eq!(tarjan(&graph).count(), 1);
+
+    graph.remove_node_by_key("n2");
+    assert_eq!(tarjan(&graph).count(), 4);
+
+    graph.scope(|ctx| {
+        let total: usize = ctx.nodes().sum();
+        assert_eq!(total, 1 + 3 + 4);
+        assert_eq!(ctx.edge_indices().count(), 3);
+    });
+}
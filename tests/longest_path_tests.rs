@@ -0,0 +1,176 @@
+use gotgraph::algo::{longest_path, longest_path_dag, longest_path_from};
+use gotgraph::prelude::*;
+
+#[test]
+fn test_pert_critical_path() {
+    // Events connected by tasks weighted with their duration
+    let mut graph: VecGraph<&str, u32> = VecGraph::default();
+    let start = graph.add_node("start");
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    let d = graph.add_node("D");
+    let end = graph.add_node("end");
+
+    let sa = graph.add_edge(3, start, a);
+    graph.add_edge(2, start, b);
+    let ac = graph.add_edge(4, a, c);
+    graph.add_edge(2, b, c);
+    graph.add_edge(2, a, d);
+    let bd = graph.add_edge(5, b, d);
+    let ce = graph.add_edge(3, c, end);
+    let de = graph.add_edge(1, d, end);
+
+    let (weight, path) = longest_path_dag(&graph, |_, &w| w).unwrap();
+    assert_eq!(weight, 10);
+    assert_eq!(path, vec![sa, ac, ce]);
+
+    let (weight, path) = longest_path_from(&graph, b, |_, &w| w).unwrap();
+    assert_eq!(weight, 6);
+    assert_eq!(path, vec![bd, de]);
+
+    assert_eq!(
+        longest_path_from(&graph, end, |_, &w| w).unwrap(),
+        (0, vec![])
+    );
+}
+
+#[test]
+fn test_negative_weights_prefer_empty_path() {
+    let mut graph: VecGraph<(), i32> = VecGraph::default();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    let d = graph.add_node(());
+    let ab = graph.add_edge(-1, a, b);
+    let bc = graph.add_edge(2, b, c);
+    graph.add_edge(-5, a, d);
+
+    assert_eq!(longest_path_dag(&graph, |_, &w| w).unwrap(), (2, vec![bc]));
+    assert_eq!(
+        longest_path_from(&graph, a, |_, &w| w).unwrap(),
+        (1, vec![ab, bc])
+    );
+
+    graph.add_edge(-1, d, c);
+    assert_eq!(
+        longest_path_from(&graph, d, |_, &w| w).unwrap(),
+        (0, vec![])
+    );
+}
+
+#[test]
+fn test_empty_graph() {
+    let graph: VecGraph<(), u32> = VecGraph::default();
+    assert_eq!(longest_path_dag(&graph, |_, &w| w).unwrap(), (0, vec![]));
+}
+
+#[test]
+fn test_cycle_is_reported() {
+    let mut graph: VecGraph<(), u32> = VecGraph::default();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    let d = graph.add_node(());
+    graph.add_edge(1, a, b);
+    graph.add_edge(1, b, c);
+    graph.add_edge(1, c, d);
+    graph.add_edge(1, d, b);
+
+    let err = longest_path_dag(&graph, |_, &w| w).unwrap_err();
+    let mut cycle = err.cycle().to_vec();
+    cycle.sort();
+    assert_eq!(cycle, vec![b, c, d]);
+    assert!(longest_path_from(&graph, a, |_, &w| w).is_err());
+}
+
+#[test]
+fn test_reported_cycle_is_closed() {
+    let mut graph: VecGraph<(), u32> = VecGraph::default();
+    let nodes: Vec<_> = (0..6).map(|_| graph.add_node(())).collect();
+    graph.add_edge(1, nodes[0], nodes[1]);
+    graph.add_edge(1, nodes[1], nodes[2]);
+    graph.add_edge(1, nodes[2], nodes[3]);
+    graph.add_edge(1, nodes[3], nodes[4]);
+    graph.add_edge(1, nodes[4], nodes[2]);
+    graph.add_edge(1, nodes[3], nodes[5]);
+
+    let cycle = longest_path_dag(&graph, |_, &w| w)
+        .unwrap_err()
+        .into_cycle();
+    assert_eq!(cycle.len(), 3);
+    for (i, &node) in cycle.iter().enumerate() {
+        let next = cycle[(i + 1) % cycle.len()];
+        assert!(graph.find_edge(node, next).is_some());
+    }
+}
+
+#[test]
+fn test_self_loop_is_a_cycle() {
+    let mut graph: VecGraph<(), u32> = VecGraph::default();
+    let a = graph.add_node(());
+    graph.add_edge(1, a, a);
+
+    let err = longest_path_dag(&graph, |_, &w| w).unwrap_err();
+    assert_eq!(err.cycle(), &[a]);
+}
+
+#[test]
+fn test_distances_accumulate_along_chain() {
+    let mut graph: VecGraph<(), u32> = VecGraph::default();
+    let nodes: Vec<_> = (0..5).map(|_| graph.add_node(())).collect();
+    for (i, pair) in nodes.windows(2).enumerate() {
+        graph.add_edge(i as u32 + 1, pair[0], pair[1]);
+    }
+
+    let dist = longest_path(&graph, |_, &w| w).unwrap();
+    assert_eq!(
+        nodes.iter().map(|&ix| dist[ix]).collect::<Vec<_>>(),
+        vec![0, 1, 3, 6, 10]
+    );
+}
+
+#[test]
+fn test_distances_take_longer_diamond_branch() {
+    let mut graph: VecGraph<&str, i32> = VecGraph::default();
+    let top = graph.add_node("top");
+    let left = graph.add_node("left");
+    let right = graph.add_node("right");
+    let bottom = graph.add_node("bottom");
+    graph.add_edge(1, top, left);
+    graph.add_edge(1, left, bottom);
+    graph.add_edge(3, top, right);
+    graph.add_edge(2, right, bottom);
+
+    {
+        let dist = longest_path(&graph, |_, &w| w).unwrap();
+        assert_eq!(
+            (dist[top], dist[left], dist[right], dist[bottom]),
+            (0, 1, 3, 5)
+        );
+    }
+
+    // Negative edges never beat the empty path
+    let bad = graph.add_node("bad");
+    graph.add_edge(-4, bad, top);
+    let dist = longest_path(&graph, |_, &w| w).unwrap();
+    assert_eq!((dist[bad], dist[top], dist[bottom]), (0, 0, 5));
+}
+
+#[test]
+fn test_distances_on_cyclic_graph() {
+    let mut graph: VecGraph<(), u32> = VecGraph::default();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    graph.add_edge(1, a, b);
+    graph.add_edge(1, b, c);
+    graph.add_edge(1, c, b);
+
+    let Err(err) = longest_path(&graph, |_, &w| w) else {
+        panic!("cycle not reported");
+    };
+    let mut cycle = err.into_cycle();
+    cycle.sort();
+    assert_eq!(cycle, vec![b, c]);
+}
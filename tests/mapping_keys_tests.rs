@@ -0,0 +1,183 @@
+use gotgraph::prelude::*;
+use gotgraph::undirected::UnVecGraph;
+use gotgraph::Mapping;
+
+fn create_graph() -> VecGraph<i32, &'static str> {
+    let mut graph = VecGraph::default();
+    let n: Vec<_> = (0..4).map(|i| graph.add_node(i * 10)).collect();
+    graph.add_edge("a", n[0], n[1]);
+    graph.add_edge("b", n[1], n[2]);
+    graph.add_edge("c", n[2], n[0]);
+    graph.add_edge("d", n[3], n[3]);
+    graph
+}
+
+#[test]
+fn test_vec_node_map_pairs_match_init_closure() {
+    let graph = create_graph();
+    let map = graph.init_node_map(|ix, &value| (ix, value));
+
+    for (key, &(ix, value)) in map.pairs() {
+        assert_eq!(key, ix);
+        assert_eq!(*graph.node(key), value);
+    }
+    assert_eq!(
+        map.keys().collect::<Vec<_>>(),
+        graph.node_indices().collect::<Vec<_>>()
+    );
+    assert_eq!(map.pairs().count(), graph.len_nodes());
+}
+
+#[test]
+fn test_vec_edge_map_pairs_match_init_closure() {
+    let graph = create_graph();
+    let map = graph.init_edge_map(|ix, &name| (ix, name));
+
+    for (key, &(ix, name)) in map.pairs() {
+        assert_eq!(key, ix);
+        assert_eq!(*graph.edge(key), name);
+    }
+    assert_eq!(
+        map.keys().collect::<Vec<_>>(),
+        graph.edge_indices().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_keys_follow_iter_order() {
+    let graph = create_graph();
+    let mut map = graph.init_node_map(|_, &value| value);
+    for value in map.iter_mut() {
+        *value += 1;
+    }
+
+    let keys: Vec<_> = map.keys().collect();
+    let values: Vec<_> = map.iter().copied().collect();
+    let pairs: Vec<_> = map.pairs().map(|(k, &v)| (k, v)).collect();
+    assert_eq!(pairs, keys.into_iter().zip(values).collect::<Vec<_>>());
+    for (key, &value) in map.pairs() {
+        assert_eq!(map[key], value);
+    }
+}
+
+#[test]
+fn test_default_map_pairs() {
+    let mut graph: UnVecGraph<char, u32> = UnVecGraph::default();
+    let a = graph.add_node('a');
+    let b = graph.add_node('b');
+    let c = graph.add_node('c');
+    graph.add_edge(1, a, b);
+    graph.add_edge(2, b, c);
+
+    let nodes = graph.init_node_map(|ix, &name| (ix, name));
+    for (key, &(ix, name)) in nodes.pairs() {
+        assert_eq!(key, ix);
+        assert_eq!(*graph.node(key), name);
+    }
+    assert_eq!(nodes.keys().collect::<Vec<_>>(), vec![a, b, c]);
+
+    let edges = graph.init_edge_map(|ix, &weight| (ix, weight));
+    for (key, &(ix, weight)) in edges.pairs() {
+        assert_eq!(key, ix);
+        assert_eq!(*graph.edge(key), weight);
+    }
+    assert_eq!(edges.keys().count(), 2);
+}
+
+#[test]
+fn test_context_and_raw_map_pairs() {
+    let graph = create_graph();
+    let raw = graph.scope(|ctx| {
+        let map = ctx.init_node_map(|tag, &value| (ctx.node(tag), value));
+        for (tag, &(node, value)) in map.pairs() {
+            assert_eq!(ctx.node(tag), node);
+            assert_eq!(*node, value);
+        }
+        assert_eq!(map.keys().count(), ctx.len_nodes());
+        assert!(map.keys().zip(ctx.node_indices()).all(|(a, b)| a == b));
+        ctx.init_node_map(|_, &value| value).into_raw(ctx)
+    });
+
+    let mut seen: Vec<_> = raw.pairs().map(|(ix, &value)| (ix, value)).collect();
+    seen.sort();
+    assert_eq!(
+        seen,
+        graph
+            .node_pairs()
+            .map(|(ix, &v)| (ix, v))
+            .collect::<Vec<_>>()
+    );
+    let mut keys: Vec<_> = raw.keys().collect();
+    keys.sort();
+    assert_eq!(keys, graph.node_indices().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_max_degree_node_via_max_by_key_value() {
+    let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    let n: Vec<_> = ["a", "b", "c", "d", "e"]
+        .iter()
+        .map(|&name| graph.add_node(name))
+        .collect();
+    for &(from, to) in &[(0, 1), (1, 2), (2, 0), (2, 3), (2, 4), (4, 2), (3, 3)] {
+        graph.add_edge((), n[from], n[to]);
+    }
+
+    let degree = graph.init_node_map(|ix, _| {
+        graph.outgoing_edge_indices(ix).count() + graph.incoming_edge_indices(ix).count()
+    });
+    assert_eq!(degree.max_by_key_value(), Some((n[2], &5)));
+    assert_eq!(
+        degree.fold_values(0, |total, &d| total + d),
+        2 * graph.len_edges()
+    );
+
+    // Ties go to the last key, as with `Iterator::max`
+    let flat = graph.init_node_map(|_, _| 1);
+    assert_eq!(flat.max_by_key_value(), Some((n[4], &1)));
+
+    let empty: VecGraph<(), ()> = VecGraph::default();
+    assert_eq!(empty.init_node_map(|_, _| 0).max_by_key_value(), None);
+
+    graph.scope(|ctx| {
+        let degree = ctx.init_node_map(|tag, _| ctx.outgoing_edge_indices(tag).count());
+        let (tag, &max) = degree.max_by_key_value().unwrap();
+        assert_eq!(tag.inner(), n[2]);
+        assert_eq!(max, 3);
+    });
+}
+
+#[test]
+fn test_hash_map_round_trip() {
+    let graph = create_graph();
+    let names = graph.init_edge_map(|ix, &name| format!("{}{}", name, ix.index()));
+    let hash_map = names.to_hash_map();
+    assert_eq!(hash_map.len(), graph.len_edges());
+
+    let back = graph.init_edge_map(|ix, _| hash_map[&ix].clone());
+    for ix in graph.edge_indices() {
+        assert_eq!(back[ix], format!("{}{}", graph.edge(ix), ix.index()));
+    }
+    assert_eq!(
+        back.into_pairs().collect::<Vec<_>>(),
+        graph
+            .edge_indices()
+            .map(|ix| (ix, hash_map[&ix].clone()))
+            .collect::<Vec<_>>()
+    );
+
+    // Default and context maps carry their keys as well
+    let mut un: UnVecGraph<char, u32> = UnVecGraph::default();
+    let a = un.add_node('a');
+    let b = un.add_node('b');
+    un.add_edge(7, a, b);
+    let nodes = un.init_node_map(|_, &name| name).to_hash_map();
+    assert_eq!((nodes[&a], nodes[&b]), ('a', 'b'));
+
+    graph.scope(|ctx| {
+        let values = ctx.init_node_map(|_, &value| value).to_hash_map();
+        for tag in ctx.node_indices() {
+            assert_eq!(values[&tag], *ctx.node(tag));
+        }
+    });
+}
@@ -0,0 +1,151 @@
+use gotgraph::algo::max_flow;
+use gotgraph::prelude::*;
+
+/// Checks capacity limits and flow conservation at every node except source and sink
+fn assert_valid_flow(
+    graph: &VecGraph<&str, u64>,
+    source: <VecGraph<&str, u64> as Graph>::NodeIx,
+    sink: <VecGraph<&str, u64> as Graph>::NodeIx,
+) -> u64 {
+    let (value, flow) = max_flow(graph, source, sink, |_, &cap| cap);
+
+    for (ix, &cap) in graph.edge_pairs() {
+        assert!(flow[ix] <= cap);
+    }
+    for node in graph.node_indices() {
+        let inflow: u64 = graph.incoming_edge_indices(node).map(|e| flow[e]).sum();
+        let outflow: u64 = graph.outgoing_edge_indices(node).map(|e| flow[e]).sum();
+        if node == source {
+            assert_eq!(outflow - inflow, value);
+        } else if node == sink {
+            assert_eq!(inflow - outflow, value);
+        } else {
+            assert_eq!(inflow, outflow);
+        }
+    }
+
+    value
+}
+
+#[test]
+fn test_clrs_network() {
+    // Network from "Introduction to Algorithms", max flow 23
+    let mut graph: VecGraph<&str, u64> = VecGraph::default();
+    let s = graph.add_node("s");
+    let v1 = graph.add_node("v1");
+    let v2 = graph.add_node("v2");
+    let v3 = graph.add_node("v3");
+    let v4 = graph.add_node("v4");
+    let t = graph.add_node("t");
+
+    graph.add_edge(16, s, v1);
+    graph.add_edge(13, s, v2);
+    graph.add_edge(4, v2, v1);
+    graph.add_edge(12, v1, v3);
+    graph.add_edge(9, v3, v2);
+    graph.add_edge(14, v2, v4);
+    graph.add_edge(7, v4, v3);
+    graph.add_edge(20, v3, t);
+    graph.add_edge(4, v4, t);
+
+    assert_eq!(assert_valid_flow(&graph, s, t), 23);
+}
+
+#[test]
+fn test_network_requiring_flow_cancellation() {
+    // The first shortest path s->a->b->t has to be partially undone
+    let mut graph: VecGraph<&str, u64> = VecGraph::default();
+    let s = graph.add_node("s");
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let t = graph.add_node("t");
+
+    graph.add_edge(1, s, a);
+    graph.add_edge(1, s, b);
+    graph.add_edge(1, a, b);
+    graph.add_edge(1, a, t);
+    graph.add_edge(1, b, t);
+
+    assert_eq!(assert_valid_flow(&graph, s, t), 2);
+}
+
+#[test]
+fn test_parallel_edges_and_self_loops() {
+    let mut graph: VecGraph<&str, u64> = VecGraph::default();
+    let s = graph.add_node("s");
+    let t = graph.add_node("t");
+
+    let e1 = graph.add_edge(3, s, t);
+    let e2 = graph.add_edge(4, s, t);
+    let l1 = graph.add_edge(100, s, s);
+    let l2 = graph.add_edge(100, t, t);
+
+    let (value, flow) = max_flow(&graph, s, t, |_, &cap| cap);
+    assert_eq!(value, 7);
+    assert_eq!(flow[e1], 3);
+    assert_eq!(flow[e2], 4);
+    assert_eq!(flow[l1], 0);
+    assert_eq!(flow[l2], 0);
+}
+
+#[test]
+fn test_disconnected_source_and_sink() {
+    let mut graph: VecGraph<&str, u64> = VecGraph::default();
+    let s = graph.add_node("s");
+    let a = graph.add_node("a");
+    let t = graph.add_node("t");
+
+    graph.add_edge(5, s, a);
+    graph.add_edge(5, t, a);
+
+    assert_eq!(assert_valid_flow(&graph, s, t), 0);
+}
+
+#[test]
+fn test_source_equals_sink() {
+    let mut graph: VecGraph<&str, u64> = VecGraph::default();
+    let s = graph.add_node("s");
+    let a = graph.add_node("a");
+    let e = graph.add_edge(5, s, a);
+    graph.add_edge(5, a, s);
+
+    let (value, flow) = max_flow(&graph, s, s, |_, &cap| cap);
+    assert_eq!(value, 0);
+    assert_eq!(flow[e], 0);
+}
+
+#[test]
+fn test_capacity_from_edge_index() {
+    let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    let s = graph.add_node("s");
+    let t = graph.add_node("t");
+    let wide = graph.add_edge((), s, t);
+    graph.add_edge((), s, t);
+
+    let (value, _) = max_flow(&graph, s, t, |ix, _| if ix == wide { 10 } else { 1 });
+    assert_eq!(value, 11);
+}
+
+#[test]
+fn test_largest_representable_flow() {
+    let mut graph: VecGraph<&str, u64> = VecGraph::default();
+    let s = graph.add_node("s");
+    let t = graph.add_node("t");
+    graph.add_edge(u64::MAX - 1, s, t);
+    graph.add_edge(1, s, t);
+
+    let (value, _) = max_flow(&graph, s, t, |_, &cap| cap);
+    assert_eq!(value, u64::MAX);
+}
+
+#[test]
+#[should_panic(expected = "maximum flow value overflows u64")]
+fn test_flow_value_overflow_panics() {
+    let mut graph: VecGraph<&str, u64> = VecGraph::default();
+    let s = graph.add_node("s");
+    let t = graph.add_node("t");
+    graph.add_edge(u64::MAX, s, t);
+    graph.add_edge(1, s, t);
+
+    max_flow(&graph, s, t, |_, &cap| cap);
+}
@@ -0,0 +1,105 @@
+use gotgraph::prelude::*;
+use gotgraph::undirected::UnVecGraph;
+
+fn create_large_graph(nodes: usize) -> VecGraph<u64, u64> {
+    let mut graph = VecGraph::default();
+    let ixs: Vec<_> = (0..nodes as u64).map(|i| graph.add_node(i)).collect();
+    for (i, w) in ixs.windows(2).enumerate() {
+        graph.add_edge(i as u64, w[0], w[1]);
+        graph.add_edge(i as u64, w[1], w[0]);
+    }
+    graph
+}
+
+#[test]
+fn test_shrink_after_drain() {
+    let mut graph = create_large_graph(10_000);
+    let before = graph.memory_footprint();
+    assert_eq!(before.len_nodes, 10_000);
+    assert_eq!(before.len_edges, 19_998);
+
+    let (nodes, edges): (Vec<_>, Vec<_>) = graph.drain();
+    assert_eq!(nodes.len(), 10_000);
+    assert_eq!(edges.len(), 19_998);
+
+    // Draining keeps the allocation
+    let drained = graph.memory_footprint();
+    assert_eq!(drained.len_nodes, 0);
+    assert_eq!(drained.len_edges, 0);
+    assert!(drained.nodes_capacity >= 10_000);
+    assert!(drained.edges_capacity >= 19_998);
+    assert_eq!(drained.total_bytes(), before.total_bytes());
+
+    graph.shrink_to_fit();
+    let shrunk = graph.memory_footprint();
+    assert_eq!(shrunk.nodes_capacity, 0);
+    assert_eq!(shrunk.edges_capacity, 0);
+    assert_eq!(shrunk.total_bytes(), 0);
+}
+
+#[test]
+fn test_shrink_after_partial_removal() {
+    let mut graph = create_large_graph(1000);
+    let doomed: Vec<_> = graph
+        .node_indices()
+        .filter(|&ix| *graph.node(ix) >= 10)
+        .collect();
+    // Highest index first, so that swap removal does not renumber the remaining ones
+    for ix in doomed.into_iter().rev() {
+        graph.remove_node(ix);
+    }
+
+    let memory = graph.memory_footprint();
+    assert_eq!(memory.len_nodes, 10);
+    assert_eq!(memory.len_edges, 18);
+    assert!(memory.nodes_capacity >= 1000);
+
+    graph.shrink_to_fit();
+    let memory = graph.memory_footprint();
+    assert_eq!(memory.nodes_capacity, memory.len_nodes);
+    assert_eq!(memory.edges_capacity, memory.len_edges);
+    assert!(memory.nodes_bytes >= 10 * std::mem::size_of::<u64>());
+
+    // The graph is still fully usable
+    let a = graph.node_indices().next().unwrap();
+    graph.add_edge(99, a, a);
+    assert_eq!(graph.len_edges(), 19);
+}
+
+#[test]
+fn test_footprint_is_forwarded() {
+    let mut graph = create_large_graph(100);
+    let _: (Vec<_>, Vec<_>) = graph.drain();
+    let expected = graph.memory_footprint();
+
+    assert_eq!(Graph::memory_footprint(&&graph), expected);
+    assert_eq!(graph.scope(|ctx| ctx.memory_footprint()), expected);
+    assert_eq!(graph.scope_mut(|ctx| ctx.memory_footprint()), expected);
+    assert_eq!(UnVecGraph(graph).memory_footprint(), expected);
+}
+
+#[test]
+fn test_capacity_drops_after_clear_and_shrink() {
+    let mut graph = create_large_graph(5_000);
+    let (node_capacity, edge_capacity) = graph.capacity();
+    assert!(node_capacity >= 5_000);
+    assert!(edge_capacity >= 9_998);
+    assert_eq!(edge_capacity, graph.edge_capacity());
+
+    graph.clear();
+    assert_eq!(graph.capacity(), (node_capacity, edge_capacity));
+
+    graph.shrink_to_fit();
+    assert_eq!(graph.capacity(), (0, 0));
+
+    // Shrinking a non-empty graph keeps room for its contents
+    let mut graph = create_large_graph(1_000);
+    let nodes: Vec<_> = graph.node_indices().skip(10).collect();
+    for &node in nodes.iter().rev() {
+        graph.remove_node(node);
+    }
+    graph.shrink_to_fit();
+    let (node_capacity, edge_capacity) = graph.capacity();
+    assert!((10..1_000).contains(&node_capacity));
+    assert!((graph.len_edges()..1_998).contains(&edge_capacity));
+}
@@ -0,0 +1,106 @@
+use gotgraph::algo::minimum_spanning_tree;
+use gotgraph::prelude::*;
+use std::collections::BTreeSet;
+
+fn total_weight(graph: &VecGraph<&str, u32>, tree: &[gotgraph::vec_graph::EdgeIx]) -> u32 {
+    tree.iter().map(|&e| *graph.edge(e)).sum()
+}
+
+#[test]
+fn test_mst_avoids_heavy_parallel_edge() {
+    let mut graph: VecGraph<&str, u32> = VecGraph::default();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    let d = graph.add_node("D");
+
+    let heavy = graph.add_edge(10, a, b);
+    let light = graph.add_edge(1, b, a); // parallel, opposite direction
+    let bc = graph.add_edge(3, b, c);
+    let ac = graph.add_edge(4, a, c);
+    let cd = graph.add_edge(2, d, c);
+    graph.add_edge(0, d, d); // self-loop
+
+    let tree = minimum_spanning_tree(&graph, |&w| w);
+    assert_eq!(tree.len(), 3);
+    assert!(!tree.contains(&heavy));
+    assert!(!tree.contains(&ac));
+    assert_eq!(
+        tree.iter().copied().collect::<BTreeSet<_>>(),
+        [light, bc, cd].into_iter().collect()
+    );
+    assert_eq!(total_weight(&graph, &tree), 6);
+
+    // Edges are returned in order of increasing weight
+    let weights: Vec<_> = tree.iter().map(|&e| *graph.edge(e)).collect();
+    assert_eq!(weights, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_mst_spanning_forest_for_disconnected_graph() {
+    let mut graph: VecGraph<&str, u32> = VecGraph::default();
+    let n: Vec<_> = ["A", "B", "C", "D", "E", "F", "G"]
+        .iter()
+        .map(|&name| graph.add_node(name))
+        .collect();
+    // Component {A, B, C}
+    graph.add_edge(1, n[0], n[1]);
+    graph.add_edge(2, n[1], n[2]);
+    graph.add_edge(5, n[2], n[0]);
+    // Component {D, E, F}
+    graph.add_edge(7, n[3], n[4]);
+    graph.add_edge(3, n[5], n[4]);
+    graph.add_edge(4, n[3], n[5]);
+    // G is isolated
+
+    let tree = minimum_spanning_tree(&graph, |&w| w);
+    // V - C = 7 - 3
+    assert_eq!(tree.len(), 4);
+    assert_eq!(total_weight(&graph, &tree), 1 + 2 + 3 + 4);
+
+    // No tree edge connects different components
+    let component = |ix| n[..3].contains(&ix);
+    for &e in &tree {
+        let [from, to] = graph.endpoints(e);
+        assert_eq!(component(from), component(to));
+    }
+}
+
+#[test]
+fn test_mst_ties_prefer_lower_edge_index() {
+    let mut graph: VecGraph<&str, u32> = VecGraph::default();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    let first = graph.add_edge(1, a, b);
+    let second = graph.add_edge(1, b, c);
+    graph.add_edge(1, c, a);
+
+    assert_eq!(minimum_spanning_tree(&graph, |&w| w), vec![first, second]);
+}
+
+#[test]
+fn test_mst_empty_and_single_node() {
+    let mut graph: VecGraph<&str, u32> = VecGraph::default();
+    assert!(minimum_spanning_tree(&graph, |&w| w).is_empty());
+    let a = graph.add_node("A");
+    graph.add_edge(1, a, a);
+    assert!(minimum_spanning_tree(&graph, |&w| w).is_empty());
+}
+
+#[test]
+fn test_mst_inside_scope() {
+    let mut graph: VecGraph<&str, u32> = VecGraph::default();
+    graph.scope_mut(|mut ctx| {
+        let a = ctx.add_node("A");
+        let b = ctx.add_node("B");
+        let c = ctx.add_node("C");
+        ctx.add_edge(5, a, b);
+        ctx.add_edge(1, b, c);
+        ctx.add_edge(2, a, c);
+
+        let tree = minimum_spanning_tree(&ctx, |&w| w);
+        let weights: Vec<_> = tree.iter().map(|&e| *ctx.edge(e)).collect();
+        assert_eq!(weights, vec![1, 2]);
+    });
+}
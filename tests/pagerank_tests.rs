@@ -0,0 +1,93 @@
+use gotgraph::algo::pagerank;
+use gotgraph::prelude::*;
+use gotgraph::Mapping;
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-9,
+        "expected {}, got {}",
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn test_most_referenced_node_ranks_highest() {
+    let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    let hub = graph.add_node("hub");
+    let others: Vec<_> = ["a", "b", "c", "d"]
+        .into_iter()
+        .map(|name| graph.add_node(name))
+        .collect();
+    for &node in &others {
+        graph.add_edge((), node, hub);
+    }
+    graph.add_edge((), hub, others[0]);
+    graph.add_edge((), others[0], others[1]);
+    // d is dangling apart from its edge to the hub
+    graph.add_edge((), others[2], others[3]);
+
+    let rank = pagerank(&graph, 0.85, 100);
+    let best = graph
+        .node_indices()
+        .max_by(|&a, &b| rank[a].total_cmp(&rank[b]))
+        .unwrap();
+    assert_eq!(best, hub);
+    assert!(rank[others[0]] > rank[others[2]]);
+    assert_close(rank.iter().sum(), 1.0);
+}
+
+#[test]
+fn test_dangling_nodes_keep_total_rank() {
+    // Nobody links anywhere: all mass is redistributed uniformly
+    let mut graph: VecGraph<(), ()> = VecGraph::default();
+    let nodes = graph.add_nodes([(), (), (), ()]);
+    {
+        let rank = pagerank(&graph, 0.85, 10);
+        for &node in &nodes {
+            assert_close(rank[node], 0.25);
+        }
+    }
+
+    // A chain ending in a dangling node
+    graph.add_edge((), nodes[0], nodes[1]);
+    graph.add_edge((), nodes[1], nodes[2]);
+    let rank = pagerank(&graph, 0.85, 100);
+    assert_close(rank.iter().sum(), 1.0);
+    assert!(rank[nodes[2]] > rank[nodes[1]]);
+    assert!(rank[nodes[1]] > rank[nodes[0]]);
+    assert_close(rank[nodes[0]], rank[nodes[3]]);
+}
+
+#[test]
+fn test_symmetric_cycle_and_edge_cases() {
+    let mut graph: VecGraph<(), ()> = VecGraph::default();
+    let nodes = graph.add_nodes([(), (), ()]);
+    graph.add_edge((), nodes[0], nodes[1]);
+    graph.add_edge((), nodes[1], nodes[2]);
+    graph.add_edge((), nodes[2], nodes[0]);
+    {
+        let rank = pagerank(&graph, 0.85, 5);
+        for &node in &nodes {
+            assert_close(rank[node], 1.0 / 3.0);
+        }
+    }
+
+    // Zero iterations leaves the uniform start, zero damping ignores the edges
+    graph.add_edge((), nodes[0], nodes[2]);
+    for rank in [pagerank(&graph, 0.85, 0), pagerank(&graph, 0.0, 10)] {
+        for &node in &nodes {
+            assert_close(rank[node], 1.0 / 3.0);
+        }
+    }
+
+    let empty: VecGraph<(), ()> = VecGraph::default();
+    assert_eq!(pagerank(&empty, 0.85, 10).iter().count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Damping factor")]
+fn test_invalid_damping_panics() {
+    let graph: VecGraph<(), ()> = VecGraph::default();
+    pagerank(&graph, 1.5, 10);
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "petgraph")]
+
+use gotgraph::algo::tarjan;
+use gotgraph::prelude::*;
+use gotgraph::vec_graph::{EdgeIx, NodeIx};
+use petgraph::graph::{DiGraph, EdgeIndex, NodeIndex};
+
+fn create_graph() -> VecGraph<u32, u32> {
+    let mut graph = VecGraph::default();
+    let n: Vec<_> = (0..8).map(|i| graph.add_node(i * 10)).collect();
+    // Two cycles joined by a bridge, plus a tail and a self-loop
+    let edges = [
+        (0, 1),
+        (1, 2),
+        (2, 0),
+        (2, 3),
+        (3, 4),
+        (4, 5),
+        (5, 3),
+        (5, 6),
+        (7, 7),
+        (0, 1),
+    ];
+    for (i, &(from, to)) in edges.iter().enumerate() {
+        graph.add_edge(i as u32, n[from], n[to]);
+    }
+    graph
+}
+
+#[test]
+fn test_to_petgraph_preserves_indices() {
+    let graph = create_graph();
+    let converted = DiGraph::from(&graph);
+
+    assert_eq!(converted.node_count(), graph.len_nodes());
+    assert_eq!(converted.edge_count(), graph.len_edges());
+    for (ix, &payload) in graph.node_pairs() {
+        assert_eq!(converted[NodeIndex::new(ix.index())], payload);
+    }
+    for (ix, &payload) in graph.edge_pairs() {
+        let [from, to] = graph.endpoints(ix);
+        let edge = EdgeIndex::new(ix.index());
+        assert_eq!(converted[edge], payload);
+        assert_eq!(
+            converted.edge_endpoints(edge),
+            Some((NodeIndex::new(from.index()), NodeIndex::new(to.index())))
+        );
+    }
+}
+
+#[test]
+fn test_from_petgraph_preserves_indices() {
+    let mut source: DiGraph<&str, u32> = DiGraph::new();
+    let a = source.add_node("A");
+    let b = source.add_node("B");
+    let c = source.add_node("C");
+    source.add_edge(a, b, 1);
+    source.add_edge(b, c, 2);
+    source.add_edge(c, c, 3);
+
+    let graph = VecGraph::from_petgraph(&source);
+    assert_eq!(graph.len_nodes(), 3);
+    assert_eq!(graph.len_edges(), 3);
+    for node in source.node_indices() {
+        let ix = NodeIx::try_from(node.index()).unwrap();
+        assert_eq!(graph.node(ix), &source[node]);
+    }
+    for edge in source.edge_indices() {
+        let ix = EdgeIx::try_from(edge.index()).unwrap();
+        let (from, to) = source.edge_endpoints(edge).unwrap();
+        assert_eq!(graph.edge(ix), &source[edge]);
+        assert_eq!(
+            graph.endpoints(ix),
+            [
+                NodeIx::try_from(from.index()).unwrap(),
+                NodeIx::try_from(to.index()).unwrap()
+            ]
+        );
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    let graph = create_graph();
+    let back = VecGraph::from_petgraph(&DiGraph::from(&graph));
+    assert_eq!(back.to_string(), graph.to_string());
+}
+
+#[test]
+fn test_scc_count_matches_kosaraju() {
+    let graph = create_graph();
+    let converted = DiGraph::from(&graph);
+
+    let mut ours: Vec<Vec<usize>> = tarjan(&graph)
+        .map(|scc| {
+            let mut scc: Vec<_> = scc.iter().map(|ix| ix.index()).collect();
+            scc.sort();
+            scc
+        })
+        .collect();
+    let mut theirs: Vec<Vec<usize>> = petgraph::algo::kosaraju_scc(&converted)
+        .into_iter()
+        .map(|scc| {
+            let mut scc: Vec<_> = scc.iter().map(|ix| ix.index()).collect();
+            scc.sort();
+            scc
+        })
+        .collect();
+
+    assert_eq!(ours.len(), theirs.len());
+    ours.sort();
+    theirs.sort();
+    assert_eq!(ours, theirs);
+}
@@ -0,0 +1,137 @@
+#![cfg(feature = "proptest")]
+
+use gotgraph::prelude::*;
+use gotgraph::proptest::vec_graph;
+use proptest::prelude::*;
+use proptest::test_runner::{TestError, TestRunner};
+use std::collections::HashSet;
+
+#[derive(Clone, Debug)]
+enum Op {
+    AddNode(u32),
+    AddEdge(usize, usize, u32),
+    RemoveNode(usize),
+    RemoveEdge(usize),
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        any::<u32>().prop_map(Op::AddNode),
+        (any::<usize>(), any::<usize>(), any::<u32>())
+            .prop_map(|(from, to, data)| Op::AddEdge(from, to, data)),
+        any::<usize>().prop_map(Op::RemoveNode),
+        any::<usize>().prop_map(Op::RemoveEdge),
+    ]
+}
+
+/// Checks the structural invariants of `graph` through its public API.
+fn check_invariants(graph: &VecGraph<u32, u32>) -> Result<(), TestCaseError> {
+    let len_edges = graph.len_edges();
+    prop_assert_eq!(graph.node_indices().count(), graph.len_nodes());
+    prop_assert_eq!(graph.edge_indices().count(), len_edges);
+
+    for edge in graph.edge_indices() {
+        let [from, to] = graph.endpoints(edge);
+        prop_assert!(graph.exists_node_index(from), "{:?} has no source", edge);
+        prop_assert!(graph.exists_node_index(to), "{:?} has no target", edge);
+    }
+
+    let mut outgoing = HashSet::new();
+    let mut incoming = HashSet::new();
+    for node in graph.node_indices() {
+        // A chain with a cycle would yield more entries than there are edges
+        let out: Vec<_> = graph
+            .outgoing_edge_indices(node)
+            .take(len_edges + 1)
+            .collect();
+        let inc: Vec<_> = graph
+            .incoming_edge_indices(node)
+            .take(len_edges + 1)
+            .collect();
+        prop_assert!(out.len() <= len_edges && inc.len() <= len_edges);
+        for edge in out {
+            prop_assert_eq!(graph.endpoints(edge)[0], node);
+            prop_assert!(outgoing.insert(edge), "{:?} is in two chains", edge);
+        }
+        for edge in inc {
+            prop_assert_eq!(graph.endpoints(edge)[1], node);
+            prop_assert!(incoming.insert(edge), "{:?} is in two chains", edge);
+        }
+    }
+    prop_assert_eq!(outgoing.len(), len_edges);
+    prop_assert_eq!(incoming.len(), len_edges);
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn generated_graphs_are_valid(graph in vec_graph(any::<u32>(), any::<u32>(), 0..20, 0..40)) {
+        prop_assert!(graph.len_nodes() < 20);
+        prop_assert!(graph.len_nodes() > 0 || graph.len_edges() == 0);
+        check_invariants(&graph)?;
+    }
+
+    #[test]
+    fn random_operations_keep_invariants(
+        mut graph in any::<VecGraph<u32, u32>>(),
+        ops in prop::collection::vec(op(), 0..64),
+    ) {
+        check_invariants(&graph)?;
+        for op in ops {
+            let nodes: Vec<_> = graph.node_indices().collect();
+            let edges: Vec<_> = graph.edge_indices().collect();
+            let (len_nodes, len_edges) = (graph.len_nodes(), graph.len_edges());
+            match op {
+                Op::AddNode(data) => {
+                    graph.add_node(data);
+                    prop_assert_eq!(graph.len_nodes(), len_nodes + 1);
+                }
+                Op::AddEdge(from, to, data) if !nodes.is_empty() => {
+                    let (from, to) = (nodes[from % nodes.len()], nodes[to % nodes.len()]);
+                    let edge = graph.add_edge(data, from, to);
+                    prop_assert_eq!(graph.endpoints(edge), [from, to]);
+                    prop_assert_eq!(graph.len_edges(), len_edges + 1);
+                }
+                Op::RemoveNode(i) if !nodes.is_empty() => {
+                    let node = nodes[i % nodes.len()];
+                    let incident: HashSet<_> = graph
+                        .outgoing_edge_indices(node)
+                        .chain(graph.incoming_edge_indices(node))
+                        .collect();
+                    let data = *graph.node(node);
+                    prop_assert_eq!(graph.remove_node(node), data);
+                    prop_assert_eq!(graph.len_nodes(), len_nodes - 1);
+                    prop_assert_eq!(graph.len_edges(), len_edges - incident.len());
+                }
+                Op::RemoveEdge(i) if !edges.is_empty() => {
+                    let edge = edges[i % edges.len()];
+                    let data = *graph.edge(edge);
+                    prop_assert_eq!(graph.remove_edge(edge), data);
+                    prop_assert_eq!(graph.len_edges(), len_edges - 1);
+                }
+                _ => {}
+            }
+            check_invariants(&graph)?;
+        }
+    }
+}
+
+#[test]
+fn test_shrinks_edges_then_isolated_nodes() {
+    let mut runner = TestRunner::deterministic();
+    let strategy = vec_graph(0..100u32, 0..100u32, 5..20, 0..40);
+    let result = runner.run(&strategy, |graph| {
+        prop_assert!(graph.len_edges() < 3);
+        Ok(())
+    });
+
+    let Err(TestError::Fail(_, graph)) = result else {
+        panic!("the property should fail, got {:?}", result);
+    };
+    // Edges are removed down to the minimum that still fails, then every node no
+    // remaining edge touches is removed down to the minimum node count
+    assert_eq!(graph.len_edges(), 3);
+    assert!(graph.len_nodes() >= 5 && graph.len_nodes() <= 6);
+    assert!(graph.nodes().all(|&data| data == 0));
+    assert!(graph.edges().all(|&data| data == 0));
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "rayon")]
+
+use gotgraph::prelude::*;
+use rayon::prelude::*;
+
+fn create_graph() -> VecGraph<u64, u64> {
+    let mut graph = VecGraph::default();
+    let nodes: Vec<_> = (0..1000).map(|i| graph.add_node(i * 3)).collect();
+    for (i, pair) in nodes.windows(2).enumerate() {
+        graph.add_edge(i as u64, pair[0], pair[1]);
+    }
+    graph
+}
+
+#[test]
+fn test_parallel_sum_matches_sequential() {
+    let graph = create_graph();
+
+    let sequential: u64 = graph.nodes().sum();
+    assert_eq!(graph.par_nodes().sum::<u64>(), sequential);
+    assert_eq!(
+        graph.par_node_pairs().map(|(_, &n)| n).sum::<u64>(),
+        sequential
+    );
+    assert_eq!(
+        graph
+            .par_node_indices()
+            .map(|ix| *graph.node(ix))
+            .sum::<u64>(),
+        sequential
+    );
+
+    let sequential: u64 = graph.edges().sum();
+    assert_eq!(graph.par_edges().sum::<u64>(), sequential);
+    assert_eq!(
+        graph.par_edge_pairs().map(|(_, &e)| e).sum::<u64>(),
+        sequential
+    );
+}
+
+#[test]
+fn test_parallel_indices_match_sequential() {
+    let graph = create_graph();
+
+    let indices: Vec<_> = graph.par_node_indices().collect();
+    assert_eq!(indices, graph.node_indices().collect::<Vec<_>>());
+    let pairs: Vec<_> = graph.par_edge_pairs().collect();
+    assert_eq!(pairs, graph.edge_pairs().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_parallel_through_shared_reference() {
+    let graph = create_graph();
+    let shared: &VecGraph<u64, u64> = &graph;
+
+    let total: u64 = std::thread::scope(|s| {
+        let a = s.spawn(|| shared.par_nodes().sum::<u64>());
+        let b = s.spawn(|| shared.par_nodes().sum::<u64>());
+        a.join().unwrap() + b.join().unwrap()
+    });
+    assert_eq!(total, 2 * shared.nodes().sum::<u64>());
+}
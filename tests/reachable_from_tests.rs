@@ -0,0 +1,126 @@
+use gotgraph::prelude::*;
+
+fn reached(
+    graph: &VecGraph<&'static str, ()>,
+    sources: &[gotgraph::vec_graph::NodeIx],
+) -> Vec<&'static str> {
+    let reachable = graph.reachable_from(sources.iter().copied());
+    graph
+        .node_pairs()
+        .filter(|&(ix, _)| reachable[ix])
+        .map(|(_, &name)| name)
+        .collect()
+}
+
+#[test]
+fn test_forest_roots_reach_disjoint_sets() {
+    let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    let r1 = graph.add_node("r1");
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let r2 = graph.add_node("r2");
+    let c = graph.add_node("c");
+    let d = graph.add_node("d");
+    let lone = graph.add_node("lone");
+    graph.add_edge((), r1, a);
+    graph.add_edge((), a, b);
+    graph.add_edge((), r2, c);
+    graph.add_edge((), r2, d);
+
+    assert_eq!(reached(&graph, &[r1]), vec!["r1", "a", "b"]);
+    assert_eq!(reached(&graph, &[r2]), vec!["r2", "c", "d"]);
+    assert_eq!(
+        reached(&graph, &[r1, r2]),
+        vec!["r1", "a", "b", "r2", "c", "d"]
+    );
+    assert_eq!(reached(&graph, &[lone]), vec!["lone"]);
+    assert!(reached(&graph, &[]).is_empty());
+}
+
+#[test]
+fn test_shared_descendant_reached_from_either_root() {
+    let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    let r1 = graph.add_node("r1");
+    let r2 = graph.add_node("r2");
+    let shared = graph.add_node("shared");
+    let leaf = graph.add_node("leaf");
+    let other = graph.add_node("other");
+    graph.add_edge((), r1, shared);
+    graph.add_edge((), r2, shared);
+    graph.add_edge((), shared, leaf);
+    graph.add_edge((), leaf, shared);
+    graph.add_edge((), other, r1);
+
+    assert_eq!(reached(&graph, &[r1]), vec!["r1", "shared", "leaf"]);
+    assert_eq!(reached(&graph, &[r2]), vec!["r2", "shared", "leaf"]);
+    assert_eq!(
+        reached(&graph, &[r2, r1, r2, r2]),
+        vec!["r1", "r2", "shared", "leaf"]
+    );
+    // Edges are followed forwards only
+    assert_eq!(reached(&graph, &[shared]), vec!["shared", "leaf"]);
+}
+
+#[test]
+fn test_reachable_from_in_scope() {
+    let mut graph: VecGraph<i32, ()> = VecGraph::default();
+    graph.scope_mut(|mut ctx| {
+        let a = ctx.add_node(1);
+        let b = ctx.add_node(2);
+        let c = ctx.add_node(3);
+        ctx.add_edge((), a, b);
+
+        let reachable = ctx.reachable_from([a]);
+        assert!(reachable[a] && reachable[b] && !reachable[c]);
+    });
+}
+
+#[test]
+#[should_panic(expected = "does not exist")]
+fn test_reachable_from_invalid_source() {
+    let mut graph: VecGraph<i32, ()> = VecGraph::default();
+    let a = graph.add_node(1);
+    graph.remove_node(a);
+    graph.reachable_from([a]);
+}
+
+#[test]
+fn test_reachable_subgraph() {
+    let mut graph: VecGraph<&'static str, u32> = VecGraph::default();
+    let before = graph.add_node("before");
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let c = graph.add_node("c");
+    let other = graph.add_node("other");
+    graph.add_edge(0, before, a);
+    graph.add_edge(1, a, b);
+    graph.add_edge(2, b, c);
+    graph.add_edge(3, c, a);
+    graph.add_edge(4, c, c);
+    graph.add_edge(5, other, c);
+
+    let (sub, remap) = graph.reachable_subgraph(a);
+    assert_eq!(remap[before], None);
+    assert_eq!(remap[other], None);
+    assert_eq!(sub.nodes().collect::<Vec<_>>(), vec![&"a", &"b", &"c"]);
+
+    // Edges among reachable nodes are kept with remapped endpoints
+    let edges: Vec<_> = sub
+        .edge_pairs()
+        .map(|(ix, &w)| {
+            let [from, to] = sub.endpoints(ix);
+            (w, *sub.node(from), *sub.node(to))
+        })
+        .collect();
+    assert_eq!(
+        edges,
+        vec![(1, "a", "b"), (2, "b", "c"), (3, "c", "a"), (4, "c", "c")]
+    );
+    for node in [a, b, c] {
+        assert_eq!(sub.node(remap[node].unwrap()), graph.node(node));
+    }
+
+    let (sub, _) = graph.reachable_subgraph(other);
+    assert_eq!(sub.len_nodes(), 4);
+    assert_eq!(sub.len_edges(), 5);
+}
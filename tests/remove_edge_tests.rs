@@ -0,0 +1,350 @@
+use gotgraph::graph::{Context, EdgeTag};
+use gotgraph::prelude::*;
+use gotgraph::vec_graph::EdgeIx;
+use std::collections::BTreeSet;
+
+fn lcg(mut seed: u64) -> impl FnMut() -> usize {
+    move || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) as usize
+    }
+}
+
+/// Dense graph with every ordered pair of nodes connected, including self-loops, plus
+/// some parallel edges. Edge payloads are unique.
+fn create_dense_graph(nodes: usize) -> VecGraph<usize, usize> {
+    let mut graph = VecGraph::default();
+    let ixs: Vec<_> = (0..nodes).map(|i| graph.add_node(i)).collect();
+    let mut payload = 0;
+    for &from in &ixs {
+        for &to in &ixs {
+            graph.add_edge(payload, from, to);
+            payload += 1;
+        }
+    }
+    for i in 0..nodes {
+        graph.add_edge(payload, ixs[i], ixs[(i + 1) % nodes]);
+        payload += 1;
+    }
+    graph
+}
+
+/// Checks every adjacency list against the endpoints reported for each edge
+fn validate(graph: &VecGraph<usize, usize>) {
+    for node in graph.node_indices() {
+        let outgoing: Vec<_> = graph.outgoing_edge_indices(node).collect();
+        let incoming: Vec<_> = graph.incoming_edge_indices(node).collect();
+        let expected_out: BTreeSet<_> = graph
+            .edge_indices()
+            .filter(|&e| graph.endpoints(e)[0] == node)
+            .collect();
+        let expected_in: BTreeSet<_> = graph
+            .edge_indices()
+            .filter(|&e| graph.endpoints(e)[1] == node)
+            .collect();
+
+        // No duplicates, and exactly the edges attached to this node
+        assert_eq!(outgoing.len(), expected_out.len());
+        assert_eq!(incoming.len(), expected_in.len());
+        assert_eq!(outgoing.into_iter().collect::<BTreeSet<_>>(), expected_out);
+        assert_eq!(incoming.into_iter().collect::<BTreeSet<_>>(), expected_in);
+    }
+}
+
+#[test]
+fn test_remove_edges_in_random_order() {
+    let mut graph = create_dense_graph(12);
+    let mut remaining: BTreeSet<_> = graph.edges().copied().collect();
+    let mut next = lcg(7);
+
+    while graph.len_edges() > 0 {
+        let ix = graph
+            .edge_indices()
+            .nth(next() % graph.len_edges())
+            .unwrap();
+        let [from, to] = graph.endpoints(ix);
+        let payload = graph.remove_edge(ix);
+        assert!(remaining.remove(&payload));
+        // Payloads encode their endpoints for the complete part of the graph
+        if payload < 144 {
+            assert_eq!(
+                (*graph.node(from), *graph.node(to)),
+                (payload / 12, payload % 12)
+            );
+        }
+
+        validate(&graph);
+        assert_eq!(graph.edges().copied().collect::<BTreeSet<_>>(), remaining);
+    }
+    for node in graph.node_indices() {
+        assert_eq!(graph.degree(node), 0);
+    }
+}
+
+#[test]
+fn test_remove_last_and_first_edges() {
+    let mut graph = create_dense_graph(5);
+    while graph.len_edges() > 0 {
+        let last = graph.edge_indices().last().unwrap();
+        graph.remove_edge(last);
+        validate(&graph);
+        let first = graph.edge_indices().next();
+        if let Some(first) = first {
+            graph.remove_edge(first);
+            validate(&graph);
+        }
+    }
+}
+
+#[test]
+fn test_remove_edges_then_add_more() {
+    let mut graph = create_dense_graph(8);
+    let nodes: Vec<_> = graph.node_indices().collect();
+    let mut next = lcg(99);
+    for round in 0..200 {
+        if round % 3 == 0 {
+            let from = nodes[next() % nodes.len()];
+            let to = nodes[next() % nodes.len()];
+            graph.add_edge(1000 + round, from, to);
+        } else if graph.len_edges() > 0 {
+            let ix = graph
+                .edge_indices()
+                .nth(next() % graph.len_edges())
+                .unwrap();
+            graph.remove_edge(ix);
+        }
+        validate(&graph);
+    }
+}
+
+#[test]
+fn test_remove_edges_batch_in_arbitrary_order() {
+    let mut graph = create_dense_graph(8);
+    let mut next = lcg(7);
+    let all: Vec<_> = graph.edge_indices().collect();
+
+    // Pick a random subset of indices in a random order, with a repeat
+    let mut batch = Vec::new();
+    for &ix in &all {
+        if next() % 3 == 0 {
+            batch.push(ix);
+        }
+    }
+    for i in (1..batch.len()).rev() {
+        batch.swap(i, next() % (i + 1));
+    }
+    batch.push(batch[0]);
+
+    let expected: Vec<_> = batch[..batch.len() - 1]
+        .iter()
+        .map(|&ix| *graph.edge(ix))
+        .collect();
+    let removed: Vec<usize> = graph.remove_edges(batch.iter().copied());
+    assert_eq!(removed, expected);
+
+    // Everything in the batch is gone in a single call, the rest is untouched
+    let removed_set: BTreeSet<_> = removed.iter().copied().collect();
+    let remaining: BTreeSet<_> = graph.edges().copied().collect();
+    assert_eq!(remaining.len() + removed.len(), all.len());
+    assert!(remaining.is_disjoint(&removed_set));
+    validate(&graph);
+}
+
+#[test]
+fn test_remove_edges_skips_invalid() {
+    let mut graph = create_dense_graph(3);
+    let last = graph.edge_indices().last().unwrap();
+    let first = graph.edge_indices().next().unwrap();
+    graph.remove_edge(last);
+
+    let removed: Vec<usize> = graph.remove_edges([last, first]);
+    assert_eq!(removed, vec![0]);
+    validate(&graph);
+}
+
+#[test]
+fn test_remove_edges_between() {
+    let mut graph: VecGraph<&str, &str> = VecGraph::default();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let c = graph.add_node("c");
+    graph.add_edge("a-b 1", a, b);
+    graph.add_edge("b-a", b, a);
+    graph.add_edge("a-b 2", a, b);
+    graph.add_edge("a-c", a, c);
+    graph.add_edge("a-b 3", a, b);
+    graph.add_edge("a-a 1", a, a);
+    graph.add_edge("a-a 2", a, a);
+
+    assert_eq!(
+        graph.remove_edges_between(a, b),
+        vec!["a-b 1", "a-b 2", "a-b 3"]
+    );
+    assert_eq!(graph.find_edge(b, a).map(|e| *graph.edge(e)), Some("b-a"));
+    assert_eq!(graph.len_edges(), 4);
+
+    let mut loops = graph.remove_edges_between(a, a);
+    loops.sort();
+    assert_eq!(loops, vec!["a-a 1", "a-a 2"]);
+
+    // No match
+    assert!(graph.remove_edges_between(a, b).is_empty());
+    assert!(graph.remove_edges_between(c, a).is_empty());
+    assert_eq!(graph.remove_edge_between(c, a), None);
+
+    let mut remaining: Vec<_> = graph.edges().copied().collect();
+    remaining.sort();
+    assert_eq!(remaining, vec!["a-c", "b-a"]);
+    for node in graph.node_indices() {
+        for e in graph.outgoing_edge_indices(node) {
+            assert_eq!(graph.endpoints(e)[0], node);
+        }
+    }
+}
+
+#[test]
+fn test_remove_edge_between_in_scope() {
+    let mut graph: VecGraph<(), u32> = VecGraph::default();
+    let removed = graph.scope_mut(|mut ctx| {
+        let a = ctx.add_node(());
+        let b = ctx.add_node(());
+        ctx.add_edge(1, a, b);
+        ctx.add_edge(2, b, a);
+        ctx.remove_edge_between(b, a)
+    });
+    assert_eq!(removed, Some(2));
+    assert_eq!(graph.edges().collect::<Vec<_>>(), vec![&1]);
+}
+
+/// Looks up the edge with payload `w` in a scope
+fn find<'s>(ctx: &Context<'s, &mut VecGraph<&str, i32>>, w: i32) -> EdgeTag<'s, EdgeIx> {
+    ctx.edge_indices().find(|&e| *ctx.edge(e) == w).unwrap()
+}
+
+#[test]
+fn test_remove_edge_and_continue_chained() {
+    let mut graph: VecGraph<&str, i32> = VecGraph::default();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    for (w, from, to) in [(1, a, b), (2, b, c), (3, c, a), (4, a, c), (5, b, a)] {
+        graph.add_edge(w, from, to);
+    }
+
+    let (first, (second, (third, left))) = graph.scope_mut(|ctx| {
+        let e = find(&ctx, 1);
+        ctx.remove_edge_and_continue(e, |ctx| {
+            // Tags are looked up again in every new scope
+            assert_eq!(ctx.len_edges(), 4);
+            let e = find(&ctx, 4);
+            ctx.remove_edge_and_continue(e, |ctx| {
+                let e = find(&ctx, 2);
+                ctx.remove_edge_and_continue(e, |ctx| {
+                    let mut left: Vec<_> = ctx
+                        .edge_indices()
+                        .map(|e| {
+                            let [from, to] = ctx.endpoints(e);
+                            (*ctx.edge(e), *ctx.node(from), *ctx.node(to))
+                        })
+                        .collect();
+                    left.sort();
+                    left
+                })
+            })
+        })
+    });
+
+    assert_eq!((first, second, third), (1, 4, 2));
+    assert_eq!(left, vec![(3, "C", "A"), (5, "B", "A")]);
+    assert_eq!(graph.len_edges(), 2);
+}
+
+#[test]
+fn test_remove_node_and_continue_chained() {
+    let mut graph: VecGraph<i32, ()> = VecGraph::default();
+    let nodes: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+    for pair in nodes.windows(2) {
+        graph.add_edge((), pair[0], pair[1]);
+    }
+
+    let (removed, left) = graph.scope_mut(|ctx| {
+        let n = ctx.node_indices().find(|&n| *ctx.node(n) == 0).unwrap();
+        let (x, (y, (z, left))) = ctx.remove_node_and_continue(n, |ctx| {
+            let n = ctx.node_indices().find(|&n| *ctx.node(n) == 4).unwrap();
+            ctx.remove_node_and_continue(n, |ctx| {
+                let n = ctx.node_indices().find(|&n| *ctx.node(n) == 2).unwrap();
+                ctx.remove_node_and_continue(n, |ctx| {
+                    (ctx.nodes().copied().collect::<Vec<_>>(), ctx.len_edges())
+                })
+            })
+        });
+        ([x, y, z], left)
+    });
+
+    assert_eq!(removed, [0, 4, 2]);
+    let (mut nodes, edges) = left;
+    nodes.sort();
+    assert_eq!(nodes, vec![1, 3]);
+    assert_eq!(edges, 0);
+    assert_eq!(graph.len_nodes(), 2);
+}
+
+#[test]
+fn test_simplify_multigraph() {
+    let mut graph: VecGraph<usize, usize> = VecGraph::default();
+    let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+    // Triple edge 0 -> 1, a double edge 2 -> 3, a reverse edge, and two self-loops on 2
+    graph.add_edge(1, n[0], n[1]);
+    graph.add_edge(10, n[2], n[3]);
+    graph.add_edge(2, n[0], n[1]);
+    graph.add_edge(100, n[1], n[0]);
+    graph.add_edge(1000, n[2], n[2]);
+    graph.add_edge(4, n[0], n[1]);
+    graph.add_edge(20, n[2], n[3]);
+    graph.add_edge(2000, n[2], n[2]);
+    graph.add_edge(7, n[3], n[0]);
+    assert_eq!(graph.count_parallel_edges(), 4);
+    assert!(graph.has_self_loops());
+
+    let mut kept = graph.clone();
+    let report = graph.simplify(|x, y| x + y, true);
+    assert_eq!(report.merged_edges, 3);
+    assert_eq!(report.dropped_self_loops, 2);
+    assert_eq!(graph.len_edges(), 4);
+    assert_eq!(graph.count_parallel_edges(), 0);
+    assert!(!graph.has_self_loops());
+    validate(&graph);
+    let mut edges: Vec<_> = graph
+        .edge_indices()
+        .map(|e| (graph.endpoint_data(e), *graph.edge(e)))
+        .map(|((&from, &to), data)| (from, to, data))
+        .collect();
+    edges.sort();
+    assert_eq!(edges, vec![(0, 1, 7), (1, 0, 100), (2, 3, 30), (3, 0, 7)]);
+
+    // Keeping self-loops merges the repeated ones, in edge index order
+    let mut calls = Vec::new();
+    let report = kept.simplify(
+        |x, y| {
+            calls.push((x, y));
+            x + y
+        },
+        false,
+    );
+    assert_eq!(report.merged_edges, 4);
+    assert_eq!(report.dropped_self_loops, 0);
+    assert_eq!(calls, vec![(1, 2), (3, 4), (10, 20), (1000, 2000)]);
+    assert_eq!(kept.len_edges(), 5);
+    validate(&kept);
+    assert_eq!(*kept.edge(kept.find_edge(n[2], n[2]).unwrap()), 3000);
+
+    // Nothing left to do
+    let before = kept.clone();
+    assert_eq!(
+        kept.simplify(|_, _| unreachable!(), false),
+        Default::default()
+    );
+    assert_eq!(kept, before);
+}
@@ -0,0 +1,189 @@
+use gotgraph::prelude::*;
+use gotgraph::undirected::UnVecGraph;
+
+fn create_graph() -> VecGraph<String, u32> {
+    let mut graph = VecGraph::default();
+    let a = graph.add_node("a".to_string());
+    let b = graph.add_node("b".to_string());
+    let c = graph.add_node("c".to_string());
+    graph.add_edge(1, a, b);
+    graph.add_edge(2, b, c);
+    graph.add_edge(3, c, c);
+    graph
+}
+
+#[test]
+fn test_replace_returns_old_payload() {
+    let mut graph = create_graph();
+    let nodes: Vec<_> = graph.node_indices().collect();
+    let edges: Vec<_> = graph.edge_indices().collect();
+
+    assert_eq!(graph.replace_node(nodes[1], "B".to_string()), "b");
+    assert_eq!(*graph.node(nodes[1]), "B");
+    assert_eq!(graph.replace_edge(edges[2], 30), 3);
+    assert_eq!(*graph.edge(edges[2]), 30);
+
+    unsafe {
+        assert_eq!(graph.replace_node_unchecked(nodes[0], "A".to_string()), "a");
+        assert_eq!(graph.replace_edge_unchecked(edges[0], 10), 1);
+    }
+    assert_eq!(graph.nodes().cloned().collect::<Vec<_>>(), ["A", "B", "c"]);
+    assert_eq!(graph.edges().copied().collect::<Vec<_>>(), [10, 2, 30]);
+    assert_eq!(graph.len_nodes(), 3);
+    assert_eq!(graph.len_edges(), 3);
+}
+
+#[test]
+fn test_swap_keeps_topology() {
+    let mut graph = create_graph();
+    let nodes: Vec<_> = graph.node_indices().collect();
+    let endpoints: Vec<_> = graph
+        .edge_indices()
+        .map(|e| (e, graph.endpoints(e)))
+        .collect();
+    let outgoing: Vec<Vec<_>> = nodes
+        .iter()
+        .map(|&n| graph.outgoing_edge_indices(n).collect())
+        .collect();
+
+    graph.swap_nodes(nodes[0], nodes[2]);
+    assert_eq!(graph.nodes().cloned().collect::<Vec<_>>(), ["c", "b", "a"]);
+    for &(edge, ends) in &endpoints {
+        assert_eq!(graph.endpoints(edge), ends);
+    }
+    for (i, &node) in nodes.iter().enumerate() {
+        assert_eq!(
+            graph.outgoing_edge_indices(node).collect::<Vec<_>>(),
+            outgoing[i]
+        );
+    }
+
+    // Swapping a node with itself does nothing
+    graph.swap_nodes(nodes[1], nodes[1]);
+    assert_eq!(*graph.node(nodes[1]), "b");
+}
+
+#[test]
+fn test_take_and_put_back() {
+    let mut graph = create_graph();
+    let node = graph.node_indices().nth(1).unwrap();
+
+    let mut state = graph.take_node(node);
+    assert_eq!(state, "b");
+    assert_eq!(*graph.node(node), "");
+    state.push_str("-processed");
+    graph.replace_node(node, state);
+    assert_eq!(*graph.node(node), "b-processed");
+}
+
+#[test]
+fn test_in_scope_with_tags() {
+    let mut graph = create_graph();
+    graph.scope_mut(|mut ctx| {
+        let nodes: Vec<_> = ctx.node_indices().collect();
+        let edge = ctx.edge_indices().next().unwrap();
+
+        assert_eq!(ctx.replace_node(nodes[0], "x".to_string()), "a");
+        assert_eq!(ctx.replace_edge(edge, 100), 1);
+        let taken = ctx.take_node(nodes[1]);
+        assert_eq!(taken, "b");
+        ctx.swap_nodes(nodes[0], nodes[2]);
+
+        assert_eq!(*ctx.node(nodes[0]), "c");
+        assert_eq!(*ctx.node(nodes[1]), "");
+        assert_eq!(*ctx.node(nodes[2]), "x");
+        assert_eq!(ctx.endpoints(edge), [nodes[0], nodes[1]]);
+    });
+    assert_eq!(graph.edges().copied().collect::<Vec<_>>(), [100, 2, 3]);
+}
+
+#[test]
+fn test_undirected_swap() {
+    let mut graph: UnVecGraph<&str, ()> = UnVecGraph::default();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let e = graph.add_edge((), a, b);
+
+    graph.swap_nodes(a, b);
+    assert_eq!((*graph.node(a), *graph.node(b)), ("b", "a"));
+    assert_eq!(graph.endpoints(e), [a, b]);
+}
+
+#[test]
+#[should_panic]
+fn test_swap_invalid_node() {
+    let mut graph = create_graph();
+    let last = graph.node_indices().last().unwrap();
+    let first = graph.node_indices().next().unwrap();
+    graph.remove_node(last);
+    graph.swap_nodes(first, last);
+}
+
+#[test]
+fn test_disjoint_mut_transfers_along_edge() {
+    let mut graph: VecGraph<u32, u32> = VecGraph::default();
+    let a = graph.add_node(100);
+    let b = graph.add_node(5);
+    let c = graph.add_node(0);
+    let ab = graph.add_edge(30, a, b);
+    let bc = graph.add_edge(7, b, c);
+
+    // Move the amount on each edge from its source to its target
+    for edge in [ab, bc] {
+        let amount = *graph.edge(edge);
+        let [from, to] = graph.get_node_disjoint_mut(graph.endpoints(edge)).unwrap();
+        *from -= amount;
+        *to += amount;
+    }
+    assert_eq!(graph.nodes().copied().collect::<Vec<_>>(), [70, 28, 7]);
+
+    // References come back in the order of the indices
+    let [x, y, z] = graph.get_node_disjoint_mut([c, a, b]).unwrap();
+    assert_eq!((*x, *y, *z), (7, 70, 28));
+    let [second, first] = graph.get_edge_disjoint_mut([bc, ab]).unwrap();
+    core::mem::swap(first, second);
+    assert_eq!(graph.edges().copied().collect::<Vec<_>>(), [7, 30]);
+    assert_eq!(graph.get_node_disjoint_mut([]), Some([]));
+
+    graph.scope_mut(|mut ctx| {
+        let edge = ctx.edge_indices().next().unwrap();
+        let [from, to] = ctx.get_node_disjoint_mut(ctx.endpoints(edge)).unwrap();
+        *to += core::mem::take(from);
+        let [_, to] = ctx.endpoints(edge);
+        assert_eq!(*ctx.node(to), 98);
+        assert!(ctx.get_edge_disjoint_mut([edge, edge]).is_none());
+    });
+}
+
+#[test]
+fn test_disjoint_mut_rejects_duplicates_and_missing() {
+    let mut graph = create_graph();
+    let nodes: Vec<_> = graph.node_indices().collect();
+    let edges: Vec<_> = graph.edge_indices().collect();
+
+    assert!(graph.get_node_disjoint_mut([nodes[0], nodes[0]]).is_none());
+    assert!(graph
+        .get_node_disjoint_mut([nodes[2], nodes[0], nodes[2]])
+        .is_none());
+    assert!(graph.get_edge_disjoint_mut([edges[1], edges[1]]).is_none());
+
+    graph.remove_node(nodes[2]);
+    assert!(graph.get_node_disjoint_mut([nodes[0], nodes[2]]).is_none());
+    assert!(graph.get_node_disjoint_mut([nodes[2]]).is_none());
+    assert!(graph.get_edge_disjoint_mut([edges[0], edges[2]]).is_none());
+    assert!(graph.get_node_disjoint_mut([nodes[1], nodes[0]]).is_some());
+
+    let mut undirected: UnVecGraph<u32, ()> = UnVecGraph::default();
+    let a = undirected.add_node(1);
+    let b = undirected.add_node(2);
+    assert!(undirected.get_node_disjoint_mut([a, a]).is_none());
+    let [x, y] = undirected.get_node_disjoint_mut([a, b]).unwrap();
+    *x += *y;
+    assert_eq!(*undirected.node(a), 3);
+
+    // The same edge seen from either endpoint is still one payload
+    undirected.add_edge((), a, b);
+    let from_a = undirected.outgoing_edge_indices(a).next().unwrap();
+    let from_b = undirected.outgoing_edge_indices(b).next().unwrap();
+    assert!(undirected.get_edge_disjoint_mut([from_a, from_b]).is_none());
+}
@@ -0,0 +1,203 @@
+use gotgraph::prelude::*;
+use gotgraph::undirected::UnVecGraph;
+use gotgraph::vec_graph::{EdgeIx, NodeIx};
+
+fn outgoing(graph: &VecGraph<&str, &str>, node: NodeIx) -> Vec<EdgeIx> {
+    let mut edges: Vec<_> = graph.outgoing_edge_indices(node).collect();
+    edges.sort();
+    edges
+}
+
+fn incoming(graph: &VecGraph<&str, &str>, node: NodeIx) -> Vec<EdgeIx> {
+    let mut edges: Vec<_> = graph.incoming_edge_indices(node).collect();
+    edges.sort();
+    edges
+}
+
+/// Checks that the adjacency lists of every node agree with the edge endpoints
+fn assert_consistent(graph: &VecGraph<&str, &str>) {
+    for node in graph.node_indices() {
+        let expected_out: Vec<_> = graph
+            .edge_indices()
+            .filter(|&e| graph.endpoints(e)[0] == node)
+            .collect();
+        let expected_in: Vec<_> = graph
+            .edge_indices()
+            .filter(|&e| graph.endpoints(e)[1] == node)
+            .collect();
+        assert_eq!(outgoing(graph, node), expected_out);
+        assert_eq!(incoming(graph, node), expected_in);
+    }
+}
+
+#[test]
+fn test_rewire_to_four_distinct_nodes() {
+    let mut graph = VecGraph::default();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let c = graph.add_node("c");
+    let d = graph.add_node("d");
+    let e = graph.add_edge("e", a, b);
+    let other = graph.add_edge("other", a, c);
+
+    assert_eq!(outgoing(&graph, a), vec![e, other]);
+    assert_eq!(incoming(&graph, b), vec![e]);
+    assert!(outgoing(&graph, c).is_empty() && incoming(&graph, d).is_empty());
+
+    graph.rewire_edge(e, c, d);
+
+    assert_eq!(graph.endpoints(e), [c, d]);
+    assert_eq!(*graph.edge(e), "e");
+    assert_eq!(outgoing(&graph, a), vec![other]);
+    assert!(incoming(&graph, b).is_empty());
+    assert_eq!(outgoing(&graph, c), vec![e]);
+    assert_eq!(incoming(&graph, c), vec![other]);
+    assert_eq!(incoming(&graph, d), vec![e]);
+    assert_consistent(&graph);
+}
+
+#[test]
+fn test_reverse_edge() {
+    let mut graph = VecGraph::default();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let e = graph.add_edge("e", a, b);
+
+    graph.reverse_edge(e);
+    assert_eq!(graph.endpoints(e), [b, a]);
+    assert!(outgoing(&graph, a).is_empty());
+    assert_eq!(incoming(&graph, a), vec![e]);
+    assert_eq!(outgoing(&graph, b), vec![e]);
+    assert!(incoming(&graph, b).is_empty());
+
+    graph.reverse_edge(e);
+    assert_eq!(graph.endpoints(e), [a, b]);
+    assert_consistent(&graph);
+}
+
+#[test]
+fn test_self_loops() {
+    let mut graph = VecGraph::default();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let lp = graph.add_edge("loop", a, a);
+    let e = graph.add_edge("e", a, b);
+
+    // Reversing a self-loop changes nothing
+    graph.reverse_edge(lp);
+    assert_eq!(graph.endpoints(lp), [a, a]);
+    assert_eq!(outgoing(&graph, a), vec![lp, e]);
+    assert_eq!(incoming(&graph, a), vec![lp]);
+
+    // Turning an edge into a self-loop and back
+    graph.rewire_edge(e, b, b);
+    assert_eq!(outgoing(&graph, a), vec![lp]);
+    assert_eq!(outgoing(&graph, b), vec![e]);
+    assert_eq!(incoming(&graph, b), vec![e]);
+    assert_consistent(&graph);
+
+    graph.rewire_edge(lp, b, a);
+    assert_eq!(outgoing(&graph, a), Vec::<EdgeIx>::new());
+    assert_eq!(incoming(&graph, a), vec![lp]);
+    assert_eq!(outgoing(&graph, b), vec![lp, e]);
+    assert_consistent(&graph);
+}
+
+#[test]
+fn test_parallel_edges() {
+    let mut graph = VecGraph::default();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let e0 = graph.add_edge("e0", a, b);
+    let e1 = graph.add_edge("e1", a, b);
+    let e2 = graph.add_edge("e2", a, b);
+
+    // Move the one in the middle of the lists
+    graph.reverse_edge(e1);
+    assert_eq!(outgoing(&graph, a), vec![e0, e2]);
+    assert_eq!(incoming(&graph, b), vec![e0, e2]);
+    assert_eq!(outgoing(&graph, b), vec![e1]);
+    assert_eq!(incoming(&graph, a), vec![e1]);
+    assert_consistent(&graph);
+
+    graph.reverse();
+    assert_eq!(outgoing(&graph, b), vec![e0, e2]);
+    assert_eq!(outgoing(&graph, a), vec![e1]);
+    assert_consistent(&graph);
+
+    // Removal still finds every edge where it should be
+    graph.remove_edge(e0);
+    assert_consistent(&graph);
+}
+
+#[test]
+fn test_reverse_graph_adjacency() {
+    let mut graph = VecGraph::default();
+    let nodes: Vec<_> = ["a", "b", "c", "d"]
+        .into_iter()
+        .map(|n| graph.add_node(n))
+        .collect();
+    for (i, &from) in nodes.iter().enumerate() {
+        for &to in &nodes[i..] {
+            graph.add_edge("e", from, to);
+        }
+    }
+
+    graph.reverse();
+    assert_consistent(&graph);
+    assert_eq!(graph.source_nodes().collect::<Vec<_>>(), vec![]);
+    assert_eq!(incoming(&graph, nodes[0]).len(), 4);
+    assert_eq!(outgoing(&graph, nodes[0]).len(), 1);
+}
+
+#[test]
+fn test_rewire_in_scope() {
+    let mut graph: VecGraph<&str, &str> = VecGraph::default();
+    graph.scope_mut(|mut ctx| {
+        let a = ctx.add_node("a");
+        let b = ctx.add_node("b");
+        let c = ctx.add_node("c");
+        let e = ctx.add_edge("e", a, b);
+
+        ctx.rewire_edge(e, c, a);
+        assert_eq!(ctx.endpoints(e), [c, a]);
+        assert_eq!(ctx.outgoing_edge_indices(c).collect::<Vec<_>>(), vec![e]);
+        assert_eq!(ctx.incoming_edge_indices(a).collect::<Vec<_>>(), vec![e]);
+        assert_eq!(ctx.outgoing_edge_indices(a).count(), 0);
+        assert_eq!(ctx.incoming_edge_indices(b).count(), 0);
+
+        ctx.reverse_edge(e);
+        assert_eq!(ctx.endpoints(e), [a, c]);
+    });
+    assert_consistent(&graph);
+}
+
+#[test]
+fn test_rewire_undirected_from_either_side() {
+    let mut graph: UnVecGraph<&str, ()> = UnVecGraph::default();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let c = graph.add_node("c");
+    graph.add_edge((), a, b);
+
+    // An index reached from `b` sees the edge as b -> a
+    let flipped = graph.outgoing_edge_indices(b).next().unwrap();
+    assert_eq!(graph.endpoints(flipped), [b, a]);
+    graph.rewire_edge(flipped, b, c);
+    assert_eq!(graph.endpoints(flipped), [b, c]);
+
+    assert_eq!(graph.outgoing_edge_indices(a).count(), 0);
+    assert_eq!(graph.outgoing_edge_indices(b).count(), 1);
+    assert_eq!(graph.outgoing_edge_indices(c).count(), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_rewire_to_missing_node() {
+    let mut graph = VecGraph::default();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let e = graph.add_edge("e", a, b);
+    graph.remove_node(b);
+    graph.rewire_edge(e, a, b);
+}
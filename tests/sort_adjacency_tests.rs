@@ -0,0 +1,96 @@
+use gotgraph::prelude::*;
+
+#[test]
+fn test_sort_outgoing_by_payload() {
+    let mut graph: VecGraph<usize, u32> = VecGraph::default();
+    let hub = graph.add_node(0);
+    let targets: Vec<_> = (1..=5).map(|i| graph.add_node(i)).collect();
+    for (weight, target) in [(30, 2), (10, 0), (50, 4), (20, 1), (40, 3)] {
+        graph.add_edge(weight, hub, targets[target]);
+    }
+    let endpoints: Vec<_> = graph
+        .edge_indices()
+        .map(|e| (e, graph.endpoints(e)))
+        .collect();
+
+    graph.sort_adjacency_by_edge_key(|&w| w);
+
+    let weights: Vec<_> = graph
+        .outgoing_edge_indices(hub)
+        .map(|e| *graph.edge(e))
+        .collect();
+    assert_eq!(weights, vec![10, 20, 30, 40, 50]);
+    let order: Vec<_> = graph
+        .outgoing_edge_indices(hub)
+        .map(|e| graph.endpoints(e)[1])
+        .collect();
+    assert_eq!(order, targets);
+
+    // Indices and endpoints are unchanged
+    for &(edge, ends) in &endpoints {
+        assert_eq!(graph.endpoints(edge), ends);
+    }
+    // Every target still sees exactly its one incoming edge
+    for &target in &targets {
+        let incoming: Vec<_> = graph.incoming_edge_indices(target).collect();
+        assert_eq!(incoming.len(), 1);
+        assert_eq!(graph.endpoints(incoming[0]), [hub, target]);
+    }
+}
+
+#[test]
+fn test_sort_incoming_chains() {
+    let mut graph: VecGraph<(), u32> = VecGraph::default();
+    let nodes: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+    graph.add_edge(3, nodes[0], nodes[3]);
+    graph.add_edge(1, nodes[1], nodes[3]);
+    graph.add_edge(2, nodes[2], nodes[3]);
+    graph.add_edge(9, nodes[3], nodes[3]);
+    graph.add_edge(0, nodes[0], nodes[1]);
+
+    graph.sort_adjacency_by(|_, a, _, b| b.cmp(a));
+
+    let incoming: Vec<_> = graph
+        .incoming_edge_indices(nodes[3])
+        .map(|e| *graph.edge(e))
+        .collect();
+    assert_eq!(incoming, vec![9, 3, 2, 1]);
+    let outgoing: Vec<_> = graph
+        .outgoing_edge_indices(nodes[0])
+        .map(|e| *graph.edge(e))
+        .collect();
+    assert_eq!(outgoing, vec![3, 0]);
+
+    for node in graph.node_indices() {
+        for edge in graph.outgoing_edge_indices(node) {
+            assert_eq!(graph.endpoints(edge)[0], node);
+        }
+        for edge in graph.incoming_edge_indices(node) {
+            assert_eq!(graph.endpoints(edge)[1], node);
+        }
+    }
+    assert_eq!(graph.len_edges(), 5);
+}
+
+#[test]
+fn test_sort_by_index_is_insertion_order() {
+    let mut graph: VecGraph<(), &str> = VecGraph::default();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    graph.add_edge("first", a, b);
+    graph.add_edge("second", a, b);
+    graph.add_edge("third", a, b);
+
+    graph.sort_adjacency_by(|x, _, y, _| x.cmp(&y));
+    let names: Vec<_> = graph
+        .outgoing_edge_indices(a)
+        .map(|e| *graph.edge(e))
+        .collect();
+    assert_eq!(names, vec!["first", "second", "third"]);
+
+    // Removing an edge keeps the remaining lists usable
+    let first = graph.outgoing_edge_indices(a).next().unwrap();
+    graph.remove_edge(first);
+    assert_eq!(graph.outgoing_edge_indices(a).count(), 2);
+    assert_eq!(graph.incoming_edge_indices(b).count(), 2);
+}
@@ -0,0 +1,179 @@
+use gotgraph::algo::is_structurally_equal;
+use gotgraph::prelude::*;
+
+/// Create a graph with cycles, a self-loop and parallel edges
+fn create_test_graph() -> VecGraph<i32, &'static str> {
+    let mut graph = VecGraph::default();
+
+    graph.scope_mut(|mut ctx| {
+        let n0 = ctx.add_node(0);
+        let n1 = ctx.add_node(1);
+        let n2 = ctx.add_node(2);
+        let n3 = ctx.add_node(3);
+
+        ctx.add_edge("a", n0, n1);
+        ctx.add_edge("b", n1, n2);
+        ctx.add_edge("c", n2, n0);
+        ctx.add_edge("d", n2, n3);
+        ctx.add_edge("d", n2, n3);
+        ctx.add_edge("e", n3, n3);
+    });
+
+    graph
+}
+
+/// Rebuild `graph` with its nodes inserted in the order given by `permutation`
+fn permuted(
+    graph: &VecGraph<i32, &'static str>,
+    permutation: &[usize],
+) -> VecGraph<i32, &'static str> {
+    let old: Vec<_> = graph.node_indices().collect();
+    let mut result = VecGraph::default();
+    let mut new = vec![None; old.len()];
+    for &i in permutation {
+        new[i] = Some(result.add_node(*graph.node(old[i])));
+    }
+    let mut edges: Vec<_> = graph.edge_indices().collect();
+    edges.reverse();
+    for edge in edges {
+        let [from, to] = graph.endpoints(edge);
+        let from = new[old.iter().position(|&ix| ix == from).unwrap()].unwrap();
+        let to = new[old.iter().position(|&ix| ix == to).unwrap()].unwrap();
+        result.add_edge(*graph.edge(edge), from, to);
+    }
+    result
+}
+
+fn eq<T: PartialEq>(a: &T, b: &T) -> bool {
+    a == b
+}
+
+#[test]
+fn test_identical_graphs_are_equal() {
+    let graph = create_test_graph();
+    assert!(is_structurally_equal(&graph, &graph.clone(), eq, eq));
+}
+
+#[test]
+fn test_permuted_copies_are_equal() {
+    let graph = create_test_graph();
+    for permutation in [[3, 2, 1, 0], [1, 3, 0, 2], [2, 0, 3, 1]] {
+        let other = permuted(&graph, &permutation);
+        assert!(is_structurally_equal(&graph, &other, eq, eq));
+        assert!(is_structurally_equal(&other, &graph, eq, eq));
+    }
+}
+
+#[test]
+fn test_flipped_edge_direction_is_unequal() {
+    let graph = create_test_graph();
+
+    let mut other: VecGraph<i32, &str> = VecGraph::default();
+    other.scope_mut(|mut ctx| {
+        let n0 = ctx.add_node(0);
+        let n1 = ctx.add_node(1);
+        let n2 = ctx.add_node(2);
+        let n3 = ctx.add_node(3);
+
+        ctx.add_edge("a", n1, n0); // flipped
+        ctx.add_edge("b", n1, n2);
+        ctx.add_edge("c", n2, n0);
+        ctx.add_edge("d", n2, n3);
+        ctx.add_edge("d", n2, n3);
+        ctx.add_edge("e", n3, n3);
+    });
+
+    assert!(!is_structurally_equal(&graph, &other, eq, eq));
+}
+
+#[test]
+fn test_parallel_edge_multiplicity_matters() {
+    let mut a: VecGraph<i32, ()> = VecGraph::default();
+    let a0 = a.add_node(0);
+    let a1 = a.add_node(1);
+    a.add_edge((), a0, a1);
+    a.add_edge((), a0, a1);
+    a.add_edge((), a1, a0);
+
+    let mut b: VecGraph<i32, ()> = VecGraph::default();
+    let b0 = b.add_node(0);
+    let b1 = b.add_node(1);
+    b.add_edge((), b0, b1);
+    b.add_edge((), b1, b0);
+    b.add_edge((), b1, b0);
+
+    assert!(!is_structurally_equal(&a, &b, eq, eq));
+}
+
+#[test]
+fn test_payload_differences_are_unequal() {
+    let graph = create_test_graph();
+
+    let mut other = graph.clone();
+    *other.edges_mut().next().unwrap() = "z";
+    assert!(!is_structurally_equal(&graph, &other, eq, eq));
+
+    let mut other = graph.clone();
+    *other.nodes_mut().next().unwrap() = 42;
+    assert!(!is_structurally_equal(&graph, &other, eq, eq));
+
+    // Ignoring payloads, the graphs have the same shape
+    assert!(is_structurally_equal(
+        &graph,
+        &other,
+        |_, _| true,
+        |_, _| true
+    ));
+}
+
+#[test]
+fn test_regular_graphs_need_backtracking() {
+    // Two 6-cycles vs. one 6-cycle split into two triangles: all nodes have the same
+    // degrees and payloads, so only the adjacency structure tells them apart.
+    let mut hexagon: VecGraph<(), ()> = VecGraph::default();
+    let nodes: Vec<_> = (0..6).map(|_| hexagon.add_node(())).collect();
+    for i in 0..6 {
+        hexagon.add_edge((), nodes[i], nodes[(i + 1) % 6]);
+    }
+
+    let mut triangles: VecGraph<(), ()> = VecGraph::default();
+    let nodes: Vec<_> = (0..6).map(|_| triangles.add_node(())).collect();
+    for i in 0..3 {
+        triangles.add_edge((), nodes[i], nodes[(i + 1) % 3]);
+        triangles.add_edge((), nodes[3 + i], nodes[3 + (i + 1) % 3]);
+    }
+
+    assert!(!is_structurally_equal(&hexagon, &triangles, eq, eq));
+
+    let mut rotated: VecGraph<(), ()> = VecGraph::default();
+    let nodes: Vec<_> = (0..6).map(|_| rotated.add_node(())).collect();
+    for i in 0..6 {
+        rotated.add_edge((), nodes[(i + 3) % 6], nodes[(i + 4) % 6]);
+    }
+    assert!(is_structurally_equal(&hexagon, &rotated, eq, eq));
+}
+
+#[test]
+fn test_append_round_trip_with_distinct_payloads() {
+    let mut source: VecGraph<usize, usize> = VecGraph::default();
+    let nodes: Vec<_> = (0..2000).map(|i| source.add_node(i)).collect();
+    for i in 0..2000 {
+        source.add_edge(i, nodes[i], nodes[(i * 7 + 3) % 2000]);
+        source.add_edge(i + 2000, nodes[(i * 13 + 5) % 2000], nodes[i]);
+    }
+
+    let mut target: VecGraph<usize, usize> = VecGraph::default();
+    target.append(source.clone());
+
+    assert!(is_structurally_equal(&source, &target, eq, eq));
+}
+
+#[test]
+fn test_empty_graphs() {
+    let a: VecGraph<i32, ()> = VecGraph::default();
+    let mut b: VecGraph<i32, ()> = VecGraph::default();
+    assert!(is_structurally_equal(&a, &b, eq, eq));
+
+    b.add_node(1);
+    assert!(!is_structurally_equal(&a, &b, eq, eq));
+}
@@ -0,0 +1,108 @@
+use gotgraph::prelude::*;
+use std::collections::BTreeMap;
+
+fn lcg(mut seed: u64) -> impl FnMut() -> usize {
+    move || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) as usize
+    }
+}
+
+/// Graph with unique payloads, including self-loops and parallel edges
+fn create_random_graph(nodes: usize, edges: usize, seed: u64) -> VecGraph<usize, usize> {
+    let mut next = lcg(seed);
+    let mut graph = VecGraph::default();
+    let ixs: Vec<_> = (0..nodes).map(|i| graph.add_node(i)).collect();
+    for payload in 0..edges {
+        graph.add_edge(payload, ixs[next() % nodes], ixs[next() % nodes]);
+    }
+    graph.add_edge(edges, ixs[0], ixs[0]);
+    graph.add_edge(edges + 1, ixs[0], ixs[1]);
+    graph.add_edge(edges + 2, ixs[0], ixs[1]);
+    graph
+}
+
+type Adjacency = BTreeMap<usize, [Vec<(usize, usize)>; 2]>;
+
+/// The outgoing and incoming lists of every node, in iteration order, as edge and
+/// neighbour payloads, so that the result does not depend on any index
+fn adjacency(graph: &VecGraph<usize, usize>) -> Adjacency {
+    graph
+        .node_indices()
+        .map(|node| {
+            let outgoing = graph
+                .outgoing_edge_indices(node)
+                .map(|e| (*graph.edge(e), *graph.node(graph.endpoints(e)[1])))
+                .collect();
+            let incoming = graph
+                .incoming_edge_indices(node)
+                .map(|e| (*graph.edge(e), *graph.node(graph.endpoints(e)[0])))
+                .collect();
+            (*graph.node(node), [outgoing, incoming])
+        })
+        .collect()
+}
+
+#[test]
+fn test_swap_node_slots_keeps_topology() {
+    let mut graph = create_random_graph(10, 40, 3);
+    let before = adjacency(&graph);
+    let nodes: Vec<_> = graph.node_indices().collect();
+    let mut next = lcg(11);
+
+    for _ in 0..50 {
+        let (a, b) = (nodes[next() % 10], nodes[next() % 10]);
+        let (data_a, data_b) = (*graph.node(a), *graph.node(b));
+        let out_b: Vec<_> = graph.outgoing_edge_indices(b).collect();
+        graph.swap_node_slots(a, b);
+        assert_eq!((*graph.node(a), *graph.node(b)), (data_b, data_a));
+        assert_eq!(graph.outgoing_edge_indices(a).collect::<Vec<_>>(), out_b);
+        assert_eq!(adjacency(&graph), before);
+    }
+}
+
+#[test]
+fn test_swap_edge_slots_keeps_topology() {
+    let mut graph = create_random_graph(6, 30, 5);
+    let before = adjacency(&graph);
+    let len = graph.len_edges();
+    let edges: Vec<_> = graph.edge_indices().collect();
+    let mut next = lcg(13);
+
+    for _ in 0..100 {
+        let (a, b) = (edges[next() % len], edges[next() % len]);
+        let (data_a, ends_a) = (*graph.edge(a), graph.endpoints(a));
+        let (data_b, ends_b) = (*graph.edge(b), graph.endpoints(b));
+        graph.swap_edge_slots(a, b);
+        assert_eq!((*graph.edge(a), graph.endpoints(a)), (data_b, ends_b));
+        assert_eq!((*graph.edge(b), graph.endpoints(b)), (data_a, ends_a));
+        assert_eq!(adjacency(&graph), before);
+    }
+
+    // Neighbouring edges in the same lists, including a self-loop
+    let mut graph: VecGraph<usize, usize> = VecGraph::default();
+    let a = graph.add_node(0);
+    let b = graph.add_node(1);
+    let loop_edge = graph.add_edge(0, a, a);
+    let ab = graph.add_edge(1, a, b);
+    let before = adjacency(&graph);
+    graph.swap_edge_slots(loop_edge, ab);
+    assert_eq!(graph.endpoints(loop_edge), [a, b]);
+    assert_eq!(
+        graph.outgoing_edge_indices(a).collect::<Vec<_>>(),
+        vec![loop_edge, ab]
+    );
+    assert_eq!(adjacency(&graph), before);
+}
+
+#[test]
+#[should_panic(expected = "does not exist")]
+fn test_swap_missing_edge_panics() {
+    let mut graph: VecGraph<(), ()> = VecGraph::default();
+    let a = graph.add_node(());
+    let e = graph.add_edge((), a, a);
+    graph.remove_edge(e);
+    graph.swap_edge_slots(e, e);
+}
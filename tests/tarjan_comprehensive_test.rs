@@ -1,6 +1,7 @@
-use gotgraph::algo::tarjan;
+use gotgraph::algo::{tarjan, tarjan_visit};
 use gotgraph::prelude::*;
 use std::collections::HashSet;
+use std::ops::ControlFlow;
 
 /// Create a comprehensive test graph with multiple SCCs and cross-SCC edges
 ///
@@ -83,7 +84,7 @@ fn create_nested_cycles_graph() -> VecGraph<i32, &'static str> {
 #[test]
 fn test_comprehensive_scc_detection() {
     let graph = create_comprehensive_test_graph();
-    let sccs: Vec<_> = tarjan(graph).collect();
+    let sccs: Vec<_> = tarjan(&graph).collect();
 
     // Should detect exactly 4 SCCs
     assert_eq!(sccs.len(), 4, "Expected 4 SCCs in comprehensive graph");
@@ -120,7 +121,7 @@ fn test_comprehensive_scc_detection() {
 #[test]
 fn test_nested_cycles_single_scc() {
     let graph = create_nested_cycles_graph();
-    let sccs: Vec<_> = tarjan(graph).collect();
+    let sccs: Vec<_> = tarjan(&graph).collect();
 
     // All nodes should be in a single SCC due to nested cycles
     assert_eq!(sccs.len(), 1, "Nested cycles should form single SCC");
@@ -156,7 +157,7 @@ fn test_tarjan_with_disconnected_components() {
         let _n5 = n5; // Silence unused variable warning
     });
 
-    let sccs: Vec<_> = tarjan(graph).collect();
+    let sccs: Vec<_> = tarjan(&graph).collect();
 
     // Should have 5 SCCs: {0,1}, {2}, {3}, {4}, {5}
     assert_eq!(
@@ -200,7 +201,7 @@ fn test_tarjan_topological_ordering() {
         ctx.add_edge("2->3", n2, n3); // B→C
     });
 
-    let sccs: Vec<_> = tarjan(graph).collect();
+    let sccs: Vec<_> = tarjan(&graph).collect();
     assert_eq!(sccs.len(), 3, "Should have 3 SCCs");
 
     // The SCCs should be returned in reverse topological order
@@ -229,7 +230,7 @@ fn test_tarjan_with_self_loops() {
         ctx.add_edge("1->2", n1, n2);
     });
 
-    let sccs: Vec<_> = tarjan(graph).collect();
+    let sccs: Vec<_> = tarjan(&graph).collect();
 
     // Each node should be its own SCC (self-loops don't create cycles with other nodes)
     assert_eq!(sccs.len(), 3, "Should have 3 SCCs");
@@ -260,7 +261,7 @@ fn test_large_graph_performance() {
         }
     });
 
-    let sccs: Vec<_> = tarjan(graph).collect();
+    let sccs: Vec<_> = tarjan(&graph).collect();
 
     // All 100 nodes should be in a single SCC due to the cycle
     assert_eq!(sccs.len(), 1, "Large cycle should form single SCC");
@@ -327,7 +328,7 @@ fn test_massive_graph_stress() {
     });
 
     let start_time = std::time::Instant::now();
-    let sccs: Vec<_> = tarjan(graph).collect();
+    let sccs: Vec<_> = tarjan(&graph).collect();
     let duration = start_time.elapsed();
 
     // Verify results
@@ -390,7 +391,7 @@ fn test_parallel_edges() {
         ctx.add_edge("edge2_1_to_2", n1, n2);
     });
 
-    let sccs: Vec<_> = tarjan(graph).collect();
+    let sccs: Vec<_> = tarjan(&graph).collect();
 
     // Should have 2 SCCs: {0,1} and {2}
     assert_eq!(sccs.len(), 2, "Should have 2 SCCs despite parallel edges");
@@ -416,7 +417,7 @@ fn test_empty_edges_after_nodes() {
         }
     });
 
-    let sccs: Vec<_> = tarjan(graph).collect();
+    let sccs: Vec<_> = tarjan(&graph).collect();
 
     // Each node should be its own SCC
     assert_eq!(sccs.len(), 5, "Should have 5 SCCs for 5 isolated nodes");
@@ -454,7 +455,7 @@ fn test_deep_recursion_pathological() {
         }
     });
 
-    let sccs: Vec<_> = tarjan(graph).collect();
+    let sccs: Vec<_> = tarjan(&graph).collect();
 
     // Should form a single SCC containing all nodes
     assert_eq!(sccs.len(), 1, "Deep chain cycle should form single SCC");
@@ -486,7 +487,7 @@ fn test_deep_tree_structure() {
         }
     });
 
-    let sccs: Vec<_> = tarjan(graph).collect();
+    let sccs: Vec<_> = tarjan(&graph).collect();
 
     // Each node should be its own SCC since there are no cycles
     assert_eq!(
@@ -540,7 +541,7 @@ fn test_randomized_properties() {
             }
         });
 
-        let sccs: Vec<_> = tarjan(graph).collect();
+        let sccs: Vec<_> = tarjan(&graph).collect();
 
         // Verify fundamental properties
 
@@ -596,7 +597,7 @@ fn test_randomized_properties() {
 #[test]
 fn test_algorithm_properties() {
     let graph = create_comprehensive_test_graph();
-    let sccs: Vec<_> = tarjan(graph).collect();
+    let sccs: Vec<_> = tarjan(&graph).collect();
 
     // Verify fundamental properties of SCC decomposition:
 
@@ -654,7 +655,7 @@ fn test_performance_benchmarks() {
             }
         }
     });
-    let dense_sccs: Vec<_> = tarjan(dense_graph).collect();
+    let dense_sccs: Vec<_> = tarjan(&dense_graph).collect();
     let dense_duration = start.elapsed();
 
     // Test 2: Sparse graph (few edges)
@@ -677,7 +678,7 @@ fn test_performance_benchmarks() {
             }
         }
     });
-    let sparse_sccs: Vec<_> = tarjan(sparse_graph).collect();
+    let sparse_sccs: Vec<_> = tarjan(&sparse_graph).collect();
     let sparse_duration = start.elapsed();
 
     // Test 3: Many small cycles
@@ -709,7 +710,7 @@ fn test_performance_benchmarks() {
             );
         }
     });
-    let cycles_sccs: Vec<_> = tarjan(cycles_graph).collect();
+    let cycles_sccs: Vec<_> = tarjan(&cycles_graph).collect();
     let cycles_duration = start.elapsed();
 
     // Performance assertions (all should complete quickly)
@@ -740,3 +741,296 @@ fn test_performance_benchmarks() {
         assert_eq!(scc.len(), 3, "Each cycle should form a 3-node SCC");
     }
 }
+
+#[test]
+fn test_self_loop_edges_and_removal() {
+    let mut graph = VecGraph::default();
+
+    // Same fixture as test_tarjan_with_self_loops
+    graph.scope_mut(|mut ctx| {
+        let n0 = ctx.add_node(0);
+        let n1 = ctx.add_node(1);
+        let n2 = ctx.add_node(2);
+
+        ctx.add_edge("0->0", n0, n0);
+        ctx.add_edge("2->2", n2, n2);
+
+        ctx.add_edge("0->1", n0, n1);
+        ctx.add_edge("1->2", n1, n2);
+    });
+
+    let loops: Vec<_> = graph.self_loop_edges().collect();
+    assert_eq!(loops.len(), 2);
+    for &ix in &loops {
+        let [from, to] = graph.endpoints(ix);
+        assert_eq!(from, to);
+    }
+    graph.scope(|ctx| assert_eq!(ctx.self_loop_edges().count(), 2));
+
+    assert_eq!(graph.remove_self_loops(), vec!["0->0", "2->2"]);
+    assert_eq!(graph.self_loop_edges().count(), 0);
+    assert_eq!(graph.remove_self_loops(), Vec::<&str>::new());
+
+    // The remaining edges are intact and the adjacency lists agree with them
+    let mut remaining: Vec<_> = graph.edges().copied().collect();
+    remaining.sort();
+    assert_eq!(remaining, vec!["0->1", "1->2"]);
+    for node in graph.node_indices() {
+        for edge in graph.outgoing_edge_indices(node) {
+            assert_eq!(graph.endpoints(edge)[0], node);
+        }
+        for edge in graph.incoming_edge_indices(node) {
+            assert_eq!(graph.endpoints(edge)[1], node);
+        }
+        assert_eq!(
+            graph.degree(node),
+            if *graph.node(node) == 1 { 2 } else { 1 }
+        );
+    }
+}
+
+#[test]
+fn test_dedup_parallel_edges() {
+    // Same fixture as test_parallel_edges
+    let mut graph = VecGraph::default();
+
+    graph.scope_mut(|mut ctx| {
+        let n0 = ctx.add_node(0);
+        let n1 = ctx.add_node(1);
+        let n2 = ctx.add_node(2);
+
+        ctx.add_edge("edge1_0_to_1", n0, n1);
+        ctx.add_edge("edge2_0_to_1", n0, n1);
+        ctx.add_edge("edge3_0_to_1", n0, n1);
+
+        ctx.add_edge("edge1_1_to_0", n1, n0);
+        ctx.add_edge("edge2_1_to_0", n1, n0);
+
+        ctx.add_edge("edge1_1_to_2", n1, n2);
+        ctx.add_edge("edge2_1_to_2", n1, n2);
+    });
+    let sccs_before = tarjan(&graph).count();
+
+    let mut calls = Vec::new();
+    let removed = graph.dedup_parallel_edges(|kept, other| calls.push((*kept, other)));
+    assert_eq!(removed, 4);
+    assert_eq!(graph.len_edges(), 3);
+
+    // The three 0->1 edges collapse into the first one, with two calls to combine
+    let calls_0_to_1: Vec<_> = calls
+        .iter()
+        .filter(|(kept, _)| *kept == "edge1_0_to_1")
+        .map(|&(_, other)| other)
+        .collect();
+    assert_eq!(calls_0_to_1, vec!["edge2_0_to_1", "edge3_0_to_1"]);
+    assert_eq!(calls.len(), 4);
+
+    let mut remaining: Vec<_> = graph.edges().copied().collect();
+    remaining.sort();
+    assert_eq!(
+        remaining,
+        vec!["edge1_0_to_1", "edge1_1_to_0", "edge1_1_to_2"]
+    );
+    for edge in graph.edge_indices() {
+        let [from, to] = graph.endpoints(edge);
+        let name = format!("edge1_{}_to_{}", graph.node(from), graph.node(to));
+        assert_eq!(*graph.edge(edge), name);
+        assert!(graph.outgoing_edge_indices(from).any(|e| e == edge));
+        assert!(graph.incoming_edge_indices(to).any(|e| e == edge));
+    }
+
+    // Connectivity is unchanged and nothing is left to merge
+    assert_eq!(tarjan(&graph).count(), sccs_before);
+    assert_eq!(graph.dedup_parallel_edges(|_, _| unreachable!()), 0);
+}
+
+#[test]
+fn test_parallel_edge_groups() {
+    // Same fixture as test_parallel_edges
+    let mut graph = VecGraph::default();
+
+    graph.scope_mut(|mut ctx| {
+        let n0 = ctx.add_node(0);
+        let n1 = ctx.add_node(1);
+        let n2 = ctx.add_node(2);
+
+        ctx.add_edge("edge1_0_to_1", n0, n1);
+        ctx.add_edge("edge2_0_to_1", n0, n1);
+        ctx.add_edge("edge3_0_to_1", n0, n1);
+
+        ctx.add_edge("edge1_1_to_0", n1, n0);
+        ctx.add_edge("edge2_1_to_0", n1, n0);
+
+        ctx.add_edge("edge1_1_to_2", n1, n2);
+        ctx.add_edge("edge2_1_to_2", n1, n2);
+    });
+    assert!(graph.has_parallel_edges());
+
+    let groups: Vec<_> = graph
+        .parallel_edge_groups()
+        .map(|(from, to, edges)| {
+            let names: Vec<_> = edges.iter().map(|&e| *graph.edge(e)).collect();
+            ((*graph.node(from), *graph.node(to)), names)
+        })
+        .collect();
+    assert_eq!(
+        groups,
+        vec![
+            ((0, 1), vec!["edge1_0_to_1", "edge2_0_to_1", "edge3_0_to_1"]),
+            ((1, 0), vec!["edge1_1_to_0", "edge2_1_to_0"]),
+            ((1, 2), vec!["edge1_1_to_2", "edge2_1_to_2"]),
+        ]
+    );
+
+    // A single self-loop is not parallel to anything, a second one is
+    let n2 = graph.node_indices().find(|&n| *graph.node(n) == 2).unwrap();
+    graph.add_edge("loop1", n2, n2);
+    assert_eq!(graph.parallel_edge_groups().count(), 3);
+    let loop2 = graph.add_edge("loop2", n2, n2);
+    let (from, to, edges) = graph.parallel_edge_groups().last().unwrap();
+    assert_eq!((from, to), (n2, n2));
+    assert_eq!(edges.len(), 2);
+    assert_eq!(edges[1], loop2);
+
+    graph.dedup_parallel_edges(|_, _| {});
+    assert!(!graph.has_parallel_edges());
+    assert_eq!(graph.parallel_edge_groups().count(), 0);
+}
+
+#[test]
+fn test_dedup_parallel_self_loops() {
+    let mut graph: VecGraph<(), u32> = VecGraph::default();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    graph.add_edge(1, a, a);
+    graph.add_edge(10, a, b);
+    graph.add_edge(2, a, a);
+    graph.add_edge(20, b, a);
+    graph.add_edge(4, a, a);
+
+    assert_eq!(graph.dedup_parallel_edges(|total, w| *total += w), 2);
+    let loops: Vec<_> = graph.self_loop_edges().map(|e| *graph.edge(e)).collect();
+    assert_eq!(loops, vec![7]);
+    assert_eq!(graph.len_edges(), 3);
+}
+
+#[test]
+fn test_extract_scc_subgraph() {
+    let mut graph = create_comprehensive_test_graph();
+    let nodes: Vec<_> = graph.node_indices().collect();
+    // A parallel edge and a self-loop inside SCC2
+    graph.add_edge("edge_1_to_2_again".to_string(), nodes[1], nodes[2]);
+    graph.add_edge("edge_2_to_2".to_string(), nodes[2], nodes[2]);
+
+    let scc = tarjan(&graph)
+        .find(|scc| scc.len() == 3)
+        .expect("SCC2 should be found");
+    let (sub, remap) = graph.extract_subgraph(scc.iter().copied());
+
+    let mut names: Vec<_> = sub.nodes().cloned().collect();
+    names.sort();
+    assert_eq!(names, vec!["Node_1", "Node_2", "Node_3"]);
+
+    let mut edges: Vec<_> = sub
+        .edge_pairs()
+        .map(|(ix, name)| {
+            let (from, to) = sub.endpoint_data(ix);
+            (name.as_str(), from.as_str(), to.as_str())
+        })
+        .collect();
+    edges.sort();
+    assert_eq!(
+        edges,
+        vec![
+            ("edge_1_to_2", "Node_1", "Node_2"),
+            ("edge_1_to_2_again", "Node_1", "Node_2"),
+            ("edge_2_to_2", "Node_2", "Node_2"),
+            ("edge_2_to_3", "Node_2", "Node_3"),
+            ("edge_3_to_1", "Node_3", "Node_1"),
+        ]
+    );
+
+    // Cross-SCC edges are not copied, and only SCC members are mapped
+    assert!(sub
+        .edges()
+        .all(|name| name != "edge_0_to_1" && name != "edge_3_to_4"));
+    for &node in &nodes {
+        match remap[node] {
+            Some(new) => assert_eq!(sub.node(new), graph.node(node)),
+            None => assert!(!scc.contains(&node)),
+        }
+    }
+    assert_eq!(tarjan(&sub).count(), 1);
+}
+
+#[test]
+fn test_extract_empty_subgraph() {
+    let graph = create_comprehensive_test_graph();
+    let (sub, remap) = graph.extract_subgraph([]);
+    assert!(sub.is_empty());
+    assert_eq!(sub.len_edges(), 0);
+    assert!(graph.node_indices().all(|node| remap[node].is_none()));
+}
+
+#[test]
+fn test_extract_subgraph_in_scope() {
+    let graph = create_comprehensive_test_graph();
+    let sub = graph.scope(|ctx| {
+        let tags: Vec<_> = ctx.node_indices().collect();
+        // Duplicates are ignored
+        let (sub, remap) = ctx.extract_subgraph([tags[4], tags[5], tags[4]]);
+        assert_eq!(remap[tags[0]], None);
+        assert_eq!(remap[tags[5]].map(|ix| ix.index()), Some(1));
+        sub
+    });
+    assert_eq!(sub.len_nodes(), 2);
+    let mut edges: Vec<_> = sub.edges().cloned().collect();
+    edges.sort();
+    assert_eq!(edges, vec!["edge_4_to_5", "edge_5_to_4"]);
+}
+
+#[test]
+fn test_tarjan_visit_matches_tarjan() {
+    fn visited<G: Graph>(graph: &G) -> Vec<Vec<G::NodeIx>> {
+        let mut sccs = Vec::new();
+        let flow = tarjan_visit(graph, |scc| {
+            sccs.push(scc.to_vec());
+            ControlFlow::Continue(())
+        });
+        assert!(flow.is_continue());
+        sccs
+    }
+    fn collected<G: Graph>(graph: &G) -> Vec<Vec<G::NodeIx>> {
+        tarjan(graph).map(|scc| scc.to_vec()).collect()
+    }
+
+    let graph = create_comprehensive_test_graph();
+    assert_eq!(visited(&graph), collected(&graph));
+    let graph = create_nested_cycles_graph();
+    assert_eq!(visited(&graph), collected(&graph));
+    let graph: VecGraph<(), ()> = VecGraph::default();
+    assert!(visited(&graph).is_empty());
+}
+
+#[test]
+fn test_tarjan_visit_stops_early() {
+    // Many small cycles, each one its own component
+    let mut graph: VecGraph<usize, ()> = VecGraph::default();
+    for i in 0..10_000 {
+        let a = graph.add_node(2 * i);
+        let b = graph.add_node(2 * i + 1);
+        graph.add_edge((), a, b);
+        graph.add_edge((), b, a);
+    }
+
+    let mut calls = 0;
+    let mut first = Vec::new();
+    let flow = tarjan_visit(&graph, |scc| {
+        calls += 1;
+        first = scc.to_vec();
+        ControlFlow::Break(())
+    });
+    assert!(flow.is_break());
+    assert_eq!(calls, 1);
+    assert_eq!(first, tarjan(&graph).next().unwrap().to_vec());
+}
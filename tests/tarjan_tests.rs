@@ -1,4 +1,5 @@
 use gotgraph::algo::tarjan;
+use gotgraph::graph::IndexConversion;
 use gotgraph::prelude::*;
 
 /// Create a simple test graph with no cycles
@@ -76,7 +77,7 @@ fn create_complex_graph() -> VecGraph<i32, &'static str> {
 #[test]
 fn test_empty_graph() {
     let graph = VecGraph::<i32, &str>::default();
-    let sccs: Vec<_> = tarjan(graph).collect();
+    let sccs: Vec<_> = tarjan(&graph).collect();
     assert_eq!(sccs.len(), 0);
 }
 
@@ -88,7 +89,7 @@ fn test_single_node() {
         ctx.add_node(42);
     });
 
-    let sccs: Vec<_> = tarjan(graph).collect();
+    let sccs: Vec<_> = tarjan(&graph).collect();
     assert_eq!(sccs.len(), 1);
     assert_eq!(sccs[0].len(), 1);
     // Just check that the SCC contains one node (don't check the exact index)
@@ -97,7 +98,7 @@ fn test_single_node() {
 #[test]
 fn test_linear_graph_no_cycles() {
     let graph = create_linear_graph();
-    let sccs: Vec<_> = tarjan(graph).collect();
+    let sccs: Vec<_> = tarjan(&graph).collect();
 
     // Each node should be its own SCC
     assert_eq!(sccs.len(), 4);
@@ -111,7 +112,7 @@ fn test_linear_graph_no_cycles() {
 #[test]
 fn test_simple_cycle() {
     let graph = create_cycle_graph();
-    let sccs: Vec<_> = tarjan(graph).collect();
+    let sccs: Vec<_> = tarjan(&graph).collect();
 
     // Should have one SCC containing all 3 nodes
     assert_eq!(sccs.len(), 1);
@@ -131,7 +132,7 @@ fn test_self_loop() {
         ctx.add_edge("self", n0, n0);
     });
 
-    let sccs: Vec<_> = tarjan(graph).collect();
+    let sccs: Vec<_> = tarjan(&graph).collect();
     assert_eq!(sccs.len(), 1);
     assert_eq!(sccs[0].len(), 1);
 }
@@ -139,7 +140,7 @@ fn test_self_loop() {
 #[test]
 fn test_complex_graph_multiple_sccs() {
     let graph = create_complex_graph();
-    let sccs: Vec<_> = tarjan(graph).collect();
+    let sccs: Vec<_> = tarjan(&graph).collect();
 
     // Should have 3 SCCs
     assert_eq!(sccs.len(), 3);
@@ -170,7 +171,7 @@ fn test_two_separate_cycles() {
         ctx.add_edge("3->2", n3, n2);
     });
 
-    let sccs: Vec<_> = tarjan(graph).collect();
+    let sccs: Vec<_> = tarjan(&graph).collect();
 
     // Should have 2 SCCs, each with 2 nodes
     assert_eq!(sccs.len(), 2);
@@ -182,7 +183,7 @@ fn test_two_separate_cycles() {
 fn test_scc_box_slice_properties() {
     // Test that Box<[NodeIx]> behaves as expected
     let graph = create_cycle_graph();
-    let sccs: Vec<_> = tarjan(graph).collect();
+    let sccs: Vec<_> = tarjan(&graph).collect();
 
     // Test the first SCC (should contain all 3 nodes from the cycle)
     assert!(!sccs.is_empty());
@@ -196,3 +197,89 @@ fn test_scc_box_slice_properties() {
     assert!(empty_scc.is_empty());
     assert_eq!(empty_scc.len(), 0);
 }
+
+#[test]
+fn test_tarjan_does_not_borrow_graph() {
+    let mut graph = VecGraph::default();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    graph.add_edge((), a, b);
+    graph.add_edge((), b, a);
+
+    let sccs = tarjan(&graph);
+
+    // The graph can be modified before the result is consumed
+    let c = graph.add_node("C");
+    graph.add_edge((), b, c);
+    graph.clear_edges();
+
+    let sccs: Vec<_> = sccs.collect();
+    assert_eq!(sccs.len(), 1);
+    let mut scc = sccs[0].to_vec();
+    scc.sort();
+    assert_eq!(scc, vec![a, b]);
+
+    // Running it again sees the modified graph
+    assert_eq!(tarjan(&graph).count(), 3);
+}
+
+#[test]
+fn test_is_mutual_on_complex_graph() {
+    let mut graph = create_complex_graph();
+    let edge = |graph: &VecGraph<i32, &str>, name: &str| {
+        graph.edge_pairs().find(|&(_, &e)| e == name).unwrap().0
+    };
+
+    assert!(graph.is_mutual(edge(&graph, "0->1")));
+    assert!(graph.is_mutual(edge(&graph, "1->0")));
+    for name in ["1->2", "2->3", "3->4", "4->2", "4->5"] {
+        assert!(!graph.is_mutual(edge(&graph, name)), "{}", name);
+    }
+
+    let nodes: Vec<_> = graph.node_indices().collect();
+    assert_eq!(
+        graph.find_edge(nodes[1], nodes[2]),
+        Some(edge(&graph, "1->2"))
+    );
+    assert_eq!(graph.find_edge(nodes[2], nodes[1]), None);
+
+    // Self-loops are their own reverse edge
+    let lp = graph.add_edge("5->5", nodes[5], nodes[5]);
+    assert!(graph.is_mutual(lp));
+}
+
+#[test]
+fn test_retag_sccs_in_scope() {
+    let graph = create_complex_graph();
+    let sccs: Vec<_> = tarjan(&graph).collect();
+
+    graph.scope(|ctx| {
+        let mut payloads: Vec<Vec<i32>> = sccs
+            .iter()
+            .map(|scc| {
+                let mut values: Vec<_> = scc
+                    .iter()
+                    .map(|&ix| *ctx.node(ctx.tag_node(ix).unwrap()))
+                    .collect();
+                values.sort();
+                values
+            })
+            .collect();
+        payloads.sort();
+        assert_eq!(payloads, vec![vec![0, 1], vec![2, 3, 4], vec![5]]);
+
+        let tags: Vec<_> = sccs[0]
+            .iter()
+            .map(|&ix| ctx.tag_node(ix).unwrap())
+            .collect();
+        assert_eq!(ctx.untag_nodes(tags), sccs[0].to_vec());
+
+        let out_of_range = VecGraph::<i32, &str>::node_from_usize(ctx.len_nodes()).unwrap();
+        assert!(ctx.tag_node(out_of_range).is_none());
+
+        let edge = graph.edge_indices().next().unwrap();
+        assert_eq!(ctx.edge(ctx.tag_edge(edge).unwrap()), graph.edge(edge));
+        let out_of_range = VecGraph::<i32, &str>::edge_from_usize(ctx.len_edges()).unwrap();
+        assert!(ctx.tag_edge(out_of_range).is_none());
+    });
+}
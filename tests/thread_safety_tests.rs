@@ -0,0 +1,79 @@
+use gotgraph::algo::tarjan;
+use gotgraph::prelude::*;
+use std::thread;
+
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<VecGraph<i32, i32>>();
+    assert_send_sync::<&VecGraph<String, Vec<u8>>>();
+    assert_send_sync::<gotgraph::undirected::UnVecGraph<i32, i32>>();
+    assert_send_sync::<gotgraph::keyed::KeyedGraph<String, i32, i32>>();
+};
+
+fn create_graph() -> VecGraph<i32, i32> {
+    let mut graph = VecGraph::default();
+    let n: Vec<_> = (0..100).map(|i| graph.add_node(i)).collect();
+    for i in 0..100 {
+        // Cycles of length 5, each linked to the next one
+        let next = if i % 5 == 4 { i - 4 } else { i + 1 };
+        graph.add_edge(i as i32, n[i], n[next]);
+        if i % 5 == 0 && i + 5 < 100 {
+            graph.add_edge(-1, n[i], n[i + 5]);
+        }
+    }
+    graph
+}
+
+fn sorted_sccs(graph: &VecGraph<i32, i32>) -> Vec<Vec<i32>> {
+    let mut sccs: Vec<Vec<i32>> = tarjan(graph)
+        .map(|scc| {
+            let mut nodes: Vec<_> = scc.iter().map(|&ix| *graph.node(ix)).collect();
+            nodes.sort();
+            nodes
+        })
+        .collect();
+    sccs.sort();
+    sccs
+}
+
+#[test]
+fn test_tarjan_on_shared_graph_from_threads() {
+    let graph = create_graph();
+    let expected = sorted_sccs(&graph);
+    assert_eq!(expected.len(), 20);
+
+    let shared = &graph;
+    thread::scope(|s| {
+        let handles: Vec<_> = (0..8)
+            .map(|_| s.spawn(move || sorted_sccs(shared)))
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), expected);
+        }
+    });
+}
+
+#[test]
+fn test_read_only_scope_shared_between_threads() {
+    let graph = create_graph();
+
+    let total = graph.scope(|ctx| {
+        let nodes: Vec<_> = ctx.node_indices().collect();
+        thread::scope(|s| {
+            let handles: Vec<_> = nodes
+                .chunks(10)
+                .map(|chunk| {
+                    s.spawn(move || {
+                        chunk
+                            .iter()
+                            .flat_map(|&n| ctx.outgoing_edge_indices(n))
+                            .map(|e| *ctx.edge(e))
+                            .sum::<i32>()
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).sum::<i32>()
+        })
+    });
+    assert_eq!(total, graph.edges().sum::<i32>());
+}
@@ -0,0 +1,129 @@
+use gotgraph::prelude::*;
+
+fn assert_respects_edges<G: Graph>(graph: &G, order: &[G::NodeIx]) {
+    assert_eq!(order.len(), graph.len_nodes());
+    let mut position = graph.init_node_map(|_, _| usize::MAX);
+    for (i, &ix) in order.iter().enumerate() {
+        position[ix] = i;
+    }
+    for edge in graph.edge_indices() {
+        let [from, to] = graph.endpoints(edge);
+        assert!(
+            position[from] < position[to],
+            "edge {:?} -> {:?} goes backward in the order",
+            from,
+            to
+        );
+    }
+}
+
+#[test]
+fn test_order_respects_every_edge() {
+    let mut graph: VecGraph<usize, ()> = VecGraph::default();
+    let nodes: Vec<_> = (0..8).map(|i| graph.add_node(i)).collect();
+    for (from, to) in [
+        (7, 3),
+        (3, 0),
+        (5, 0),
+        (7, 5),
+        (1, 2),
+        (6, 1),
+        (6, 4),
+        (4, 2),
+    ] {
+        graph.add_edge((), nodes[from], nodes[to]);
+    }
+
+    graph.scope(|ctx| {
+        let order = ctx.topological_order().unwrap();
+        assert_respects_edges(ctx, &order);
+        let mut labels: Vec<_> = order.iter().map(|&n| *ctx.node(n)).collect();
+        labels.sort();
+        assert_eq!(labels, (0..8).collect::<Vec<_>>());
+    });
+
+    graph.scope_mut(|mut ctx| {
+        // The order is made of tags, so it can be used to write to the nodes directly
+        let order = ctx.topological_order().unwrap();
+        for (rank, &n) in order.iter().enumerate() {
+            *ctx.node_mut(n) = rank;
+        }
+    });
+    for edge in graph.edge_indices() {
+        let (from, to) = graph.endpoint_data(edge);
+        assert!(from < to);
+    }
+}
+
+#[test]
+fn test_tie_break_orders_independent_nodes() {
+    let mut graph: VecGraph<(&str, u32), ()> = VecGraph::default();
+    let a = graph.add_node(("a", 5));
+    let b = graph.add_node(("b", 1));
+    let c = graph.add_node(("c", 4));
+    let d = graph.add_node(("d", 0));
+    let e = graph.add_node(("e", 3));
+    let f = graph.add_node(("f", 3));
+    // d is urgent but waits for a
+    graph.add_edge((), a, d);
+    graph.add_edge((), b, c);
+
+    graph.scope(|ctx| {
+        let order = ctx.topological_order_by(|x, y| x.1.cmp(&y.1)).unwrap();
+        assert_respects_edges(ctx, &order);
+        let names: Vec<_> = order.iter().map(|&n| ctx.node(n).0).collect();
+        assert_eq!(names, vec!["b", "e", "f", "c", "a", "d"]);
+
+        let order = ctx.topological_order_by(|x, y| y.1.cmp(&x.1)).unwrap();
+        let names: Vec<_> = order.iter().map(|&n| ctx.node(n).0).collect();
+        assert_eq!(names, vec!["a", "e", "f", "b", "c", "d"]);
+    });
+
+    // Equal priorities keep the order in which the nodes became ready
+    let order = graph
+        .topological_order_by(|_, _| core::cmp::Ordering::Equal)
+        .unwrap();
+    assert_eq!(order, vec![a, b, e, f, d, c]);
+}
+
+#[test]
+fn test_cycle_error_names_a_node_on_the_cycle() {
+    // a -> b -> c -> d -> b, and d -> e, so a is upstream and e downstream of the cycle
+    let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let c = graph.add_node("c");
+    let d = graph.add_node("d");
+    let e = graph.add_node("e");
+    graph.add_edge((), a, b);
+    graph.add_edge((), b, c);
+    graph.add_edge((), c, d);
+    graph.add_edge((), d, b);
+    graph.add_edge((), d, e);
+    graph.add_edge((), e, e);
+
+    graph.scope(|ctx| {
+        let node = ctx.topological_order().unwrap_err();
+        assert!(["b", "c", "d", "e"].contains(ctx.node(node)));
+        // The node reaches itself again
+        assert!(ctx.descendants(node).any(|n| n == node));
+
+        let node = ctx.topological_order_by(|x, y| x.cmp(y)).unwrap_err();
+        assert!(ctx.descendants(node).any(|n| n == node));
+    });
+
+    let mut graph: VecGraph<(), ()> = VecGraph::default();
+    let a = graph.add_node(());
+    graph.add_edge((), a, a);
+    assert_eq!(graph.topological_order(), Err(a));
+}
+
+#[test]
+fn test_empty_graph() {
+    let graph: VecGraph<(), ()> = VecGraph::default();
+    assert_eq!(graph.topological_order(), Ok(vec![]));
+    assert_eq!(
+        graph.topological_order_by(|_, _| core::cmp::Ordering::Less),
+        Ok(vec![])
+    );
+}
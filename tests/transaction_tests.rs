@@ -0,0 +1,108 @@
+use gotgraph::prelude::*;
+use gotgraph::transaction::NodeRef;
+
+fn edge_endpoints(
+    graph: &VecGraph<&'static str, &'static str>,
+) -> Vec<(&'static str, &'static str)> {
+    let mut edges: Vec<_> = graph
+        .edge_indices()
+        .map(|ix| {
+            let (from, to) = graph.endpoint_data(ix);
+            (*from, *to)
+        })
+        .collect();
+    edges.sort();
+    edges
+}
+
+#[test]
+fn test_replace_subgraph_wired_to_survivors() {
+    let mut graph: VecGraph<&str, &str> = VecGraph::default();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let c = graph.add_node("c");
+    let d = graph.add_node("d");
+    let e = graph.add_node("e");
+    let ab = graph.add_edge("a->b", a, b);
+    graph.add_edge("b->c", b, c);
+    graph.add_edge("c->d", c, d);
+    graph.add_edge("d->e", d, e);
+    graph.add_edge("a->e", a, e);
+
+    let mut txn = graph.transaction();
+    txn.remove_node(b);
+    txn.remove_node(c);
+    txn.remove_node(b);
+    txn.remove_edge(ab);
+    let x = txn.add_node("x");
+    let y = txn.add_node("y");
+    let ax = txn.add_edge("a->x", a, x);
+    txn.add_edge("x->y", x, y);
+    txn.add_edge("y->d", y, NodeRef::Existing(d));
+    let result = txn.commit().unwrap();
+
+    assert_eq!(graph.len_nodes(), 5);
+    assert_eq!(*graph.node(result.node(x)), "x");
+    assert_eq!(*graph.node(result.node(y)), "y");
+    assert_eq!(*graph.edge(result.edge(ax)), "a->x");
+    for (old, name) in [(a, "a"), (d, "d"), (e, "e")] {
+        assert_eq!(*graph.node(result.remapped(old).unwrap()), name);
+    }
+    assert_eq!(result.remapped(b), None);
+    assert_eq!(result.remapped(c), None);
+    assert_eq!(
+        edge_endpoints(&graph),
+        vec![("a", "e"), ("a", "x"), ("d", "e"), ("x", "y"), ("y", "d")]
+    );
+
+    let (mut nodes, mut edges) = result.into_removed();
+    nodes.sort();
+    edges.sort();
+    assert_eq!(nodes, vec!["b", "c"]);
+    assert_eq!(edges, vec!["a->b", "b->c", "c->d"]);
+}
+
+#[test]
+fn test_conflicting_transaction_leaves_graph_untouched() {
+    let mut graph: VecGraph<&str, &str> = VecGraph::default();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let c = graph.add_node("c");
+    let ab = graph.add_edge("a->b", a, b);
+    graph.add_edge("b->c", b, c);
+    let before = graph.clone();
+
+    let mut txn = graph.transaction();
+    txn.remove_edge(ab);
+    txn.remove_node(c);
+    let x = txn.add_node("x");
+    txn.add_edge("a->x", a, x);
+    let xc = txn.add_edge("x->c", x, c);
+    let err = txn.commit().unwrap_err();
+
+    assert_eq!(err.edge(), xc);
+    assert_eq!(err.node(), c);
+    assert_eq!(graph, before);
+}
+
+#[test]
+fn test_dropped_transaction_does_nothing() {
+    let mut graph: VecGraph<&str, &str> = VecGraph::default();
+    let a = graph.add_node("a");
+    let before = graph.clone();
+
+    let mut txn = graph.transaction();
+    txn.remove_node(a);
+    txn.add_node("x");
+    drop(txn);
+    assert_eq!(graph, before);
+}
+
+#[test]
+#[should_panic(expected = "does not exist")]
+fn test_queueing_missing_node_panics() {
+    let mut graph: VecGraph<&str, &str> = VecGraph::default();
+    let a = graph.add_node("a");
+    graph.remove_node(a);
+    graph.transaction().remove_node(a);
+}
@@ -0,0 +1,98 @@
+use gotgraph::algo::transitive_closure;
+use gotgraph::prelude::*;
+
+#[test]
+fn test_chain() {
+    let mut graph: VecGraph<usize, ()> = VecGraph::default();
+    let nodes: Vec<_> = (0..100).map(|i| graph.add_node(i)).collect();
+    for pair in nodes.windows(2) {
+        graph.add_edge((), pair[0], pair[1]);
+    }
+
+    let closure = transitive_closure(&graph);
+    for (i, &node) in nodes.iter().enumerate() {
+        assert_eq!(closure[node], nodes[i + 1..].to_vec());
+    }
+}
+
+#[test]
+fn test_cycle() {
+    let mut graph: VecGraph<usize, ()> = VecGraph::default();
+    let nodes: Vec<_> = (0..70).map(|i| graph.add_node(i)).collect();
+    for i in 0..nodes.len() {
+        graph.add_edge((), nodes[i], nodes[(i + 1) % nodes.len()]);
+    }
+
+    let closure = transitive_closure(&graph);
+    for &node in &nodes {
+        assert_eq!(closure[node], nodes);
+    }
+}
+
+#[test]
+fn test_self_loop_and_condensation() {
+    // a -> (b <-> c) -> d, d has a self-loop, e is isolated
+    let mut graph: VecGraph<&str, ()> = VecGraph::default();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let c = graph.add_node("c");
+    let d = graph.add_node("d");
+    let e = graph.add_node("e");
+
+    graph.add_edge((), a, b);
+    graph.add_edge((), b, c);
+    graph.add_edge((), c, b);
+    graph.add_edge((), c, d);
+    graph.add_edge((), d, d);
+
+    let closure = transitive_closure(&graph);
+    assert_eq!(closure[a], vec![b, c, d]);
+    assert_eq!(closure[b], vec![b, c, d]);
+    assert_eq!(closure[c], vec![b, c, d]);
+    assert_eq!(closure[d], vec![d]);
+    assert!(closure[e].is_empty());
+}
+
+#[test]
+fn test_matches_breadth_first_search() {
+    let mut graph: VecGraph<usize, ()> = VecGraph::default();
+    let nodes: Vec<_> = (0..40).map(|i| graph.add_node(i)).collect();
+    let mut seed = 12345u64;
+    for _ in 0..80 {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let from = nodes[(seed >> 33) as usize % nodes.len()];
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let to = nodes[(seed >> 33) as usize % nodes.len()];
+        graph.add_edge((), from, to);
+    }
+
+    let closure = transitive_closure(&graph);
+    for &start in &nodes {
+        let mut seen = vec![false; nodes.len()];
+        let mut stack: Vec<_> = graph
+            .outgoing_edge_indices(start)
+            .map(|e| graph.endpoints(e)[1])
+            .collect();
+        while let Some(node) = stack.pop() {
+            let i = *graph.node(node);
+            if !seen[i] {
+                seen[i] = true;
+                stack.extend(
+                    graph
+                        .outgoing_edge_indices(node)
+                        .map(|e| graph.endpoints(e)[1]),
+                );
+            }
+        }
+        let expected: Vec<_> = nodes
+            .iter()
+            .copied()
+            .filter(|&n| seen[*graph.node(n)])
+            .collect();
+        assert_eq!(closure[start], expected);
+    }
+}
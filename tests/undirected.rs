@@ -0,0 +1,187 @@
+use gotgraph::algo::tarjan;
+use gotgraph::prelude::*;
+use gotgraph::undirected::UnVecGraph;
+use gotgraph::Mapping;
+use std::collections::HashSet;
+
+fn create_test_graph() -> UnVecGraph<i32, &'static str> {
+    let mut graph = UnVecGraph::default();
+
+    graph.scope_mut(|mut ctx| {
+        let n0 = ctx.add_node(0);
+        let n1 = ctx.add_node(1);
+        let n2 = ctx.add_node(2);
+        let n3 = ctx.add_node(3);
+        let n4 = ctx.add_node(4);
+        let _n5 = ctx.add_node(5);
+
+        ctx.add_edge("0->1", n0, n1);
+        ctx.add_edge("2->1", n2, n1);
+        ctx.add_edge("1->2", n1, n2);
+        ctx.add_edge("3->3", n3, n3);
+        ctx.add_edge("4->3", n4, n3);
+    });
+
+    graph
+}
+
+#[test]
+fn test_every_edge_listed_by_both_endpoints() {
+    let graph = create_test_graph();
+
+    for edge in graph.edge_indices() {
+        let [from, to] = graph.endpoints(edge);
+        for node in [from, to] {
+            assert!(graph
+                .outgoing_edge_indices(node)
+                .any(|e| e.inner() == edge.inner()));
+            assert!(graph
+                .incoming_edge_indices(node)
+                .any(|e| e.inner() == edge.inner()));
+        }
+    }
+}
+
+#[test]
+fn test_traversal_orientation() {
+    let graph = create_test_graph();
+
+    for node in graph.node_indices() {
+        for edge in graph.outgoing_edge_indices(node) {
+            assert_eq!(graph.endpoints(edge)[0], node);
+        }
+        for edge in graph.incoming_edge_indices(node) {
+            assert_eq!(graph.endpoints(edge)[1], node);
+        }
+    }
+}
+
+#[test]
+fn test_outgoing_and_incoming_are_symmetric() {
+    let graph = create_test_graph();
+
+    for node in graph.node_indices() {
+        let outgoing: HashSet<_> = graph.outgoing_edge_indices(node).collect();
+        let incoming: HashSet<_> = graph.incoming_edge_indices(node).collect();
+        let reversed: HashSet<_> = outgoing.iter().map(|e| e.reversed()).collect();
+        assert_eq!(reversed, incoming);
+
+        let neighbours: HashSet<_> = graph
+            .outgoing_edge_indices(node)
+            .map(|e| graph.endpoints(e)[1])
+            .collect();
+        for neighbour in neighbours {
+            assert!(graph
+                .outgoing_edge_indices(neighbour)
+                .any(|e| graph.endpoints(e)[1] == node));
+        }
+    }
+}
+
+#[test]
+fn test_self_loop_counted_once() {
+    let mut graph: UnVecGraph<&str, &str> = UnVecGraph::default();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let self_loop = graph.add_edge("loop", a, a);
+    graph.add_edge("AB", a, b);
+
+    let outgoing: Vec<_> = graph.outgoing_edge_indices(a).collect();
+    let incoming: Vec<_> = graph.incoming_edge_indices(a).collect();
+    assert_eq!(outgoing.len(), 2);
+    assert_eq!(incoming.len(), 2);
+    assert_eq!(outgoing.iter().filter(|&&e| e == self_loop).count(), 1);
+    assert_eq!(graph.connecting_edge_indices(a).count(), 2);
+    assert_eq!(graph.endpoints(self_loop), [a, a]);
+}
+
+#[test]
+fn test_parallel_edges_in_both_directions() {
+    let mut graph: UnVecGraph<i32, &str> = UnVecGraph::default();
+    let a = graph.add_node(0);
+    let b = graph.add_node(1);
+    graph.add_edge("AB", a, b);
+    graph.add_edge("BA", b, a);
+
+    let from_a: Vec<_> = graph.outgoing_edges(a).cloned().collect();
+    let from_b: Vec<_> = graph.outgoing_edges(b).cloned().collect();
+    assert_eq!(from_a.len(), 2);
+    assert_eq!(from_b.len(), 2);
+    assert!(from_a.contains(&"AB") && from_a.contains(&"BA"));
+    assert!(from_b.contains(&"AB") && from_b.contains(&"BA"));
+}
+
+#[test]
+fn test_tarjan_yields_connected_components() {
+    let graph = create_test_graph();
+
+    let mut components: Vec<Vec<i32>> = tarjan(&graph)
+        .map(|scc| {
+            let mut nodes: Vec<_> = scc.iter().map(|&ix| *graph.node(ix)).collect();
+            nodes.sort();
+            nodes
+        })
+        .collect();
+    components.sort();
+
+    assert_eq!(components, vec![vec![0, 1, 2], vec![3, 4], vec![5]]);
+}
+
+#[test]
+fn test_edge_mutation_and_removal() {
+    let mut graph = create_test_graph();
+    let n1 = graph.node_indices().nth(1).unwrap();
+
+    for edge in graph.incoming_edges_mut(n1) {
+        *edge = "touched";
+    }
+    assert_eq!(graph.edges().filter(|&&e| e == "touched").count(), 3);
+
+    let edge = graph.outgoing_edge_indices(n1).next().unwrap();
+    graph.remove_edge(edge);
+    assert_eq!(graph.len_edges(), 4);
+    assert_eq!(graph.outgoing_edge_indices(n1).count(), 2);
+
+    let directed = graph.into_inner();
+    assert_eq!(directed.len_edges(), 4);
+}
+
+#[test]
+fn test_edge_orientation_is_part_of_the_index() {
+    let mut graph: UnVecGraph<&str, u32> = UnVecGraph::default();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let ab = graph.add_edge(7, a, b);
+
+    let from_a = graph.outgoing_edge_indices(a).next().unwrap();
+    let from_b = graph.outgoing_edge_indices(b).next().unwrap();
+    assert_eq!(graph.endpoints(from_a), [a, b]);
+    assert_eq!(graph.endpoints(from_b), [b, a]);
+
+    // Equal indices report the same endpoints
+    assert_eq!(from_a, ab);
+    assert_ne!(from_a, from_b);
+    assert_eq!(from_a.inner(), from_b.inner());
+    assert_eq!(from_b.reversed(), ab);
+    assert_eq!(graph.incoming_edge_indices(a).next(), Some(from_b));
+    assert_eq!(graph.incoming_edge_indices(b).next(), Some(from_a));
+    let set: HashSet<_> = [from_a, from_b, ab].into_iter().collect();
+    assert_eq!(set.len(), 2);
+    for e in set {
+        let [from, to] = graph.endpoints(e);
+        assert!(graph.outgoing_edge_indices(from).any(|x| x == e));
+        assert!(graph.incoming_edge_indices(to).any(|x| x == e));
+    }
+
+    // Both orientations refer to the same data and edge map entry
+    assert_eq!(graph.edge(from_b), &7);
+    {
+        let mut map = graph.init_edge_map(|_, &w| w);
+        map[from_b] += 1;
+        assert_eq!(map[from_a], 8);
+        assert_eq!(map.keys().collect::<Vec<_>>(), vec![ab]);
+    }
+
+    assert_eq!(graph.remove_edges::<_, Vec<_>>([from_a, from_b]), vec![7]);
+    assert_eq!(graph.len_edges(), 0);
+}
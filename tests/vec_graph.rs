@@ -764,6 +764,13 @@ fn test_large_scale_graph_with_loops() {
     // Verify final graph state
     assert_eq!(graph.len_nodes(), NUM_NODES);
     assert_eq!(graph.len_edges(), NUM_NODES * EDGES_PER_NODE);
+
+    // Every node has EDGES_PER_NODE outgoing and as many incoming edges
+    let histogram = graph.degree_histogram();
+    assert_eq!(
+        histogram.into_iter().collect::<Vec<_>>(),
+        vec![(2 * EDGES_PER_NODE, NUM_NODES)]
+    );
 }
 
 #[test]
@@ -874,3 +881,42 @@ fn test_dense_graph_modification_with_loops() {
     assert_eq!(corner_edges, 2, "Corner node should have 2 outgoing edges");
     assert_eq!(center_edges, 2, "Center node should have 2 outgoing edges");
 }
+
+#[test]
+fn test_reserve_edges_for_dense_grid() {
+    const GRID_SIZE: usize = 50;
+    const NUM_EDGES: usize = 2 * GRID_SIZE * (GRID_SIZE - 1);
+
+    let mut graph: VecGraph<i32, f32> = VecGraph::default();
+    graph.reserve_edges_for(NUM_EDGES);
+    let capacity = graph.edge_capacity();
+    assert!(capacity >= NUM_EDGES);
+
+    graph.scope_mut(|mut ctx| {
+        let node_grid: Vec<Vec<_>> = (0..GRID_SIZE)
+            .map(|row| {
+                (0..GRID_SIZE)
+                    .map(|col| ctx.add_node((row * GRID_SIZE + col) as i32))
+                    .collect()
+            })
+            .collect();
+
+        for row in 0..GRID_SIZE {
+            for col in 0..GRID_SIZE {
+                if col + 1 < GRID_SIZE {
+                    ctx.add_edge(0.0, node_grid[row][col], node_grid[row][col + 1]);
+                }
+                if row + 1 < GRID_SIZE {
+                    ctx.add_edge(1.0, node_grid[row][col], node_grid[row + 1][col]);
+                }
+            }
+        }
+    });
+
+    assert_eq!(graph.len_edges(), NUM_EDGES);
+    assert_eq!(graph.edge_capacity(), capacity);
+
+    // Reserving for fewer edges than already present is a no-op
+    graph.reserve_edges_for(10);
+    assert_eq!(graph.edge_capacity(), capacity);
+}
@@ -1,4 +1,5 @@
 use gotgraph::prelude::*;
+use gotgraph::Mapping;
 
 #[test]
 fn test_default_creation() {
@@ -304,3 +305,618 @@ fn test_complex_graph_with_multiple_and_recursive_edges() {
     assert_eq!(graph.endpoints(edge_cd), [node_c, node_d]);
     assert_eq!(graph.endpoints(edge_de), [node_d, node_e]);
 }
+
+#[test]
+fn test_display_adjacency_dump() {
+    let mut graph: VecGraph<&str, i32> = VecGraph::default();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    graph.add_edge(1, a, b);
+    graph.add_edge(2, a, c);
+    graph.add_edge(3, c, c);
+    graph.add_edge(4, c, a);
+
+    assert_eq!(
+        graph.to_string(),
+        "0: A\n  -> 1 (1)\n  -> 2 (2)\n1: B\n2: C\n  -> 2 (3)\n  -> 0 (4)\n"
+    );
+}
+
+#[test]
+fn test_len_of_large_graph() {
+    let mut graph: VecGraph<usize, ()> = VecGraph::default();
+    let nodes: Vec<_> = (0..1000).map(|i| graph.add_node(i)).collect();
+    for pair in nodes.windows(2) {
+        graph.add_edge((), pair[0], pair[1]);
+    }
+
+    assert_eq!(graph.len_nodes(), 1000);
+    assert_eq!(graph.len_edges(), 999);
+    assert_eq!(graph.len_nodes(), graph.node_indices().count());
+    assert_eq!(graph.len_edges(), graph.edge_indices().count());
+
+    graph.remove_node(nodes[500]);
+    assert_eq!(graph.len_nodes(), 999);
+    assert_eq!(graph.len_edges(), 997);
+}
+
+#[test]
+fn test_compact_after_removals() {
+    let mut graph: VecGraph<usize, ()> = VecGraph::default();
+    let nodes: Vec<_> = (0..10).map(|i| graph.add_node(i)).collect();
+    for pair in nodes.windows(2) {
+        graph.add_edge((), pair[0], pair[1]);
+    }
+    graph.remove_node(nodes[3]);
+    graph.remove_node(nodes[0]);
+    graph.remove_node(nodes[7]);
+
+    let before: Vec<_> = graph.node_pairs().map(|(ix, &data)| (ix, data)).collect();
+    let remap = graph.compact();
+
+    let mut seen = std::collections::HashSet::new();
+    for &(old, data) in &before {
+        let new = remap[old];
+        assert!(graph.exists_node_index(new));
+        assert_eq!(*graph.node(new), data);
+        assert!(seen.insert(new));
+    }
+    assert_eq!(seen.len(), graph.len_nodes());
+    assert_eq!(remap.iter().count(), graph.len_nodes());
+}
+
+#[test]
+fn test_try_add_node_and_edge() {
+    let mut graph: VecGraph<&str, i32> = VecGraph::default();
+    let a = graph.try_add_node("A").unwrap();
+    let b = graph.try_add_node("B").unwrap();
+    let e = graph.try_add_edge(7, a, b).unwrap();
+
+    assert_eq!(*graph.node(a), "A");
+    assert_eq!(*graph.edge(e), 7);
+    assert_eq!(graph.endpoints(e), [a, b]);
+    assert_eq!(graph.outgoing_edge_indices(a).collect::<Vec<_>>(), vec![e]);
+
+    graph.scope_mut(|mut ctx| {
+        let c = ctx.try_add_node("C").unwrap();
+        let e = ctx.try_add_edge(8, c, c).unwrap();
+        assert_eq!(ctx.endpoints(e), [c, c]);
+    });
+    assert_eq!(graph.len_nodes(), 3);
+    assert_eq!(graph.len_edges(), 2);
+}
+
+#[test]
+fn test_capacity_error_payload() {
+    use gotgraph::graph::CapacityError;
+
+    let err = CapacityError::new(vec![1, 2, 3]);
+    assert_eq!(err.to_string(), "graph has reached its maximum capacity");
+    assert_eq!(err.into_inner(), vec![1, 2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "does not exist")]
+fn test_try_add_edge_invalid_node_panics() {
+    let mut graph: VecGraph<&str, i32> = VecGraph::default();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    graph.remove_node(b);
+    let _ = graph.try_add_edge(1, a, b);
+}
+
+#[test]
+fn test_partition_nodes() {
+    let mut graph: VecGraph<i32, &str> = VecGraph::default();
+    let n: Vec<_> = [1, -1, 2, -2, 3]
+        .iter()
+        .map(|&v| graph.add_node(v))
+        .collect();
+    graph.add_edge("1->2", n[0], n[2]);
+    graph.add_edge("1->-1", n[0], n[1]);
+    graph.add_edge("-1->-2", n[1], n[3]);
+    graph.add_edge("3->3", n[4], n[4]);
+    graph.add_edge("-2->3", n[3], n[4]);
+    graph.add_edge("2->1", n[2], n[0]);
+    graph.add_edge("-2->-2", n[3], n[3]);
+
+    let (positive, negative, crossing) = graph.partition_nodes(|&v| v > 0);
+
+    assert_eq!(positive.nodes().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+    assert_eq!(negative.nodes().copied().collect::<Vec<_>>(), vec![-1, -2]);
+    assert_eq!(crossing, vec!["1->-1", "-2->3"]);
+
+    // Internal edges keep their endpoints
+    let endpoints = |g: &VecGraph<i32, &'static str>| {
+        let mut result: Vec<_> = g
+            .edge_pairs()
+            .map(|(ix, &e)| {
+                let [from, to] = g.endpoints(ix);
+                (e, *g.node(from), *g.node(to))
+            })
+            .collect();
+        result.sort();
+        result
+    };
+    assert_eq!(
+        endpoints(&positive),
+        vec![("1->2", 1, 2), ("2->1", 2, 1), ("3->3", 3, 3)]
+    );
+    assert_eq!(
+        endpoints(&negative),
+        vec![("-1->-2", -1, -2), ("-2->-2", -2, -2)]
+    );
+
+    // Adjacency lists of the new graphs are consistent
+    for g in [&positive, &negative] {
+        for node in g.node_indices() {
+            for e in g.outgoing_edge_indices(node) {
+                assert_eq!(g.endpoints(e)[0], node);
+            }
+            for e in g.incoming_edge_indices(node) {
+                assert_eq!(g.endpoints(e)[1], node);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_partition_nodes_one_side_empty() {
+    let mut graph: VecGraph<i32, ()> = VecGraph::default();
+    let a = graph.add_node(1);
+    let b = graph.add_node(2);
+    graph.add_edge((), a, b);
+
+    let (all, none, crossing) = graph.clone().partition_nodes(|_| true);
+    assert_eq!((all.len_nodes(), all.len_edges()), (2, 1));
+    assert!(none.is_empty());
+    assert!(crossing.is_empty());
+
+    let (none, all, crossing) = graph.partition_nodes(|_| false);
+    assert!(none.is_empty());
+    assert_eq!((all.len_nodes(), all.len_edges()), (2, 1));
+    assert!(crossing.is_empty());
+}
+
+/// Opens a scope on whatever graph type it is given, such as `&mut VecGraph`
+fn add_in_scope<G: GraphUpdate<Node = i32, Edge = ()> + gotgraph::graph::NotAContext>(
+    mut graph: G,
+    value: i32,
+) {
+    graph.scope_mut(|mut ctx| {
+        ctx.add_node(value);
+    });
+}
+
+#[test]
+fn test_scope_through_references() {
+    let mut graph: VecGraph<i32, ()> = VecGraph::default();
+
+    add_in_scope(&mut graph, 1);
+    add_in_scope(&mut &mut graph, 2);
+    assert_eq!(graph.len_nodes(), 2);
+
+    let shared = &graph;
+    let total = Graph::scope(&shared, |ctx| {
+        ctx.node_indices().map(|n| *ctx.node(n)).sum::<i32>()
+    });
+    assert_eq!(total, 3);
+
+    // Separate scopes one after another are fine
+    graph.scope_mut(|mut ctx| {
+        ctx.add_node(4);
+    });
+    graph.scope(|ctx| assert_eq!(ctx.len_nodes(), 3));
+}
+
+#[test]
+fn test_add_with_receives_own_index() {
+    let mut graph: VecGraph<String, String> = VecGraph::default();
+    let a = graph.add_node_with(|ix| format!("{:?}", ix));
+    let b = graph.add_node_with(|ix| format!("{:?}", ix));
+    let e = graph.add_edge_with(a, b, |ix| format!("{:?}", ix));
+    assert_eq!(*graph.node(a), format!("{:?}", a));
+    assert_eq!(*graph.node(b), format!("{:?}", b));
+    assert_eq!(*graph.edge(e), format!("{:?}", e));
+
+    graph.scope_mut(|mut ctx| {
+        let nodes: Vec<_> = (0..3)
+            .map(|_| ctx.add_node_with(|tag| format!("{:?}", tag.inner())))
+            .collect();
+        let edges: Vec<_> = nodes
+            .windows(2)
+            .map(|w| ctx.add_edge_with(w[0], w[1], |tag| format!("{:?}", tag.inner())))
+            .collect();
+
+        for n in nodes {
+            assert_eq!(*ctx.node(n), format!("{:?}", n.inner()));
+        }
+        for e in edges {
+            assert_eq!(*ctx.edge(e), format!("{:?}", e.inner()));
+        }
+    });
+    assert_eq!(graph.len_nodes(), 5);
+    assert_eq!(graph.len_edges(), 3);
+}
+
+#[test]
+#[should_panic]
+fn test_add_edge_with_invalid_node() {
+    let mut graph: VecGraph<i32, i32> = VecGraph::default();
+    let a = graph.add_node(0);
+    graph.remove_node(a);
+    graph.add_edge_with(a, a, |_| unreachable!());
+}
+
+#[test]
+fn test_subscope_read_only_analysis() {
+    use gotgraph::algo::weakly_connected_components;
+
+    let mut graph: VecGraph<i32, ()> = VecGraph::default();
+    graph.scope_mut(|mut ctx| {
+        let a = ctx.add_node(1);
+        let b = ctx.add_node(2);
+        let c = ctx.add_node(3);
+        ctx.add_edge((), a, b);
+
+        let (components, total) = ctx.subscope(|sub| {
+            // Scratch mapping private to the subscope
+            let doubled = sub.init_node_map(|_, &v| v * 2);
+            let total: i32 = sub.node_indices().map(|n| doubled[n]).sum();
+            (weakly_connected_components(sub).count(), total)
+        });
+        assert_eq!(components, 2);
+        assert_eq!(total, 12);
+
+        // The outer scope can still mutate and use its tags afterwards
+        ctx.add_edge((), b, c);
+        assert_eq!(
+            ctx.subscope(|sub| weakly_connected_components(sub).count()),
+            1
+        );
+        assert_eq!(*ctx.node(c), 3);
+    });
+
+    let degrees = graph.scope(|ctx| {
+        ctx.subscope(|sub| {
+            let mut degrees: Vec<_> = sub
+                .node_indices()
+                .map(|n| sub.outgoing_edge_indices(n).count())
+                .collect();
+            degrees.sort();
+            degrees
+        })
+    });
+    assert_eq!(degrees, vec![0, 1, 1]);
+}
+
+#[test]
+fn test_extend_with_edges() {
+    let mut graph: VecGraph<&str, u32> = VecGraph::default();
+    let a = graph.add_node("A");
+    let b = graph.add_node("B");
+    let c = graph.add_node("C");
+    graph.add_edge(0, a, a);
+
+    graph.extend(vec![(a, b, 1), (b, c, 2), (c, a, 3), (a, c, 4)]);
+
+    assert_eq!(graph.len_nodes(), 3);
+    assert_eq!(graph.len_edges(), 5);
+    let edges: Vec<_> = graph
+        .edge_indices()
+        .map(|e| (*graph.edge(e), graph.endpoints(e)))
+        .collect();
+    assert_eq!(
+        edges,
+        vec![
+            (0, [a, a]),
+            (1, [a, b]),
+            (2, [b, c]),
+            (3, [c, a]),
+            (4, [a, c]),
+        ]
+    );
+    assert_eq!(graph.outgoing_edge_indices(a).count(), 3);
+    assert_eq!(graph.incoming_edge_indices(c).count(), 2);
+}
+
+#[test]
+#[should_panic]
+fn test_extend_with_invalid_endpoint() {
+    let mut graph: VecGraph<(), ()> = VecGraph::default();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    graph.remove_node(b);
+    graph.extend([(a, b, ())]);
+}
+
+#[test]
+fn test_add_nodes_returns_consecutive_indices() {
+    use gotgraph::vec_graph::NodeIx;
+
+    let mut graph: VecGraph<usize, ()> = VecGraph::default();
+    let first = graph.add_node(0);
+    let nodes = graph.add_nodes(1..=5);
+    assert_eq!(nodes.len(), 5);
+    for (i, &node) in nodes.iter().enumerate() {
+        assert_eq!(node.index(), first.index() + 1 + i);
+        assert_eq!(*graph.node(node), i + 1);
+    }
+
+    // Iterators without an exact size hint work too
+    let more = graph.add_nodes((6..100).filter(|i| i % 10 == 0));
+    let indices: Vec<_> = more.iter().map(|ix| ix.index()).collect();
+    assert_eq!(indices, (6..15).collect::<Vec<_>>());
+
+    let range = graph.add_nodes_range(vec![100, 101, 102]);
+    assert_eq!(range, 15..18);
+    for raw in range {
+        let node = NodeIx::try_from(raw as usize).unwrap();
+        assert_eq!(*graph.node(node), 85 + raw as usize);
+    }
+    assert_eq!(graph.add_nodes_range(std::iter::empty()), 18..18);
+    assert_eq!(graph.len_nodes(), 18);
+
+    graph.scope_mut(|mut ctx| {
+        let tags = ctx.add_nodes([200, 201]);
+        ctx.add_edge((), tags[0], tags[1]);
+        assert_eq!(*ctx.node(tags[1]), 201);
+    });
+    assert_eq!(graph.len_nodes(), 20);
+}
+
+#[test]
+fn test_remove_node_owning_last_edge() {
+    let mut graph: VecGraph<&str, u32> = VecGraph::default();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let c = graph.add_node("c");
+    let bc = graph.add_edge(0, b, c);
+    graph.add_edge(1, a, b);
+    graph.add_edge(2, a, c);
+
+    // Edge 2 moves into slot 0, so the outgoing list of `a` now starts with the
+    // lowest index and ends with the last one
+    graph.remove_edge(bc);
+    assert_eq!(graph.remove_node(a), "a");
+    assert_eq!(graph.len_nodes(), 2);
+    assert_eq!(graph.len_edges(), 0);
+}
+
+#[test]
+fn test_filter_map_nodes() {
+    let mut graph: VecGraph<i32, &str> = VecGraph::default();
+    let n: Vec<_> = [1, -1, 2, -2, 3]
+        .iter()
+        .map(|&v| graph.add_node(v))
+        .collect();
+    graph.add_edge("1->2", n[0], n[2]);
+    graph.add_edge("1->-1", n[0], n[1]);
+    graph.add_edge("3->3", n[4], n[4]);
+    graph.add_edge("-2->3", n[3], n[4]);
+    graph.add_edge("3->1", n[4], n[0]);
+
+    let mut seen = Vec::new();
+    let filtered = graph.filter_map_nodes(|ix, v| {
+        seen.push(ix);
+        (v > 0).then(|| v * 10)
+    });
+    assert_eq!(seen, n);
+
+    assert_eq!(
+        filtered.nodes().copied().collect::<Vec<_>>(),
+        vec![10, 20, 30]
+    );
+    assert_eq!(
+        filtered.edges().copied().collect::<Vec<_>>(),
+        vec!["1->2", "3->3", "3->1"]
+    );
+
+    // Surviving edges point to the renumbered nodes
+    let endpoints: Vec<_> = filtered
+        .edge_pairs()
+        .map(|(ix, &e)| {
+            let [from, to] = filtered.endpoints(ix);
+            (e, from.index(), to.index())
+        })
+        .collect();
+    assert_eq!(
+        endpoints,
+        vec![("1->2", 0, 1), ("3->3", 2, 2), ("3->1", 2, 0)]
+    );
+    for node in filtered.node_indices() {
+        for e in filtered.outgoing_edge_indices(node) {
+            assert_eq!(filtered.endpoints(e)[0], node);
+        }
+        for e in filtered.incoming_edge_indices(node) {
+            assert_eq!(filtered.endpoints(e)[1], node);
+        }
+    }
+
+    let empty = filtered.filter_map_nodes(|_, _| None::<()>);
+    assert!(empty.is_empty());
+    assert_eq!(empty.len_edges(), 0);
+}
+
+#[test]
+fn test_filter_edges_by_payload() {
+    let mut graph: VecGraph<&str, &str> = VecGraph::default();
+    let n: Vec<_> = ["A", "B", "C", "D"]
+        .iter()
+        .map(|&v| graph.add_node(v))
+        .collect();
+    graph.add_edge("keep", n[0], n[1]);
+    graph.add_edge("drop", n[1], n[2]);
+    graph.add_edge("keep", n[2], n[2]);
+    graph.add_edge("drop", n[3], n[0]);
+    graph.add_edge("keep", n[3], n[1]);
+    graph.add_edge("drop", n[0], n[1]);
+
+    let mut seen = Vec::new();
+    let filtered = graph.filter_edges(|ix, &e| {
+        seen.push(ix.index());
+        e != "drop"
+    });
+    assert_eq!(seen, vec![0, 1, 2, 3, 4, 5]);
+
+    // Every node remains at its index, including the ones left without edges
+    assert_eq!(
+        filtered.nodes().copied().collect::<Vec<_>>(),
+        vec!["A", "B", "C", "D"]
+    );
+    assert_eq!(filtered.len_edges(), 3);
+    assert!(filtered.edges().all(|&e| e == "keep"));
+
+    let endpoints: Vec<_> = filtered
+        .edge_indices()
+        .map(|ix| {
+            let [from, to] = filtered.endpoints(ix);
+            (from.index(), to.index())
+        })
+        .collect();
+    assert_eq!(endpoints, vec![(0, 1), (2, 2), (3, 1)]);
+
+    let neighbors = |node, incoming: bool| {
+        let mut out: Vec<_> = if incoming {
+            filtered
+                .incoming_edge_indices(n[node])
+                .map(|e| filtered.endpoints(e)[0].index())
+                .collect()
+        } else {
+            filtered
+                .outgoing_edge_indices(n[node])
+                .map(|e| filtered.endpoints(e)[1].index())
+                .collect()
+        };
+        out.sort();
+        out
+    };
+    assert_eq!(neighbors(0, false), vec![1]);
+    assert_eq!(neighbors(0, true), Vec::<usize>::new());
+    assert_eq!(neighbors(1, false), Vec::<usize>::new());
+    assert_eq!(neighbors(1, true), vec![0, 3]);
+    assert_eq!(neighbors(2, false), vec![2]);
+    assert_eq!(neighbors(2, true), vec![2]);
+    assert_eq!(neighbors(3, false), vec![1]);
+    assert_eq!(neighbors(3, true), Vec::<usize>::new());
+}
+
+#[test]
+fn test_batch_remove_unlinks_edges_of_remaining_nodes() {
+    let mut graph: VecGraph<&str, &str> = VecGraph::default();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let c = graph.add_node("c");
+    let d = graph.add_node("d");
+    graph.add_edge("a-b", a, b);
+    graph.add_edge("b-c", b, c);
+    graph.add_edge("c-a", c, a);
+    graph.add_edge("a-d", a, d);
+    graph.add_edge("d-d", d, d);
+
+    let (nodes, mut edges): (Vec<_>, Vec<_>) =
+        unsafe { graph.remove_nodes_edges_unchecked([b], []) };
+    edges.sort();
+    assert_eq!(nodes, vec!["b"]);
+    assert_eq!(edges, vec!["a-b", "b-c"]);
+
+    let mut seen = Vec::new();
+    for node in graph.node_indices() {
+        for e in graph.outgoing_edge_indices(node) {
+            assert_eq!(graph.endpoints(e)[0], node);
+            seen.push(*graph.edge(e));
+        }
+        for e in graph.incoming_edge_indices(node) {
+            assert_eq!(graph.endpoints(e)[1], node);
+        }
+    }
+    seen.sort();
+    assert_eq!(seen, vec!["a-d", "c-a", "d-d"]);
+}
+
+#[test]
+fn test_clear_without_edges() {
+    let mut graph: VecGraph<i32, ()> = VecGraph::default();
+    graph.add_node(1);
+    graph.add_node(2);
+    graph.clear();
+    assert!(graph.is_empty());
+}
+
+#[test]
+fn test_eq_ignores_adjacency_order() {
+    let mut graph: VecGraph<&str, &str> = VecGraph::default();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let c = graph.add_node("c");
+    graph.add_edge("a-b", a, b);
+    graph.add_edge("a-c", a, c);
+
+    // Same nodes and edges at the same indices, but the outgoing list of `a` is linked
+    // in a different order
+    let mut other: VecGraph<&str, &str> = VecGraph::default();
+    let a2 = other.add_node("a");
+    let b2 = other.add_node("b");
+    let c2 = other.add_node("c");
+    let tmp = other.add_edge("tmp", a2, b2);
+    other.add_edge("a-c", a2, c2);
+    other.add_edge("a-b", a2, b2);
+    other.remove_edge(tmp);
+
+    assert_eq!(graph, other);
+    assert_ne!(
+        graph.outgoing_edge_indices(a).collect::<Vec<_>>(),
+        other.outgoing_edge_indices(a2).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_eq_detects_differences() {
+    let mut graph: VecGraph<i32, ()> = VecGraph::default();
+    let a = graph.add_node(1);
+    let b = graph.add_node(2);
+    graph.add_edge((), a, b);
+    assert_eq!(graph, graph.clone());
+    assert_eq!(VecGraph::<i32, ()>::default(), VecGraph::default());
+
+    let mut reversed: VecGraph<i32, ()> = VecGraph::default();
+    let a2 = reversed.add_node(1);
+    let b2 = reversed.add_node(2);
+    reversed.add_edge((), b2, a2);
+    assert_ne!(graph, reversed);
+
+    let mut payload = graph.clone();
+    *payload.node_mut(a) = 3;
+    assert_ne!(graph, payload);
+
+    let mut extra = graph.clone();
+    extra.add_node(3);
+    assert_ne!(graph, extra);
+
+    // Renumbered but isomorphic graphs are not equal
+    let mut swapped: VecGraph<i32, ()> = VecGraph::default();
+    let b3 = swapped.add_node(2);
+    let a3 = swapped.add_node(1);
+    swapped.add_edge((), a3, b3);
+    assert_ne!(graph, swapped);
+}
+
+#[test]
+fn test_remove_node_owning_last_incoming_edge() {
+    let mut graph: VecGraph<&str, u32> = VecGraph::default();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let c = graph.add_node("c");
+    graph.add_edge(0, c, b);
+    let bc = graph.add_edge(1, b, c);
+    graph.add_edge(2, c, a);
+    graph.add_edge(3, b, a);
+
+    // Edge 3 moves into slot 1, so the incoming list of `a` starts with the lowest index
+    // and ends with the last one, which moves again when the first is removed
+    graph.remove_edge(bc);
+    assert_eq!(graph.remove_node(a), "a");
+    assert_eq!(graph.len_nodes(), 2);
+    assert_eq!(graph.edges().collect::<Vec<_>>(), vec![&0]);
+}
@@ -0,0 +1,123 @@
+use gotgraph::algo::{disjoint_set, tarjan, weakly_connected_components};
+use gotgraph::prelude::*;
+use gotgraph::undirected::UnVecGraph;
+use gotgraph::vec_graph::NodeIx;
+
+fn payloads(graph: &VecGraph<usize, ()>, components: &[Box<[NodeIx]>]) -> Vec<Vec<usize>> {
+    components
+        .iter()
+        .map(|c| c.iter().map(|&ix| *graph.node(ix)).collect())
+        .collect()
+}
+
+#[test]
+fn test_components_of_different_sizes() {
+    let mut graph: VecGraph<usize, ()> = VecGraph::default();
+    let n: Vec<_> = (0..9).map(|i| graph.add_node(i)).collect();
+    // {0, 3, 5, 8} connected through edges in both directions
+    graph.add_edge((), n[0], n[3]);
+    graph.add_edge((), n[5], n[3]);
+    graph.add_edge((), n[8], n[5]);
+    // {1, 2}
+    graph.add_edge((), n[2], n[1]);
+    // {4, 6, 7}
+    graph.add_edge((), n[4], n[6]);
+    graph.add_edge((), n[7], n[6]);
+    graph.add_edge((), n[6], n[4]);
+
+    let components: Vec<_> = weakly_connected_components(&graph).collect();
+    assert_eq!(
+        payloads(&graph, &components),
+        vec![vec![0, 3, 5, 8], vec![1, 2], vec![4, 6, 7]]
+    );
+}
+
+#[test]
+fn test_isolated_nodes_and_self_loops() {
+    let mut graph: VecGraph<usize, ()> = VecGraph::default();
+    let n: Vec<_> = (0..5).map(|i| graph.add_node(i)).collect();
+    graph.add_edge((), n[1], n[1]);
+    graph.add_edge((), n[3], n[3]);
+    graph.add_edge((), n[3], n[4]);
+    graph.add_edge((), n[3], n[4]);
+
+    let components: Vec<_> = weakly_connected_components(&graph).collect();
+    assert_eq!(
+        payloads(&graph, &components),
+        vec![vec![0], vec![1], vec![2], vec![3, 4]]
+    );
+
+    let empty: VecGraph<usize, ()> = VecGraph::default();
+    assert_eq!(weakly_connected_components(&empty).count(), 0);
+}
+
+#[test]
+fn test_matches_undirected_adapter() {
+    for seed in 0..5u64 {
+        let mut state = seed;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) as usize
+        };
+
+        let mut graph: VecGraph<usize, ()> = VecGraph::default();
+        let n: Vec<_> = (0..40).map(|i| graph.add_node(i)).collect();
+        for _ in 0..30 {
+            graph.add_edge((), n[next() % 40], n[next() % 40]);
+        }
+
+        let mut ours: Vec<Vec<NodeIx>> = weakly_connected_components(&graph)
+            .map(|c| c.to_vec())
+            .collect();
+        let undirected = UnVecGraph::from(graph.clone());
+        let mut theirs: Vec<Vec<NodeIx>> = tarjan(&undirected)
+            .map(|c| {
+                let mut c = c.to_vec();
+                c.sort();
+                c
+            })
+            .collect();
+        theirs.sort();
+
+        // Already sorted internally and by smallest index
+        assert!(ours.iter().all(|c| c.windows(2).all(|w| w[0] < w[1])));
+        assert!(ours.windows(2).all(|w| w[0][0] < w[1][0]));
+        ours.sort();
+        assert_eq!(ours, theirs);
+    }
+}
+
+#[test]
+fn test_disjoint_set() {
+    let mut graph: VecGraph<usize, ()> = VecGraph::default();
+    let n: Vec<_> = (0..6).map(|i| graph.add_node(i)).collect();
+    let mut sets = disjoint_set(&graph);
+
+    for &node in &n {
+        assert_eq!(sets.find(node), node);
+    }
+    assert!(sets.union(n[0], n[1]));
+    assert!(sets.union(n[2], n[3]));
+    assert!(sets.union(n[1], n[3]));
+    assert!(!sets.union(n[0], n[2]));
+    assert!(sets.same_set(n[0], n[3]));
+    assert!(!sets.same_set(n[0], n[4]));
+    assert_eq!(sets.find(n[1]), sets.find(n[2]));
+    assert_ne!(sets.find(n[4]), sets.find(n[5]));
+}
+
+#[test]
+fn test_disjoint_set_in_scope() {
+    let mut graph: VecGraph<usize, ()> = VecGraph::default();
+    graph.scope_mut(|mut ctx| {
+        let a = ctx.add_node(0);
+        let b = ctx.add_node(1);
+        let c = ctx.add_node(2);
+        let mut sets = disjoint_set(&ctx);
+        sets.union(a, c);
+        assert!(sets.same_set(c, a));
+        assert!(!sets.same_set(a, b));
+    });
+}
@@ -0,0 +1,88 @@
+use gotgraph::io::{from_edge_list_str, to_edge_list_str};
+use gotgraph::prelude::*;
+use gotgraph::vec_graph::ParseError;
+
+fn triples(graph: &VecGraph<u32, f64>) -> Vec<(u32, u32, f64)> {
+    graph
+        .edge_pairs()
+        .map(|(ix, &weight)| {
+            let [from, to] = graph.endpoints(ix);
+            (*graph.node(from), *graph.node(to), weight)
+        })
+        .collect()
+}
+
+#[test]
+fn test_parse_with_comments_and_blank_lines() {
+    let input =
+        "# from,to,weight\n\n0,1,1.5\n  2 , 0 ,-3  \n# comment\n\n1,1,0.25 # loop\n4,2,1e3\n";
+    let graph = from_edge_list_str(input).unwrap();
+
+    assert_eq!(
+        graph.nodes().copied().collect::<Vec<_>>(),
+        vec![0, 1, 2, 3, 4]
+    );
+    assert_eq!(
+        triples(&graph),
+        vec![(0, 1, 1.5), (2, 0, -3.0), (1, 1, 0.25), (4, 2, 1000.0)]
+    );
+}
+
+#[test]
+fn test_round_trip() {
+    let mut graph: VecGraph<u32, f64> = VecGraph::default();
+    let n: Vec<_> = (0..4).map(|i| graph.add_node(i)).collect();
+    graph.add_edge(0.1, n[0], n[3]);
+    graph.add_edge(f64::MAX, n[3], n[1]);
+    graph.add_edge(-1.0 / 3.0, n[2], n[2]);
+    graph.add_edge(0.1, n[0], n[3]);
+
+    let text = to_edge_list_str(&graph);
+    assert_eq!(text.lines().count(), 4);
+    let parsed = from_edge_list_str(&text).unwrap();
+    assert_eq!(triples(&parsed), triples(&graph));
+    assert_eq!(to_edge_list_str(&parsed), text);
+
+    let empty = from_edge_list_str("").unwrap();
+    assert!(empty.is_empty());
+    assert_eq!(to_edge_list_str(&empty), "");
+}
+
+#[test]
+fn test_bad_row_reports_line() {
+    let input = "# header\n0,1,1\n\n1,2\n";
+    assert_eq!(
+        from_edge_list_str(input).unwrap_err(),
+        ParseError::FieldCount { line: 4, fields: 2 }
+    );
+
+    let input = "0,1,1\n1,2,3,4\n";
+    assert_eq!(
+        from_edge_list_str(input).unwrap_err(),
+        ParseError::FieldCount { line: 2, fields: 4 }
+    );
+
+    let input = "0,1,1\n# ok\n1,two,3\n";
+    let err = from_edge_list_str(input).unwrap_err();
+    assert_eq!(
+        err,
+        ParseError::InvalidIndex {
+            line: 3,
+            token: "two".to_string()
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        r#"line 3: "two" is not a valid node index"#
+    );
+
+    let err = from_edge_list_str("0,1,\n").unwrap_err();
+    assert_eq!(
+        err,
+        ParseError::InvalidWeight {
+            line: 1,
+            token: String::new()
+        }
+    );
+    assert!(from_edge_list_str("-1,0,1\n").is_err());
+}